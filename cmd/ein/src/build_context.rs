@@ -0,0 +1,225 @@
+use super::{
+    compile_configuration::COMPILE_CONFIGURATION, file_path_configuration::FILE_PATH_CONFIGURATION,
+    jobs::default_jobs, package_directory::find_package_directory,
+    system_package_configuration::SYSTEM_PACKAGE_CONFIGURATION,
+};
+use std::sync::Arc;
+
+pub struct BuildOptions<'a> {
+    pub jobs: usize,
+    pub profile_name: &'a str,
+    pub emissions: &'a [app::Emission],
+    pub target: Option<&'a str>,
+}
+
+impl<'a> Default for BuildOptions<'a> {
+    fn default() -> Self {
+        Self {
+            jobs: default_jobs(),
+            profile_name: app::RELEASE_PROFILE_NAME,
+            emissions: &[],
+            target: None,
+        }
+    }
+}
+
+// Services shared by commands which build packages. Linkers and runners are
+// chosen by a target.
+pub struct BuildContext<'a> {
+    pub logger: &'a infra::Logger,
+    pub file_path_converter: &'a infra::FilePathConverter,
+    pub file_system: &'a infra::FileSystem<'a>,
+    pub file_path_displayer: &'a infra::FilePathDisplayer<'a>,
+    pub file_path_resolver: &'a app::FilePathResolver<'a>,
+    pub static_file_path_manager: &'a app::StaticFilePathManager,
+    pub compile_configuration: Arc<lang::CompileConfiguration>,
+    pub package_configuration_reader: &'a app::PackageConfigurationReader<'a>,
+    pub module_parser: &'a app::ModuleParser<'a>,
+    pub module_compiler: &'a app::ModuleCompiler<'a>,
+    pub modules_builder: &'a app::ModulesBuilder<'a>,
+    pub object_cache_manager: &'a app::ObjectCacheManager<'a>,
+    pub application_linker: &'a dyn app::ApplicationLinker,
+    pub application_runner: &'a dyn app::ApplicationRunner,
+    pub dependency_interfaces_builder: &'a app::DependencyInterfacesBuilder<'a>,
+    pub main_package_builder: &'a app::MainPackageBuilder<'a>,
+}
+
+pub fn with_build_context<T>(
+    options: &BuildOptions,
+    callback: impl FnOnce(&BuildContext) -> Result<T, Box<dyn std::error::Error>>,
+) -> Result<T, Box<dyn std::error::Error>> {
+    let package_directory = find_package_directory()?;
+
+    let logger = infra::Logger::new();
+
+    let file_path_converter = infra::FilePathConverter::new(package_directory);
+    let file_system = infra::FileSystem::new(&file_path_converter);
+    let static_file_path_manager = app::StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
+    let file_path_resolver =
+        app::FilePathResolver::new(&static_file_path_manager, &FILE_PATH_CONFIGURATION);
+    let file_path_displayer = infra::FilePathDisplayer::new(&file_path_converter);
+
+    let package_configuration_reader = app::PackageConfigurationReader::new(
+        &file_system,
+        &file_path_displayer,
+        &static_file_path_manager,
+    );
+    let profile =
+        app::ProfileReader::new(&package_configuration_reader).read(options.profile_name)?;
+    let toolchain_configuration =
+        app::ToolchainConfigurationReader::new(&package_configuration_reader).read()?;
+
+    let compile_configuration = Arc::new(lang::CompileConfiguration {
+        target_triple: options.target.map(String::from),
+        ..COMPILE_CONFIGURATION.as_ref().clone()
+    });
+
+    let command_runner = infra::CommandRunner::new();
+    let module_parser = app::ModuleParser::new(&file_path_displayer);
+    let toolchain = infra::Toolchain::new(
+        &command_runner,
+        &file_path_converter,
+        &toolchain_configuration,
+    );
+    let bit_code_converter =
+        infra::BitCodeConverter::new(&command_runner, &file_path_converter, &toolchain, &profile);
+    let module_emitter = app::ModuleEmitter::new(
+        &file_system,
+        &bit_code_converter,
+        &static_file_path_manager,
+        options.emissions,
+    );
+    let object_cache_manager = app::ObjectCacheManager::new(
+        &file_system,
+        &static_file_path_manager,
+        options.profile_name,
+        options.target,
+    );
+    let module_compiler = app::ModuleCompiler::new(
+        &module_parser,
+        &file_path_resolver,
+        &file_system,
+        &object_cache_manager,
+        &module_emitter,
+        &logger,
+        compile_configuration.clone(),
+        &profile,
+        &FILE_PATH_CONFIGURATION,
+    );
+    let modules_finder = app::ModulesFinder::new(&file_system, &FILE_PATH_CONFIGURATION);
+    let modules_builder = app::ModulesBuilder::new(
+        &module_parser,
+        &module_compiler,
+        &modules_finder,
+        &file_system,
+        &file_path_resolver,
+        &logger,
+        options.jobs,
+    );
+
+    let ffi_package_initializer =
+        infra::FfiPackageInitializer::new(&command_runner, &file_path_converter, options.target);
+    let package_builder = app::PackageBuilder::new(
+        &modules_builder,
+        &ffi_package_initializer,
+        &file_system,
+        &logger,
+    );
+
+    let root_directory_string = std::env::var("EIN_ROOT")?;
+    let root_directory = std::path::Path::new(&root_directory_string);
+
+    let prelude_package_downloader = infra::PreludePackageDownloader::new(
+        &command_runner,
+        &file_path_converter,
+        root_directory.join("lib/prelude"),
+    );
+    let prelude_package_builder = app::PreludePackageBuilder::new(
+        &package_configuration_reader,
+        &package_builder,
+        &prelude_package_downloader,
+        &static_file_path_manager,
+    );
+    let application_linker = infra::ApplicationLinker::new(
+        &command_runner,
+        &file_path_converter,
+        &toolchain,
+        options.jobs,
+        &profile,
+        static_file_path_manager.lto_directory_path(),
+    );
+    let wasm_application_linker = infra::WasmApplicationLinker::new(
+        &command_runner,
+        &file_path_converter,
+        &toolchain,
+        options.jobs,
+        &profile,
+    );
+    let application_runner = infra::ApplicationRunner::new(&file_path_converter);
+    let wasm_application_runner = infra::WasmApplicationRunner::new(&file_path_converter);
+    let (application_linker, application_runner): (
+        &dyn app::ApplicationLinker,
+        &dyn app::ApplicationRunner,
+    ) = if options.target.is_some() {
+        (&wasm_application_linker, &wasm_application_runner)
+    } else {
+        (&application_linker, &application_runner)
+    };
+
+    let external_package_downloader = infra::ExternalPackageDownloader::new(&file_path_converter);
+    let package_lock_manager =
+        app::PackageLockManager::new(&file_system, &static_file_path_manager);
+    let cached_external_package_downloader = app::CachedExternalPackageDownloader::new(
+        &package_configuration_reader,
+        &package_lock_manager,
+        &external_package_downloader,
+        &file_system,
+        &file_path_resolver,
+        &logger,
+    );
+    let external_packages_downloader = app::ExternalPackagesDownloader::new(
+        &cached_external_package_downloader,
+        &package_lock_manager,
+        &SYSTEM_PACKAGE_CONFIGURATION,
+    );
+    let external_packages_builder = app::ExternalPackagesBuilder::new(&package_builder);
+    let system_package_builder =
+        app::SystemPackageBuilder::new(&package_builder, &cached_external_package_downloader);
+    let dependency_interfaces_builder = app::DependencyInterfacesBuilder::new(
+        &prelude_package_builder,
+        &system_package_builder,
+        &external_packages_downloader,
+        &external_packages_builder,
+    );
+    let main_package_builder = app::MainPackageBuilder::new(
+        &package_configuration_reader,
+        &package_builder,
+        application_linker,
+        &prelude_package_builder,
+        &system_package_builder,
+        &external_packages_downloader,
+        &external_packages_builder,
+        &object_cache_manager,
+        &logger,
+        &SYSTEM_PACKAGE_CONFIGURATION,
+    );
+
+    callback(&BuildContext {
+        logger: &logger,
+        file_path_converter: &file_path_converter,
+        file_system: &file_system,
+        file_path_displayer: &file_path_displayer,
+        file_path_resolver: &file_path_resolver,
+        static_file_path_manager: &static_file_path_manager,
+        compile_configuration,
+        package_configuration_reader: &package_configuration_reader,
+        module_parser: &module_parser,
+        module_compiler: &module_compiler,
+        modules_builder: &modules_builder,
+        object_cache_manager: &object_cache_manager,
+        application_linker,
+        application_runner,
+        dependency_interfaces_builder: &dependency_interfaces_builder,
+        main_package_builder: &main_package_builder,
+    })
+}
@@ -0,0 +1,13 @@
+use super::build_context::{with_build_context, BuildOptions};
+
+pub fn check() -> Result<(), Box<dyn std::error::Error>> {
+    with_build_context(&BuildOptions::default(), |context| {
+        app::PackageChecker::new(
+            context.package_configuration_reader,
+            context.dependency_interfaces_builder,
+            context.modules_builder,
+            context.logger,
+        )
+        .check()
+    })
+}
@@ -0,0 +1,15 @@
+use super::{
+    file_path_configuration::FILE_PATH_CONFIGURATION, package_directory::find_package_directory,
+};
+
+pub fn clean() -> Result<(), Box<dyn std::error::Error>> {
+    let package_directory = find_package_directory()?;
+
+    let logger = infra::Logger::new();
+
+    let file_path_converter = infra::FilePathConverter::new(package_directory);
+    let file_system = infra::FileSystem::new(&file_path_converter);
+    let static_file_path_manager = app::StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
+
+    app::PackageCleaner::new(&file_system, &logger, &static_file_path_manager).clean()
+}
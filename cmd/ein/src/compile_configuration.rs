@@ -6,6 +6,12 @@ pub static COMPILE_CONFIGURATION: Lazy<Arc<lang::CompileConfiguration>> = Lazy::
         malloc_function_name: "_ein_malloc".into(),
         realloc_function_name: "_ein_realloc".into(),
         free_function_name: "_ein_free".into(),
+        array_type_configuration: lang::ArrayTypeConfiguration {
+            array_type_name: "_Array".into(),
+            empty_array_variable_name: "_emptyArray".into(),
+            push_function_name: "_pushToArray".into(),
+        }
+        .into(),
         list_type_configuration: lang::ListTypeConfiguration {
             empty_list_variable_name: "_emptyList".into(),
             concatenate_function_name: "_concatenateLists".into(),
@@ -14,11 +20,21 @@ pub static COMPILE_CONFIGURATION: Lazy<Arc<lang::CompileConfiguration>> = Lazy::
             deconstruct_function_name: "_firstRest".into(),
             first_function_name: "_first".into(),
             rest_function_name: "_rest".into(),
-            list_type_name: "_AnyList".into(),
+            list_type_name: "_List".into(),
             first_rest_type_name: "_FirstRest".into(),
             map_function_name: "_mapList".into(),
         }
         .into(),
+        map_type_configuration: lang::MapTypeConfiguration {
+            map_type_name: "_Map".into(),
+            empty_map_function_name: "_emptyMap".into(),
+            insert_function_name: "_insertIntoMap".into(),
+            combine_hashes_function_name: "_combineHashes".into(),
+            hash_list_function_name: "_hashList".into(),
+            hash_number_function_name: "_hashNumber".into(),
+            hash_string_function_name: "_hashString".into(),
+        }
+        .into(),
         string_type_configuration: lang::StringTypeConfiguration {
             equal_function_name: "_equalStrings".into(),
         }
@@ -35,6 +51,9 @@ pub static COMPILE_CONFIGURATION: Lazy<Arc<lang::CompileConfiguration>> = Lazy::
             }
             .into(),
         ),
+        assertions: true,
+        debug_information: false,
+        target_triple: None,
     }
     .into()
 });
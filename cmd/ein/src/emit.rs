@@ -0,0 +1,29 @@
+pub fn emit_argument<'a, 'b>() -> clap::Arg<'a, 'b> {
+    clap::Arg::with_name("emit")
+        .long("emit")
+        .takes_value(true)
+        .use_delimiter(true)
+        .possible_values(
+            &app::Emission::ALL
+                .iter()
+                .map(app::Emission::name)
+                .collect::<Vec<_>>(),
+        )
+        .help("Emits intermediate representations of modules in a package")
+}
+
+pub fn parse_emissions(matches: &clap::ArgMatches) -> Vec<app::Emission> {
+    matches
+        .values_of("emit")
+        .map(|names| {
+            names
+                .filter_map(|name| {
+                    app::Emission::ALL
+                        .iter()
+                        .find(|emission| emission.name() == name)
+                        .copied()
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
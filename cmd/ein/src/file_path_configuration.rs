@@ -1,6 +1,8 @@
 pub static FILE_PATH_CONFIGURATION: app::FilePathConfiguration = app::FilePathConfiguration {
     build_configuration_filename: "ein.json",
+    package_lock_filename: "ein.lock",
     output_directory_name: ".ein",
     source_file_extension: "ein",
     main_file_basename: "Main",
+    test_module_suffix: "Test",
 };
@@ -0,0 +1,18 @@
+use super::{
+    file_path_configuration::FILE_PATH_CONFIGURATION, package_directory::find_package_directory,
+};
+
+pub fn format(check: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let package_directory = find_package_directory()?;
+
+    let file_path_converter = infra::FilePathConverter::new(package_directory);
+    let file_system = infra::FileSystem::new(&file_path_converter);
+    let file_path_displayer = infra::FilePathDisplayer::new(&file_path_converter);
+
+    let module_parser = app::ModuleParser::new(&file_path_displayer);
+    let modules_finder = app::ModulesFinder::new(&file_system, &FILE_PATH_CONFIGURATION);
+    let package_formatter =
+        app::PackageFormatter::new(&module_parser, &modules_finder, &file_system);
+
+    package_formatter.format(check)
+}
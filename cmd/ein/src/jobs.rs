@@ -0,0 +1,20 @@
+pub fn jobs_argument<'a, 'b>() -> clap::Arg<'a, 'b> {
+    clap::Arg::with_name("jobs")
+        .short("j")
+        .long("jobs")
+        .takes_value(true)
+        .help("Specifies a number of parallel jobs")
+}
+
+pub fn parse_jobs(matches: &clap::ArgMatches) -> Result<usize, Box<dyn std::error::Error>> {
+    Ok(match matches.value_of("jobs") {
+        Some(jobs) => jobs.parse()?,
+        None => default_jobs(),
+    })
+}
+
+pub fn default_jobs() -> usize {
+    std::thread::available_parallelism()
+        .map(usize::from)
+        .unwrap_or(1)
+}
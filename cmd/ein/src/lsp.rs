@@ -0,0 +1,31 @@
+use super::{
+    build_context::{with_build_context, BuildOptions},
+    file_path_configuration::FILE_PATH_CONFIGURATION,
+};
+
+pub fn lsp() -> Result<(), Box<dyn std::error::Error>> {
+    with_build_context(&BuildOptions::default(), |context| {
+        let file_uri_converter = infra::FileUriConverter::new(context.file_path_converter);
+        let message_transport = infra::StdioMessageTransport::new();
+        let module_analyzer = app::ModuleAnalyzer::new(
+            context.module_parser,
+            context.file_path_resolver,
+            context.file_system,
+            context.compile_configuration.clone(),
+            &FILE_PATH_CONFIGURATION,
+        );
+
+        app::LanguageServer::new(
+            &message_transport,
+            &file_uri_converter,
+            context.file_path_displayer,
+            context.module_parser,
+            &module_analyzer,
+            context.package_configuration_reader,
+            context.dependency_interfaces_builder,
+            context.file_path_resolver,
+            context.static_file_path_manager,
+        )
+        .run()
+    })
+}
@@ -1,25 +1,74 @@
 mod build;
+mod build_context;
+mod check;
+mod clean;
 mod compile_configuration;
+mod emit;
 mod file_path_configuration;
+mod format;
 mod init;
+mod jobs;
+mod lsp;
+mod package_directory;
 mod package_initialization_configuration;
+mod profile;
+mod run;
 mod system_package_configuration;
+mod target;
+mod test;
+mod test_configuration;
+mod update;
 
 use build::build;
+use build_context::BuildOptions;
+use check::check;
+use clean::clean;
+use emit::{emit_argument, parse_emissions};
+use format::format;
 use init::init;
+use jobs::{jobs_argument, parse_jobs};
+use lsp::lsp;
+use profile::{parse_profile_name, profile_arguments};
+use run::run;
+use target::{parse_target, target_argument};
+use test::test;
+use update::update;
 
 fn main() {
-    if let Err(error) = run() {
+    if let Err(error) = run_command() {
         infra::Logger::new().log_error(error.as_ref()).unwrap();
         std::process::exit(1);
     }
 }
 
-fn run() -> Result<(), Box<dyn std::error::Error>> {
+fn run_command() -> Result<(), Box<dyn std::error::Error>> {
     match clap::App::new("ein")
         .version("0.1.0")
         .setting(clap::AppSettings::SubcommandRequired)
-        .subcommand(clap::SubCommand::with_name("build").about("Builds a package"))
+        .subcommand(
+            clap::SubCommand::with_name("build")
+                .arg(jobs_argument())
+                .args(&profile_arguments())
+                .arg(emit_argument())
+                .arg(target_argument())
+                .about("Builds a package"),
+        )
+        .subcommand(
+            clap::SubCommand::with_name("check")
+                .about("Checks types in a package without generating code"),
+        )
+        .subcommand(
+            clap::SubCommand::with_name("clean").about("Removes caches of external packages"),
+        )
+        .subcommand(
+            clap::SubCommand::with_name("format")
+                .arg(
+                    clap::Arg::with_name("check")
+                        .long("check")
+                        .help("Checks if modules are formatted without modifying them"),
+                )
+                .about("Formats modules in a package"),
+        )
         .subcommand(
             clap::SubCommand::with_name("init")
                 .arg(
@@ -35,10 +84,48 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 )
                 .about("Initializes a package"),
         )
+        .subcommand(clap::SubCommand::with_name("lsp").about("Runs a language server over stdio"))
+        .subcommand(
+            clap::SubCommand::with_name("run")
+                .arg(jobs_argument())
+                .args(&profile_arguments())
+                .arg(target_argument())
+                .arg(
+                    clap::Arg::with_name("arguments")
+                        .multiple(true)
+                        .last(true)
+                        .help("Specifies arguments passed to an application"),
+                )
+                .about("Builds and runs an application"),
+        )
+        .subcommand(
+            clap::SubCommand::with_name("test")
+                .arg(jobs_argument())
+                .args(&profile_arguments())
+                .arg(target_argument())
+                .about("Runs tests in a package"),
+        )
+        .subcommand(
+            clap::SubCommand::with_name("update")
+                .arg(clap::Arg::with_name("package").help("Specifies a package to update"))
+                .about("Updates locked versions of external packages"),
+        )
         .get_matches()
         .subcommand()
     {
-        ("build", _) => build(),
+        ("build", matches) => {
+            let matches = matches.unwrap();
+
+            build(&BuildOptions {
+                jobs: parse_jobs(matches)?,
+                profile_name: parse_profile_name(matches),
+                emissions: &parse_emissions(matches),
+                target: parse_target(matches),
+            })
+        }
+        ("check", _) => check(),
+        ("clean", _) => clean(),
+        ("format", matches) => format(matches.unwrap().is_present("check")),
         ("init", matches) => {
             let matches = matches.unwrap();
 
@@ -47,6 +134,34 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
                 matches.is_present("lib"),
             )
         }
+        ("lsp", _) => lsp(),
+        ("run", matches) => {
+            let matches = matches.unwrap();
+
+            run(
+                &matches
+                    .values_of("arguments")
+                    .map(|values| values.map(String::from).collect::<Vec<_>>())
+                    .unwrap_or_default(),
+                &BuildOptions {
+                    jobs: parse_jobs(matches)?,
+                    profile_name: parse_profile_name(matches),
+                    target: parse_target(matches),
+                    ..Default::default()
+                },
+            )
+        }
+        ("test", matches) => {
+            let matches = matches.unwrap();
+
+            test(&BuildOptions {
+                jobs: parse_jobs(matches)?,
+                profile_name: parse_profile_name(matches),
+                target: parse_target(matches),
+                ..Default::default()
+            })
+        }
+        ("update", matches) => update(matches.unwrap().value_of("package")),
         _ => unreachable!(),
     }
 }
@@ -0,0 +1,22 @@
+use super::file_path_configuration::FILE_PATH_CONFIGURATION;
+
+pub fn find_package_directory() -> Result<std::path::PathBuf, Box<dyn std::error::Error>> {
+    let mut directory: &std::path::Path = &std::env::current_dir()?;
+
+    while !directory
+        .join(FILE_PATH_CONFIGURATION.build_configuration_filename)
+        .exists()
+    {
+        directory = directory.parent().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!(
+                    "file {} not found in any parent directory",
+                    FILE_PATH_CONFIGURATION.build_configuration_filename,
+                ),
+            )
+        })?
+    }
+
+    Ok(directory.into())
+}
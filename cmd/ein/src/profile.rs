@@ -0,0 +1,28 @@
+pub fn profile_arguments<'a, 'b>() -> Vec<clap::Arg<'a, 'b>> {
+    vec![
+        clap::Arg::with_name("profile")
+            .long("profile")
+            .takes_value(true)
+            .help("Specifies a build profile"),
+        clap::Arg::with_name("release")
+            .long("release")
+            .conflicts_with("profile")
+            .help("Builds with the release profile"),
+        clap::Arg::with_name("debug")
+            .long("debug")
+            .conflicts_with_all(&["profile", "release"])
+            .help("Builds with the debug profile"),
+    ]
+}
+
+pub fn parse_profile_name<'a>(matches: &'a clap::ArgMatches) -> &'a str {
+    if matches.is_present("release") {
+        app::RELEASE_PROFILE_NAME
+    } else if matches.is_present("debug") {
+        app::DEBUG_PROFILE_NAME
+    } else {
+        matches
+            .value_of("profile")
+            .unwrap_or(app::RELEASE_PROFILE_NAME)
+    }
+}
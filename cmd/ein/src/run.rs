@@ -0,0 +1,17 @@
+use super::build_context::{with_build_context, BuildOptions};
+
+pub fn run(arguments: &[String], options: &BuildOptions) -> Result<(), Box<dyn std::error::Error>> {
+    let status_code = with_build_context(options, |context| {
+        app::PackageRunner::new(
+            context.package_configuration_reader,
+            context.main_package_builder,
+            context.application_runner,
+        )
+        .run(arguments)
+    })?;
+
+    match status_code {
+        Some(0) => Ok(()),
+        status_code => std::process::exit(status_code.unwrap_or(1)),
+    }
+}
@@ -1,4 +1,7 @@
-pub static SYSTEM_PACKAGE_CONFIGURATION: app::SystemPackageConfiguration =
-    app::SystemPackageConfiguration {
+use once_cell::sync::Lazy;
+
+pub static SYSTEM_PACKAGE_CONFIGURATION: Lazy<app::SystemPackageConfiguration> =
+    Lazy::new(|| app::SystemPackageConfiguration {
         main_function_module_name: "MainFunction",
-    };
+        default_system_package: app::ExternalPackage::new("github.com/ein-lang/os", "main"),
+    });
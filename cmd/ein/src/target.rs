@@ -0,0 +1,13 @@
+pub const WASM32_WASI_TARGET: &str = "wasm32-wasi";
+
+pub fn target_argument<'a, 'b>() -> clap::Arg<'a, 'b> {
+    clap::Arg::with_name("target")
+        .long("target")
+        .takes_value(true)
+        .possible_values(&[WASM32_WASI_TARGET])
+        .help("Specifies a target triple")
+}
+
+pub fn parse_target<'a>(matches: &'a clap::ArgMatches) -> Option<&'a str> {
+    matches.value_of("target")
+}
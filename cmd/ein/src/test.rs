@@ -0,0 +1,24 @@
+use super::{
+    build_context::{with_build_context, BuildOptions},
+    file_path_configuration::FILE_PATH_CONFIGURATION,
+    test_configuration::TEST_CONFIGURATION,
+};
+
+pub fn test(options: &BuildOptions) -> Result<(), Box<dyn std::error::Error>> {
+    with_build_context(options, |context| {
+        app::TestRunner::new(
+            context.package_configuration_reader,
+            context.main_package_builder,
+            context.module_compiler,
+            context.application_linker,
+            context.application_runner,
+            context.object_cache_manager,
+            context.file_system,
+            context.logger,
+            context.static_file_path_manager,
+            &FILE_PATH_CONFIGURATION,
+            &TEST_CONFIGURATION,
+        )
+        .run()
+    })
+}
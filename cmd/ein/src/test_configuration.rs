@@ -0,0 +1,16 @@
+use once_cell::sync::Lazy;
+
+pub static TEST_CONFIGURATION: Lazy<app::TestConfiguration> =
+    Lazy::new(|| app::TestConfiguration {
+        test_name_prefix: "test".into(),
+        main_module_configuration: lang::MainModuleConfiguration {
+            source_main_function_name: "_runTests".into(),
+            object_main_function_name: "_ein_main".into(),
+            main_function_type_name: "MainFunction".into(),
+        }
+        .into(),
+        system_module_name: "Os".into(),
+        system_type_name: "Os".into(),
+        write_function_name: "fdWrite".into(),
+        stdout_variable_name: "stdout".into(),
+    });
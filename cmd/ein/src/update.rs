@@ -0,0 +1,48 @@
+use super::{
+    file_path_configuration::FILE_PATH_CONFIGURATION, package_directory::find_package_directory,
+    system_package_configuration::SYSTEM_PACKAGE_CONFIGURATION,
+};
+
+pub fn update(package_name: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+    let package_directory = find_package_directory()?;
+
+    let logger = infra::Logger::new();
+
+    let file_path_converter = infra::FilePathConverter::new(package_directory);
+    let file_system = infra::FileSystem::new(&file_path_converter);
+    let static_file_path_manager = app::StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
+    let file_path_resolver =
+        app::FilePathResolver::new(&static_file_path_manager, &FILE_PATH_CONFIGURATION);
+    let file_path_displayer = infra::FilePathDisplayer::new(&file_path_converter);
+
+    let package_configuration_reader = app::PackageConfigurationReader::new(
+        &file_system,
+        &file_path_displayer,
+        &static_file_path_manager,
+    );
+    let external_package_downloader = infra::ExternalPackageDownloader::new(&file_path_converter);
+    let package_lock_manager =
+        app::PackageLockManager::new(&file_system, &static_file_path_manager);
+    let cached_external_package_downloader = app::CachedExternalPackageDownloader::new(
+        &package_configuration_reader,
+        &package_lock_manager,
+        &external_package_downloader,
+        &file_system,
+        &file_path_resolver,
+        &logger,
+    );
+    let external_packages_downloader = app::ExternalPackagesDownloader::new(
+        &cached_external_package_downloader,
+        &package_lock_manager,
+        &SYSTEM_PACKAGE_CONFIGURATION,
+    );
+
+    app::PackageUpdater::new(
+        &package_configuration_reader,
+        &package_lock_manager,
+        &external_packages_downloader,
+        &cached_external_package_downloader,
+        &logger,
+    )
+    .update(package_name)
+}
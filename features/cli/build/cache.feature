@@ -0,0 +1,48 @@
+Feature: Build cache
+  Background:
+    Given I successfully run `ein init -l .`
+    And a file named "Foo.ein" with:
+    """
+    export { foo }
+
+    foo : Number -> Number
+    foo x = x
+    """
+
+  Scenario: Reuse compiled modules
+    Given I successfully run `ein build`
+    When I successfully run `ein build`
+    Then the stderr should not contain "compiling module"
+
+  Scenario: Recompile modified modules
+    Given I successfully run `ein build`
+    And a file named "Foo.ein" with:
+    """
+    export { foo }
+
+    foo : Number -> Number
+    foo x = x + 1
+    """
+    When I successfully run `ein build`
+    Then the stderr should contain "compiling module"
+    And a file named ".ein/objects/manifest.json" should exist
+
+  Scenario: Reuse compiled modules across optimization levels
+    Given a file named "ein.json" with:
+    """
+    {
+      "dependencies": {},
+      "profiles": {
+        "fast": { "optimizationLevel": 1, "debugInformation": false, "assertions": false }
+      }
+    }
+    """
+    And I successfully run `ein build --release`
+    When I successfully run `ein build --profile fast`
+    Then the stderr should not contain "compiling module"
+
+  Scenario: Keep compiled modules of other profiles
+    Given I successfully run `ein build --debug`
+    And I successfully run `ein build --release`
+    When I successfully run `ein build --debug`
+    Then the stderr should not contain "compiling module"
@@ -0,0 +1,30 @@
+Feature: Debug build
+  Background:
+    Given I successfully run `ein init foo`
+    And I cd to "foo"
+    And a file named "Main.ein" with:
+    """
+    import "github.com/ein-lang/os/Os"
+
+    main : Os.Os -> Number
+    main os =
+      let
+        _ = Os.fdWrite os Os.stdout "Hello, world!\n"
+      in
+        0
+    """
+
+  Scenario: Build an application for debugging
+    When I successfully run `ein build --debug`
+    Then I successfully run `sh -c ./foo`
+
+  Scenario: Run an application for debugging
+    When I successfully run `ein run --debug`
+    Then the exit status should be 0
+
+  Scenario: Set a breakpoint on a source line
+    Given I successfully run `ein build --debug`
+    When I successfully run `gdb -batch -ex "break Main.ein:6" -ex run -ex bt ./foo`
+    Then the stdout should contain "Breakpoint 1 at"
+    And the stdout should contain "Main.ein:6"
+    And the stdout should contain "main"
@@ -0,0 +1,28 @@
+Feature: Emission of intermediate representations
+  Background:
+    Given I successfully run `ein init -l .`
+    And a file named "Foo.ein" with:
+    """
+    export { foo }
+
+    foo : Number -> Number
+    foo x = x
+    """
+
+  Scenario: Emit intermediate representations
+    When I successfully run `ein build --emit=ast,typed-ast,eir,fmm,llvm-ir,asm`
+    Then a file named ".ein/emit/Foo.ast" should exist
+    And a file named ".ein/emit/Foo.typed.ast" should exist
+    And a file named ".ein/emit/Foo.eir" should exist
+    And a file named ".ein/emit/Foo.fmm" should exist
+    And a file named ".ein/emit/Foo.ll" should exist
+    And a file named ".ein/emit/Foo.s" should exist
+
+  Scenario: Emit intermediate representations of cached modules
+    Given I successfully run `ein build`
+    When I successfully run `ein build --emit=eir`
+    Then a file named ".ein/emit/Foo.eir" should exist
+
+  Scenario: Fail to emit an unknown intermediate representation
+    When I run `ein build --emit=foo`
+    Then the exit status should not be 0
@@ -15,6 +15,28 @@ Feature: Error
     Then stderr from "ein build" should contain "types not matched"
     And the exit status should not be 0
 
+  Scenario: Show types in a type error
+    Given a file named "Main.ein" with:
+    """
+    x : Number
+    x = None
+    """
+    When I run `ein build`
+    Then stderr from "ein build" should contain "None is not a subtype of Number"
+    And the exit status should not be 0
+
+  Scenario: Show names of types in a type error
+    Given a file named "Main.ein" with:
+    """
+    type Foo { foo : Number }
+
+    x : Foo
+    x = None
+    """
+    When I run `ein build`
+    Then stderr from "ein build" should contain "None is not a subtype of Foo"
+    And the exit status should not be 0
+
   Scenario: Fail to build due to duplicate names
     Given a file named "Main.ein" with:
     """
@@ -27,3 +49,57 @@ Feature: Error
     When I run `ein build`
     Then stderr from "ein build" should contain "duplicate names"
     And the exit status should not be 0
+
+  Scenario: Show an error code
+    Given a file named "Main.ein" with:
+    """
+    a : Number
+    a = 42
+
+    a : Number
+    a = 42
+    """
+    When I run `ein build`
+    Then stderr from "ein build" should contain "error[E0003]"
+    And the exit status should not be 0
+
+  Scenario: Show multiple errors
+    Given a file named "Main.ein" with:
+    """
+    a : Number
+    a = 42
+
+    a : Number
+    a = 42
+
+    b : Number
+    b = 42
+
+    b : Number
+    b = 42
+    """
+    When I run `ein build`
+    Then stderr from "ein build" should contain "4:1:"
+    And stderr from "ein build" should contain "10:1:"
+    And the exit status should not be 0
+
+  Scenario: Fail to parse a module
+    Given a file named "Main.ein" with:
+    """
+    a : Number
+    a = )
+    """
+    When I run `ein build`
+    Then stderr from "ein build" should contain "error[P0002]: failed to parse module"
+    And the exit status should not be 0
+
+  Scenario: Report an unexpected token
+    Given a file named "Main.ein" with:
+    """
+    a : Number
+    a = 1 +
+    """
+    When I run `ein build`
+    Then stderr from "ein build" should contain "unexpected `+`"
+    And stderr from "ein build" should contain "expected end of file"
+    And the exit status should not be 0
@@ -0,0 +1,62 @@
+Feature: Git package
+  Background:
+    Given I successfully run `ein init -l bar`
+    And a file named "bar/Bar.ein" with:
+    """
+    export { bar }
+
+    bar : Number -> Number
+    bar x = x
+    """
+    And a file named "bar.sh" with:
+    """
+    set -e
+    cd bar
+    git init -q
+    git add .
+    git -c user.name=foo -c user.email=foo@example.com commit -qm init
+    git tag v1.0.0
+    git tag v1.1.0
+    """
+    And I successfully run `sh bar.sh`
+    And I successfully run `ein init foo`
+    And a file named "foo/Main.ein" with:
+    """
+    import "github.com/ein-lang/os/Os"
+    import "github.com/foo/bar/Bar"
+
+    main : Os.Os -> Number
+    main os = Bar.bar 0
+    """
+    And a file named "foo.sh" with:
+    """
+    cat > foo/ein.json <<EOF
+    {
+      "application": {
+        "name": "foo",
+        "system": { "name": "github.com/ein-lang/os", "version": "main" }
+      },
+      "dependencies": {
+        "github.com/foo/bar": { "url": "file://$PWD/bar", "version": "$1" }
+      }
+    }
+    EOF
+    """
+
+  Scenario Outline: Build an application with a package of a file URL
+    Given I successfully run `sh foo.sh <version>`
+    And I cd to "foo"
+    When I successfully run `ein build`
+    Then I successfully run `sh -c ./foo`
+
+    Examples:
+      | version |
+      | v1.0.0  |
+      | ^1.0    |
+      | HEAD    |
+
+  Scenario: Fail to build with an unknown version
+    Given I successfully run `sh foo.sh ^2.0`
+    And I cd to "foo"
+    When I run `ein build`
+    Then the exit status should not be 0
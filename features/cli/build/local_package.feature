@@ -0,0 +1,53 @@
+Feature: Local package
+  Background:
+    Given I successfully run `ein init -l bar`
+    And a file named "bar/Bar.ein" with:
+    """
+    export { bar }
+
+    bar : Number -> Number
+    bar x = x
+    """
+    And I successfully run `ein init foo`
+    And I cd to "foo"
+    And a file named "ein.json" with:
+    """
+    {
+      "application": {
+        "name": "foo",
+        "system": {
+          "name": "github.com/ein-lang/os",
+          "version": "main"
+        }
+      },
+      "dependencies": {
+        "github.com/foo/bar": { "path": "../bar" }
+      }
+    }
+    """
+    And a file named "Main.ein" with:
+    """
+    import "github.com/ein-lang/os/Os"
+    import "github.com/foo/bar/Bar"
+
+    main : Os.Os -> Number
+    main os = Bar.bar 0
+    """
+
+  Scenario: Build an application with a local package
+    When I successfully run `ein build`
+    Then I successfully run `sh -c ./foo`
+    And a directory named ".ein/packages/github.com/foo/bar" should not exist
+
+  Scenario: Rebuild an application after a local package changes
+    Given I successfully run `ein build`
+    And a file named "../bar/Bar.ein" with:
+    """
+    export { bar }
+
+    bar : Number -> Number
+    bar x = x + 1
+    """
+    When I successfully run `ein build`
+    Then I run `sh -c ./foo`
+    And the exit status should be 1
@@ -0,0 +1,41 @@
+Feature: Parallel build
+  Background:
+    Given I successfully run `ein init -l .`
+    And a file named "Foo.ein" with:
+    """
+    export { foo }
+
+    foo : Number -> Number
+    foo x = x
+    """
+    And a file named "Bar.ein" with:
+    """
+    import "/Foo"
+
+    export { bar }
+
+    bar : Number -> Number
+    bar x = Foo.foo x
+    """
+    And a file named "Baz.ein" with:
+    """
+    export { baz }
+
+    baz : Number -> Number
+    baz x = x
+    """
+
+  Scenario Outline: Build modules with a number of jobs
+    When I successfully run `ein build -j <jobs>`
+    Then the stderr should contain "Foo"
+    And the stderr should contain "Bar"
+    And the stderr should contain "Baz"
+
+    Examples:
+      | jobs |
+      | 1    |
+      | 4    |
+
+  Scenario: Fail to build with an invalid number of jobs
+    When I run `ein build -j foo`
+    Then the exit status should not be 0
@@ -0,0 +1,65 @@
+Feature: Profile
+  Background:
+    Given I successfully run `ein init foo`
+    And I cd to "foo"
+
+  Scenario Outline: Build an application with a built-in profile
+    When I successfully run `ein build <option>`
+    Then I successfully run `sh -c ./foo`
+
+    Examples:
+      | option            |
+      |                   |
+      | --debug           |
+      | --release         |
+      | --profile debug   |
+      | --profile release |
+
+  Scenario: Build an application with a custom profile
+    Given a file named "ein.json" with:
+    """
+    {
+      "application": {
+        "name": "foo",
+        "system": {
+          "name": "github.com/ein-lang/os",
+          "version": "main"
+        }
+      },
+      "dependencies": {},
+      "profiles": {
+        "profiling": { "optimizationLevel": 2, "linkArguments": ["-lm"] }
+      }
+    }
+    """
+    When I successfully run `ein build --profile profiling`
+    Then I successfully run `sh -c ./foo`
+
+  Scenario: Build an application with link-time optimization
+    Given a file named "ein.json" with:
+    """
+    {
+      "application": {
+        "name": "foo",
+        "system": {
+          "name": "github.com/ein-lang/os",
+          "version": "main"
+        }
+      },
+      "dependencies": {},
+      "profiles": {
+        "release": { "lto": true }
+      }
+    }
+    """
+    When I successfully run `ein build --release`
+    Then I successfully run `sh -c ./foo`
+
+  Scenario: Run an application with the release profile
+    When I successfully run `ein run --release`
+    Then the exit status should be 0
+
+  Scenario: Fail to build with an unknown profile
+    When I run `ein build --profile foo`
+    Then the exit status should not be 0
+    And the stderr should contain "profile \"foo\" not found"
@@ -0,0 +1,56 @@
+Feature: Toolchain
+  Background:
+    Given I successfully run `ein init foo`
+    And I cd to "foo"
+
+  Scenario: Build an application with extra libraries
+    Given a file named "ein.json" with:
+    """
+    {
+      "application": {
+        "name": "foo",
+        "system": {
+          "name": "github.com/ein-lang/os",
+          "version": "main"
+        }
+      },
+      "dependencies": {},
+      "toolchain": { "libraries": ["m"], "linkArguments": ["-s"] }
+    }
+    """
+    When I successfully run `ein build`
+    Then I successfully run `sh -c ./foo`
+
+  Scenario: Build an application with a toolchain in environment variables
+    When I successfully run `sh -c 'EIN_CLANG=clang ein build'`
+    Then I successfully run `sh -c ./foo`
+
+  Scenario: Fail to build with an unsupported LLVM
+    When I run `sh -c 'EIN_LLC=true ein build'`
+    Then the exit status should not be 0
+    And the stderr should contain "no compatible LLVM found"
+
+  Scenario: Fail to build without clang
+    When I run `sh -c 'EIN_CLANG=no-clang ein build'`
+    Then the exit status should not be 0
+    And the stderr should contain "command \"no-clang\" not found"
+
+  Scenario: Build an application with a toolchain relative to a package directory
+    Given a file named "ein.json" with:
+    """
+    {
+      "application": {
+        "name": "foo",
+        "system": {
+          "name": "github.com/ein-lang/os",
+          "version": "main"
+        }
+      },
+      "dependencies": {},
+      "toolchain": { "clang": "./bin/clang" }
+    }
+    """
+    And I successfully run `mkdir bin`
+    And I successfully run `sh -c 'ln -s "$(command -v clang)" bin/clang'`
+    When I successfully run `ein build`
+    Then I successfully run `sh -c ./foo`
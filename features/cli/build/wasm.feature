@@ -0,0 +1,28 @@
+Feature: WebAssembly
+  Background:
+    Given I successfully run `ein init foo`
+    And I cd to "foo"
+
+  Scenario: Build a WebAssembly application
+    When I successfully run `ein build --target wasm32-wasi`
+    Then I successfully run `wasmtime run -W tail-call=y foo`
+
+  Scenario: Run a WebAssembly application
+    Given a file named "Main.ein" with:
+    """
+    import "github.com/ein-lang/os/Os"
+
+    main : Os.Os -> Number
+    main os =
+      let
+        _ = Os.fdWrite os Os.stdout "Hello, world!\n"
+      in
+        42
+    """
+    When I run `ein run --target wasm32-wasi`
+    Then the stdout should contain "Hello, world!"
+    And the exit status should be 42
+
+  Scenario: Fail to build with an unknown target
+    When I run `ein build --target foo`
+    Then the exit status should not be 0
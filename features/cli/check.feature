@@ -0,0 +1,22 @@
+Feature: Check
+  Background:
+    Given I successfully run `ein init -l .`
+
+  Scenario: Check modules
+    Given a file named "Foo.ein" with:
+    """
+    export { foo }
+
+    foo : Number -> Number
+    foo x = x
+    """
+    When I successfully run `ein check`
+
+  Scenario: Check modules with type errors
+    Given a file named "Foo.ein" with:
+    """
+    foo : Number
+    foo = None
+    """
+    When I run `ein check`
+    Then the exit status should not be 0
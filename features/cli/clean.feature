@@ -0,0 +1,23 @@
+Feature: Clean
+  Background:
+    Given I successfully run `ein init -l .`
+    And a file named "ein.json" with:
+    """
+    {
+      "dependencies": {
+        "github.com/ein-lang/sample-package": { "version": "HEAD" }
+      }
+    }
+    """
+
+  Scenario: Remove caches of external packages
+    Given I successfully run `ein build`
+    When I successfully run `ein clean`
+    Then a directory named ".ein/packages" should not exist
+    And a file named "ein.lock" should exist
+
+  Scenario: Build a package after cleaning
+    Given I successfully run `ein build`
+    And I successfully run `ein clean`
+    When I successfully run `ein build`
+    Then a directory named ".ein/packages/github.com/ein-lang/sample-package" should exist
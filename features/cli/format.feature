@@ -0,0 +1,36 @@
+Feature: Format
+  Background:
+    Given I successfully run `ein init -l .`
+
+  Scenario: Format modules
+    Given a file named "Foo.ein" with:
+    """
+    export { foo }
+    # bar
+    foo : Number -> Number
+    foo x =   x
+    """
+    When I successfully run `ein format`
+    Then the file "Foo.ein" should contain:
+    """
+    export { foo }
+
+    # bar
+    foo : Number -> Number
+    foo x = x
+    """
+
+  Scenario: Check formatted modules
+    When I run `ein format --check`
+    Then the exit status should be 0
+
+  Scenario: Check unformatted modules
+    Given a file named "Foo.ein" with:
+    """
+    export { foo }
+    foo : Number -> Number
+    foo x =   x
+    """
+    When I run `ein format --check`
+    Then stderr from "ein format --check" should contain "modules not formatted"
+    And the exit status should not be 0
@@ -0,0 +1,24 @@
+Feature: Run
+  Scenario: Run an application
+    Given I successfully run `ein init foo`
+    And I cd to "foo"
+    And a file named "Main.ein" with:
+    """
+    import "github.com/ein-lang/os/Os"
+
+    main : Os.Os -> Number
+    main os =
+      let
+        _ = Os.fdWrite os Os.stdout "Hello, world!\n"
+      in
+        42
+    """
+    When I run `ein run`
+    Then the stdout should contain "Hello, world!"
+    And the exit status should be 42
+
+  Scenario: Run a library
+    Given I successfully run `ein init -l .`
+    When I run `ein run`
+    Then stderr from "ein run" should contain "library packages cannot be run"
+    And the exit status should not be 0
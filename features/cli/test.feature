@@ -0,0 +1,31 @@
+Feature: Test
+  Background:
+    Given I successfully run `ein init -l .`
+
+  Scenario: Run passing tests
+    Given a file named "FooTest.ein" with:
+    """
+    export { testFoo }
+
+    testFoo : Boolean
+    testFoo = 1 + 1 == 2
+    """
+    When I successfully run `ein test`
+    Then the stdout should contain "PASS FooTest.testFoo"
+
+  Scenario: Run failing tests
+    Given a file named "FooTest.ein" with:
+    """
+    export { testFoo }
+
+    testFoo : Boolean
+    testFoo = False
+    """
+    When I run `ein test`
+    Then the stdout should contain "FAIL FooTest.testFoo"
+    And the stdout should contain "FAIL FooTest.testFoo at FooTest.ein:3:1"
+    And the exit status should not be 0
+
+  Scenario: Run no tests
+    When I successfully run `ein test`
+    Then the stderr should contain "no tests found"
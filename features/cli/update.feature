@@ -0,0 +1,31 @@
+Feature: Update
+  Background:
+    Given I successfully run `ein init -l .`
+    And a file named "ein.json" with:
+    """
+    {
+      "dependencies": {
+        "github.com/ein-lang/sample-package": { "version": "HEAD" }
+      }
+    }
+    """
+
+  Scenario: Lock versions of external packages
+    When I successfully run `ein build`
+    Then the file "ein.lock" should contain "github.com/ein-lang/sample-package"
+
+  Scenario: Update all external packages
+    Given I successfully run `ein build`
+    When I successfully run `ein update`
+    Then the file "ein.lock" should contain "github.com/ein-lang/sample-package"
+    And I successfully run `ein build`
+
+  Scenario: Update an external package
+    Given I successfully run `ein build`
+    When I successfully run `ein update github.com/ein-lang/sample-package`
+    Then the file "ein.lock" should contain "github.com/ein-lang/sample-package"
+
+  Scenario: Fail to update an unknown package
+    Given I successfully run `ein build`
+    When I run `ein update github.com/foo/bar`
+    Then the exit status should not be 0
@@ -60,3 +60,52 @@ Feature: Case expressions
     """
     When I run `ein build`
     Then the exit status should be 0
+
+  Scenario: Fail to build a case expression with a missing alternative
+    Given a file named "Foo.ein" with:
+    """
+    x : Number | Boolean | None
+    x = None
+
+    y : Number
+    y =
+      case x = x
+        Number => 0
+        Boolean => 1
+    """
+    When I run `ein build`
+    Then stderr from "ein build" should contain "error[E0019]"
+    And stderr from "ein build" should contain "missing alternatives for None"
+    And the exit status should not be 0
+
+  Scenario: Fail to build a case expression of Any without a default alternative
+    Given a file named "Foo.ein" with:
+    """
+    x : Any
+    x = 0
+
+    y : Number
+    y =
+      case x = x
+        Number => x
+    """
+    When I run `ein build`
+    Then stderr from "ein build" should contain "error[E0019]"
+    And stderr from "ein build" should contain "missing alternatives for Any"
+    And the exit status should not be 0
+
+  Scenario: Fail to build a case expression with an unreachable alternative
+    Given a file named "Foo.ein" with:
+    """
+    x : Any
+    x = 0
+
+    y : Number
+    y =
+      case x = x
+        Any => 1
+        Number => x
+    """
+    When I run `ein build`
+    Then stderr from "ein build" should contain "error[E0020]"
+    And the exit status should not be 0
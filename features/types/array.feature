@@ -0,0 +1,69 @@
+Feature: Array
+  Background:
+    Given I successfully run `ein init -l .`
+
+  Scenario: Create an empty array
+    Given a file named "Foo.ein" with:
+    """
+    foo : Array Number
+    foo = Array[]
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Create an array with elements
+    Given a file named "Foo.ein" with:
+    """
+    foo : Array Number
+    foo = Array[ 1, 2, 3 ]
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Get an element in an array
+    Given a file named "Foo.ein" with:
+    """
+    foo : Array Number -> Number | None
+    foo x = _getFromArray x 1
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Update an element in an array
+    Given a file named "Foo.ein" with:
+    """
+    foo : Array Number -> Array Number
+    foo x = _setInArray x 1 42
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Convert a list into an array
+    Given a file named "Foo.ein" with:
+    """
+    foo : List Number -> List Number
+    foo x = _arrayToList (_listToArray x)
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Get no element at invalid indices
+    Given a file named "FooTest.ein" with:
+    """
+    export { testFractionalIndex, testNanIndex }
+
+    isNone : Number | None -> Boolean
+    isNone x =
+      case x = x
+        Number => False
+        None => True
+
+    testFractionalIndex : Boolean
+    testFractionalIndex = isNone (_getFromArray Array[ 1, 2 ] 1.5)
+
+    testNanIndex : Boolean
+    testNanIndex = isNone (_getFromArray Array[ 1, 2 ] (0 / 0))
+    """
+    When I successfully run `ein test`
+    Then the stdout should contain "PASS FooTest.testFractionalIndex"
+    And the stdout should contain "PASS FooTest.testNanIndex"
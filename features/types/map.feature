@@ -0,0 +1,48 @@
+Feature: Map
+  Background:
+    Given I successfully run `ein init -l .`
+
+  Scenario: Create an empty map
+    Given a file named "Foo.ein" with:
+    """
+    foo : {Number : String}
+    foo = {}
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Create a map with elements
+    Given a file named "Foo.ein" with:
+    """
+    foo : {String : Number}
+    foo = { "foo" : 1, "bar" : 2 }
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Insert an element into a map
+    Given a file named "Foo.ein" with:
+    """
+    foo : {Number : Number} -> {Number : Number}
+    foo x = _insertIntoMap x 1 2
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Look up an element in a map
+    Given a file named "Foo.ein" with:
+    """
+    foo : {Number : Number} -> Number | None
+    foo x = _lookupInMap x 1
+    """
+    When I run `ein build`
+    Then the exit status should be 0
+
+  Scenario: Fail to create a map with function keys
+    Given a file named "Foo.ein" with:
+    """
+    foo : {(Number -> Number) : Number}
+    foo = {}
+    """
+    When I run `ein build`
+    Then the exit status should not be 0
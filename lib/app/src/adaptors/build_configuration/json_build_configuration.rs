@@ -1,7 +1,8 @@
 use super::{
     json_application_build_configuration::JsonApplicationBuildConfiguration,
     json_external_package_configuration::JsonExternalPackageConfiguration,
-    json_system_package_configuration::JsonSystemPackageConfiguration,
+    json_profile::JsonProfile, json_system_package_configuration::JsonSystemPackageConfiguration,
+    json_toolchain_configuration::JsonToolchainConfiguration,
 };
 use crate::{
     common::{ApplicationTarget, BuildConfiguration},
@@ -14,16 +15,27 @@ use std::collections::HashMap;
 pub struct JsonBuildConfiguration {
     application: Option<JsonApplicationBuildConfiguration>,
     dependencies: HashMap<String, JsonExternalPackageConfiguration>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    profiles: HashMap<String, JsonProfile>,
+    #[serde(
+        default,
+        skip_serializing_if = "JsonToolchainConfiguration::is_default"
+    )]
+    toolchain: JsonToolchainConfiguration,
 }
 
 impl JsonBuildConfiguration {
     pub fn new(
         application: Option<JsonApplicationBuildConfiguration>,
         dependencies: HashMap<String, JsonExternalPackageConfiguration>,
+        profiles: HashMap<String, JsonProfile>,
+        toolchain: JsonToolchainConfiguration,
     ) -> Self {
         Self {
             application,
             dependencies,
+            profiles,
+            toolchain,
         }
     }
 
@@ -45,15 +57,25 @@ impl JsonBuildConfiguration {
                 .map(|external_package| {
                     (
                         external_package.name().into(),
-                        JsonExternalPackageConfiguration::new(external_package.version()),
+                        JsonExternalPackageConfiguration::new(
+                            external_package.version(),
+                            external_package.url().map(|url| url.as_str().into()),
+                            external_package.path().map(|path| format!("{}", path)),
+                        ),
                     )
                 })
                 .collect(),
+            configuration
+                .profiles()
+                .iter()
+                .map(|(name, profile)| (name.clone(), JsonProfile::serialize(profile)))
+                .collect(),
+            JsonToolchainConfiguration::serialize(configuration.toolchain_configuration()),
         )
     }
 
-    pub fn deserialize(&self) -> BuildConfiguration {
-        BuildConfiguration::new(
+    pub fn deserialize(&self) -> Result<BuildConfiguration, url::ParseError> {
+        Ok(BuildConfiguration::new(
             self.application
                 .as_ref()
                 .map(|application| {
@@ -69,8 +91,25 @@ impl JsonBuildConfiguration {
                 .unwrap_or(Target::Library),
             self.dependencies
                 .iter()
-                .map(|(name, configuration)| ExternalPackage::new(name, configuration.version()))
+                .map(|(name, configuration)| {
+                    Ok(if let Some(path) = configuration.path() {
+                        ExternalPackage::with_path(name, path.parse().unwrap())
+                    } else if let Some(url) = configuration.url() {
+                        ExternalPackage::with_url(
+                            name,
+                            configuration.version(),
+                            url::Url::parse(url)?,
+                        )
+                    } else {
+                        ExternalPackage::new(name, configuration.version())
+                    })
+                })
+                .collect::<Result<_, url::ParseError>>()?,
+            self.profiles
+                .iter()
+                .map(|(name, profile)| (name.clone(), profile.deserialize(name)))
                 .collect(),
-        )
+            self.toolchain.deserialize(),
+        ))
     }
 }
@@ -2,17 +2,32 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
 pub struct JsonExternalPackageConfiguration {
+    #[serde(default)]
     version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
 }
 
 impl JsonExternalPackageConfiguration {
-    pub fn new(version: impl Into<String>) -> Self {
+    pub fn new(version: impl Into<String>, url: Option<String>, path: Option<String>) -> Self {
         Self {
             version: version.into(),
+            url,
+            path,
         }
     }
 
     pub fn version(&self) -> &str {
         &self.version
     }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn path(&self) -> Option<&str> {
+        self.path.as_deref()
+    }
 }
@@ -0,0 +1,74 @@
+use crate::common::Profile;
+use serde::{Deserialize, Serialize};
+
+// Omitted fields default to the ones of built-in profiles of the same names or
+// the debug profile.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonProfile {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    optimization_level: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    debug_information: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    assertions: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    lto: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    link_arguments: Option<Vec<String>>,
+}
+
+impl JsonProfile {
+    pub fn serialize(profile: &Profile) -> Self {
+        Self {
+            optimization_level: Some(profile.optimization_level()),
+            debug_information: Some(profile.debug_information()),
+            assertions: Some(profile.assertions()),
+            lto: Some(profile.lto()),
+            link_arguments: Some(profile.link_arguments().to_vec()),
+        }
+    }
+
+    pub fn deserialize(&self, name: &str) -> Profile {
+        let profile = Profile::built_in(name).unwrap_or_else(Profile::debug);
+
+        Profile::new(
+            self.optimization_level
+                .unwrap_or_else(|| profile.optimization_level()),
+            self.debug_information
+                .unwrap_or_else(|| profile.debug_information()),
+            self.assertions.unwrap_or_else(|| profile.assertions()),
+            self.lto.unwrap_or_else(|| profile.lto()),
+            self.link_arguments
+                .clone()
+                .unwrap_or_else(|| profile.link_arguments().to_vec()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_profile_with_defaults_of_built_in_profile() {
+        assert_eq!(
+            serde_json::from_str::<JsonProfile>(r#"{"debugInformation":true}"#)
+                .unwrap()
+                .deserialize("release"),
+            Profile::new(3, true, false, false, vec![])
+        );
+    }
+
+    #[test]
+    fn deserialize_custom_profile() {
+        assert_eq!(
+            serde_json::from_str::<JsonProfile>(
+                r#"{"optimizationLevel":2,"lto":true,"linkArguments":["-lm"]}"#
+            )
+            .unwrap()
+            .deserialize("foo"),
+            Profile::new(2, true, true, true, vec!["-lm".into()])
+        );
+    }
+}
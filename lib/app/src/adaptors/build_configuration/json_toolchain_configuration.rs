@@ -0,0 +1,66 @@
+use crate::common::ToolchainConfiguration;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonToolchainConfiguration {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    llc: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    clang: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    libraries: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    link_arguments: Vec<String>,
+    #[serde(default, rename = "static", skip_serializing_if = "std::ops::Not::not")]
+    static_linking: bool,
+}
+
+impl JsonToolchainConfiguration {
+    pub fn serialize(configuration: &ToolchainConfiguration) -> Self {
+        Self {
+            llc: configuration.llc().map(String::from),
+            clang: configuration.clang().map(String::from),
+            libraries: configuration.libraries().to_vec(),
+            link_arguments: configuration.link_arguments().to_vec(),
+            static_linking: configuration.static_linking(),
+        }
+    }
+
+    pub fn deserialize(&self) -> ToolchainConfiguration {
+        ToolchainConfiguration::new(
+            self.llc.clone(),
+            self.clang.clone(),
+            self.libraries.clone(),
+            self.link_arguments.clone(),
+            self.static_linking,
+        )
+    }
+
+    pub fn is_default(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialize_toolchain_configuration() {
+        assert_eq!(
+            serde_json::from_str::<JsonToolchainConfiguration>(
+                r#"{"llc":"/opt/llvm/bin/llc","libraries":["m"],"linkArguments":["-s"],"static":true}"#
+            )
+            .unwrap()
+            .deserialize(),
+            ToolchainConfiguration::new(
+                Some("/opt/llvm/bin/llc".into()),
+                None,
+                vec!["m".into()],
+                vec!["-s".into()],
+                true
+            )
+        );
+    }
+}
@@ -1,7 +1,9 @@
 mod json_application_build_configuration;
 mod json_build_configuration;
 mod json_external_package_configuration;
+mod json_profile;
 mod json_system_package_configuration;
+mod json_toolchain_configuration;
 
 use self::json_build_configuration::JsonBuildConfiguration;
 use crate::common::BuildConfiguration;
@@ -14,6 +16,6 @@ pub fn serialize_build_configuration(
 
 pub fn deserialize_build_configuration(
     string: &str,
-) -> Result<BuildConfiguration, serde_json::Error> {
-    Ok(serde_json::from_str::<JsonBuildConfiguration>(string)?.deserialize())
+) -> Result<BuildConfiguration, Box<dyn std::error::Error>> {
+    Ok(serde_json::from_str::<JsonBuildConfiguration>(string)?.deserialize()?)
 }
@@ -1,3 +1,7 @@
 mod build_configuration;
+mod object_cache_manifest;
+mod package_lock;
 
 pub use build_configuration::*;
+pub use object_cache_manifest::*;
+pub use package_lock::*;
@@ -0,0 +1,23 @@
+use crate::common::ObjectCacheManifest;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonObjectCacheManifest {
+    compiler_version: String,
+    module_ids: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl JsonObjectCacheManifest {
+    pub fn serialize(manifest: &ObjectCacheManifest) -> Self {
+        Self {
+            compiler_version: manifest.compiler_version().into(),
+            module_ids: manifest.module_ids().clone(),
+        }
+    }
+
+    pub fn deserialize(self) -> ObjectCacheManifest {
+        ObjectCacheManifest::new(self.compiler_version, self.module_ids)
+    }
+}
@@ -0,0 +1,43 @@
+mod json_object_cache_manifest;
+
+use self::json_object_cache_manifest::JsonObjectCacheManifest;
+use crate::common::ObjectCacheManifest;
+
+pub fn serialize_object_cache_manifest(
+    manifest: &ObjectCacheManifest,
+) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&JsonObjectCacheManifest::serialize(manifest))
+}
+
+pub fn deserialize_object_cache_manifest(
+    string: &str,
+) -> Result<ObjectCacheManifest, serde_json::Error> {
+    Ok(serde_json::from_str::<JsonObjectCacheManifest>(string)?.deserialize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialize_and_deserialize_object_cache_manifest() {
+        let manifest = ObjectCacheManifest::new(
+            "0.1.0",
+            vec![
+                ("build".into(), vec!["01ab".into()].into_iter().collect()),
+                (
+                    "test".into(),
+                    vec!["01ab".into(), "23cd".into()].into_iter().collect(),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(
+            deserialize_object_cache_manifest(&serialize_object_cache_manifest(&manifest).unwrap())
+                .unwrap(),
+            manifest
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use super::json_package_lock_entry::JsonPackageLockEntry;
+use crate::common::{ExternalPackage, PackageLock};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct JsonPackageLock {
+    packages: Vec<JsonPackageLockEntry>,
+}
+
+impl JsonPackageLock {
+    pub fn new(packages: Vec<JsonPackageLockEntry>) -> Self {
+        Self { packages }
+    }
+
+    // Entries are sorted to keep lock files stable under version control.
+    pub fn serialize(package_lock: &PackageLock) -> Self {
+        let mut packages = package_lock
+            .commit_ids()
+            .iter()
+            .map(|(external_package, commit_id)| {
+                JsonPackageLockEntry::new(
+                    external_package.name(),
+                    external_package.version(),
+                    external_package.url().map(|url| url.as_str().into()),
+                    commit_id,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        packages.sort_by(|one, other| {
+            (one.name(), one.version()).cmp(&(other.name(), other.version()))
+        });
+
+        Self::new(packages)
+    }
+
+    pub fn deserialize(&self) -> Result<PackageLock, url::ParseError> {
+        Ok(PackageLock::new(
+            self.packages
+                .iter()
+                .map(|package| {
+                    Ok((
+                        if let Some(url) = package.url() {
+                            ExternalPackage::with_url(
+                                package.name(),
+                                package.version(),
+                                url::Url::parse(url)?,
+                            )
+                        } else {
+                            ExternalPackage::new(package.name(), package.version())
+                        },
+                        package.commit().into(),
+                    ))
+                })
+                .collect::<Result<_, url::ParseError>>()?,
+        ))
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+pub struct JsonPackageLockEntry {
+    name: String,
+    version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    commit: String,
+}
+
+impl JsonPackageLockEntry {
+    pub fn new(
+        name: impl Into<String>,
+        version: impl Into<String>,
+        url: Option<String>,
+        commit: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            url,
+            commit: commit.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn commit(&self) -> &str {
+        &self.commit
+    }
+}
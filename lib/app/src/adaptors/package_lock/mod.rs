@@ -0,0 +1,42 @@
+mod json_package_lock;
+mod json_package_lock_entry;
+
+use self::json_package_lock::JsonPackageLock;
+use crate::common::PackageLock;
+
+pub fn serialize_package_lock(package_lock: &PackageLock) -> Result<String, serde_json::Error> {
+    serde_json::to_string_pretty(&JsonPackageLock::serialize(package_lock))
+}
+
+pub fn deserialize_package_lock(string: &str) -> Result<PackageLock, Box<dyn std::error::Error>> {
+    Ok(serde_json::from_str::<JsonPackageLock>(string)?.deserialize()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::ExternalPackage;
+
+    #[test]
+    fn serialize_and_deserialize_package_lock() {
+        let package_lock = PackageLock::new(
+            vec![
+                (
+                    ExternalPackage::new("github.com/foo/bar", "main"),
+                    "01ab".into(),
+                ),
+                (
+                    ExternalPackage::new("github.com/foo/baz", "v1"),
+                    "23cd".into(),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(
+            deserialize_package_lock(&serialize_package_lock(&package_lock).unwrap()).unwrap(),
+            package_lock
+        );
+    }
+}
@@ -0,0 +1,58 @@
+use crate::common::{ExternalPackage, FilePath};
+
+// Object files of dependencies are ordered as prelude, system and external
+// packages.
+pub struct ApplicationModules {
+    system_package: ExternalPackage,
+    dependency_object_file_paths: Vec<FilePath>,
+    object_file_paths: Vec<FilePath>,
+    module_interfaces: Vec<lang::ModuleInterface>,
+    prelude_module_interfaces: Vec<lang::ModuleInterface>,
+    system_module_interfaces: Vec<lang::ModuleInterface>,
+}
+
+impl ApplicationModules {
+    pub fn new(
+        system_package: ExternalPackage,
+        dependency_object_file_paths: Vec<FilePath>,
+        object_file_paths: Vec<FilePath>,
+        module_interfaces: Vec<lang::ModuleInterface>,
+        prelude_module_interfaces: Vec<lang::ModuleInterface>,
+        system_module_interfaces: Vec<lang::ModuleInterface>,
+    ) -> Self {
+        Self {
+            system_package,
+            dependency_object_file_paths,
+            object_file_paths,
+            module_interfaces,
+            prelude_module_interfaces,
+            system_module_interfaces,
+        }
+    }
+
+    pub fn system_package(&self) -> &ExternalPackage {
+        &self.system_package
+    }
+
+    pub fn dependency_object_file_paths(&self) -> &[FilePath] {
+        &self.dependency_object_file_paths
+    }
+
+    pub fn object_file_paths(&self) -> &[FilePath] {
+        &self.object_file_paths
+    }
+
+    pub fn module_interfaces(&self) -> &[lang::ModuleInterface] {
+        &self.module_interfaces
+    }
+
+    // Prelude module interfaces include a main function module of a system
+    // package.
+    pub fn prelude_module_interfaces(&self) -> &[lang::ModuleInterface] {
+        &self.prelude_module_interfaces
+    }
+
+    pub fn system_module_interfaces(&self) -> &[lang::ModuleInterface] {
+        &self.system_module_interfaces
+    }
+}
@@ -1,11 +1,18 @@
-use super::package_configuration_reader::PackageConfigurationReader;
+use super::{
+    package_configuration_reader::PackageConfigurationReader,
+    package_lock_manager::PackageLockManager,
+};
 use crate::{
-    common::{ExternalPackage, FilePathResolver, PackageConfiguration},
+    common::{
+        ExternalPackage, FilePath, FilePathResolver, PackageConfiguration,
+        EXTERNAL_PACKAGE_CACHE_MARKER_FILENAME,
+    },
     infra::{ExternalPackageDownloader, FileSystem, Logger},
 };
 
 pub struct CachedExternalPackageDownloader<'a> {
     package_configuration_reader: &'a PackageConfigurationReader<'a>,
+    package_lock_manager: &'a PackageLockManager<'a>,
     external_package_downloader: &'a dyn ExternalPackageDownloader,
     file_system: &'a dyn FileSystem,
     file_path_resolver: &'a FilePathResolver<'a>,
@@ -15,6 +22,7 @@ pub struct CachedExternalPackageDownloader<'a> {
 impl<'a> CachedExternalPackageDownloader<'a> {
     pub fn new(
         package_configuration_reader: &'a PackageConfigurationReader<'a>,
+        package_lock_manager: &'a PackageLockManager<'a>,
         external_package_downloader: &'a dyn ExternalPackageDownloader,
         file_system: &'a dyn FileSystem,
         file_path_resolver: &'a FilePathResolver<'a>,
@@ -22,6 +30,7 @@ impl<'a> CachedExternalPackageDownloader<'a> {
     ) -> Self {
         Self {
             package_configuration_reader,
+            package_lock_manager,
             external_package_downloader,
             file_system,
             file_path_resolver,
@@ -29,25 +38,152 @@ impl<'a> CachedExternalPackageDownloader<'a> {
         }
     }
 
+    // Versions of external packages are resolved into commit IDs on their
+    // first downloads and recorded in a lock file. Caches of packages are
+    // keyed by the commit IDs and marked as complete only after checkouts
+    // succeed so that interrupted downloads are retried.
     pub fn download(
         &self,
         external_package: &ExternalPackage,
     ) -> Result<PackageConfiguration, Box<dyn std::error::Error>> {
-        let directory_path = self
+        if let Some(path) = external_package.path() {
+            return self
+                .package_configuration_reader
+                .read_with_name(path, external_package.name());
+        }
+
+        let mut package_lock = self.package_lock_manager.read()?;
+
+        let commit_id = if let Some(commit_id) = package_lock.commit_id(external_package) {
+            let commit_id = commit_id.to_owned();
+            let directory_path = self.resolve_directory_path(external_package, &commit_id);
+
+            if !self.is_cached(&directory_path, &commit_id)? {
+                self.download_to_directory(
+                    &Self::lock_external_package(external_package, &commit_id),
+                    &directory_path,
+                )?;
+                self.mark_cached(&directory_path, &commit_id)?;
+            }
+
+            commit_id
+        } else {
+            let commit_id = self.resolve_commit_id(external_package)?;
+
+            package_lock.insert(external_package.clone(), &commit_id);
+            self.package_lock_manager.write(&package_lock)?;
+
+            commit_id
+        };
+
+        self.package_configuration_reader.read_with_name(
+            &self.resolve_directory_path(external_package, &commit_id),
+            external_package.name(),
+        )
+    }
+
+    // Packages are downloaded into temporary directories keyed by their
+    // versions and moved to ones keyed by resolved commit IDs.
+    fn resolve_commit_id(
+        &self,
+        external_package: &ExternalPackage,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let temporary_directory_path = self
             .file_path_resolver
             .resolve_external_package_directory_path(external_package);
 
-        if !self.file_system.exists(&directory_path) {
-            self.logger.log(&format!(
-                "downloading package {} {}",
-                external_package.name(),
-                external_package.version()
-            ))?;
+        self.download_to_directory(external_package, &temporary_directory_path)?;
+
+        let commit_id = self
+            .file_system
+            .read_repository(&temporary_directory_path)?
+            .map(|repository| repository.version().to_owned())
+            .unwrap_or_default();
+        let directory_path = self.resolve_directory_path(external_package, &commit_id);
+
+        if directory_path != temporary_directory_path {
+            if self.is_cached(&directory_path, &commit_id)? {
+                self.file_system
+                    .remove_directory(&temporary_directory_path)?;
+            } else {
+                if self.file_system.exists(&directory_path) {
+                    self.file_system.remove_directory(&directory_path)?;
+                }
+
+                self.file_system
+                    .rename(&temporary_directory_path, &directory_path)?;
+            }
+        }
+
+        self.mark_cached(&directory_path, &commit_id)?;
+
+        Ok(commit_id)
+    }
 
-            self.external_package_downloader
-                .download(external_package, &directory_path)?;
+    fn download_to_directory(
+        &self,
+        external_package: &ExternalPackage,
+        directory_path: &FilePath,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if self.file_system.exists(directory_path) {
+            self.file_system.remove_directory(directory_path)?;
         }
 
-        self.package_configuration_reader.read(&directory_path)
+        self.logger.log(&format!(
+            "downloading package {} {}",
+            external_package.name(),
+            external_package.version()
+        ))?;
+
+        self.external_package_downloader
+            .download(external_package, directory_path)
+    }
+
+    fn is_cached(
+        &self,
+        directory_path: &FilePath,
+        commit_id: &str,
+    ) -> Result<bool, Box<dyn std::error::Error>> {
+        let marker_file_path = Self::resolve_marker_file_path(directory_path);
+
+        Ok(self.file_system.exists(&marker_file_path)
+            && self.file_system.read_to_string(&marker_file_path)? == commit_id)
+    }
+
+    fn mark_cached(
+        &self,
+        directory_path: &FilePath,
+        commit_id: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.file_system.write(
+            &Self::resolve_marker_file_path(directory_path),
+            commit_id.as_bytes(),
+        )
+    }
+
+    fn resolve_directory_path(
+        &self,
+        external_package: &ExternalPackage,
+        commit_id: &str,
+    ) -> FilePath {
+        self.file_path_resolver
+            .resolve_external_package_directory_path(&Self::lock_external_package(
+                external_package,
+                commit_id,
+            ))
+    }
+
+    fn resolve_marker_file_path(directory_path: &FilePath) -> FilePath {
+        directory_path.join(&FilePath::new(&[EXTERNAL_PACKAGE_CACHE_MARKER_FILENAME]))
+    }
+
+    fn lock_external_package(
+        external_package: &ExternalPackage,
+        commit_id: &str,
+    ) -> ExternalPackage {
+        match external_package.url() {
+            Some(url) => ExternalPackage::with_url(external_package.name(), commit_id, url.clone()),
+            None => ExternalPackage::new(external_package.name(), commit_id),
+        }
     }
 }
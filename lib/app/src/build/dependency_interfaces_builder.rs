@@ -0,0 +1,93 @@
+use super::{
+    external_packages_builder::ExternalPackagesBuilder,
+    external_packages_downloader::ExternalPackagesDownloader,
+    prelude_package_builder::PreludePackageBuilder, system_package_builder::SystemPackageBuilder,
+};
+use crate::common::PackageConfiguration;
+use std::collections::HashMap;
+
+type ExternalModuleInterfaces = HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>;
+
+// Dependencies are built in the same way as the build command so that their
+// module interfaces are cached and shared with it.
+pub struct DependencyInterfacesBuilder<'a> {
+    prelude_package_builder: &'a PreludePackageBuilder<'a>,
+    system_package_builder: &'a SystemPackageBuilder<'a>,
+    external_packages_downloader: &'a ExternalPackagesDownloader<'a>,
+    external_packages_builder: &'a ExternalPackagesBuilder<'a>,
+}
+
+impl<'a> DependencyInterfacesBuilder<'a> {
+    pub fn new(
+        prelude_package_builder: &'a PreludePackageBuilder<'a>,
+        system_package_builder: &'a SystemPackageBuilder<'a>,
+        external_packages_downloader: &'a ExternalPackagesDownloader<'a>,
+        external_packages_builder: &'a ExternalPackagesBuilder<'a>,
+    ) -> Self {
+        Self {
+            prelude_package_builder,
+            system_package_builder,
+            external_packages_downloader,
+            external_packages_builder,
+        }
+    }
+
+    pub fn build(
+        &self,
+        package_configuration: &PackageConfiguration,
+    ) -> Result<(Vec<lang::ModuleInterface>, ExternalModuleInterfaces), Box<dyn std::error::Error>>
+    {
+        let (_, mut prelude_module_interfaces) = self.prelude_package_builder.build()?;
+        let mut external_module_interfaces = vec![];
+
+        if let Some(application_target) = package_configuration
+            .build_configuration()
+            .target()
+            .as_application()
+        {
+            let (_, system_module_interfaces) = self.system_package_builder.build(
+                application_target.system_package(),
+                &prelude_module_interfaces,
+            )?;
+            let (main_function_module_interfaces, system_module_interfaces) =
+                system_module_interfaces
+                    .into_iter()
+                    .partition::<Vec<_>, _>(|interface| {
+                        interface.path().components().collect::<Vec<_>>() == vec!["MainFunction"]
+                    });
+
+            prelude_module_interfaces.extend(main_function_module_interfaces);
+            external_module_interfaces.extend(system_module_interfaces);
+        }
+
+        let external_package_configurations = self
+            .external_packages_downloader
+            .download_dependencies(package_configuration)?;
+
+        let (_, module_interfaces) = self
+            .external_packages_builder
+            .build(&external_package_configurations, &prelude_module_interfaces)?;
+
+        for external_package in package_configuration.build_configuration().dependencies() {
+            external_module_interfaces.extend(
+                module_interfaces
+                    .get(external_package)
+                    .into_iter()
+                    .flat_map(|module_interfaces| module_interfaces.values().cloned()),
+            );
+        }
+
+        Ok((
+            prelude_module_interfaces,
+            external_module_interfaces
+                .into_iter()
+                .map(|module_interface| {
+                    (
+                        module_interface.path().external_unresolved().into(),
+                        module_interface,
+                    )
+                })
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,55 @@
+// Emissions are intermediate representations of modules dumped on builds for
+// debugging of compilers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Emission {
+    Ast,
+    TypedAst,
+    Eir,
+    Fmm,
+    LlvmIr,
+    Assembly,
+}
+
+impl Emission {
+    pub const ALL: &'static [Self] = &[
+        Self::Ast,
+        Self::TypedAst,
+        Self::Eir,
+        Self::Fmm,
+        Self::LlvmIr,
+        Self::Assembly,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Ast => "ast",
+            Self::TypedAst => "typed-ast",
+            Self::Eir => "eir",
+            Self::Fmm => "fmm",
+            Self::LlvmIr => "llvm-ir",
+            Self::Assembly => "asm",
+        }
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::Ast => "ast",
+            Self::TypedAst => "typed.ast",
+            Self::Eir => "eir",
+            Self::Fmm => "fmm",
+            Self::LlvmIr => "ll",
+            Self::Assembly => "s",
+        }
+    }
+
+    // LLVM IR and assembly are generated from bitcode by external tools.
+    pub fn intermediate_representation(&self) -> Option<lang::IntermediateRepresentation> {
+        match self {
+            Self::Ast => Some(lang::IntermediateRepresentation::Ast),
+            Self::TypedAst => Some(lang::IntermediateRepresentation::TypedAst),
+            Self::Eir => Some(lang::IntermediateRepresentation::Eir),
+            Self::Fmm => Some(lang::IntermediateRepresentation::Fmm),
+            Self::LlvmIr | Self::Assembly => None,
+        }
+    }
+}
@@ -1,7 +1,14 @@
-use crate::common::{ExternalPackage, FilePath};
+use crate::common::{ExternalPackage, FilePath, MAX_OPTIMIZATION_LEVEL};
 
 #[derive(Debug, PartialEq)]
 pub enum BuildError {
+    AbsolutePackagePath {
+        package_name: String,
+        path: FilePath,
+    },
+    CommandNotFound {
+        command: String,
+    },
     ExternalPackageConfigurationFileNotFound {
         package_name: String,
     },
@@ -9,12 +16,43 @@ pub enum BuildError {
         main_function_module_name: &'static str,
         external_package: ExternalPackage,
     },
+    LlvmNotFound {
+        supported_versions: &'static [u32],
+    },
     ModuleCircularDependency(FilePath),
     ModuleNotFound {
         module_path: lang::UnresolvedModulePath,
         source_file_path: FilePath,
     },
+    OptimizationLevelInvalid {
+        profile_name: String,
+        optimization_level: u8,
+    },
     PackageCircularDependency(ExternalPackage),
+    PackageNotFound {
+        package_name: String,
+    },
+    ProfileNotFound {
+        profile_name: String,
+    },
+}
+
+impl BuildError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ExternalPackageConfigurationFileNotFound { .. } => "B0001",
+            Self::MainFunctionModuleNotFound { .. } => "B0002",
+            Self::ModuleCircularDependency(_) => "B0003",
+            Self::ModuleNotFound { .. } => "B0004",
+            Self::PackageCircularDependency(_) => "B0005",
+            Self::PackageNotFound { .. } => "B0006",
+            Self::ProfileNotFound { .. } => "B0007",
+            Self::LlvmNotFound { .. } => "B0008",
+            Self::CommandNotFound { .. } => "B0009",
+            Self::AbsolutePackagePath { .. } => "B0010",
+            Self::OptimizationLevelInvalid { .. } => "B0011",
+        }
+    }
 }
 
 impl std::error::Error for BuildError {}
@@ -22,6 +60,14 @@ impl std::error::Error for BuildError {}
 impl std::fmt::Display for BuildError {
     fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
+            Self::AbsolutePackagePath { package_name, path } => write!(
+                formatter,
+                "absolute path \"{}\" of package \"{}\" not supported",
+                path, package_name
+            ),
+            Self::CommandNotFound { command } => {
+                write!(formatter, "command \"{}\" not found", command)
+            }
             Self::ExternalPackageConfigurationFileNotFound { package_name } => write!(
                 formatter,
                 "package configuration file not found in external package \"{}\"",
@@ -36,6 +82,15 @@ impl std::fmt::Display for BuildError {
                 &main_function_module_name,
                 external_package.name(),
             ),
+            Self::LlvmNotFound { supported_versions } => write!(
+                formatter,
+                "no compatible LLVM found (supported versions: {})",
+                supported_versions
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
             Self::ModuleCircularDependency(file_path) => write!(
                 formatter,
                 "circular module dependency detected: {}",
@@ -51,12 +106,28 @@ impl std::fmt::Display for BuildError {
                     module_path, source_file_path
                 )
             }
+            Self::OptimizationLevelInvalid {
+                profile_name,
+                optimization_level,
+            } => write!(
+                formatter,
+                "invalid optimization level {} of profile \"{}\" (expected 0 to {})",
+                optimization_level, profile_name, MAX_OPTIMIZATION_LEVEL
+            ),
             Self::PackageCircularDependency(external_package) => write!(
                 formatter,
                 "circular package dependency detected: {} {}",
                 external_package.name(),
                 external_package.version()
             ),
+            Self::PackageNotFound { package_name } => write!(
+                formatter,
+                "package \"{}\" not found in lock file",
+                package_name
+            ),
+            Self::ProfileNotFound { profile_name } => {
+                write!(formatter, "profile \"{}\" not found", profile_name)
+            }
         }
     }
 }
@@ -65,6 +136,33 @@ impl std::fmt::Display for BuildError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn display_absolute_package_path() {
+        assert_eq!(
+            format!(
+                "{}",
+                BuildError::AbsolutePackagePath {
+                    package_name: "foo".into(),
+                    path: "/foo/bar".parse().unwrap(),
+                }
+            ),
+            "absolute path \"/foo/bar\" of package \"foo\" not supported"
+        );
+    }
+
+    #[test]
+    fn display_command_not_found() {
+        assert_eq!(
+            format!(
+                "{}",
+                BuildError::CommandNotFound {
+                    command: "clang".into()
+                }
+            ),
+            "command \"clang\" not found"
+        );
+    }
+
     #[test]
     fn display_external_package_configuration_not_found_error() {
         assert_eq!(
@@ -78,6 +176,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_llvm_not_found() {
+        assert_eq!(
+            format!(
+                "{}",
+                BuildError::LlvmNotFound {
+                    supported_versions: &[13, 12]
+                }
+            ),
+            "no compatible LLVM found (supported versions: 13, 12)"
+        );
+    }
+
     #[test]
     fn display_module_circular_dependency() {
         assert_eq!(
@@ -89,6 +200,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_optimization_level_invalid() {
+        assert_eq!(
+            format!(
+                "{}",
+                BuildError::OptimizationLevelInvalid {
+                    profile_name: "foo".into(),
+                    optimization_level: 4,
+                }
+            ),
+            "invalid optimization level 4 of profile \"foo\" (expected 0 to 3)"
+        );
+    }
+
     #[test]
     fn display_package_circular_dependency() {
         assert_eq!(
@@ -99,4 +224,30 @@ mod tests {
             "circular package dependency detected: foo 1.2.3"
         );
     }
+
+    #[test]
+    fn display_package_not_found() {
+        assert_eq!(
+            format!(
+                "{}",
+                BuildError::PackageNotFound {
+                    package_name: "foo".into()
+                }
+            ),
+            "package \"foo\" not found in lock file"
+        );
+    }
+
+    #[test]
+    fn display_profile_not_found() {
+        assert_eq!(
+            format!(
+                "{}",
+                BuildError::ProfileNotFound {
+                    profile_name: "foo".into()
+                }
+            ),
+            "profile \"foo\" not found"
+        );
+    }
 }
@@ -1,5 +1,5 @@
 use super::{
-    error::BuildError, package_builder::PackageBuilder,
+    error::BuildError, package_builder::PackageBuilder, package_modules::PackageModules,
     utilities::convert_module_interface_vec_to_map,
 };
 use crate::common::{ExternalPackage, FilePath, PackageConfiguration};
@@ -23,18 +23,40 @@ impl<'a> ExternalPackagesBuilder<'a> {
         package_configurations: &HashMap<ExternalPackage, PackageConfiguration>,
         prelude_module_interfaces: &[lang::ModuleInterface],
     ) -> Result<(Vec<FilePath>, ExternalModuleInterfaces), Box<dyn std::error::Error>> {
+        let external_packages = self.sort_external_packages(package_configurations)?;
+        let positions = external_packages
+            .iter()
+            .enumerate()
+            .map(|(position, external_package)| (external_package, position))
+            .collect::<HashMap<_, _>>();
+
         let mut package_object_file_paths = vec![];
         let mut external_module_interfaces = HashMap::new();
 
-        for external_package in self.sort_external_packages(package_configurations)? {
-            let package_configuration = &package_configurations[&external_package];
-
-            let (object_file_paths, module_interfaces) = self.package_builder.build(
-                package_configuration,
-                &external_module_interfaces,
-                prelude_module_interfaces,
-            )?;
+        for (external_package, (object_file_paths, module_interfaces)) in
+            external_packages.iter().zip(
+                self.package_builder.build_packages(
+                    &external_packages
+                        .iter()
+                        .map(|external_package| {
+                            let package_configuration = &package_configurations[external_package];
 
+                            PackageModules::new(
+                                package_configuration,
+                                HashMap::new(),
+                                package_configuration
+                                    .build_configuration()
+                                    .dependencies()
+                                    .iter()
+                                    .map(|external_package| positions[external_package])
+                                    .collect(),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                    prelude_module_interfaces,
+                )?,
+            )
+        {
             package_object_file_paths.extend(object_file_paths);
 
             external_module_interfaces.insert(
@@ -1,20 +1,62 @@
-use super::cached_external_package_downloader::CachedExternalPackageDownloader;
+use super::{
+    cached_external_package_downloader::CachedExternalPackageDownloader,
+    package_lock_manager::PackageLockManager,
+    system_package_configuration::SystemPackageConfiguration,
+};
 use crate::common::{ExternalPackage, PackageConfiguration};
 use std::collections::HashMap;
 
 pub struct ExternalPackagesDownloader<'a> {
     cached_external_package_downloader: &'a CachedExternalPackageDownloader<'a>,
+    package_lock_manager: &'a PackageLockManager<'a>,
+    system_package_configuration: &'a SystemPackageConfiguration,
 }
 
 impl<'a> ExternalPackagesDownloader<'a> {
     pub fn new(
         cached_external_package_downloader: &'a CachedExternalPackageDownloader<'a>,
+        package_lock_manager: &'a PackageLockManager<'a>,
+        system_package_configuration: &'a SystemPackageConfiguration,
     ) -> Self {
         Self {
             cached_external_package_downloader,
+            package_lock_manager,
+            system_package_configuration,
         }
     }
 
+    // Lock entries of packages which are not dependencies of a main package
+    // anymore are pruned. System packages are kept as they are downloaded
+    // separately.
+    pub fn download_dependencies(
+        &self,
+        package_configuration: &PackageConfiguration,
+    ) -> Result<HashMap<ExternalPackage, PackageConfiguration>, Box<dyn std::error::Error>> {
+        let package_configurations = self.download(
+            &package_configuration
+                .build_configuration()
+                .dependencies()
+                .iter()
+                .collect::<Vec<_>>(),
+        )?;
+
+        self.package_lock_manager.prune(
+            &package_configurations
+                .keys()
+                .chain(Some(
+                    package_configuration
+                        .build_configuration()
+                        .target()
+                        .as_application()
+                        .map(|application_target| application_target.system_package())
+                        .unwrap_or(&self.system_package_configuration.default_system_package),
+                ))
+                .collect::<Vec<_>>(),
+        )?;
+
+        Ok(package_configurations)
+    }
+
     pub fn download(
         &self,
         external_packages: &[&ExternalPackage],
@@ -1,6 +1,8 @@
 use super::{
-    error::BuildError, external_packages_builder::ExternalPackagesBuilder,
-    external_packages_downloader::ExternalPackagesDownloader, package_builder::PackageBuilder,
+    application_modules::ApplicationModules, error::BuildError,
+    external_packages_builder::ExternalPackagesBuilder,
+    external_packages_downloader::ExternalPackagesDownloader,
+    object_cache_manager::ObjectCacheManager, package_builder::PackageBuilder,
     package_configuration_reader::PackageConfigurationReader,
     prelude_package_builder::PreludePackageBuilder, system_package_builder::SystemPackageBuilder,
     system_package_configuration::SystemPackageConfiguration,
@@ -10,7 +12,8 @@ use crate::{
     common::{ApplicationTarget, FilePath, PackageConfiguration, Target},
     infra::{ApplicationLinker, Logger},
 };
-use std::collections::HashMap;
+
+const BUILD_TARGET_NAME: &str = "build";
 
 pub struct MainPackageBuilder<'a> {
     package_configuration_reader: &'a PackageConfigurationReader<'a>,
@@ -20,6 +23,7 @@ pub struct MainPackageBuilder<'a> {
     system_package_builder: &'a SystemPackageBuilder<'a>,
     external_packages_downloader: &'a ExternalPackagesDownloader<'a>,
     external_packages_builder: &'a ExternalPackagesBuilder<'a>,
+    object_cache_manager: &'a ObjectCacheManager<'a>,
     logger: &'a dyn Logger,
     system_package_configuration: &'a SystemPackageConfiguration,
 }
@@ -34,6 +38,7 @@ impl<'a> MainPackageBuilder<'a> {
         system_package_builder: &'a SystemPackageBuilder<'a>,
         external_packages_downloader: &'a ExternalPackagesDownloader<'a>,
         external_packages_builder: &'a ExternalPackagesBuilder<'a>,
+        object_cache_manager: &'a ObjectCacheManager<'a>,
         logger: &'a dyn Logger,
         system_package_configuration: &'a SystemPackageConfiguration,
     ) -> Self {
@@ -45,6 +50,7 @@ impl<'a> MainPackageBuilder<'a> {
             system_package_builder,
             external_packages_downloader,
             external_packages_builder,
+            object_cache_manager,
             logger,
             system_package_configuration,
         }
@@ -55,29 +61,39 @@ impl<'a> MainPackageBuilder<'a> {
 
         match package_configuration.build_configuration().target() {
             Target::Application(application_target) => {
-                self.build_application(&package_configuration, application_target)
+                self.build_application(&package_configuration, application_target)?
             }
-            Target::Library => self.build_library(&package_configuration),
+            Target::Library => self.build_library(&package_configuration)?,
         }
+
+        self.object_cache_manager.collect_garbage(BUILD_TARGET_NAME)
     }
 
-    fn build_application(
+    // Modules of main packages are built with a system package even if they
+    // are libraries so that they can be linked into test harnesses. Library
+    // packages use a default system package.
+    pub fn build_application_modules(
         &self,
         package_configuration: &PackageConfiguration,
-        application_target: &ApplicationTarget,
-    ) -> Result<(), Box<dyn std::error::Error>> {
+        test_modules_included: bool,
+    ) -> Result<ApplicationModules, Box<dyn std::error::Error>> {
+        let system_package = package_configuration
+            .build_configuration()
+            .target()
+            .as_application()
+            .map(|application_target| application_target.system_package())
+            .unwrap_or(&self.system_package_configuration.default_system_package);
         let (prelude_module_object_paths, prelude_module_interfaces) =
             self.prelude_package_builder.build()?;
 
-        let (system_module_object_paths, system_module_interfaces) =
-            self.system_package_builder.build(
-                application_target.system_package(),
-                &prelude_module_interfaces,
-            )?;
+        let (system_module_object_paths, system_module_interfaces) = self
+            .system_package_builder
+            .build(system_package, &prelude_module_interfaces)?;
         let (main_function_module_interfaces, system_module_interfaces) = system_module_interfaces
             .into_iter()
             .partition::<Vec<_>, _>(|interface| {
-                interface.path().components().collect::<Vec<_>>() == vec!["MainFunction"]
+                interface.path().components().collect::<Vec<_>>()
+                    == vec![self.system_package_configuration.main_function_module_name]
             });
 
         if main_function_module_interfaces.is_empty() {
@@ -85,7 +101,7 @@ impl<'a> MainPackageBuilder<'a> {
                 main_function_module_name: self
                     .system_package_configuration
                     .main_function_module_name,
-                external_package: application_target.system_package().clone(),
+                external_package: system_package.clone(),
             }
             .into());
         }
@@ -96,29 +112,53 @@ impl<'a> MainPackageBuilder<'a> {
             .chain(main_function_module_interfaces)
             .collect::<Vec<_>>();
 
-        let external_package_configurations = self.external_packages_downloader.download(
-            &package_configuration
-                .build_configuration()
-                .dependencies()
-                .iter()
-                .collect::<Vec<_>>(),
-        )?;
+        let external_package_configurations = self
+            .external_packages_downloader
+            .download_dependencies(package_configuration)?;
 
         let (external_module_object_paths, mut external_module_interfaces) = self
             .external_packages_builder
             .build(&external_package_configurations, &prelude_module_interfaces)?;
 
-        let (module_object_paths, _) = self.package_builder.build(
-            package_configuration,
-            &external_module_interfaces
-                .drain()
-                .chain(vec![(
-                    application_target.system_package().clone(),
-                    convert_module_interface_vec_to_map(&system_module_interfaces),
-                )])
-                .collect::<HashMap<_, _>>(),
-            &prelude_module_interfaces,
-        )?;
+        external_module_interfaces.insert(
+            system_package.clone(),
+            convert_module_interface_vec_to_map(&system_module_interfaces),
+        );
+
+        let (module_object_paths, module_interfaces) = if test_modules_included {
+            self.package_builder.build_with_tests(
+                package_configuration,
+                &external_module_interfaces,
+                &prelude_module_interfaces,
+            )?
+        } else {
+            self.package_builder.build(
+                package_configuration,
+                &external_module_interfaces,
+                &prelude_module_interfaces,
+            )?
+        };
+
+        Ok(ApplicationModules::new(
+            system_package.clone(),
+            prelude_module_object_paths
+                .into_iter()
+                .chain(system_module_object_paths)
+                .chain(external_module_object_paths)
+                .collect(),
+            module_object_paths,
+            module_interfaces,
+            prelude_module_interfaces,
+            system_module_interfaces,
+        ))
+    }
+
+    fn build_application(
+        &self,
+        package_configuration: &PackageConfiguration,
+        application_target: &ApplicationTarget,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let application_modules = self.build_application_modules(package_configuration, false)?;
 
         self.logger.log(&format!(
             "linking application {}",
@@ -126,13 +166,13 @@ impl<'a> MainPackageBuilder<'a> {
         ))?;
 
         self.application_linker.link(
-            &prelude_module_object_paths
-                .into_iter()
-                .chain(system_module_object_paths)
-                .chain(external_module_object_paths)
-                .chain(module_object_paths)
+            &application_modules
+                .dependency_object_file_paths()
+                .iter()
+                .chain(application_modules.object_file_paths())
+                .cloned()
                 .collect::<Vec<_>>(),
-            application_target.name(),
+            &FilePath::new(&[application_target.name()]),
         )?;
 
         Ok(())
@@ -144,13 +184,9 @@ impl<'a> MainPackageBuilder<'a> {
     ) -> Result<(), Box<dyn std::error::Error>> {
         let (_, prelude_module_interfaces) = self.prelude_package_builder.build()?;
 
-        let external_package_configurations = self.external_packages_downloader.download(
-            &package_configuration
-                .build_configuration()
-                .dependencies()
-                .iter()
-                .collect::<Vec<_>>(),
-        )?;
+        let external_package_configurations = self
+            .external_packages_downloader
+            .download_dependencies(package_configuration)?;
 
         let (_, external_module_interfaces) = self
             .external_packages_builder
@@ -1,30 +1,56 @@
+mod application_modules;
 mod cached_external_package_downloader;
+mod dependency_interfaces_builder;
+mod emission;
 mod error;
 mod external_packages_builder;
 mod external_packages_downloader;
 mod main_package_builder;
+mod module_compilation;
 mod module_compiler;
+mod module_emitter;
 mod module_parser;
 mod modules_builder;
 mod modules_finder;
+mod object_cache_manager;
 mod package_builder;
+mod package_checker;
+mod package_cleaner;
 mod package_configuration_reader;
+mod package_lock_manager;
+mod package_modules;
+mod package_updater;
 mod prelude_package_builder;
+mod profile_reader;
 mod system_package_builder;
 mod system_package_configuration;
+mod toolchain_configuration_reader;
 mod utilities;
 
+pub use application_modules::*;
 pub use cached_external_package_downloader::*;
+pub use dependency_interfaces_builder::*;
+pub use emission::*;
 pub use error::*;
 pub use external_packages_builder::*;
 pub use external_packages_downloader::*;
 pub use main_package_builder::*;
+pub use module_compilation::*;
 pub use module_compiler::*;
+pub use module_emitter::*;
 pub use module_parser::*;
 pub use modules_builder::*;
 pub use modules_finder::*;
+pub use object_cache_manager::*;
 pub use package_builder::*;
+pub use package_checker::*;
+pub use package_cleaner::*;
 pub use package_configuration_reader::*;
+pub use package_lock_manager::*;
+pub use package_modules::*;
+pub use package_updater::*;
 pub use prelude_package_builder::*;
+pub use profile_reader::*;
 pub use system_package_builder::*;
 pub use system_package_configuration::*;
+pub use toolchain_configuration_reader::*;
@@ -0,0 +1,77 @@
+use crate::common::FilePath;
+use std::sync::Arc;
+
+// Module compilations own everything needed to generate code so that they can
+// be run on other threads.
+pub struct ModuleCompilation {
+    module: lang::UnresolvedModule,
+    module_path: lang::ModulePath,
+    imports: Vec<lang::Import>,
+    compile_configuration: Arc<lang::CompileConfiguration>,
+    object_file_path: FilePath,
+    interface_file_path: FilePath,
+    emission_file_path: Option<FilePath>,
+    intermediate_representations: Vec<lang::IntermediateRepresentation>,
+}
+
+impl ModuleCompilation {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        module: lang::UnresolvedModule,
+        module_path: lang::ModulePath,
+        imports: Vec<lang::Import>,
+        compile_configuration: Arc<lang::CompileConfiguration>,
+        object_file_path: FilePath,
+        interface_file_path: FilePath,
+        emission_file_path: Option<FilePath>,
+        intermediate_representations: Vec<lang::IntermediateRepresentation>,
+    ) -> Self {
+        Self {
+            module,
+            module_path,
+            imports,
+            compile_configuration,
+            object_file_path,
+            interface_file_path,
+            emission_file_path,
+            intermediate_representations,
+        }
+    }
+
+    pub fn module_path(&self) -> &lang::ModulePath {
+        &self.module_path
+    }
+
+    pub fn object_file_path(&self) -> &FilePath {
+        &self.object_file_path
+    }
+
+    pub fn interface_file_path(&self) -> &FilePath {
+        &self.interface_file_path
+    }
+
+    pub fn emission_file_path(&self) -> Option<&FilePath> {
+        self.emission_file_path.as_ref()
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub fn run(
+        &self,
+    ) -> Result<
+        (
+            Vec<u8>,
+            lang::ModuleInterface,
+            Vec<(lang::IntermediateRepresentation, String)>,
+        ),
+        lang::CompileError,
+    > {
+        lang::compile_with_intermediate_representations(
+            &self
+                .module
+                .clone()
+                .resolve(self.module_path.clone(), self.imports.clone()),
+            self.compile_configuration.clone(),
+            &self.intermediate_representations,
+        )
+    }
+}
@@ -1,38 +1,55 @@
-use super::{error::BuildError, module_parser::ModuleParser};
+use super::{
+    error::BuildError, module_compilation::ModuleCompilation, module_emitter::ModuleEmitter,
+    module_parser::ModuleParser, object_cache_manager::ObjectCacheManager,
+};
 use crate::{
-    common::{FilePath, FilePathConfiguration, FilePathResolver, PackageConfiguration, Target},
+    common::{
+        FilePath, FilePathConfiguration, FilePathResolver, PackageConfiguration, Profile, Target,
+    },
     infra::{FileSystem, Logger},
 };
-use std::{
-    collections::{hash_map::DefaultHasher, HashMap},
-    hash::{Hash, Hasher},
-    sync::Arc,
-};
+use sha2::{Digest, Sha256};
+use std::{collections::HashMap, sync::Arc};
 
 pub struct ModuleCompiler<'a> {
     module_parser: &'a ModuleParser<'a>,
     file_path_resolver: &'a FilePathResolver<'a>,
     file_system: &'a dyn FileSystem,
+    object_cache_manager: &'a ObjectCacheManager<'a>,
+    module_emitter: &'a ModuleEmitter<'a>,
     logger: &'a dyn Logger,
     compile_configuration: Arc<lang::CompileConfiguration>,
     file_path_configuration: &'a FilePathConfiguration,
 }
 
 impl<'a> ModuleCompiler<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         module_parser: &'a ModuleParser<'a>,
         file_path_resolver: &'a FilePathResolver<'a>,
         file_system: &'a dyn FileSystem,
+        object_cache_manager: &'a ObjectCacheManager<'a>,
+        module_emitter: &'a ModuleEmitter<'a>,
         logger: &'a dyn Logger,
         compile_configuration: Arc<lang::CompileConfiguration>,
+        profile: &'a Profile,
         file_path_configuration: &'a FilePathConfiguration,
     ) -> Self {
+        // Only options of profiles which change generated code are folded into
+        // compile configurations and hence module IDs. Optimization levels and
+        // link arguments are applied on linking.
+        let mut compile_configuration = compile_configuration.as_ref().clone();
+        compile_configuration.assertions = profile.assertions();
+        compile_configuration.debug_information = profile.debug_information();
+
         Self {
             module_parser,
             file_path_resolver,
             file_system,
+            object_cache_manager,
+            module_emitter,
             logger,
-            compile_configuration,
+            compile_configuration: compile_configuration.into(),
             file_path_configuration,
         }
     }
@@ -44,33 +61,52 @@ impl<'a> ModuleCompiler<'a> {
         prelude_module_interfaces: &[lang::ModuleInterface],
         package_configuration: &PackageConfiguration,
     ) -> Result<(FilePath, FilePath), Box<dyn std::error::Error>> {
-        let source = self.file_system.read_to_string(source_file_path)?;
-        let module = self.module_parser.parse(&source, source_file_path)?;
+        self.compile_with_main_module_configuration(
+            source_file_path,
+            module_interfaces,
+            prelude_module_interfaces,
+            package_configuration,
+            None,
+        )
+    }
 
-        let imported_module_interfaces = module
-            .imports()
-            .iter()
-            .map(|import| {
-                Ok(module_interfaces
-                    .get(import.module_path())
-                    .ok_or(BuildError::ModuleNotFound {
-                        module_path: import.module_path().clone(),
-                        source_file_path: source_file_path.clone(),
-                    })?
-                    .clone())
-            })
-            .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?;
+    // Main modules compiled by this method are not the ones of packages but
+    // generated ones, such as test harnesses.
+    pub fn compile_main_module(
+        &self,
+        source_file_path: &FilePath,
+        module_interfaces: &HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        package_configuration: &PackageConfiguration,
+        main_module_configuration: Arc<lang::MainModuleConfiguration>,
+    ) -> Result<(FilePath, FilePath), Box<dyn std::error::Error>> {
+        self.compile_with_main_module_configuration(
+            source_file_path,
+            module_interfaces,
+            prelude_module_interfaces,
+            package_configuration,
+            Some(main_module_configuration),
+        )
+    }
 
-        let module_id =
-            self.generate_module_id(source_file_path, &source, &imported_module_interfaces);
-        let object_file_path = self.file_path_resolver.resolve_object_file_path(&module_id);
-        let interface_file_path = self
-            .file_path_resolver
-            .resolve_interface_file_path(&module_id);
+    // Modules are only type-checked without code generation.
+    pub fn check(
+        &self,
+        source_file_path: &FilePath,
+        module_interfaces: &HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        package_configuration: &PackageConfiguration,
+    ) -> Result<lang::ModuleInterface, Box<dyn std::error::Error>> {
+        let module = self.module_parser.parse(
+            &self.file_system.read_to_string(source_file_path)?,
+            source_file_path,
+        )?;
 
-        if self.file_system.exists(&object_file_path) {
-            return Ok((object_file_path, interface_file_path));
-        }
+        let imports = self.create_imports(
+            &module,
+            self.find_imported_module_interfaces(&module, module_interfaces, source_file_path)?,
+            prelude_module_interfaces,
+        );
 
         let module_path = self.file_path_resolver.resolve_module_path(
             &source_file_path.relative_to(package_configuration.directory_path()),
@@ -78,61 +114,266 @@ impl<'a> ModuleCompiler<'a> {
         );
 
         self.logger.log(&format!(
-            "compiling module {}",
+            "checking module {}",
             &module_path.external_unresolved()
         ))?;
 
-        let (module_object_data, module_interface) =
-            lang::compile(
-                &module.resolve(
-                    module_path.clone(),
-                    imported_module_interfaces
-                        .into_iter()
-                        .map(|module_interface| lang::Import::new(module_interface, true))
-                        .chain(prelude_module_interfaces.iter().map(|module_interface| {
-                            lang::Import::new(module_interface.clone(), false)
-                        }))
-                        .collect(),
-                ),
-                // TODO Refactor this by creating the following classes.
-                // - MainModuleCompiler
-                // - ApplicationPackageBuilder
-                // - LibraryPackageBuilder
-                if self.is_main_module(&module_path, package_configuration) {
-                    self.compile_configuration.clone()
-                } else {
-                    let mut configuration = self.compile_configuration.as_ref().clone();
-                    configuration.main_module_configuration = None;
-                    configuration.into()
-                },
-            )?;
+        Ok(lang::check(
+            &module.resolve(module_path.clone(), imports),
+            self.create_compile_configuration(&module_path, package_configuration, None),
+        )?)
+    }
+
+    // Modules are prepared for compilation on a current thread while their
+    // compilations can be run on any threads. No compilation is returned if
+    // a module is already compiled.
+    pub fn prepare(
+        &self,
+        source_file_path: &FilePath,
+        module_interfaces: &HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        package_configuration: &PackageConfiguration,
+    ) -> Result<(FilePath, FilePath, Option<ModuleCompilation>), Box<dyn std::error::Error>> {
+        self.prepare_with_main_module_configuration(
+            source_file_path,
+            module_interfaces,
+            prelude_module_interfaces,
+            package_configuration,
+            None,
+        )
+    }
 
+    pub fn finish(
+        &self,
+        module_compilation: &ModuleCompilation,
+        module_object_data: &[u8],
+        module_interface: &lang::ModuleInterface,
+        intermediate_representations: &[(lang::IntermediateRepresentation, String)],
+    ) -> Result<(), Box<dyn std::error::Error>> {
         self.file_system
-            .write(&object_file_path, &module_object_data)?;
+            .write(module_compilation.object_file_path(), module_object_data)?;
         self.file_system.write(
-            &interface_file_path,
-            serde_json::to_string(&module_interface)?.as_bytes(),
+            module_compilation.interface_file_path(),
+            serde_json::to_string(module_interface)?.as_bytes(),
         )?;
 
+        if let Some(emission_file_path) = module_compilation.emission_file_path() {
+            self.module_emitter.emit(
+                emission_file_path,
+                module_compilation.object_file_path(),
+                intermediate_representations,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn compile_with_main_module_configuration(
+        &self,
+        source_file_path: &FilePath,
+        module_interfaces: &HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        package_configuration: &PackageConfiguration,
+        main_module_configuration: Option<Arc<lang::MainModuleConfiguration>>,
+    ) -> Result<(FilePath, FilePath), Box<dyn std::error::Error>> {
+        let (object_file_path, interface_file_path, module_compilation) = self
+            .prepare_with_main_module_configuration(
+                source_file_path,
+                module_interfaces,
+                prelude_module_interfaces,
+                package_configuration,
+                main_module_configuration,
+            )?;
+
+        if let Some(module_compilation) = module_compilation {
+            self.logger.log(&format!(
+                "compiling module {}",
+                &module_compilation.module_path().external_unresolved()
+            ))?;
+
+            let (module_object_data, module_interface, intermediate_representations) =
+                module_compilation.run()?;
+
+            self.finish(
+                &module_compilation,
+                &module_object_data,
+                &module_interface,
+                &intermediate_representations,
+            )?;
+        }
+
         Ok((object_file_path, interface_file_path))
     }
 
-    fn generate_module_id<'b>(
+    fn prepare_with_main_module_configuration(
         &self,
         source_file_path: &FilePath,
-        source: &str,
-        imported_module_interfaces: impl IntoIterator<Item = &'b lang::ModuleInterface>,
-    ) -> String {
-        let mut hasher = DefaultHasher::new();
+        module_interfaces: &HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        package_configuration: &PackageConfiguration,
+        main_module_configuration: Option<Arc<lang::MainModuleConfiguration>>,
+    ) -> Result<(FilePath, FilePath, Option<ModuleCompilation>), Box<dyn std::error::Error>> {
+        let source = self.file_system.read_to_string(source_file_path)?;
+        let module = self.module_parser.parse(&source, source_file_path)?;
 
-        source_file_path.hash(&mut hasher);
-        source.hash(&mut hasher);
+        let imported_module_interfaces =
+            self.find_imported_module_interfaces(&module, module_interfaces, source_file_path)?;
 
-        for module_interface in imported_module_interfaces {
-            module_interface.hash(&mut hasher);
+        let module_path = self.file_path_resolver.resolve_module_path(
+            &source_file_path.relative_to(package_configuration.directory_path()),
+            package_configuration.package(),
+        );
+        let compile_configuration = self.create_compile_configuration(
+            &module_path,
+            package_configuration,
+            main_module_configuration,
+        );
+
+        let module_id = self.generate_module_id(
+            source_file_path,
+            &source,
+            &imported_module_interfaces,
+            prelude_module_interfaces,
+            &compile_configuration,
+        )?;
+        let object_file_path = self.file_path_resolver.resolve_object_file_path(&module_id);
+        let interface_file_path = self
+            .file_path_resolver
+            .resolve_interface_file_path(&module_id);
+
+        self.object_cache_manager.reference(&module_id);
+
+        let emission_file_path = self.module_emitter.resolve_emission_file_path(
+            &source_file_path.relative_to(package_configuration.directory_path()),
+            package_configuration,
+        );
+
+        // Modules are compiled again on emission as intermediate representations
+        // are not cached.
+        if self.file_system.exists(&object_file_path) && emission_file_path.is_none() {
+            return Ok((object_file_path, interface_file_path, None));
         }
 
-        format!("{:x}", hasher.finish())
+        let imports = self.create_imports(
+            &module,
+            imported_module_interfaces,
+            prelude_module_interfaces,
+        );
+
+        Ok((
+            object_file_path.clone(),
+            interface_file_path.clone(),
+            Some(ModuleCompilation::new(
+                module,
+                module_path,
+                imports,
+                compile_configuration,
+                object_file_path,
+                interface_file_path,
+                emission_file_path,
+                self.module_emitter.intermediate_representations(),
+            )),
+        ))
+    }
+
+    fn find_imported_module_interfaces(
+        &self,
+        module: &lang::UnresolvedModule,
+        module_interfaces: &HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
+        source_file_path: &FilePath,
+    ) -> Result<Vec<lang::ModuleInterface>, Box<dyn std::error::Error>> {
+        module
+            .imports()
+            .iter()
+            .map(|import| {
+                Ok(module_interfaces
+                    .get(import.module_path())
+                    .ok_or(BuildError::ModuleNotFound {
+                        module_path: import.module_path().clone(),
+                        source_file_path: source_file_path.clone(),
+                    })?
+                    .clone())
+            })
+            .collect()
+    }
+
+    fn create_imports(
+        &self,
+        module: &lang::UnresolvedModule,
+        imported_module_interfaces: Vec<lang::ModuleInterface>,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+    ) -> Vec<lang::Import> {
+        module
+            .imports()
+            .iter()
+            .zip(imported_module_interfaces)
+            .map(|(import, module_interface)| {
+                lang::Import::with_alias_and_names(
+                    module_interface,
+                    true,
+                    import.alias().map(String::from),
+                    import.names().to_vec(),
+                    import.source_information().clone(),
+                )
+            })
+            .chain(
+                prelude_module_interfaces
+                    .iter()
+                    .map(|module_interface| lang::Import::new(module_interface.clone(), false)),
+            )
+            .collect()
+    }
+
+    // TODO Refactor this by creating the following classes.
+    // - MainModuleCompiler
+    // - ApplicationPackageBuilder
+    // - LibraryPackageBuilder
+    fn create_compile_configuration(
+        &self,
+        module_path: &lang::ModulePath,
+        package_configuration: &PackageConfiguration,
+        main_module_configuration: Option<Arc<lang::MainModuleConfiguration>>,
+    ) -> Arc<lang::CompileConfiguration> {
+        if let Some(main_module_configuration) = main_module_configuration {
+            let mut configuration = self.compile_configuration.as_ref().clone();
+            configuration.main_module_configuration = Some(main_module_configuration);
+            configuration.into()
+        } else if self.is_main_module(module_path, package_configuration) {
+            self.compile_configuration.clone()
+        } else {
+            let mut configuration = self.compile_configuration.as_ref().clone();
+            configuration.main_module_configuration = None;
+            configuration.into()
+        }
+    }
+
+    // Module IDs are stable across compiler runs and platforms so that object
+    // files can be reused as long as all inputs of compilation are identical.
+    fn generate_module_id(
+        &self,
+        source_file_path: &FilePath,
+        source: &str,
+        imported_module_interfaces: &[lang::ModuleInterface],
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        compile_configuration: &lang::CompileConfiguration,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let mut hasher = Sha256::new();
+
+        for data in [
+            lang::VERSION.into(),
+            lang::BUILD_ID.into(),
+            serde_json::to_string(compile_configuration)?,
+            format!("{}", source_file_path),
+            source.into(),
+            serialize_module_interfaces(imported_module_interfaces)?,
+            serialize_module_interfaces(prelude_module_interfaces)?,
+        ] {
+            // Lengths are prefixed to avoid collisions between adjacent inputs.
+            hasher.update((data.len() as u64).to_le_bytes());
+            hasher.update(data.as_bytes());
+        }
+
+        Ok(format!("{:x}", hasher.finalize()))
     }
 
     fn is_main_module(
@@ -147,3 +388,76 @@ impl<'a> ModuleCompiler<'a> {
             == vec![self.file_path_configuration.main_file_basename]
     }
 }
+
+// Source information is excluded from serialized module interfaces so that
+// changes of only positions in imported modules do not invalidate object files.
+fn serialize_module_interfaces(
+    module_interfaces: &[lang::ModuleInterface],
+) -> Result<String, serde_json::Error> {
+    let mut value = serde_json::to_value(module_interfaces)?;
+
+    remove_source_informations(&mut value);
+
+    serde_json::to_string(&value)
+}
+
+fn remove_source_informations(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Array(values) => {
+            for value in values {
+                remove_source_informations(value);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            map.remove("source_information");
+            map.remove("variable_source_informations");
+
+            for value in map.values_mut() {
+                remove_source_informations(value);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lang::{debug::*, types};
+
+    fn create_module_interface(source_information: SourceInformation) -> lang::ModuleInterface {
+        lang::ModuleInterface::with_variable_source_informations(
+            lang::ModulePath::new(lang::Package::new("foo", ""), vec!["Foo".into()]),
+            vec!["x".into()].into_iter().collect(),
+            Default::default(),
+            vec![(
+                "x".into(),
+                types::Number::new(source_information.clone()).into(),
+            )]
+            .into_iter()
+            .collect(),
+            Default::default(),
+            vec![("x".into(), source_information.into())]
+                .into_iter()
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn serialize_module_interfaces_without_source_information() {
+        assert_eq!(
+            serialize_module_interfaces(&[create_module_interface(SourceInformation::new(
+                "Foo.ein",
+                Location::new(1, 1),
+                "x = 1",
+            ))])
+            .unwrap(),
+            serialize_module_interfaces(&[create_module_interface(SourceInformation::new(
+                "Foo.ein",
+                Location::new(3, 5),
+                "    x = 1",
+            ))])
+            .unwrap()
+        );
+    }
+}
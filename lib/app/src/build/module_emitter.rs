@@ -0,0 +1,85 @@
+use super::emission::Emission;
+use crate::{
+    common::{FilePath, PackageConfiguration, StaticFilePathManager},
+    infra::{BitCodeConverter, FileSystem},
+};
+
+// Module emitters write intermediate representations of modules in a main
+// package into an emission directory. Paths of emitted files are the ones of
+// source files relative to a package directory with different extensions.
+pub struct ModuleEmitter<'a> {
+    file_system: &'a dyn FileSystem,
+    bit_code_converter: &'a dyn BitCodeConverter,
+    static_file_path_manager: &'a StaticFilePathManager,
+    emissions: &'a [Emission],
+}
+
+impl<'a> ModuleEmitter<'a> {
+    pub fn new(
+        file_system: &'a dyn FileSystem,
+        bit_code_converter: &'a dyn BitCodeConverter,
+        static_file_path_manager: &'a StaticFilePathManager,
+        emissions: &'a [Emission],
+    ) -> Self {
+        Self {
+            file_system,
+            bit_code_converter,
+            static_file_path_manager,
+            emissions,
+        }
+    }
+
+    pub fn resolve_emission_file_path(
+        &self,
+        source_file_path: &FilePath,
+        package_configuration: &PackageConfiguration,
+    ) -> Option<FilePath> {
+        if self.emissions.is_empty() || package_configuration.directory_path() != &FilePath::empty()
+        {
+            return None;
+        }
+
+        Some(
+            self.static_file_path_manager
+                .emission_directory_path()
+                .join(source_file_path),
+        )
+    }
+
+    pub fn intermediate_representations(&self) -> Vec<lang::IntermediateRepresentation> {
+        self.emissions
+            .iter()
+            .filter_map(Emission::intermediate_representation)
+            .collect()
+    }
+
+    pub fn emit(
+        &self,
+        emission_file_path: &FilePath,
+        object_file_path: &FilePath,
+        intermediate_representations: &[(lang::IntermediateRepresentation, String)],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        for emission in self.emissions {
+            let file_path = emission_file_path.with_extension(emission.file_extension());
+
+            match emission {
+                Emission::LlvmIr => self
+                    .bit_code_converter
+                    .convert_to_llvm_ir(object_file_path, &file_path)?,
+                Emission::Assembly => self
+                    .bit_code_converter
+                    .convert_to_assembly(object_file_path, &file_path)?,
+                _ => {
+                    if let Some((_, string)) = intermediate_representations
+                        .iter()
+                        .find(|(kind, _)| Some(*kind) == emission.intermediate_representation())
+                    {
+                        self.file_system.write(&file_path, string.as_bytes())?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
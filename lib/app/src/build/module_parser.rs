@@ -18,4 +18,12 @@ impl<'a> ModuleParser<'a> {
     ) -> Result<lang::UnresolvedModule, lang::ParseError> {
         lang::parse(source, &self.file_path_displayer.display(file_path))
     }
+
+    pub fn parse_comments(
+        &self,
+        source: &str,
+        file_path: &FilePath,
+    ) -> Result<Vec<lang::Comment>, lang::ParseError> {
+        lang::parse_comments(source, &self.file_path_displayer.display(file_path))
+    }
 }
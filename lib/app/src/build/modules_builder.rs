@@ -1,13 +1,25 @@
 use super::{
-    error::BuildError, module_compiler::ModuleCompiler, module_parser::ModuleParser,
-    modules_finder::ModulesFinder,
+    error::BuildError, module_compilation::ModuleCompilation, module_compiler::ModuleCompiler,
+    module_parser::ModuleParser, modules_finder::ModulesFinder, package_modules::PackageModules,
 };
 use crate::{
     common::{FilePath, FilePathResolver, PackageConfiguration},
-    infra::FileSystem,
+    infra::{FileSystem, Logger},
+};
+use petgraph::{
+    algo::toposort,
+    graph::{Graph, NodeIndex},
+    Direction,
+};
+use std::{
+    collections::{HashMap, VecDeque},
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Mutex},
+    thread,
 };
-use petgraph::{algo::toposort, graph::Graph};
-use std::collections::HashMap;
+
+// Type inference and code generation recurse deeply into expressions.
+const COMPILATION_THREAD_STACK_SIZE: usize = 64 * 1024 * 1024;
 
 pub struct ModulesBuilder<'a> {
     module_parser: &'a ModuleParser<'a>,
@@ -15,6 +27,8 @@ pub struct ModulesBuilder<'a> {
     modules_finder: &'a ModulesFinder<'a>,
     file_system: &'a dyn FileSystem,
     file_path_resolver: &'a FilePathResolver<'a>,
+    logger: &'a dyn Logger,
+    jobs: usize,
 }
 
 impl<'a> ModulesBuilder<'a> {
@@ -24,6 +38,8 @@ impl<'a> ModulesBuilder<'a> {
         modules_finder: &'a ModulesFinder<'a>,
         file_system: &'a dyn FileSystem,
         file_path_resolver: &'a FilePathResolver<'a>,
+        logger: &'a dyn Logger,
+        jobs: usize,
     ) -> Self {
         Self {
             module_parser,
@@ -31,10 +47,302 @@ impl<'a> ModulesBuilder<'a> {
             modules_finder,
             file_system,
             file_path_resolver,
+            logger,
+            jobs,
         }
     }
 
+    // Modules whose imported modules are compiled are compiled concurrently
+    // across packages. Packages must be sorted topologically. Logs are written
+    // in the same order as modules are sorted so that they are deterministic.
+    // On failure, the error of the first module in the order is returned.
+    #[allow(clippy::type_complexity)]
     pub fn build(
+        &self,
+        package_modules: &[PackageModules],
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        test_modules_included: bool,
+    ) -> Result<Vec<(Vec<FilePath>, Vec<FilePath>)>, Box<dyn std::error::Error>> {
+        let mut module_interfaces = package_modules
+            .iter()
+            .map(|package_modules| {
+                package_modules
+                    .external_module_interfaces()
+                    .iter()
+                    .map(|(path, module_interface)| (path.clone().into(), module_interface.clone()))
+                    .collect::<HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>>()
+            })
+            .collect::<Vec<_>>();
+
+        let source_file_paths = package_modules
+            .iter()
+            .map(|package_modules| {
+                let directory_path = package_modules.package_configuration().directory_path();
+
+                if test_modules_included {
+                    self.modules_finder.find(directory_path)
+                } else {
+                    self.modules_finder.find_without_tests(directory_path)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let graphs = package_modules
+            .iter()
+            .zip(&source_file_paths)
+            .map(|(package_modules, source_file_paths)| {
+                self.create_module_graph(source_file_paths, package_modules.package_configuration())
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut nodes = vec![];
+        let mut offsets = vec![];
+
+        for (package, graph) in graphs.iter().enumerate() {
+            offsets.push(nodes.len());
+            nodes.extend(
+                self.sort_modules(graph)?
+                    .into_iter()
+                    .map(|index| (package, index)),
+            );
+        }
+
+        let positions = nodes
+            .iter()
+            .enumerate()
+            .map(|(position, &node)| (node, position))
+            .collect::<HashMap<_, _>>();
+
+        let mut dependency_counts = nodes
+            .iter()
+            .map(|&(package, index)| {
+                graphs[package]
+                    .neighbors_directed(index, Direction::Incoming)
+                    .count()
+            })
+            .collect::<Vec<_>>();
+        let mut package_dependency_counts = package_modules
+            .iter()
+            .map(|package_modules| package_modules.dependencies().len())
+            .collect::<Vec<_>>();
+        let mut package_dependents = vec![vec![]; package_modules.len()];
+
+        for (package, package_modules) in package_modules.iter().enumerate() {
+            for &dependency in package_modules.dependencies() {
+                package_dependents[dependency].push(package);
+            }
+        }
+
+        let mut remaining_counts = graphs
+            .iter()
+            .map(|graph| graph.node_count())
+            .collect::<Vec<_>>();
+        let mut package_module_interfaces =
+            vec![Vec::<lang::ModuleInterface>::new(); package_modules.len()];
+        let mut ready_packages = (0..package_modules.len())
+            .filter(|&package| package_dependency_counts[package] == 0)
+            .collect::<VecDeque<_>>();
+        let mut ready_positions = VecDeque::new();
+        let mut completions = VecDeque::new();
+        let mut file_paths = vec![None; nodes.len()];
+        let mut messages = vec![None::<Option<String>>; nodes.len()];
+        let mut logged_count = 0;
+        let mut first_error = None;
+
+        let (job_sender, job_receiver) = mpsc::channel::<(usize, ModuleCompilation)>();
+        let job_receiver = Mutex::new(job_receiver);
+        let (result_sender, result_receiver) = mpsc::channel();
+
+        thread::scope(|scope| -> Result<(), Box<dyn std::error::Error>> {
+            // Workers stop when the job sender is dropped on return.
+            let job_sender = job_sender;
+
+            for _ in 0..self.jobs.max(1).min(nodes.len()) {
+                let job_receiver = &job_receiver;
+                let result_sender = result_sender.clone();
+
+                thread::Builder::new()
+                    .stack_size(COMPILATION_THREAD_STACK_SIZE)
+                    .spawn_scoped(scope, move || loop {
+                        let job = job_receiver.lock().unwrap().recv();
+                        let (position, module_compilation) = match job {
+                            Ok(job) => job,
+                            Err(_) => break,
+                        };
+                        let result =
+                            panic::catch_unwind(AssertUnwindSafe(|| module_compilation.run()));
+
+                        if result_sender
+                            .send((position, module_compilation, result))
+                            .is_err()
+                        {
+                            break;
+                        }
+                    })?;
+            }
+
+            let mut running_count = 0;
+
+            loop {
+                while let Some(package) = ready_packages.pop_front() {
+                    for &dependency in package_modules[package].dependencies() {
+                        for module_interface in &package_module_interfaces[dependency] {
+                            module_interfaces[package].insert(
+                                module_interface.path().external_unresolved().into(),
+                                module_interface.clone(),
+                            );
+                        }
+                    }
+
+                    if remaining_counts[package] == 0 {
+                        for &dependent in &package_dependents[package] {
+                            package_dependency_counts[dependent] -= 1;
+
+                            if package_dependency_counts[dependent] == 0 {
+                                ready_packages.push_back(dependent);
+                            }
+                        }
+                    }
+
+                    ready_positions.extend(
+                        (offsets[package]..offsets[package] + graphs[package].node_count())
+                            .filter(|&position| dependency_counts[position] == 0),
+                    );
+                }
+
+                while let Some(position) = ready_positions.pop_front() {
+                    // Modules after a failed one are not compiled.
+                    if is_after_error(&first_error, position) {
+                        continue;
+                    }
+
+                    let (package, index) = nodes[position];
+
+                    match self.module_compiler.prepare(
+                        graphs[package][index],
+                        &module_interfaces[package],
+                        prelude_module_interfaces,
+                        package_modules[package].package_configuration(),
+                    ) {
+                        Ok((object_file_path, interface_file_path, Some(module_compilation))) => {
+                            job_sender.send((position, module_compilation))?;
+                            running_count += 1;
+
+                            file_paths[position] = Some((object_file_path, interface_file_path));
+                        }
+                        Ok((object_file_path, interface_file_path, None)) => {
+                            match self.read_module_interface(&interface_file_path) {
+                                Ok(module_interface) => {
+                                    completions.push_back((position, module_interface, None))
+                                }
+                                Err(error) => record_error(&mut first_error, position, error),
+                            }
+
+                            file_paths[position] = Some((object_file_path, interface_file_path));
+                        }
+                        Err(error) => record_error(&mut first_error, position, error),
+                    }
+                }
+
+                if let Some((position, module_interface, message)) = completions.pop_front() {
+                    let (package, index) = nodes[position];
+
+                    module_interfaces[package].insert(
+                        module_interface.path().internal_unresolved().into(),
+                        module_interface.clone(),
+                    );
+                    package_module_interfaces[package].push(module_interface);
+                    messages[position] = Some(message);
+
+                    while let Some(message) = messages.get_mut(logged_count).and_then(Option::take)
+                    {
+                        if let Some(message) = message {
+                            self.logger.log(&message)?;
+                        }
+
+                        logged_count += 1;
+                    }
+
+                    for index in graphs[package].neighbors_directed(index, Direction::Outgoing) {
+                        let position = positions[&(package, index)];
+
+                        dependency_counts[position] -= 1;
+
+                        if dependency_counts[position] == 0 {
+                            ready_positions.push_back(position);
+                        }
+                    }
+
+                    remaining_counts[package] -= 1;
+
+                    if remaining_counts[package] == 0 {
+                        for &dependent in &package_dependents[package] {
+                            package_dependency_counts[dependent] -= 1;
+
+                            if package_dependency_counts[dependent] == 0 {
+                                ready_packages.push_back(dependent);
+                            }
+                        }
+                    }
+
+                    continue;
+                }
+
+                if running_count == 0 {
+                    break;
+                }
+
+                let (position, module_compilation, result) = result_receiver.recv()?;
+                running_count -= 1;
+
+                match match result {
+                    Ok(result) => result.map_err(Box::from).and_then(
+                        |(module_object_data, module_interface, intermediate_representations)| {
+                            self.module_compiler.finish(
+                                &module_compilation,
+                                &module_object_data,
+                                &module_interface,
+                                &intermediate_representations,
+                            )?;
+
+                            Ok(module_interface)
+                        },
+                    ),
+                    Err(payload) => panic::resume_unwind(payload),
+                } {
+                    Ok(module_interface) => completions.push_back((
+                        position,
+                        module_interface,
+                        Some(format!(
+                            "compiling module {}",
+                            module_compilation.module_path().external_unresolved()
+                        )),
+                    )),
+                    Err(error) => record_error(&mut first_error, position, error),
+                }
+            }
+
+            Ok(())
+        })?;
+
+        if let Some((_, error)) = first_error {
+            return Err(error);
+        }
+
+        let mut file_paths = file_paths.into_iter();
+
+        Ok(graphs
+            .iter()
+            .map(|graph| {
+                file_paths
+                    .by_ref()
+                    .take(graph.node_count())
+                    .flatten()
+                    .unzip()
+            })
+            .collect())
+    }
+
+    pub fn check(
         &self,
         package_configuration: &PackageConfiguration,
         external_module_interfaces: &HashMap<
@@ -42,14 +350,13 @@ impl<'a> ModulesBuilder<'a> {
             lang::ModuleInterface,
         >,
         prelude_module_interfaces: &[lang::ModuleInterface],
-    ) -> Result<(Vec<FilePath>, Vec<FilePath>), Box<dyn std::error::Error>> {
+    ) -> Result<Vec<lang::ModuleInterface>, Box<dyn std::error::Error>> {
         let mut module_interfaces = external_module_interfaces
             .iter()
             .map(|(path, module_interface)| (path.clone().into(), module_interface.clone()))
             .collect::<HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>>();
 
-        let mut object_file_paths = vec![];
-        let mut interface_file_paths = vec![];
+        let mut checked_module_interfaces = vec![];
 
         for source_file_path in self.sort_source_file_paths(
             &self
@@ -57,26 +364,30 @@ impl<'a> ModulesBuilder<'a> {
                 .find(package_configuration.directory_path())?,
             package_configuration,
         )? {
-            let (object_file_path, interface_file_path) = self.module_compiler.compile(
+            let module_interface = self.module_compiler.check(
                 source_file_path,
                 &module_interfaces,
                 prelude_module_interfaces,
                 package_configuration,
             )?;
 
-            let module_interface = serde_json::from_str::<lang::ModuleInterface>(
-                &self.file_system.read_to_string(&interface_file_path)?,
-            )?;
             module_interfaces.insert(
                 module_interface.path().internal_unresolved().into(),
-                module_interface,
+                module_interface.clone(),
             );
-
-            object_file_paths.push(object_file_path);
-            interface_file_paths.push(interface_file_path);
+            checked_module_interfaces.push(module_interface);
         }
 
-        Ok((object_file_paths, interface_file_paths))
+        Ok(checked_module_interfaces)
+    }
+
+    fn read_module_interface(
+        &self,
+        interface_file_path: &FilePath,
+    ) -> Result<lang::ModuleInterface, Box<dyn std::error::Error>> {
+        Ok(serde_json::from_str(
+            &self.file_system.read_to_string(interface_file_path)?,
+        )?)
     }
 
     fn sort_source_file_paths<'b>(
@@ -84,6 +395,30 @@ impl<'a> ModulesBuilder<'a> {
         source_file_paths: &'b [FilePath],
         package_configuration: &PackageConfiguration,
     ) -> Result<Vec<&'b FilePath>, Box<dyn std::error::Error>> {
+        let graph = self.create_module_graph(source_file_paths, package_configuration)?;
+
+        Ok(self
+            .sort_modules(&graph)?
+            .into_iter()
+            .map(|index| graph[index])
+            .collect())
+    }
+
+    fn sort_modules(
+        &self,
+        graph: &Graph<&FilePath, ()>,
+    ) -> Result<Vec<NodeIndex>, Box<dyn std::error::Error>> {
+        Ok(toposort(graph, None).map_err(|cycle| {
+            BuildError::ModuleCircularDependency(graph[cycle.node_id()].clone())
+        })?)
+    }
+
+    // Edges of graphs are directed from imported modules to importing ones.
+    fn create_module_graph<'b>(
+        &self,
+        source_file_paths: &'b [FilePath],
+        package_configuration: &PackageConfiguration,
+    ) -> Result<Graph<&'b FilePath, ()>, Box<dyn std::error::Error>> {
         let mut graph = Graph::<&FilePath, ()>::new();
         let mut indices = HashMap::<&FilePath, _>::new();
 
@@ -114,10 +449,23 @@ impl<'a> ModulesBuilder<'a> {
             }
         }
 
-        Ok(toposort(&graph, None)
-            .map_err(|cycle| BuildError::ModuleCircularDependency(graph[cycle.node_id()].clone()))?
-            .into_iter()
-            .map(|index| graph[index])
-            .collect())
+        Ok(graph)
+    }
+}
+
+fn is_after_error(
+    first_error: &Option<(usize, Box<dyn std::error::Error>)>,
+    position: usize,
+) -> bool {
+    matches!(first_error, Some((error_position, _)) if position > *error_position)
+}
+
+fn record_error(
+    first_error: &mut Option<(usize, Box<dyn std::error::Error>)>,
+    position: usize,
+    error: Box<dyn std::error::Error>,
+) {
+    if !is_after_error(first_error, position) {
+        *first_error = Some((position, error));
     }
 }
@@ -42,4 +42,61 @@ impl<'a> ModulesFinder<'a> {
 
         Ok(source_file_paths)
     }
+
+    // Test modules are built only when packages are tested.
+    pub fn find_without_tests(
+        &self,
+        directory_path: &FilePath,
+    ) -> Result<Vec<FilePath>, Box<dyn std::error::Error>> {
+        Ok(self
+            .find(directory_path)?
+            .into_iter()
+            .filter(|path| !self.is_test_module(path))
+            .collect())
+    }
+
+    fn is_test_module(&self, path: &FilePath) -> bool {
+        path.with_extension("")
+            .components()
+            .last()
+            .map(|component| component.ends_with(self.file_path_configuration.test_module_suffix))
+            .unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::FakeFileSystem;
+
+    const FILE_PATH_CONFIGURATION: FilePathConfiguration = FilePathConfiguration {
+        build_configuration_filename: "ein.json",
+        package_lock_filename: "ein.lock",
+        output_directory_name: ".ein",
+        source_file_extension: "ein",
+        main_file_basename: "Main",
+        test_module_suffix: "Test",
+    };
+
+    #[test]
+    fn find_modules_without_tests() {
+        let file_system = FakeFileSystem::new(
+            vec![
+                (FilePath::new(&["Foo.ein"]), vec![]),
+                (FilePath::new(&["FooTest.ein"]), vec![]),
+                (FilePath::new(&["bar", "BarTest.ein"]), vec![]),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let modules_finder = ModulesFinder::new(&file_system, &FILE_PATH_CONFIGURATION);
+
+        assert_eq!(modules_finder.find(&FilePath::empty()).unwrap().len(), 3);
+        assert_eq!(
+            modules_finder
+                .find_without_tests(&FilePath::empty())
+                .unwrap(),
+            vec![FilePath::new(&["Foo.ein"])]
+        );
+    }
 }
@@ -0,0 +1,206 @@
+use crate::{
+    adaptors::{deserialize_object_cache_manifest, serialize_object_cache_manifest},
+    common::{ObjectCacheManifest, StaticFilePathManager},
+    infra::FileSystem,
+};
+use std::{collections::BTreeSet, sync::Mutex};
+
+const HOST_TARGET_NAME: &str = "host";
+
+// Object cache managers track modules referenced by a current build and
+// remove object and interface files which no build target refers to anymore.
+// Build targets are distinguished by profiles and target triples so that
+// switching them does not remove objects of each other.
+pub struct ObjectCacheManager<'a> {
+    file_system: &'a dyn FileSystem,
+    static_file_path_manager: &'a StaticFilePathManager,
+    profile_name: &'a str,
+    target_triple: Option<&'a str>,
+    module_ids: Mutex<BTreeSet<String>>,
+}
+
+impl<'a> ObjectCacheManager<'a> {
+    pub fn new(
+        file_system: &'a dyn FileSystem,
+        static_file_path_manager: &'a StaticFilePathManager,
+        profile_name: &'a str,
+        target_triple: Option<&'a str>,
+    ) -> Self {
+        Self {
+            file_system,
+            static_file_path_manager,
+            profile_name,
+            target_triple,
+            module_ids: Default::default(),
+        }
+    }
+
+    pub fn reference(&self, module_id: &str) {
+        self.module_ids.lock().unwrap().insert(module_id.into());
+    }
+
+    pub fn collect_garbage(&self, target_name: &str) -> Result<(), Box<dyn std::error::Error>> {
+        let object_directory_path = self.static_file_path_manager.object_directory_path();
+        let manifest_file_path = self
+            .static_file_path_manager
+            .object_cache_manifest_file_path();
+
+        if !self.file_system.is_directory(object_directory_path) {
+            return Ok(());
+        }
+
+        let mut manifest = self.read_manifest()?;
+        manifest.insert(
+            format!(
+                "{}:{}:{}",
+                target_name,
+                self.profile_name,
+                self.target_triple.unwrap_or(HOST_TARGET_NAME)
+            ),
+            self.module_ids.lock().unwrap().clone(),
+        );
+
+        for file_path in self.file_system.read_directory(object_directory_path)? {
+            if &file_path == manifest_file_path {
+                continue;
+            }
+
+            let module_id = file_path
+                .components()
+                .last()
+                .and_then(|name| name.split('.').next())
+                .unwrap_or_default();
+
+            if !manifest.is_referenced(module_id) {
+                self.file_system.remove_file(&file_path)?;
+            }
+        }
+
+        self.file_system.write(
+            manifest_file_path,
+            serialize_object_cache_manifest(&manifest)?.as_bytes(),
+        )
+    }
+
+    // Manifests written by other compiler versions are discarded as their
+    // module IDs never match ones generated by a current compiler.
+    fn read_manifest(&self) -> Result<ObjectCacheManifest, Box<dyn std::error::Error>> {
+        let file_path = self
+            .static_file_path_manager
+            .object_cache_manifest_file_path();
+
+        if self.file_system.exists(file_path) {
+            let manifest =
+                deserialize_object_cache_manifest(&self.file_system.read_to_string(file_path)?)?;
+
+            if manifest.compiler_version() == lang::VERSION {
+                return Ok(manifest);
+            }
+        }
+
+        Ok(ObjectCacheManifest::new(lang::VERSION, Default::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::{FilePath, FilePathConfiguration},
+        infra::FakeFileSystem,
+    };
+
+    const FILE_PATH_CONFIGURATION: FilePathConfiguration = FilePathConfiguration {
+        build_configuration_filename: "ein.json",
+        package_lock_filename: "ein.lock",
+        output_directory_name: ".ein",
+        source_file_extension: "ein",
+        main_file_basename: "Main",
+        test_module_suffix: "Test",
+    };
+
+    fn object_file_path(file_name: &str) -> FilePath {
+        FilePath::new(&[".ein", "objects", file_name])
+    }
+
+    #[test]
+    fn collect_garbage() {
+        let file_system = FakeFileSystem::new(
+            vec![
+                (object_file_path("01ab.bc"), vec![]),
+                (object_file_path("01ab.json"), vec![]),
+                (object_file_path("23cd.bc"), vec![]),
+                (object_file_path("23cd.json"), vec![]),
+            ]
+            .drain(..)
+            .collect(),
+        );
+        let static_file_path_manager = StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
+        let object_cache_manager =
+            ObjectCacheManager::new(&file_system, &static_file_path_manager, "debug", None);
+
+        object_cache_manager.reference("01ab");
+        object_cache_manager.collect_garbage("build").unwrap();
+
+        assert!(file_system.exists(&object_file_path("01ab.bc")));
+        assert!(file_system.exists(&object_file_path("01ab.json")));
+        assert!(!file_system.exists(&object_file_path("23cd.bc")));
+        assert!(!file_system.exists(&object_file_path("23cd.json")));
+        assert!(file_system.exists(&object_file_path("manifest.json")));
+    }
+
+    #[test]
+    fn keep_objects_referenced_by_other_targets() {
+        let file_system = FakeFileSystem::new(
+            vec![
+                (object_file_path("01ab.bc"), vec![]),
+                (object_file_path("23cd.bc"), vec![]),
+            ]
+            .drain(..)
+            .collect(),
+        );
+        let static_file_path_manager = StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
+
+        let object_cache_manager =
+            ObjectCacheManager::new(&file_system, &static_file_path_manager, "debug", None);
+        object_cache_manager.reference("01ab");
+        object_cache_manager.reference("23cd");
+        object_cache_manager.collect_garbage("test").unwrap();
+
+        let object_cache_manager =
+            ObjectCacheManager::new(&file_system, &static_file_path_manager, "debug", None);
+        object_cache_manager.reference("01ab");
+        object_cache_manager.collect_garbage("build").unwrap();
+
+        assert!(file_system.exists(&object_file_path("01ab.bc")));
+        assert!(file_system.exists(&object_file_path("23cd.bc")));
+    }
+
+    #[test]
+    fn keep_objects_referenced_by_other_profiles_and_targets() {
+        let file_system = FakeFileSystem::new(Default::default());
+        let static_file_path_manager = StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
+
+        for (module_id, profile_name, target_triple) in [
+            ("01ab", "debug", None),
+            ("23cd", "release", None),
+            ("45ef", "release", Some("wasm32-wasi")),
+        ] {
+            let object_cache_manager = ObjectCacheManager::new(
+                &file_system,
+                &static_file_path_manager,
+                profile_name,
+                target_triple,
+            );
+            file_system
+                .write(&object_file_path(&format!("{}.bc", module_id)), &[])
+                .unwrap();
+            object_cache_manager.reference(module_id);
+            object_cache_manager.collect_garbage("build").unwrap();
+        }
+
+        assert!(file_system.exists(&object_file_path("01ab.bc")));
+        assert!(file_system.exists(&object_file_path("23cd.bc")));
+        assert!(file_system.exists(&object_file_path("45ef.bc")));
+    }
+}
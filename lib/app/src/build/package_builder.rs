@@ -1,4 +1,4 @@
-use super::modules_builder::ModulesBuilder;
+use super::{modules_builder::ModulesBuilder, package_modules::PackageModules};
 use crate::{
     common::{ExternalPackage, FilePath, PackageConfiguration},
     infra::{FfiPackageInitializer, FileSystem, Logger},
@@ -36,28 +36,51 @@ impl<'a> PackageBuilder<'a> {
         >,
         prelude_module_interfaces: &[lang::ModuleInterface],
     ) -> Result<(Vec<FilePath>, Vec<lang::ModuleInterface>), Box<dyn std::error::Error>> {
-        self.logger.log(&format!(
-            "building package {} {}",
-            package_configuration.package().name(),
-            package_configuration.package().version()
-        ))?;
+        self.build_package(
+            package_configuration,
+            external_module_interfaces,
+            prelude_module_interfaces,
+            false,
+        )
+    }
 
-        let ffi_object_file_path = if self
-            .ffi_package_initializer
-            .is_ffi_used(package_configuration.directory_path())
-        {
-            self.logger.log(&format!(
-                "building FFI for package {} {}",
-                package_configuration.package().name(),
-                package_configuration.package().version()
-            ))?;
+    pub fn build_with_tests(
+        &self,
+        package_configuration: &PackageConfiguration,
+        external_module_interfaces: &HashMap<
+            ExternalPackage,
+            HashMap<lang::ExternalUnresolvedModulePath, lang::ModuleInterface>,
+        >,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+    ) -> Result<(Vec<FilePath>, Vec<lang::ModuleInterface>), Box<dyn std::error::Error>> {
+        self.build_package(
+            package_configuration,
+            external_module_interfaces,
+            prelude_module_interfaces,
+            true,
+        )
+    }
 
-            self.ffi_package_initializer
-                .initialize(package_configuration.directory_path())?
-        } else {
-            None
-        };
+    // Packages are built concurrently. They must be sorted topologically.
+    #[allow(clippy::type_complexity)]
+    pub fn build_packages(
+        &self,
+        package_modules: &[PackageModules],
+        prelude_module_interfaces: &[lang::ModuleInterface],
+    ) -> Result<Vec<(Vec<FilePath>, Vec<lang::ModuleInterface>)>, Box<dyn std::error::Error>> {
+        self.build_package_modules(package_modules, prelude_module_interfaces, false)
+    }
 
+    fn build_package(
+        &self,
+        package_configuration: &PackageConfiguration,
+        external_module_interfaces: &HashMap<
+            ExternalPackage,
+            HashMap<lang::ExternalUnresolvedModulePath, lang::ModuleInterface>,
+        >,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        test_modules_included: bool,
+    ) -> Result<(Vec<FilePath>, Vec<lang::ModuleInterface>), Box<dyn std::error::Error>> {
         let external_module_interfaces = package_configuration
             .build_configuration()
             .dependencies()
@@ -79,25 +102,82 @@ impl<'a> PackageBuilder<'a> {
             .flatten()
             .collect();
 
-        let (object_file_paths, interface_file_paths) = self.modules_builder.build(
-            package_configuration,
-            &external_module_interfaces,
-            prelude_module_interfaces,
-        )?;
+        Ok(self
+            .build_package_modules(
+                &[PackageModules::new(
+                    package_configuration,
+                    external_module_interfaces,
+                    vec![],
+                )],
+                prelude_module_interfaces,
+                test_modules_included,
+            )?
+            .remove(0))
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn build_package_modules(
+        &self,
+        package_modules: &[PackageModules],
+        prelude_module_interfaces: &[lang::ModuleInterface],
+        test_modules_included: bool,
+    ) -> Result<Vec<(Vec<FilePath>, Vec<lang::ModuleInterface>)>, Box<dyn std::error::Error>> {
+        let mut ffi_object_file_paths = vec![];
+
+        for package_modules in package_modules {
+            let package_configuration = package_modules.package_configuration();
 
-        Ok((
-            object_file_paths
-                .into_iter()
-                .chain(ffi_object_file_path)
-                .collect(),
-            interface_file_paths
-                .iter()
-                .map(|file_path| {
-                    Ok(serde_json::from_slice(
-                        &self.file_system.read_to_vec(file_path)?,
-                    )?)
-                })
-                .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?,
-        ))
+            self.logger.log(&format!(
+                "building package {} {}",
+                package_configuration.package().name(),
+                package_configuration.package().version()
+            ))?;
+
+            ffi_object_file_paths.push(
+                if self
+                    .ffi_package_initializer
+                    .is_ffi_used(package_configuration.directory_path())
+                {
+                    self.logger.log(&format!(
+                        "building FFI for package {} {}",
+                        package_configuration.package().name(),
+                        package_configuration.package().version()
+                    ))?;
+
+                    self.ffi_package_initializer
+                        .initialize(package_configuration.directory_path())?
+                } else {
+                    None
+                },
+            );
+        }
+
+        self.modules_builder
+            .build(
+                package_modules,
+                prelude_module_interfaces,
+                test_modules_included,
+            )?
+            .into_iter()
+            .zip(ffi_object_file_paths)
+            .map(
+                |((object_file_paths, interface_file_paths), ffi_object_file_path)| {
+                    Ok((
+                        object_file_paths
+                            .into_iter()
+                            .chain(ffi_object_file_path)
+                            .collect(),
+                        interface_file_paths
+                            .iter()
+                            .map(|file_path| {
+                                Ok(serde_json::from_slice(
+                                    &self.file_system.read_to_vec(file_path)?,
+                                )?)
+                            })
+                            .collect::<Result<Vec<_>, Box<dyn std::error::Error>>>()?,
+                    ))
+                },
+            )
+            .collect()
     }
 }
@@ -0,0 +1,60 @@
+use super::{
+    dependency_interfaces_builder::DependencyInterfacesBuilder, modules_builder::ModulesBuilder,
+    package_configuration_reader::PackageConfigurationReader,
+};
+use crate::{common::FilePath, infra::Logger};
+
+// Dependencies are built and cached as usual while modules in a main package
+// are only type-checked.
+pub struct PackageChecker<'a> {
+    package_configuration_reader: &'a PackageConfigurationReader<'a>,
+    dependency_interfaces_builder: &'a DependencyInterfacesBuilder<'a>,
+    modules_builder: &'a ModulesBuilder<'a>,
+    logger: &'a dyn Logger,
+}
+
+impl<'a> PackageChecker<'a> {
+    pub fn new(
+        package_configuration_reader: &'a PackageConfigurationReader<'a>,
+        dependency_interfaces_builder: &'a DependencyInterfacesBuilder<'a>,
+        modules_builder: &'a ModulesBuilder<'a>,
+        logger: &'a dyn Logger,
+    ) -> Self {
+        Self {
+            package_configuration_reader,
+            dependency_interfaces_builder,
+            modules_builder,
+            logger,
+        }
+    }
+
+    pub fn check(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let package_configuration = self.package_configuration_reader.read(&FilePath::empty())?;
+
+        let (prelude_module_interfaces, external_module_interfaces) = self
+            .dependency_interfaces_builder
+            .build(&package_configuration)?;
+
+        self.logger.log(&format!(
+            "checking package {} {}",
+            package_configuration.package().name(),
+            package_configuration.package().version()
+        ))?;
+
+        self.modules_builder.check(
+            &package_configuration,
+            &external_module_interfaces
+                .values()
+                .map(|module_interface| {
+                    (
+                        module_interface.path().external_unresolved(),
+                        module_interface.clone(),
+                    )
+                })
+                .collect(),
+            &prelude_module_interfaces,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use crate::{
+    common::StaticFilePathManager,
+    infra::{FileSystem, Logger},
+};
+
+pub struct PackageCleaner<'a> {
+    file_system: &'a dyn FileSystem,
+    logger: &'a dyn Logger,
+    static_file_path_manager: &'a StaticFilePathManager,
+}
+
+impl<'a> PackageCleaner<'a> {
+    pub fn new(
+        file_system: &'a dyn FileSystem,
+        logger: &'a dyn Logger,
+        static_file_path_manager: &'a StaticFilePathManager,
+    ) -> Self {
+        Self {
+            file_system,
+            logger,
+            static_file_path_manager,
+        }
+    }
+
+    // Lock files are kept so that the same commits are downloaded again.
+    pub fn clean(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let directory_path = self
+            .static_file_path_manager
+            .external_packages_directory_path();
+
+        if self.file_system.exists(directory_path) {
+            self.logger.log("removing external packages")?;
+            self.file_system.remove_directory(directory_path)?;
+        }
+
+        Ok(())
+    }
+}
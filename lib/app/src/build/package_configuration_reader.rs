@@ -1,6 +1,10 @@
+use super::error::BuildError;
 use crate::{
     adaptors::deserialize_build_configuration,
-    common::{FilePath, PackageConfiguration, StaticFilePathManager},
+    common::{
+        BuildConfiguration, ExternalPackage, FilePath, PackageConfiguration, StaticFilePathManager,
+        MAX_OPTIMIZATION_LEVEL,
+    },
     infra::{FilePathDisplayer, FileSystem},
 };
 
@@ -47,15 +51,230 @@ impl<'a> PackageConfigurationReader<'a> {
             } else {
                 lang::Package::new(self.file_path_displayer.display(directory_path), "")
             },
-            deserialize_build_configuration(
-                &self.file_system.read_to_string(
-                    &directory_path.join(
-                        self.static_file_path_manager
-                            .build_configuration_file_path(),
-                    ),
-                )?,
-            )?,
+            self.read_build_configuration(directory_path)?,
+            directory_path.clone(),
+        ))
+    }
+
+    // External packages are named after their names in dependencies rather
+    // than their locations so that imports of their modules do not depend on
+    // where they are.
+    pub fn read_with_name(
+        &self,
+        directory_path: &FilePath,
+        package_name: &str,
+    ) -> Result<PackageConfiguration, Box<dyn std::error::Error>> {
+        Ok(PackageConfiguration::new(
+            lang::Package::new(
+                package_name,
+                self.file_system
+                    .read_repository(directory_path)?
+                    .map(|repository| repository.version().to_owned())
+                    .unwrap_or_default(),
+            ),
+            self.read_build_configuration(directory_path)?,
             directory_path.clone(),
         ))
     }
+
+    fn read_build_configuration(
+        &self,
+        directory_path: &FilePath,
+    ) -> Result<BuildConfiguration, Box<dyn std::error::Error>> {
+        let build_configuration = deserialize_build_configuration(
+            &self.file_system.read_to_string(
+                &directory_path.join(
+                    self.static_file_path_manager
+                        .build_configuration_file_path(),
+                ),
+            )?,
+        )?;
+
+        for (name, profile) in build_configuration.profiles() {
+            if profile.optimization_level() > MAX_OPTIMIZATION_LEVEL {
+                return Err(BuildError::OptimizationLevelInvalid {
+                    profile_name: name.clone(),
+                    optimization_level: profile.optimization_level(),
+                }
+                .into());
+            }
+        }
+
+        // Paths of dependencies are relative to their dependent packages.
+        Ok(BuildConfiguration::new(
+            build_configuration.target().clone(),
+            build_configuration
+                .dependencies()
+                .iter()
+                .map(|external_package| {
+                    Ok(match external_package.path() {
+                        Some(path) if is_absolute_path(path) => {
+                            return Err(BuildError::AbsolutePackagePath {
+                                package_name: external_package.name().into(),
+                                path: path.clone(),
+                            })
+                        }
+                        Some(path) => ExternalPackage::with_path(
+                            external_package.name(),
+                            resolve_relative_path(directory_path, path),
+                        ),
+                        None => external_package.clone(),
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+            build_configuration.profiles().clone(),
+            build_configuration.toolchain_configuration().clone(),
+        ))
+    }
+}
+
+// Absolute paths start with empty components as they are split by slashes.
+fn is_absolute_path(path: &FilePath) -> bool {
+    path.components().count() > 1 && path.components().next() == Some("")
+}
+
+fn resolve_relative_path(directory_path: &FilePath, path: &FilePath) -> FilePath {
+    let mut components = Vec::<&str>::new();
+
+    for component in directory_path.components().chain(path.components()) {
+        match component {
+            "" | "." => {}
+            ".." if !components.is_empty() && components.last() != Some(&"..") => {
+                components.pop();
+            }
+            _ => components.push(component),
+        }
+    }
+
+    FilePath::new(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::FilePathConfiguration,
+        infra::{FakeFilePathDisplayer, FakeFileSystem},
+    };
+
+    const FILE_PATH_CONFIGURATION: FilePathConfiguration = FilePathConfiguration {
+        build_configuration_filename: "ein.json",
+        package_lock_filename: "ein.lock",
+        output_directory_name: ".ein",
+        source_file_extension: "ein",
+        main_file_basename: "Main",
+        test_module_suffix: "Test",
+    };
+
+    fn read_package_configuration(
+        build_configuration: &str,
+    ) -> Result<PackageConfiguration, Box<dyn std::error::Error>> {
+        let file_system = FakeFileSystem::new(
+            vec![(
+                FilePath::new(&["foo", "ein.json"]),
+                build_configuration.into(),
+            )]
+            .drain(..)
+            .collect(),
+        );
+        let file_path_displayer = FakeFilePathDisplayer::new();
+        let static_file_path_manager = StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
+
+        PackageConfigurationReader::new(
+            &file_system,
+            &file_path_displayer,
+            &static_file_path_manager,
+        )
+        .read(&FilePath::new(&["foo"]))
+    }
+
+    #[test]
+    fn read_relative_package_path() {
+        assert_eq!(
+            read_package_configuration(
+                r#"{ "dependencies": { "bar": { "version": "", "path": "../bar" } } }"#
+            )
+            .unwrap()
+            .build_configuration()
+            .dependencies(),
+            &vec![ExternalPackage::with_path("bar", FilePath::new(&["bar"]))]
+                .into_iter()
+                .collect()
+        );
+    }
+
+    #[test]
+    fn fail_to_read_absolute_package_path() {
+        assert_eq!(
+            read_package_configuration(
+                r#"{ "dependencies": { "bar": { "version": "", "path": "/abs/lib" } } }"#
+            )
+            .unwrap_err()
+            .downcast_ref::<BuildError>(),
+            Some(&BuildError::AbsolutePackagePath {
+                package_name: "bar".into(),
+                path: "/abs/lib".parse().unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn read_optimization_level() {
+        assert_eq!(
+            read_package_configuration(
+                r#"{ "dependencies": {}, "profiles": { "foo": { "optimizationLevel": 3 } } }"#
+            )
+            .unwrap()
+            .build_configuration()
+            .profiles()["foo"]
+                .optimization_level(),
+            3
+        );
+    }
+
+    #[test]
+    fn fail_to_read_invalid_optimization_level() {
+        assert_eq!(
+            read_package_configuration(
+                r#"{ "dependencies": {}, "profiles": { "foo": { "optimizationLevel": 4 } } }"#
+            )
+            .unwrap_err()
+            .downcast_ref::<BuildError>(),
+            Some(&BuildError::OptimizationLevelInvalid {
+                profile_name: "foo".into(),
+                optimization_level: 4,
+            })
+        );
+    }
+
+    #[test]
+    fn resolve_relative_paths() {
+        assert_eq!(
+            resolve_relative_path(&FilePath::empty(), &FilePath::new(&["foo"])),
+            FilePath::new(&["foo"])
+        );
+        assert_eq!(
+            resolve_relative_path(&FilePath::empty(), &FilePath::new(&["..", "foo"])),
+            FilePath::new(&["..", "foo"])
+        );
+        assert_eq!(
+            resolve_relative_path(&FilePath::new(&["foo"]), &FilePath::new(&[".", "bar", ""])),
+            FilePath::new(&["foo", "bar"])
+        );
+        assert_eq!(
+            resolve_relative_path(
+                &FilePath::new(&["..", "foo"]),
+                &FilePath::new(&["..", "..", "bar"])
+            ),
+            FilePath::new(&["..", "..", "bar"])
+        );
+    }
+
+    #[test]
+    fn check_absolute_paths() {
+        assert!(is_absolute_path(&"/foo/bar".parse().unwrap()));
+        assert!(!is_absolute_path(&"foo/bar".parse().unwrap()));
+        assert!(!is_absolute_path(&"./foo".parse().unwrap()));
+        assert!(!is_absolute_path(&FilePath::empty()));
+    }
 }
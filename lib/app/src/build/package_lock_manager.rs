@@ -0,0 +1,99 @@
+use crate::{
+    adaptors::{deserialize_package_lock, serialize_package_lock},
+    common::{ExternalPackage, PackageLock, StaticFilePathManager},
+    infra::FileSystem,
+};
+
+pub struct PackageLockManager<'a> {
+    file_system: &'a dyn FileSystem,
+    static_file_path_manager: &'a StaticFilePathManager,
+}
+
+impl<'a> PackageLockManager<'a> {
+    pub fn new(
+        file_system: &'a dyn FileSystem,
+        static_file_path_manager: &'a StaticFilePathManager,
+    ) -> Self {
+        Self {
+            file_system,
+            static_file_path_manager,
+        }
+    }
+
+    pub fn read(&self) -> Result<PackageLock, Box<dyn std::error::Error>> {
+        let file_path = self.static_file_path_manager.package_lock_file_path();
+
+        Ok(if self.file_system.exists(file_path) {
+            deserialize_package_lock(&self.file_system.read_to_string(file_path)?)?
+        } else {
+            Default::default()
+        })
+    }
+
+    pub fn write(&self, package_lock: &PackageLock) -> Result<(), Box<dyn std::error::Error>> {
+        self.file_system.write(
+            self.static_file_path_manager.package_lock_file_path(),
+            serialize_package_lock(package_lock)?.as_bytes(),
+        )
+    }
+
+    // Entries of packages other than given ones are removed.
+    pub fn prune(
+        &self,
+        external_packages: &[&ExternalPackage],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let package_lock = self.read()?;
+        let pruned_package_lock = PackageLock::new(
+            package_lock
+                .commit_ids()
+                .iter()
+                .filter(|(external_package, _)| external_packages.contains(external_package))
+                .map(|(external_package, commit_id)| (external_package.clone(), commit_id.clone()))
+                .collect(),
+        );
+
+        if pruned_package_lock != package_lock {
+            self.write(&pruned_package_lock)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{common::FilePathConfiguration, infra::FakeFileSystem};
+
+    const FILE_PATH_CONFIGURATION: FilePathConfiguration = FilePathConfiguration {
+        build_configuration_filename: "ein.json",
+        package_lock_filename: "ein.lock",
+        output_directory_name: ".ein",
+        source_file_extension: "ein",
+        main_file_basename: "Main",
+        test_module_suffix: "Test",
+    };
+
+    #[test]
+    fn prune_package_lock() {
+        let file_system = FakeFileSystem::new(Default::default());
+        let static_file_path_manager = StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
+        let package_lock_manager = PackageLockManager::new(&file_system, &static_file_path_manager);
+        let foo = ExternalPackage::new("github.com/foo/foo", "main");
+        let bar = ExternalPackage::new("github.com/foo/bar", "main");
+
+        package_lock_manager
+            .write(&PackageLock::new(
+                vec![(foo.clone(), "foo".into()), (bar.clone(), "bar".into())]
+                    .into_iter()
+                    .collect(),
+            ))
+            .unwrap();
+        package_lock_manager.prune(&[&foo]).unwrap();
+
+        assert_eq!(
+            package_lock_manager.read().unwrap(),
+            PackageLock::new(vec![(foo, "foo".into())].into_iter().collect())
+        );
+    }
+}
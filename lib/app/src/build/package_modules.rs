@@ -0,0 +1,41 @@
+use crate::common::PackageConfiguration;
+use std::collections::HashMap;
+
+// Packages built together can import modules of packages before them which
+// are specified by their indices as dependencies.
+pub struct PackageModules<'a> {
+    package_configuration: &'a PackageConfiguration,
+    external_module_interfaces: HashMap<lang::ExternalUnresolvedModulePath, lang::ModuleInterface>,
+    dependencies: Vec<usize>,
+}
+
+impl<'a> PackageModules<'a> {
+    pub fn new(
+        package_configuration: &'a PackageConfiguration,
+        external_module_interfaces: HashMap<
+            lang::ExternalUnresolvedModulePath,
+            lang::ModuleInterface,
+        >,
+        dependencies: Vec<usize>,
+    ) -> Self {
+        Self {
+            package_configuration,
+            external_module_interfaces,
+            dependencies,
+        }
+    }
+
+    pub fn package_configuration(&self) -> &PackageConfiguration {
+        self.package_configuration
+    }
+
+    pub fn external_module_interfaces(
+        &self,
+    ) -> &HashMap<lang::ExternalUnresolvedModulePath, lang::ModuleInterface> {
+        &self.external_module_interfaces
+    }
+
+    pub fn dependencies(&self) -> &[usize] {
+        &self.dependencies
+    }
+}
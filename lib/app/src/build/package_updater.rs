@@ -0,0 +1,86 @@
+use super::{
+    cached_external_package_downloader::CachedExternalPackageDownloader, error::BuildError,
+    external_packages_downloader::ExternalPackagesDownloader,
+    package_configuration_reader::PackageConfigurationReader,
+    package_lock_manager::PackageLockManager,
+};
+use crate::{common::FilePath, infra::Logger};
+
+pub struct PackageUpdater<'a> {
+    package_configuration_reader: &'a PackageConfigurationReader<'a>,
+    package_lock_manager: &'a PackageLockManager<'a>,
+    external_packages_downloader: &'a ExternalPackagesDownloader<'a>,
+    cached_external_package_downloader: &'a CachedExternalPackageDownloader<'a>,
+    logger: &'a dyn Logger,
+}
+
+impl<'a> PackageUpdater<'a> {
+    pub fn new(
+        package_configuration_reader: &'a PackageConfigurationReader<'a>,
+        package_lock_manager: &'a PackageLockManager<'a>,
+        external_packages_downloader: &'a ExternalPackagesDownloader<'a>,
+        cached_external_package_downloader: &'a CachedExternalPackageDownloader<'a>,
+        logger: &'a dyn Logger,
+    ) -> Self {
+        Self {
+            package_configuration_reader,
+            package_lock_manager,
+            external_packages_downloader,
+            cached_external_package_downloader,
+            logger,
+        }
+    }
+
+    // Lock entries of packages are dropped so that their versions are resolved
+    // again.
+    pub fn update(&self, package_name: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
+        let package_configuration = self.package_configuration_reader.read(&FilePath::empty())?;
+        let mut package_lock = self.package_lock_manager.read()?;
+
+        let external_packages = package_lock
+            .commit_ids()
+            .keys()
+            .filter(|external_package| {
+                package_name
+                    .map(|name| external_package.name() == name)
+                    .unwrap_or(true)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if let Some(package_name) = package_name {
+            if external_packages.is_empty() {
+                return Err(BuildError::PackageNotFound {
+                    package_name: package_name.into(),
+                }
+                .into());
+            }
+        }
+
+        for external_package in &external_packages {
+            self.logger.log(&format!(
+                "updating package {} {}",
+                external_package.name(),
+                external_package.version()
+            ))?;
+
+            package_lock.remove(external_package);
+        }
+
+        self.package_lock_manager.write(&package_lock)?;
+
+        self.external_packages_downloader
+            .download_dependencies(&package_configuration)?;
+
+        if let Some(application_target) = package_configuration
+            .build_configuration()
+            .target()
+            .as_application()
+        {
+            self.cached_external_package_downloader
+                .download(application_target.system_package())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+use super::{error::BuildError, package_configuration_reader::PackageConfigurationReader};
+use crate::common::{FilePath, Profile};
+
+pub struct ProfileReader<'a> {
+    package_configuration_reader: &'a PackageConfigurationReader<'a>,
+}
+
+impl<'a> ProfileReader<'a> {
+    pub fn new(package_configuration_reader: &'a PackageConfigurationReader<'a>) -> Self {
+        Self {
+            package_configuration_reader,
+        }
+    }
+
+    // Profiles are always read from a main package. Profiles of external
+    // packages are ignored.
+    pub fn read(&self, profile_name: &str) -> Result<Profile, Box<dyn std::error::Error>> {
+        Ok(self
+            .package_configuration_reader
+            .read(&FilePath::empty())?
+            .build_configuration()
+            .profile(profile_name)
+            .ok_or_else(|| BuildError::ProfileNotFound {
+                profile_name: profile_name.into(),
+            })?)
+    }
+}
@@ -1,3 +1,7 @@
+use crate::common::ExternalPackage;
+
 pub struct SystemPackageConfiguration {
     pub main_function_module_name: &'static str,
+    // A default system package is used to run tests of library packages.
+    pub default_system_package: ExternalPackage,
 }
@@ -0,0 +1,25 @@
+use super::package_configuration_reader::PackageConfigurationReader;
+use crate::common::{FilePath, ToolchainConfiguration};
+
+pub struct ToolchainConfigurationReader<'a> {
+    package_configuration_reader: &'a PackageConfigurationReader<'a>,
+}
+
+impl<'a> ToolchainConfigurationReader<'a> {
+    pub fn new(package_configuration_reader: &'a PackageConfigurationReader<'a>) -> Self {
+        Self {
+            package_configuration_reader,
+        }
+    }
+
+    // Toolchain configurations are always read from a main package as
+    // profiles are.
+    pub fn read(&self) -> Result<ToolchainConfiguration, Box<dyn std::error::Error>> {
+        Ok(self
+            .package_configuration_reader
+            .read(&FilePath::empty())?
+            .build_configuration()
+            .toolchain_configuration()
+            .clone())
+    }
+}
@@ -1,12 +1,22 @@
+pub const EMISSION_DIRECTORY: &str = "emit";
+pub const EXTERNAL_PACKAGE_CACHE_MARKER_FILENAME: &str = ".ein-cache";
 pub const EXTERNAL_PACKAGES_DIRECTORY: &str = "packages";
 pub const INTERFACE_FILE_EXTENSION: &str = "json";
+pub const LTO_DIRECTORY: &str = "lto";
+pub const OBJECT_CACHE_MANIFEST_FILENAME: &str = "manifest.json";
 pub const OBJECT_DIRECTORY: &str = "objects";
 pub const OBJECT_FILE_EXTENSION: &str = "bc";
 pub const PRELUDE_PACKAGE_DIRECTORY: &str = "prelude";
+pub const TEST_HARNESS_APPLICATION_FILENAME: &str = "harness";
+pub const TEST_HARNESS_BASENAME: &str = "Harness";
+pub const TEST_HARNESS_DIRECTORY: &str = "test";
 
 pub struct FilePathConfiguration {
     pub build_configuration_filename: &'static str,
+    pub package_lock_filename: &'static str,
     pub output_directory_name: &'static str,
     pub source_file_extension: &'static str,
     pub main_file_basename: &'static str,
+    // Modules whose basenames end with this suffix are built only by tests.
+    pub test_module_suffix: &'static str,
 }
@@ -1,11 +1,15 @@
 mod file_path;
 mod file_path_configuration;
 mod file_path_resolver;
+mod object_cache_manifest;
 mod package_configuration;
+mod package_lock;
 mod static_file_path_manager;
 
 pub use file_path::*;
 pub use file_path_configuration::*;
 pub use file_path_resolver::*;
+pub use object_cache_manifest::*;
 pub use package_configuration::*;
+pub use package_lock::*;
 pub use static_file_path_manager::*;
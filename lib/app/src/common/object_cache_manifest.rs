@@ -0,0 +1,39 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+// Object cache manifests map build targets, such as applications and test
+// harnesses, to module IDs referenced by their last builds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectCacheManifest {
+    compiler_version: String,
+    module_ids: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl ObjectCacheManifest {
+    pub fn new(
+        compiler_version: impl Into<String>,
+        module_ids: BTreeMap<String, BTreeSet<String>>,
+    ) -> Self {
+        Self {
+            compiler_version: compiler_version.into(),
+            module_ids,
+        }
+    }
+
+    pub fn compiler_version(&self) -> &str {
+        &self.compiler_version
+    }
+
+    pub fn module_ids(&self) -> &BTreeMap<String, BTreeSet<String>> {
+        &self.module_ids
+    }
+
+    pub fn insert(&mut self, target_name: impl Into<String>, module_ids: BTreeSet<String>) {
+        self.module_ids.insert(target_name.into(), module_ids);
+    }
+
+    pub fn is_referenced(&self, module_id: &str) -> bool {
+        self.module_ids
+            .values()
+            .any(|module_ids| module_ids.contains(module_id))
+    }
+}
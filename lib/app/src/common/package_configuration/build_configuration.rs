@@ -1,18 +1,27 @@
-use super::target::Target;
+use super::{profile::Profile, target::Target, toolchain_configuration::ToolchainConfiguration};
 use crate::common::ExternalPackage;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 #[derive(Clone, Debug)]
 pub struct BuildConfiguration {
     target: Target,
     dependencies: HashSet<ExternalPackage>,
+    profiles: HashMap<String, Profile>,
+    toolchain_configuration: ToolchainConfiguration,
 }
 
 impl BuildConfiguration {
-    pub fn new(target: Target, dependencies: HashSet<ExternalPackage>) -> Self {
+    pub fn new(
+        target: Target,
+        dependencies: HashSet<ExternalPackage>,
+        profiles: HashMap<String, Profile>,
+        toolchain_configuration: ToolchainConfiguration,
+    ) -> Self {
         Self {
             target,
             dependencies,
+            profiles,
+            toolchain_configuration,
         }
     }
 
@@ -23,4 +32,20 @@ impl BuildConfiguration {
     pub fn dependencies(&self) -> &HashSet<ExternalPackage> {
         &self.dependencies
     }
+
+    pub fn profiles(&self) -> &HashMap<String, Profile> {
+        &self.profiles
+    }
+
+    pub fn toolchain_configuration(&self) -> &ToolchainConfiguration {
+        &self.toolchain_configuration
+    }
+
+    // Profiles defined in packages take precedence over built-in ones.
+    pub fn profile(&self, name: &str) -> Option<Profile> {
+        self.profiles
+            .get(name)
+            .cloned()
+            .or_else(|| Profile::built_in(name))
+    }
 }
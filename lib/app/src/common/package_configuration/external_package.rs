@@ -1,8 +1,14 @@
+use crate::common::FilePath;
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct ExternalPackage {
     name: String,
     // This version is the "expected" one different from lang::Package.
     version: String,
+    // Repository URLs default to HTTPS ones derived from package names.
+    url: Option<url::Url>,
+    // Packages with paths are built from local directories without download.
+    path: Option<FilePath>,
 }
 
 impl ExternalPackage {
@@ -10,6 +16,26 @@ impl ExternalPackage {
         Self {
             name: name.into(),
             version: version.into(),
+            url: None,
+            path: None,
+        }
+    }
+
+    pub fn with_url(name: impl Into<String>, version: impl Into<String>, url: url::Url) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            url: Some(url),
+            path: None,
+        }
+    }
+
+    pub fn with_path(name: impl Into<String>, path: FilePath) -> Self {
+        Self {
+            name: name.into(),
+            version: "".into(),
+            url: None,
+            path: Some(path),
         }
     }
 
@@ -20,4 +46,12 @@ impl ExternalPackage {
     pub fn version(&self) -> &str {
         &self.version
     }
+
+    pub fn url(&self) -> Option<&url::Url> {
+        self.url.as_ref()
+    }
+
+    pub fn path(&self) -> Option<&FilePath> {
+        self.path.as_ref()
+    }
 }
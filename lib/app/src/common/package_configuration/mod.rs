@@ -1,13 +1,17 @@
 mod application_target;
 mod build_configuration;
 mod external_package;
+mod profile;
 mod target;
+mod toolchain_configuration;
 
 use crate::common::FilePath;
 pub use application_target::ApplicationTarget;
 pub use build_configuration::BuildConfiguration;
 pub use external_package::ExternalPackage;
+pub use profile::{Profile, DEBUG_PROFILE_NAME, MAX_OPTIMIZATION_LEVEL, RELEASE_PROFILE_NAME};
 pub use target::Target;
+pub use toolchain_configuration::ToolchainConfiguration;
 
 #[derive(Clone, Debug)]
 pub struct PackageConfiguration {
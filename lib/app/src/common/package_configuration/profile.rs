@@ -0,0 +1,70 @@
+pub const DEBUG_PROFILE_NAME: &str = "debug";
+pub const RELEASE_PROFILE_NAME: &str = "release";
+pub const MAX_OPTIMIZATION_LEVEL: u8 = 3;
+
+// Profiles select trade-offs between build speed and runtime performance of
+// applications.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Profile {
+    optimization_level: u8,
+    debug_information: bool,
+    assertions: bool,
+    lto: bool,
+    link_arguments: Vec<String>,
+}
+
+impl Profile {
+    pub fn new(
+        optimization_level: u8,
+        debug_information: bool,
+        assertions: bool,
+        lto: bool,
+        link_arguments: Vec<String>,
+    ) -> Self {
+        Self {
+            optimization_level,
+            debug_information,
+            assertions,
+            lto,
+            link_arguments,
+        }
+    }
+
+    pub fn debug() -> Self {
+        Self::new(0, true, true, false, vec![])
+    }
+
+    pub fn release() -> Self {
+        Self::new(3, false, false, false, vec![])
+    }
+
+    pub fn built_in(name: &str) -> Option<Self> {
+        match name {
+            DEBUG_PROFILE_NAME => Some(Self::debug()),
+            RELEASE_PROFILE_NAME => Some(Self::release()),
+            _ => None,
+        }
+    }
+
+    pub fn optimization_level(&self) -> u8 {
+        self.optimization_level
+    }
+
+    pub fn debug_information(&self) -> bool {
+        self.debug_information
+    }
+
+    pub fn assertions(&self) -> bool {
+        self.assertions
+    }
+
+    // Link-time optimization merges all modules into one before code
+    // generation.
+    pub fn lto(&self) -> bool {
+        self.lto
+    }
+
+    pub fn link_arguments(&self) -> &[String] {
+        &self.link_arguments
+    }
+}
@@ -0,0 +1,48 @@
+// Toolchain configurations specify external tools and options used to link
+// applications.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ToolchainConfiguration {
+    llc: Option<String>,
+    clang: Option<String>,
+    libraries: Vec<String>,
+    link_arguments: Vec<String>,
+    static_linking: bool,
+}
+
+impl ToolchainConfiguration {
+    pub fn new(
+        llc: Option<String>,
+        clang: Option<String>,
+        libraries: Vec<String>,
+        link_arguments: Vec<String>,
+        static_linking: bool,
+    ) -> Self {
+        Self {
+            llc,
+            clang,
+            libraries,
+            link_arguments,
+            static_linking,
+        }
+    }
+
+    pub fn llc(&self) -> Option<&str> {
+        self.llc.as_deref()
+    }
+
+    pub fn clang(&self) -> Option<&str> {
+        self.clang.as_deref()
+    }
+
+    pub fn libraries(&self) -> &[String] {
+        &self.libraries
+    }
+
+    pub fn link_arguments(&self) -> &[String] {
+        &self.link_arguments
+    }
+
+    pub fn static_linking(&self) -> bool {
+        self.static_linking
+    }
+}
@@ -0,0 +1,31 @@
+use super::package_configuration::ExternalPackage;
+use std::collections::HashMap;
+
+// Package locks map external packages to commit IDs resolved when they are
+// downloaded first.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PackageLock {
+    commit_ids: HashMap<ExternalPackage, String>,
+}
+
+impl PackageLock {
+    pub fn new(commit_ids: HashMap<ExternalPackage, String>) -> Self {
+        Self { commit_ids }
+    }
+
+    pub fn commit_ids(&self) -> &HashMap<ExternalPackage, String> {
+        &self.commit_ids
+    }
+
+    pub fn commit_id(&self, external_package: &ExternalPackage) -> Option<&str> {
+        self.commit_ids.get(external_package).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, external_package: ExternalPackage, commit_id: impl Into<String>) {
+        self.commit_ids.insert(external_package, commit_id.into());
+    }
+
+    pub fn remove(&mut self, external_package: &ExternalPackage) {
+        self.commit_ids.remove(external_package);
+    }
+}
@@ -1,17 +1,24 @@
 use super::{
     file_path::FilePath,
     file_path_configuration::{
-        FilePathConfiguration, EXTERNAL_PACKAGES_DIRECTORY, OBJECT_DIRECTORY,
-        PRELUDE_PACKAGE_DIRECTORY,
+        FilePathConfiguration, EMISSION_DIRECTORY, EXTERNAL_PACKAGES_DIRECTORY, LTO_DIRECTORY,
+        OBJECT_CACHE_MANIFEST_FILENAME, OBJECT_DIRECTORY, PRELUDE_PACKAGE_DIRECTORY,
+        TEST_HARNESS_APPLICATION_FILENAME, TEST_HARNESS_BASENAME, TEST_HARNESS_DIRECTORY,
     },
 };
 
 pub struct StaticFilePathManager {
     build_configuration_file_path: FilePath,
+    package_lock_file_path: FilePath,
     object_directory_path: FilePath,
+    object_cache_manifest_file_path: FilePath,
+    emission_directory_path: FilePath,
+    lto_directory_path: FilePath,
     external_packages_directory_path: FilePath,
     prelude_package_directory_path: FilePath,
     main_source_file_path: FilePath,
+    test_harness_source_file_path: FilePath,
+    test_harness_application_file_path: FilePath,
 }
 
 impl StaticFilePathManager {
@@ -19,17 +26,31 @@ impl StaticFilePathManager {
         let output_directory_path = FilePath::new(&[configuration.output_directory_name]);
         let external_packages_directory_path =
             output_directory_path.join(&FilePath::new(&[EXTERNAL_PACKAGES_DIRECTORY]));
+        let object_directory_path = output_directory_path.join(&FilePath::new(&[OBJECT_DIRECTORY]));
+        let test_harness_directory_path =
+            output_directory_path.join(&FilePath::new(&[TEST_HARNESS_DIRECTORY]));
 
         Self {
             prelude_package_directory_path: external_packages_directory_path
                 .join(&FilePath::new(&[PRELUDE_PACKAGE_DIRECTORY])),
             external_packages_directory_path,
-            object_directory_path: output_directory_path.join(&FilePath::new(&[OBJECT_DIRECTORY])),
+            object_cache_manifest_file_path: object_directory_path
+                .join(&FilePath::new(&[OBJECT_CACHE_MANIFEST_FILENAME])),
+            object_directory_path,
+            emission_directory_path: output_directory_path
+                .join(&FilePath::new(&[EMISSION_DIRECTORY])),
+            lto_directory_path: output_directory_path.join(&FilePath::new(&[LTO_DIRECTORY])),
             main_source_file_path: FilePath::new(&[configuration.main_file_basename])
                 .with_extension(configuration.source_file_extension),
             build_configuration_file_path: FilePath::new(&[
                 configuration.build_configuration_filename
             ]),
+            package_lock_file_path: FilePath::new(&[configuration.package_lock_filename]),
+            test_harness_source_file_path: test_harness_directory_path
+                .join(&FilePath::new(&[TEST_HARNESS_BASENAME]))
+                .with_extension(configuration.source_file_extension),
+            test_harness_application_file_path: test_harness_directory_path
+                .join(&FilePath::new(&[TEST_HARNESS_APPLICATION_FILENAME])),
         }
     }
 
@@ -37,10 +58,28 @@ impl StaticFilePathManager {
         &self.build_configuration_file_path
     }
 
+    pub fn package_lock_file_path(&self) -> &FilePath {
+        &self.package_lock_file_path
+    }
+
     pub fn object_directory_path(&self) -> &FilePath {
         &self.object_directory_path
     }
 
+    pub fn object_cache_manifest_file_path(&self) -> &FilePath {
+        &self.object_cache_manifest_file_path
+    }
+
+    pub fn emission_directory_path(&self) -> &FilePath {
+        &self.emission_directory_path
+    }
+
+    // Intermediate files of link-time optimization are written into a
+    // directory separate from object caches.
+    pub fn lto_directory_path(&self) -> &FilePath {
+        &self.lto_directory_path
+    }
+
     pub fn external_packages_directory_path(&self) -> &FilePath {
         &self.external_packages_directory_path
     }
@@ -52,4 +91,12 @@ impl StaticFilePathManager {
     pub fn main_source_file_path(&self) -> &FilePath {
         &self.main_source_file_path
     }
+
+    pub fn test_harness_source_file_path(&self) -> &FilePath {
+        &self.test_harness_source_file_path
+    }
+
+    pub fn test_harness_application_file_path(&self) -> &FilePath {
+        &self.test_harness_application_file_path
+    }
 }
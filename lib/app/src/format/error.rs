@@ -0,0 +1,51 @@
+use crate::common::FilePath;
+
+#[derive(Debug, PartialEq)]
+pub enum FormatError {
+    ModulesNotFormatted(Vec<FilePath>),
+}
+
+impl FormatError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::ModulesNotFormatted(_) => "F0001",
+        }
+    }
+}
+
+impl std::error::Error for FormatError {}
+
+impl std::fmt::Display for FormatError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::ModulesNotFormatted(file_paths) => write!(
+                formatter,
+                "modules not formatted:\n{}",
+                file_paths
+                    .iter()
+                    .map(|file_path| format!("{}", file_path))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_modules_not_formatted() {
+        assert_eq!(
+            format!(
+                "{}",
+                FormatError::ModulesNotFormatted(vec![
+                    FilePath::new(vec!["foo.ein"]),
+                    FilePath::new(vec!["bar", "baz.ein"])
+                ])
+            ),
+            "modules not formatted:\nfoo.ein\nbar/baz.ein"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod error;
+mod package_formatter;
+
+pub use error::*;
+pub use package_formatter::*;
@@ -0,0 +1,55 @@
+use super::error::FormatError;
+use crate::{
+    build::{ModuleParser, ModulesFinder},
+    common::FilePath,
+    infra::FileSystem,
+};
+
+pub struct PackageFormatter<'a> {
+    module_parser: &'a ModuleParser<'a>,
+    modules_finder: &'a ModulesFinder<'a>,
+    file_system: &'a dyn FileSystem,
+}
+
+impl<'a> PackageFormatter<'a> {
+    pub fn new(
+        module_parser: &'a ModuleParser<'a>,
+        modules_finder: &'a ModulesFinder<'a>,
+        file_system: &'a dyn FileSystem,
+    ) -> Self {
+        Self {
+            module_parser,
+            modules_finder,
+            file_system,
+        }
+    }
+
+    // In check mode, source files are not modified and unformatted ones are
+    // reported as an error.
+    pub fn format(&self, check: bool) -> Result<(), Box<dyn std::error::Error>> {
+        let mut unformatted_file_paths = vec![];
+
+        for file_path in self.modules_finder.find(&FilePath::empty())? {
+            let source = self.file_system.read_to_string(&file_path)?;
+            let formatted_source = lang::format(
+                &self.module_parser.parse(&source, &file_path)?,
+                &self.module_parser.parse_comments(&source, &file_path)?,
+            );
+
+            if formatted_source == source {
+                continue;
+            } else if check {
+                unformatted_file_paths.push(file_path);
+            } else {
+                self.file_system
+                    .write(&file_path, formatted_source.as_bytes())?;
+            }
+        }
+
+        if unformatted_file_paths.is_empty() {
+            Ok(())
+        } else {
+            Err(FormatError::ModulesNotFormatted(unformatted_file_paths).into())
+        }
+    }
+}
@@ -4,6 +4,6 @@ pub trait ApplicationLinker {
     fn link(
         &self,
         object_file_paths: &[FilePath],
-        application_name: &str,
+        application_file_path: &FilePath,
     ) -> Result<(), Box<dyn std::error::Error>>;
 }
@@ -0,0 +1,11 @@
+use crate::common::FilePath;
+
+pub trait ApplicationRunner {
+    // It returns a status code of an application or None if the application
+    // is terminated by a signal.
+    fn run(
+        &self,
+        application_file_path: &FilePath,
+        arguments: &[String],
+    ) -> Result<Option<i32>, Box<dyn std::error::Error>>;
+}
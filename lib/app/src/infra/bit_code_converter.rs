@@ -0,0 +1,44 @@
+use crate::common::FilePath;
+
+pub trait BitCodeConverter {
+    fn convert_to_llvm_ir(
+        &self,
+        bit_code_file_path: &FilePath,
+        llvm_ir_file_path: &FilePath,
+    ) -> Result<(), Box<dyn std::error::Error>>;
+    fn convert_to_assembly(
+        &self,
+        bit_code_file_path: &FilePath,
+        assembly_file_path: &FilePath,
+    ) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+#[cfg(test)]
+#[derive(Default)]
+pub struct FakeBitCodeConverter {}
+
+#[cfg(test)]
+impl FakeBitCodeConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+impl BitCodeConverter for FakeBitCodeConverter {
+    fn convert_to_llvm_ir(
+        &self,
+        _: &FilePath,
+        _: &FilePath,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+
+    fn convert_to_assembly(
+        &self,
+        _: &FilePath,
+        _: &FilePath,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+}
@@ -8,3 +8,25 @@ pub trait FfiPackageInitializer {
 
     fn is_ffi_used(&self, directory_path: &FilePath) -> bool;
 }
+
+#[cfg(test)]
+#[derive(Default)]
+pub struct FakeFfiPackageInitializer {}
+
+#[cfg(test)]
+impl FakeFfiPackageInitializer {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+impl FfiPackageInitializer for FakeFfiPackageInitializer {
+    fn initialize(&self, _: &FilePath) -> Result<Option<FilePath>, Box<dyn std::error::Error>> {
+        Ok(None)
+    }
+
+    fn is_ffi_used(&self, _: &FilePath) -> bool {
+        false
+    }
+}
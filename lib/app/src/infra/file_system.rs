@@ -12,6 +12,9 @@ pub trait FileSystem {
     fn read_to_string(&self, path: &FilePath) -> Result<String, Box<dyn std::error::Error>>;
     fn read_to_vec(&self, path: &FilePath) -> Result<Vec<u8>, Box<dyn std::error::Error>>;
     fn write(&self, path: &FilePath, data: &[u8]) -> Result<(), Box<dyn std::error::Error>>;
+    fn remove_file(&self, path: &FilePath) -> Result<(), Box<dyn std::error::Error>>;
+    fn remove_directory(&self, path: &FilePath) -> Result<(), Box<dyn std::error::Error>>;
+    fn rename(&self, from: &FilePath, to: &FilePath) -> Result<(), Box<dyn std::error::Error>>;
 }
 
 #[cfg(test)]
@@ -34,22 +37,47 @@ impl FileSystem for FakeFileSystem {
         self.files.lock().unwrap().contains_key(path)
     }
 
-    fn is_directory(&self, _: &FilePath) -> bool {
-        todo!()
+    fn is_directory(&self, path: &FilePath) -> bool {
+        let components = path.components().collect::<Vec<_>>();
+
+        self.files.lock().unwrap().keys().any(|file_path| {
+            let file_components = file_path.components().collect::<Vec<_>>();
+
+            file_components.starts_with(&components) && file_components.len() > components.len()
+        })
     }
 
-    fn read_directory(&self, _: &FilePath) -> Result<Vec<FilePath>, Box<dyn std::error::Error>> {
-        todo!()
+    fn read_directory(&self, path: &FilePath) -> Result<Vec<FilePath>, Box<dyn std::error::Error>> {
+        let components = path.components().collect::<Vec<_>>();
+
+        Ok(self
+            .files
+            .lock()
+            .unwrap()
+            .keys()
+            .filter_map(|file_path| {
+                let file_components = file_path.components().collect::<Vec<_>>();
+
+                if file_components.starts_with(&components)
+                    && file_components.len() > components.len()
+                {
+                    Some(FilePath::new(&file_components[..components.len() + 1]))
+                } else {
+                    None
+                }
+            })
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect())
     }
 
     fn read_repository(
         &self,
         directory_path: &FilePath,
     ) -> Result<Option<Repository>, Box<dyn std::error::Error>> {
-        Ok(Some(Repository::new(
-            url::Url::parse(&format!("{}", directory_path))?,
-            "v1",
-        )))
+        Ok(url::Url::parse(&format!("{}", directory_path))
+            .ok()
+            .map(|url| Repository::new(url, "v1")))
     }
 
     fn read_to_string(&self, path: &FilePath) -> Result<String, Box<dyn std::error::Error>> {
@@ -77,6 +105,41 @@ impl FileSystem for FakeFileSystem {
         self.files.lock().unwrap().insert(path.clone(), data.into());
         Ok(())
     }
+
+    fn remove_file(&self, path: &FilePath) -> Result<(), Box<dyn std::error::Error>> {
+        self.files.lock().unwrap().remove(path);
+        Ok(())
+    }
+
+    fn remove_directory(&self, path: &FilePath) -> Result<(), Box<dyn std::error::Error>> {
+        self.files.lock().unwrap().retain(|file_path, _| {
+            !file_path
+                .components()
+                .collect::<Vec<_>>()
+                .starts_with(&path.components().collect::<Vec<_>>())
+        });
+        Ok(())
+    }
+
+    fn rename(&self, from: &FilePath, to: &FilePath) -> Result<(), Box<dyn std::error::Error>> {
+        let mut files = self.files.lock().unwrap();
+        let from_components = from.components().collect::<Vec<_>>();
+
+        for file_path in files.keys().cloned().collect::<Vec<_>>() {
+            let components = file_path.components().collect::<Vec<_>>();
+
+            if components.starts_with(&from_components) {
+                let data = files.remove(&file_path).unwrap();
+
+                files.insert(
+                    to.join(&FilePath::new(&components[from_components.len()..])),
+                    data,
+                );
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -131,4 +194,67 @@ mod tests {
             .write(&FilePath::new(&["foo"]), &[])
             .unwrap();
     }
+
+    #[test]
+    fn read_directory() {
+        let file_system = FakeFileSystem::new(
+            vec![
+                (FilePath::new(&["foo", "bar"]), vec![]),
+                (FilePath::new(&["foo", "baz", "qux"]), vec![]),
+            ]
+            .drain(..)
+            .collect(),
+        );
+
+        assert_eq!(
+            file_system
+                .read_directory(&FilePath::new(&["foo"]))
+                .unwrap(),
+            vec![
+                FilePath::new(&["foo", "bar"]),
+                FilePath::new(&["foo", "baz"])
+            ]
+        );
+    }
+
+    #[test]
+    fn remove_file() {
+        let file_system =
+            FakeFileSystem::new(vec![(FilePath::new(&["foo"]), vec![])].drain(..).collect());
+
+        file_system.remove_file(&FilePath::new(&["foo"])).unwrap();
+
+        assert!(!file_system.exists(&FilePath::new(&["foo"])));
+    }
+
+    #[test]
+    fn remove_directory() {
+        let file_system = FakeFileSystem::new(
+            vec![(FilePath::new(&["foo", "bar"]), vec![])]
+                .drain(..)
+                .collect(),
+        );
+
+        file_system
+            .remove_directory(&FilePath::new(&["foo"]))
+            .unwrap();
+
+        assert!(!file_system.exists(&FilePath::new(&["foo", "bar"])));
+    }
+
+    #[test]
+    fn rename() {
+        let file_system = FakeFileSystem::new(
+            vec![(FilePath::new(&["foo", "bar"]), vec![])]
+                .drain(..)
+                .collect(),
+        );
+
+        file_system
+            .rename(&FilePath::new(&["foo"]), &FilePath::new(&["baz"]))
+            .unwrap();
+
+        assert!(!file_system.exists(&FilePath::new(&["foo", "bar"])));
+        assert!(file_system.exists(&FilePath::new(&["baz", "bar"])));
+    }
 }
@@ -0,0 +1,32 @@
+use crate::common::FilePath;
+
+pub trait FileUriConverter {
+    fn convert_to_file_path(&self, uri: &str) -> Result<FilePath, Box<dyn std::error::Error>>;
+    fn convert_to_uri(&self, file_path: &FilePath) -> Result<String, Box<dyn std::error::Error>>;
+}
+
+#[cfg(test)]
+#[derive(Default)]
+pub struct FakeFileUriConverter {}
+
+#[cfg(test)]
+impl FakeFileUriConverter {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+impl FileUriConverter for FakeFileUriConverter {
+    fn convert_to_file_path(&self, uri: &str) -> Result<FilePath, Box<dyn std::error::Error>> {
+        Ok(FilePath::new(
+            uri.strip_prefix("file:///")
+                .ok_or("invalid file URI")?
+                .split('/'),
+        ))
+    }
+
+    fn convert_to_uri(&self, file_path: &FilePath) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(format!("file:///{}", file_path))
+    }
+}
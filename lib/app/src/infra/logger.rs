@@ -1,3 +1,21 @@
 pub trait Logger {
     fn log(&self, log: &str) -> Result<(), Box<dyn std::error::Error>>;
 }
+
+#[cfg(test)]
+#[derive(Default)]
+pub struct FakeLogger {}
+
+#[cfg(test)]
+impl FakeLogger {
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+#[cfg(test)]
+impl Logger for FakeLogger {
+    fn log(&self, _: &str) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+pub trait MessageTransport {
+    fn read_message(&self) -> Result<Option<serde_json::Value>, Box<dyn std::error::Error>>;
+    fn write_message(&self, message: &serde_json::Value) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+#[cfg(test)]
+pub struct FakeMessageTransport {
+    input_messages: std::sync::Mutex<std::collections::VecDeque<serde_json::Value>>,
+    output_messages: std::sync::Mutex<Vec<serde_json::Value>>,
+}
+
+#[cfg(test)]
+impl FakeMessageTransport {
+    pub fn new(input_messages: Vec<serde_json::Value>) -> Self {
+        Self {
+            input_messages: std::sync::Mutex::new(input_messages.into()),
+            output_messages: Default::default(),
+        }
+    }
+
+    pub fn output_messages(&self) -> Vec<serde_json::Value> {
+        self.output_messages.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+impl MessageTransport for FakeMessageTransport {
+    fn read_message(&self) -> Result<Option<serde_json::Value>, Box<dyn std::error::Error>> {
+        Ok(self.input_messages.lock().unwrap().pop_front())
+    }
+
+    fn write_message(&self, message: &serde_json::Value) -> Result<(), Box<dyn std::error::Error>> {
+        self.output_messages.lock().unwrap().push(message.clone());
+        Ok(())
+    }
+}
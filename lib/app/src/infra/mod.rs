@@ -1,17 +1,25 @@
 mod application_linker;
+mod application_runner;
+mod bit_code_converter;
 mod external_package_downloader;
 mod ffi_package_initializer;
 mod file_path_displayer;
 mod file_system;
+mod file_uri_converter;
 mod logger;
+mod message_transport;
 mod prelude_package_downloader;
 mod repository;
 
 pub use application_linker::*;
+pub use application_runner::*;
+pub use bit_code_converter::*;
 pub use external_package_downloader::*;
 pub use ffi_package_initializer::*;
 pub use file_path_displayer::*;
 pub use file_system::*;
+pub use file_uri_converter::*;
 pub use logger::*;
+pub use message_transport::*;
 pub use prelude_package_downloader::*;
 pub use repository::*;
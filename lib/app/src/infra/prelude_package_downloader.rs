@@ -6,3 +6,29 @@ pub trait PreludePackageDownloader {
         external_packages_directory_path: &FilePath,
     ) -> Result<(), Box<dyn std::error::Error>>;
 }
+
+#[cfg(test)]
+pub struct FakePreludePackageDownloader<'a, S: crate::infra::FileSystem> {
+    files: std::collections::HashMap<FilePath, Vec<u8>>,
+    file_system: &'a S,
+}
+
+#[cfg(test)]
+impl<'a, S: crate::infra::FileSystem> FakePreludePackageDownloader<'a, S> {
+    pub fn new(files: std::collections::HashMap<FilePath, Vec<u8>>, file_system: &'a S) -> Self {
+        Self { files, file_system }
+    }
+}
+
+#[cfg(test)]
+impl<'a, S: crate::infra::FileSystem> PreludePackageDownloader
+    for FakePreludePackageDownloader<'a, S>
+{
+    fn download(&self, directory_path: &FilePath) -> Result<(), Box<dyn std::error::Error>> {
+        for (path, data) in &self.files {
+            self.file_system.write(&directory_path.join(path), data)?;
+        }
+
+        Ok(())
+    }
+}
@@ -33,6 +33,8 @@ impl<'a> PackageInitializer<'a> {
             serialize_build_configuration(&BuildConfiguration::new(
                 target.clone(),
                 Default::default(),
+                Default::default(),
+                Default::default(),
             ))?
             .as_bytes(),
         )?;
@@ -1,10 +1,18 @@
 mod adaptors;
 mod build;
 mod common;
+mod format;
 mod infra;
 mod init;
+mod lsp;
+mod run;
+mod test;
 
 pub use build::*;
 pub use common::*;
+pub use format::*;
 pub use infra::*;
 pub use init::*;
+pub use lsp::*;
+pub use run::*;
+pub use test::*;
@@ -0,0 +1,1020 @@
+use super::{
+    module_analyzer::ModuleAnalyzer,
+    source_text::{convert_from_utf16, convert_to_utf16, find_word, find_word_prefix},
+};
+use crate::{
+    build::{BuildError, DependencyInterfacesBuilder, ModuleParser, PackageConfigurationReader},
+    common::{
+        ExternalPackage, FilePath, FilePathResolver, PackageConfiguration, StaticFilePathManager,
+    },
+    infra::{FilePathDisplayer, FileUriConverter, MessageTransport},
+};
+use lang::{
+    debug::{Location, SourceInformation},
+    types::Type,
+};
+use serde_json::{json, Value};
+use std::{collections::HashMap, sync::Arc};
+
+const METHOD_NOT_FOUND_ERROR_CODE: i64 = -32601;
+const INTERNAL_ERROR_CODE: i64 = -32603;
+const ERROR_DIAGNOSTIC_SEVERITY: u64 = 1;
+const ERROR_MESSAGE_TYPE: u64 = 1;
+const FULL_TEXT_DOCUMENT_SYNC_KIND: u64 = 1;
+const FIELD_COMPLETION_ITEM_KIND: u64 = 5;
+const VARIABLE_COMPLETION_ITEM_KIND: u64 = 6;
+
+struct Workspace {
+    package_configuration: PackageConfiguration,
+    prelude_module_interfaces: Vec<lang::ModuleInterface>,
+    external_module_interfaces: HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
+    documents: HashMap<FilePath, String>,
+}
+
+pub struct LanguageServer<'a> {
+    message_transport: &'a dyn MessageTransport,
+    file_uri_converter: &'a dyn FileUriConverter,
+    file_path_displayer: &'a dyn FilePathDisplayer,
+    module_parser: &'a ModuleParser<'a>,
+    module_analyzer: &'a ModuleAnalyzer<'a>,
+    package_configuration_reader: &'a PackageConfigurationReader<'a>,
+    dependency_interfaces_builder: &'a DependencyInterfacesBuilder<'a>,
+    file_path_resolver: &'a FilePathResolver<'a>,
+    static_file_path_manager: &'a StaticFilePathManager,
+}
+
+impl<'a> LanguageServer<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        message_transport: &'a dyn MessageTransport,
+        file_uri_converter: &'a dyn FileUriConverter,
+        file_path_displayer: &'a dyn FilePathDisplayer,
+        module_parser: &'a ModuleParser<'a>,
+        module_analyzer: &'a ModuleAnalyzer<'a>,
+        package_configuration_reader: &'a PackageConfigurationReader<'a>,
+        dependency_interfaces_builder: &'a DependencyInterfacesBuilder<'a>,
+        file_path_resolver: &'a FilePathResolver<'a>,
+        static_file_path_manager: &'a StaticFilePathManager,
+    ) -> Self {
+        Self {
+            message_transport,
+            file_uri_converter,
+            file_path_displayer,
+            module_parser,
+            module_analyzer,
+            package_configuration_reader,
+            dependency_interfaces_builder,
+            file_path_resolver,
+            static_file_path_manager,
+        }
+    }
+
+    pub fn run(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let package_configuration = self.package_configuration_reader.read(&FilePath::empty())?;
+        let (prelude_module_interfaces, external_module_interfaces) = self
+            .dependency_interfaces_builder
+            .build(&package_configuration)?;
+
+        let mut workspace = Workspace {
+            package_configuration,
+            prelude_module_interfaces,
+            external_module_interfaces,
+            documents: HashMap::new(),
+        };
+
+        while let Some(message) = self.message_transport.read_message()? {
+            let method = message["method"].as_str().unwrap_or_default();
+
+            if method == "exit" {
+                break;
+            }
+
+            let result = self.handle_message(&mut workspace, method, &message["params"]);
+
+            match (&message["id"], result) {
+                (Value::Null, Ok(_)) => {}
+                (Value::Null, Err(error)) => self.send_notification(
+                    "window/logMessage",
+                    json!({ "type": ERROR_MESSAGE_TYPE, "message": error.to_string() }),
+                )?,
+                (id, Ok(Some(result))) => self.send_response(id, result)?,
+                (id, Ok(None)) => self.send_error(
+                    id,
+                    METHOD_NOT_FOUND_ERROR_CODE,
+                    &format!("method not found: {}", method),
+                )?,
+                (id, Err(error)) => self.send_error(id, INTERNAL_ERROR_CODE, &error.to_string())?,
+            }
+        }
+
+        Ok(())
+    }
+
+    // Notifications and unknown methods result in no values.
+    fn handle_message(
+        &self,
+        workspace: &mut Workspace,
+        method: &str,
+        params: &Value,
+    ) -> Result<Option<Value>, Box<dyn std::error::Error>> {
+        Ok(match method {
+            "initialize" => Some(json!({
+                "capabilities": {
+                    "textDocumentSync": FULL_TEXT_DOCUMENT_SYNC_KIND,
+                    "hoverProvider": true,
+                    "definitionProvider": true,
+                    "completionProvider": { "triggerCharacters": ["."] },
+                },
+                "serverInfo": { "name": "ein" },
+            })),
+            "shutdown" => Some(Value::Null),
+            "textDocument/didOpen" => {
+                workspace.documents.insert(
+                    self.convert_to_file_path(&params["textDocument"])?,
+                    params["textDocument"]["text"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .into(),
+                );
+                self.publish_diagnostics(workspace)?;
+
+                None
+            }
+            "textDocument/didChange" => {
+                if let Some(change) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                {
+                    workspace.documents.insert(
+                        self.convert_to_file_path(&params["textDocument"])?,
+                        change["text"].as_str().unwrap_or_default().into(),
+                    );
+                }
+
+                self.publish_diagnostics(workspace)?;
+
+                None
+            }
+            "textDocument/didClose" => {
+                workspace
+                    .documents
+                    .remove(&self.convert_to_file_path(&params["textDocument"])?);
+                self.send_notification(
+                    "textDocument/publishDiagnostics",
+                    json!({ "uri": params["textDocument"]["uri"], "diagnostics": [] }),
+                )?;
+
+                None
+            }
+            "textDocument/hover" => Some(self.hover(workspace, params)?),
+            "textDocument/definition" => Some(self.find_definition(workspace, params)?),
+            "textDocument/completion" => Some(self.complete(workspace, params)?),
+            _ => None,
+        })
+    }
+
+    // Diagnostics of all open documents are updated because they can depend on
+    // changed ones.
+    fn publish_diagnostics(&self, workspace: &Workspace) -> Result<(), Box<dyn std::error::Error>> {
+        for file_path in workspace.documents.keys() {
+            let diagnostics = match self.analyze(workspace, file_path) {
+                Ok(_) => vec![],
+                Err(error) => self.create_diagnostics(
+                    error.as_ref(),
+                    file_path,
+                    &self
+                        .module_analyzer
+                        .read_source(file_path, &workspace.documents)
+                        .unwrap_or_default(),
+                ),
+            };
+
+            self.send_notification(
+                "textDocument/publishDiagnostics",
+                json!({
+                    "uri": self.file_uri_converter.convert_to_uri(file_path)?,
+                    "diagnostics": diagnostics,
+                }),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn create_diagnostics(
+        &self,
+        error: &(dyn std::error::Error + 'static),
+        file_path: &FilePath,
+        source: &str,
+    ) -> Vec<Value> {
+        let source_name = self.file_path_displayer.display(file_path);
+
+        if let Some(compile_error) = error.downcast_ref::<lang::CompileError>() {
+            compile_error
+                .errors()
+                .into_iter()
+                .map(|error| {
+                    self.create_diagnostic(
+                        source,
+                        &error.to_string(),
+                        Some(error.code()),
+                        error.source_information().map(AsRef::as_ref).filter(
+                            |source_information| source_information.source_name() == source_name,
+                        ),
+                    )
+                })
+                .collect()
+        } else if let Some(parse_error) = error.downcast_ref::<lang::ParseError>() {
+            vec![self.create_diagnostic(
+                source,
+                &error.to_string(),
+                Some(parse_error.code()),
+                Some(parse_error.source_information())
+                    .filter(|source_information| source_information.source_name() == source_name),
+            )]
+        } else if let Some(build_error) = error.downcast_ref::<BuildError>() {
+            vec![self.create_diagnostic(source, &error.to_string(), Some(build_error.code()), None)]
+        } else {
+            vec![self.create_diagnostic(source, &error.to_string(), None, None)]
+        }
+    }
+
+    // Errors without source information in the documents are shown at their
+    // beginnings.
+    fn create_diagnostic(
+        &self,
+        source: &str,
+        message: &str,
+        code: Option<&str>,
+        source_information: Option<&SourceInformation>,
+    ) -> Value {
+        json!({
+            "range": source_information
+                .map(|source_information| convert_to_range(source, source_information))
+                .unwrap_or_else(|| json!({
+                    "start": { "line": 0, "character": 0 },
+                    "end": { "line": 0, "character": 0 },
+                })),
+            "severity": ERROR_DIAGNOSTIC_SEVERITY,
+            "code": code,
+            "source": "ein",
+            "message": message.lines().next().unwrap_or_default(),
+        })
+    }
+
+    fn hover(
+        &self,
+        workspace: &Workspace,
+        params: &Value,
+    ) -> Result<Value, Box<dyn std::error::Error>> {
+        let file_path = self.convert_to_file_path(&params["textDocument"])?;
+        let source = self
+            .module_analyzer
+            .read_source(&file_path, &workspace.documents)?;
+        let (line, character) = convert_from_position(&source, &params["position"]);
+
+        let analysis = match self.analyze(workspace, &file_path) {
+            Ok(analysis) => analysis,
+            Err(_) => return Ok(Value::Null),
+        };
+
+        Ok(find_by_position(analysis.variable_types(), line, character)
+            .map(|(source_information, type_)| {
+                json!({
+                    "contents": { "kind": "plaintext", "value": type_.to_string() },
+                    "range": convert_to_range(&source, source_information),
+                })
+            })
+            .unwrap_or(Value::Null))
+    }
+
+    fn find_definition(
+        &self,
+        workspace: &Workspace,
+        params: &Value,
+    ) -> Result<Value, Box<dyn std::error::Error>> {
+        let file_path = self.convert_to_file_path(&params["textDocument"])?;
+        let source = self
+            .module_analyzer
+            .read_source(&file_path, &workspace.documents)?;
+        let (line, character) = convert_from_position(&source, &params["position"]);
+
+        // Variables are resolved in analyzed modules so that local variables
+        // shadowing others are found.
+        if let Ok(analysis) = self.analyze(workspace, &file_path) {
+            if let Some((_, source_information)) =
+                find_by_position(analysis.variable_definitions(), line, character)
+            {
+                return self.convert_to_location(workspace, &file_path, source_information);
+            }
+        }
+
+        let name = match find_word(&source, line, character) {
+            Some(name) => name,
+            None => return Ok(Value::Null),
+        };
+        let module = match self.module_parser.parse(&source, &file_path) {
+            Ok(module) => module,
+            Err(_) => return Ok(Value::Null),
+        };
+
+        if let Some(source_information) = find_type_definition_in_module(&module, name) {
+            return self.convert_to_location(workspace, &file_path, &source_information);
+        }
+
+        for import in module.imports() {
+            let qualifier = import
+                .alias()
+                .map(String::from)
+                .unwrap_or_else(|| get_module_name(import.module_path()));
+
+            let name = match name.strip_prefix(&format!("{}.", qualifier)) {
+                Some(name) => name,
+                None if import
+                    .names()
+                    .iter()
+                    .any(|imported_name| imported_name == name) =>
+                {
+                    name
+                }
+                None => continue,
+            };
+
+            if let Some((imported_file_path, imported_module)) =
+                self.parse_imported_module(workspace, import.module_path())
+            {
+                if let Some(source_information) = find_definition_in_module(&imported_module, name)
+                {
+                    return self.convert_to_location(
+                        workspace,
+                        &imported_file_path,
+                        &source_information,
+                    );
+                }
+            }
+        }
+
+        for module_interface in &workspace.prelude_module_interfaces {
+            if !module_interface.exported_names().contains(name) {
+                continue;
+            }
+
+            let prelude_file_path = self.file_path_resolver.resolve_source_file_path(
+                self.static_file_path_manager
+                    .prelude_package_directory_path(),
+                &module_interface.path().internal_unresolved(),
+            );
+
+            if let Some(source_information) = self
+                .module_analyzer
+                .read_source(&prelude_file_path, &workspace.documents)
+                .ok()
+                .and_then(|source| self.module_parser.parse(&source, &prelude_file_path).ok())
+                .and_then(|module| find_definition_in_module(&module, name))
+            {
+                return self.convert_to_location(
+                    workspace,
+                    &prelude_file_path,
+                    &source_information,
+                );
+            }
+        }
+
+        Ok(Value::Null)
+    }
+
+    fn complete(
+        &self,
+        workspace: &Workspace,
+        params: &Value,
+    ) -> Result<Value, Box<dyn std::error::Error>> {
+        let file_path = self.convert_to_file_path(&params["textDocument"])?;
+        let source = self
+            .module_analyzer
+            .read_source(&file_path, &workspace.documents)?;
+        let (line, character) = convert_from_position(&source, &params["position"]);
+        let prefix = find_word_prefix(&source, line, character);
+
+        // Incomplete sources are parsed without the current line.
+        let module = match self.module_parser.parse(&source, &file_path) {
+            Ok(module) => module,
+            Err(_) => match self.module_parser.parse(
+                &source
+                    .lines()
+                    .enumerate()
+                    .filter(|(index, _)| *index != line)
+                    .map(|(_, line)| line)
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                &file_path,
+            ) {
+                Ok(module) => module,
+                Err(_) => return Ok(json!([])),
+            },
+        };
+
+        let mut items = vec![];
+        let mut record_types = HashMap::<String, lang::types::Record>::new();
+
+        for definition in module.definitions() {
+            items.push((definition.name().to_string(), VARIABLE_COMPLETION_ITEM_KIND));
+        }
+
+        for type_definition in module.type_definitions() {
+            if let Type::Record(record) = type_definition.type_() {
+                record_types.insert(type_definition.name().into(), record.clone());
+            }
+        }
+
+        for import in module.imports() {
+            let qualifier = import
+                .alias()
+                .map(String::from)
+                .unwrap_or_else(|| get_module_name(import.module_path()));
+
+            if let Some((_, imported_module)) =
+                self.parse_imported_module(workspace, import.module_path())
+            {
+                for definition in imported_module.definitions() {
+                    if imported_module.export().names().contains(definition.name()) {
+                        items.push((
+                            format!("{}.{}", qualifier, definition.name()),
+                            VARIABLE_COMPLETION_ITEM_KIND,
+                        ));
+                    }
+                }
+
+                for name in import.names() {
+                    if let Some(type_definition) = imported_module
+                        .type_definitions()
+                        .iter()
+                        .find(|type_definition| type_definition.name() == name)
+                    {
+                        if let Type::Record(record) = type_definition.type_() {
+                            record_types.insert(name.clone(), record.clone());
+                        }
+                    } else {
+                        items.push((name.clone(), VARIABLE_COMPLETION_ITEM_KIND));
+                    }
+                }
+            }
+        }
+
+        for module_interface in &workspace.prelude_module_interfaces {
+            for name in module_interface.exported_names() {
+                if module_interface
+                    .variables()
+                    .contains_key(&module_interface.path().fully_qualify_name(name))
+                {
+                    items.push((name.clone(), VARIABLE_COMPLETION_ITEM_KIND));
+                }
+            }
+        }
+
+        for (name, record) in &record_types {
+            for element in record.elements() {
+                items.push((
+                    format!("{}.{}", name, element.name()),
+                    FIELD_COMPLETION_ITEM_KIND,
+                ));
+            }
+        }
+
+        items.sort();
+        items.dedup();
+
+        Ok(Value::Array(
+            items
+                .into_iter()
+                .filter(|(label, _)| label.starts_with(prefix))
+                .map(|(label, kind)| json!({ "label": label, "kind": kind }))
+                .collect(),
+        ))
+    }
+
+    fn analyze(
+        &self,
+        workspace: &Workspace,
+        file_path: &FilePath,
+    ) -> Result<lang::ModuleAnalysis, Box<dyn std::error::Error>> {
+        self.module_analyzer.analyze(
+            file_path,
+            &workspace.documents,
+            &workspace.package_configuration,
+            &workspace.external_module_interfaces,
+            &workspace.prelude_module_interfaces,
+        )
+    }
+
+    fn parse_imported_module(
+        &self,
+        workspace: &Workspace,
+        module_path: &lang::UnresolvedModulePath,
+    ) -> Option<(FilePath, lang::UnresolvedModule)> {
+        let file_path = match module_path {
+            lang::UnresolvedModulePath::Internal(internal_module_path) => {
+                self.file_path_resolver.resolve_source_file_path(
+                    workspace.package_configuration.directory_path(),
+                    internal_module_path,
+                )
+            }
+            lang::UnresolvedModulePath::External(_) => {
+                let module_path = workspace
+                    .external_module_interfaces
+                    .get(module_path)?
+                    .path();
+
+                self.file_path_resolver.resolve_source_file_path(
+                    &self
+                        .file_path_resolver
+                        .resolve_external_package_directory_path(&ExternalPackage::new(
+                            module_path.package().name(),
+                            module_path.package().version(),
+                        )),
+                    &module_path.internal_unresolved(),
+                )
+            }
+        };
+
+        let module = self
+            .module_parser
+            .parse(
+                &self
+                    .module_analyzer
+                    .read_source(&file_path, &workspace.documents)
+                    .ok()?,
+                &file_path,
+            )
+            .ok()?;
+
+        Some((file_path, module))
+    }
+
+    fn convert_to_file_path(
+        &self,
+        text_document: &Value,
+    ) -> Result<FilePath, Box<dyn std::error::Error>> {
+        self.file_uri_converter
+            .convert_to_file_path(text_document["uri"].as_str().unwrap_or_default())
+    }
+
+    fn convert_to_location(
+        &self,
+        workspace: &Workspace,
+        file_path: &FilePath,
+        source_information: &SourceInformation,
+    ) -> Result<Value, Box<dyn std::error::Error>> {
+        Ok(json!({
+            "uri": self.file_uri_converter.convert_to_uri(file_path)?,
+            "range": convert_to_range(
+                &self.module_analyzer.read_source(file_path, &workspace.documents)?,
+                source_information,
+            ),
+        }))
+    }
+
+    fn send_response(&self, id: &Value, result: Value) -> Result<(), Box<dyn std::error::Error>> {
+        self.message_transport
+            .write_message(&json!({ "jsonrpc": "2.0", "id": id, "result": result }))
+    }
+
+    fn send_error(
+        &self,
+        id: &Value,
+        code: i64,
+        message: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.message_transport.write_message(&json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": code, "message": message },
+        }))
+    }
+
+    fn send_notification(
+        &self,
+        method: &str,
+        params: Value,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        self.message_transport
+            .write_message(&json!({ "jsonrpc": "2.0", "method": method, "params": params }))
+    }
+}
+
+// Only top-level definitions are searched because names in imported modules
+// cannot be shadowed by local variables.
+fn find_definition_in_module(
+    module: &lang::UnresolvedModule,
+    name: &str,
+) -> Option<SourceInformation> {
+    module
+        .definitions()
+        .iter()
+        .find(|definition| definition.name() == name)
+        .map(|definition| definition.source_information().as_ref().clone())
+        .or_else(|| find_type_definition_in_module(module, name))
+}
+
+fn find_type_definition_in_module(
+    module: &lang::UnresolvedModule,
+    name: &str,
+) -> Option<SourceInformation> {
+    module
+        .type_definitions()
+        .iter()
+        .find(|type_definition| type_definition.name() == name)
+        .map(|type_definition| type_definition.source_information().as_ref().clone())
+}
+
+// The innermost one is found if source ranges are nested.
+fn find_by_position<T>(
+    values: &[(Arc<SourceInformation>, T)],
+    line: usize,
+    character: usize,
+) -> Option<&(Arc<SourceInformation>, T)> {
+    values
+        .iter()
+        .filter(|(source_information, _)| {
+            let start = source_information.location();
+            let end = source_information.end_location();
+
+            (start.line_number(), start.column_number()) <= (line + 1, character + 1)
+                && (line + 1, character + 1) < (end.line_number(), end.column_number())
+        })
+        .min_by_key(|(source_information, _)| {
+            let start = source_information.location();
+            let end = source_information.end_location();
+
+            (
+                end.line_number() - start.line_number(),
+                end.column_number() as isize - start.column_number() as isize,
+            )
+        })
+}
+
+fn get_module_name(module_path: &lang::UnresolvedModulePath) -> String {
+    match module_path {
+        lang::UnresolvedModulePath::External(path) => path.components().last(),
+        lang::UnresolvedModulePath::Internal(path) => path.components().last(),
+    }
+    .unwrap_or_default()
+    .into()
+}
+
+fn convert_from_position(source: &str, position: &Value) -> (usize, usize) {
+    let line = position["line"].as_u64().unwrap_or_default() as usize;
+
+    (
+        line,
+        convert_from_utf16(
+            source,
+            line,
+            position["character"].as_u64().unwrap_or_default() as usize,
+        ),
+    )
+}
+
+// Locations in source information are one-based while positions in the
+// protocol are zero-based.
+fn convert_to_range(source: &str, source_information: &SourceInformation) -> Value {
+    json!({
+        "start": convert_to_position(source, source_information.location()),
+        "end": convert_to_position(source, source_information.end_location()),
+    })
+}
+
+fn convert_to_position(source: &str, location: Location) -> Value {
+    let line = location.line_number() - 1;
+
+    json!({
+        "line": line,
+        "character": convert_to_utf16(source, line, location.column_number() - 1),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        build::{
+            CachedExternalPackageDownloader, ExternalPackagesBuilder, ExternalPackagesDownloader,
+            ModuleCompiler, ModuleEmitter, ModulesBuilder, ModulesFinder, ObjectCacheManager,
+            PackageBuilder, PackageLockManager, PreludePackageBuilder, SystemPackageBuilder,
+            SystemPackageConfiguration,
+        },
+        common::{ExternalPackage, FilePathConfiguration, Profile, DEBUG_PROFILE_NAME},
+        infra::{
+            FakeBitCodeConverter, FakeExternalPackageDownloader, FakeFfiPackageInitializer,
+            FakeFilePathDisplayer, FakeFileSystem, FakeFileUriConverter, FakeLogger,
+            FakeMessageTransport, FakePreludePackageDownloader,
+        },
+    };
+
+    const FILE_PATH_CONFIGURATION: FilePathConfiguration = FilePathConfiguration {
+        build_configuration_filename: "ein.json",
+        package_lock_filename: "ein.lock",
+        output_directory_name: ".ein",
+        source_file_extension: "ein",
+        main_file_basename: "Main",
+        test_module_suffix: "Test",
+    };
+    const BUILD_CONFIGURATION: &str = r#"{ "dependencies": {} }"#;
+
+    fn create_compile_configuration() -> Arc<lang::CompileConfiguration> {
+        lang::CompileConfiguration {
+            malloc_function_name: "malloc".into(),
+            realloc_function_name: "realloc".into(),
+            free_function_name: "free".into(),
+            array_type_configuration: lang::ArrayTypeConfiguration {
+                array_type_name: "_Array".into(),
+                empty_array_variable_name: "_emptyArray".into(),
+                push_function_name: "_pushToArray".into(),
+            }
+            .into(),
+            list_type_configuration: lang::ListTypeConfiguration {
+                empty_list_variable_name: "_emptyList".into(),
+                concatenate_function_name: "_concatenateLists".into(),
+                equal_function_name: "_equalLists".into(),
+                prepend_function_name: "_prependToList".into(),
+                deconstruct_function_name: "_firstRest".into(),
+                first_function_name: "_first".into(),
+                rest_function_name: "_rest".into(),
+                list_type_name: "_List".into(),
+                first_rest_type_name: "_FirstRest".into(),
+                map_function_name: "_mapList".into(),
+            }
+            .into(),
+            map_type_configuration: lang::MapTypeConfiguration {
+                map_type_name: "_Map".into(),
+                empty_map_function_name: "_emptyMap".into(),
+                insert_function_name: "_insertIntoMap".into(),
+                combine_hashes_function_name: "_combineHashes".into(),
+                hash_list_function_name: "_hashList".into(),
+                hash_number_function_name: "_hashNumber".into(),
+                hash_string_function_name: "_hashString".into(),
+            }
+            .into(),
+            string_type_configuration: lang::StringTypeConfiguration {
+                equal_function_name: "_equalStrings".into(),
+            }
+            .into(),
+            error_type_configuration: lang::ErrorTypeConfiguration {
+                error_type_name: "Error".into(),
+            }
+            .into(),
+            main_module_configuration: None,
+            assertions: true,
+            debug_information: false,
+            target_triple: None,
+        }
+        .into()
+    }
+
+    // Language servers are run with packages of no dependencies on fake file
+    // systems.
+    fn run_language_server(source: &str, messages: Vec<Value>) -> Vec<Value> {
+        let file_system = FakeFileSystem::new(
+            vec![
+                (FilePath::new(&["ein.json"]), BUILD_CONFIGURATION.into()),
+                (FilePath::new(&["Foo.ein"]), source.into()),
+            ]
+            .drain(..)
+            .collect(),
+        );
+        let message_transport = FakeMessageTransport::new(messages);
+        let file_uri_converter = FakeFileUriConverter::new();
+        let file_path_displayer = FakeFilePathDisplayer::new();
+        let logger = FakeLogger::new();
+        let bit_code_converter = FakeBitCodeConverter::new();
+        let ffi_package_initializer = FakeFfiPackageInitializer::new();
+        let prelude_package_downloader = FakePreludePackageDownloader::new(
+            vec![(FilePath::new(&["ein.json"]), BUILD_CONFIGURATION.into())]
+                .drain(..)
+                .collect(),
+            &file_system,
+        );
+        let external_package_downloader =
+            FakeExternalPackageDownloader::new(Default::default(), &file_system);
+
+        let compile_configuration = create_compile_configuration();
+        let profile = Profile::debug();
+        let static_file_path_manager = StaticFilePathManager::new(&FILE_PATH_CONFIGURATION);
+        let file_path_resolver =
+            FilePathResolver::new(&static_file_path_manager, &FILE_PATH_CONFIGURATION);
+        let module_parser = ModuleParser::new(&file_path_displayer);
+        let module_emitter = ModuleEmitter::new(
+            &file_system,
+            &bit_code_converter,
+            &static_file_path_manager,
+            &[],
+        );
+        let object_cache_manager = ObjectCacheManager::new(
+            &file_system,
+            &static_file_path_manager,
+            DEBUG_PROFILE_NAME,
+            None,
+        );
+        let module_compiler = ModuleCompiler::new(
+            &module_parser,
+            &file_path_resolver,
+            &file_system,
+            &object_cache_manager,
+            &module_emitter,
+            &logger,
+            compile_configuration.clone(),
+            &profile,
+            &FILE_PATH_CONFIGURATION,
+        );
+        let modules_finder = ModulesFinder::new(&file_system, &FILE_PATH_CONFIGURATION);
+        let modules_builder = ModulesBuilder::new(
+            &module_parser,
+            &module_compiler,
+            &modules_finder,
+            &file_system,
+            &file_path_resolver,
+            &logger,
+            1,
+        );
+        let module_analyzer = ModuleAnalyzer::new(
+            &module_parser,
+            &file_path_resolver,
+            &file_system,
+            compile_configuration,
+            &FILE_PATH_CONFIGURATION,
+        );
+        let package_configuration_reader = PackageConfigurationReader::new(
+            &file_system,
+            &file_path_displayer,
+            &static_file_path_manager,
+        );
+        let package_builder = PackageBuilder::new(
+            &modules_builder,
+            &ffi_package_initializer,
+            &file_system,
+            &logger,
+        );
+        let prelude_package_builder = PreludePackageBuilder::new(
+            &package_configuration_reader,
+            &package_builder,
+            &prelude_package_downloader,
+            &static_file_path_manager,
+        );
+        let package_lock_manager = PackageLockManager::new(&file_system, &static_file_path_manager);
+        let cached_external_package_downloader = CachedExternalPackageDownloader::new(
+            &package_configuration_reader,
+            &package_lock_manager,
+            &external_package_downloader,
+            &file_system,
+            &file_path_resolver,
+            &logger,
+        );
+        let system_package_configuration = SystemPackageConfiguration {
+            main_function_module_name: "MainFunction",
+            default_system_package: ExternalPackage::new("github.com/foo/os", "main"),
+        };
+        let external_packages_downloader = ExternalPackagesDownloader::new(
+            &cached_external_package_downloader,
+            &package_lock_manager,
+            &system_package_configuration,
+        );
+        let external_packages_builder = ExternalPackagesBuilder::new(&package_builder);
+        let system_package_builder =
+            SystemPackageBuilder::new(&package_builder, &cached_external_package_downloader);
+        let dependency_interfaces_builder = DependencyInterfacesBuilder::new(
+            &prelude_package_builder,
+            &system_package_builder,
+            &external_packages_downloader,
+            &external_packages_builder,
+        );
+
+        LanguageServer::new(
+            &message_transport,
+            &file_uri_converter,
+            &file_path_displayer,
+            &module_parser,
+            &module_analyzer,
+            &package_configuration_reader,
+            &dependency_interfaces_builder,
+            &file_path_resolver,
+            &static_file_path_manager,
+        )
+        .run()
+        .unwrap();
+
+        message_transport.output_messages()
+    }
+
+    fn create_request(id: u64, method: &str, params: Value) -> Value {
+        json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params })
+    }
+
+    fn create_position_params(line: u64, character: u64) -> Value {
+        json!({
+            "textDocument": { "uri": "file:///Foo.ein" },
+            "position": { "line": line, "character": character },
+        })
+    }
+
+    fn create_range(start: (u64, u64), end: (u64, u64)) -> Value {
+        json!({
+            "start": { "line": start.0, "character": start.1 },
+            "end": { "line": end.0, "character": end.1 },
+        })
+    }
+
+    fn find_response(messages: &[Value], id: u64) -> &Value {
+        messages
+            .iter()
+            .find(|message| message["id"] == json!(id))
+            .unwrap()
+    }
+
+    #[test]
+    fn initialize() {
+        let messages = run_language_server(
+            "",
+            vec![
+                create_request(1, "initialize", json!({})),
+                create_request(2, "shutdown", Value::Null),
+                json!({ "jsonrpc": "2.0", "method": "exit" }),
+            ],
+        );
+
+        assert_eq!(
+            find_response(&messages, 1)["result"]["capabilities"]["definitionProvider"],
+            json!(true)
+        );
+        assert_eq!(find_response(&messages, 2)["result"], Value::Null);
+    }
+
+    #[test]
+    fn respond_to_unknown_method() {
+        let messages = run_language_server("", vec![create_request(1, "foo", json!({}))]);
+
+        assert_eq!(
+            find_response(&messages, 1)["error"]["code"],
+            json!(METHOD_NOT_FOUND_ERROR_CODE)
+        );
+    }
+
+    #[test]
+    fn publish_diagnostics() {
+        let messages = run_language_server(
+            "",
+            vec![json!({
+                "jsonrpc": "2.0",
+                "method": "textDocument/didOpen",
+                "params": {
+                    "textDocument": { "uri": "file:///Foo.ein", "text": "x : Number\nx = None" },
+                },
+            })],
+        );
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            messages[0]["method"],
+            json!("textDocument/publishDiagnostics")
+        );
+        assert_eq!(
+            messages[0]["params"]["diagnostics"][0]["range"],
+            create_range((1, 4), (1, 8))
+        );
+    }
+
+    #[test]
+    fn find_definitions_of_shadowing_arguments() {
+        let messages = run_language_server(
+            "x : Number\nx = 42\n\nf : Number -> Number\nf x = x\n",
+            vec![create_request(
+                1,
+                "textDocument/definition",
+                create_position_params(4, 6),
+            )],
+        );
+
+        assert_eq!(
+            find_response(&messages, 1)["result"],
+            json!({ "uri": "file:///Foo.ein", "range": create_range((3, 0), (4, 7)) })
+        );
+    }
+
+    #[test]
+    fn find_definitions_with_utf16_positions() {
+        let messages = run_language_server(
+            "f : String -> String -> String\nf x y = y\n\nx : String\nx = f \"😀\" x\n",
+            vec![
+                create_request(1, "textDocument/definition", create_position_params(4, 11)),
+                create_request(2, "textDocument/hover", create_position_params(4, 11)),
+            ],
+        );
+
+        assert_eq!(
+            find_response(&messages, 1)["result"],
+            json!({ "uri": "file:///Foo.ein", "range": create_range((3, 0), (4, 12)) })
+        );
+        assert_eq!(
+            find_response(&messages, 2)["result"]["range"],
+            create_range((4, 11), (4, 12))
+        );
+    }
+}
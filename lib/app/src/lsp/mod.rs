@@ -0,0 +1,6 @@
+mod language_server;
+mod module_analyzer;
+mod source_text;
+
+pub use language_server::*;
+pub use module_analyzer::*;
@@ -0,0 +1,197 @@
+use crate::{
+    build::{BuildError, ModuleParser},
+    common::{FilePath, FilePathConfiguration, FilePathResolver, PackageConfiguration, Target},
+    infra::FileSystem,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+pub struct ModuleAnalyzer<'a> {
+    module_parser: &'a ModuleParser<'a>,
+    file_path_resolver: &'a FilePathResolver<'a>,
+    file_system: &'a dyn FileSystem,
+    compile_configuration: Arc<lang::CompileConfiguration>,
+    file_path_configuration: &'a FilePathConfiguration,
+}
+
+impl<'a> ModuleAnalyzer<'a> {
+    pub fn new(
+        module_parser: &'a ModuleParser<'a>,
+        file_path_resolver: &'a FilePathResolver<'a>,
+        file_system: &'a dyn FileSystem,
+        compile_configuration: Arc<lang::CompileConfiguration>,
+        file_path_configuration: &'a FilePathConfiguration,
+    ) -> Self {
+        Self {
+            module_parser,
+            file_path_resolver,
+            file_system,
+            compile_configuration,
+            file_path_configuration,
+        }
+    }
+
+    // Sources of open documents take precedence over files on disk. Modules
+    // imported from the same package are analyzed recursively.
+    pub fn analyze(
+        &self,
+        source_file_path: &FilePath,
+        documents: &HashMap<FilePath, String>,
+        package_configuration: &PackageConfiguration,
+        external_module_interfaces: &HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
+        prelude_module_interfaces: &[lang::ModuleInterface],
+    ) -> Result<lang::ModuleAnalysis, Box<dyn std::error::Error>> {
+        self.analyze_module(
+            source_file_path,
+            &ModuleContext {
+                documents,
+                package_configuration,
+                external_module_interfaces,
+                prelude_module_interfaces,
+            },
+            &mut HashMap::new(),
+            &mut HashSet::new(),
+        )
+    }
+
+    pub fn read_source(
+        &self,
+        source_file_path: &FilePath,
+        documents: &HashMap<FilePath, String>,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        Ok(if let Some(source) = documents.get(source_file_path) {
+            source.clone()
+        } else {
+            self.file_system.read_to_string(source_file_path)?
+        })
+    }
+
+    fn analyze_module(
+        &self,
+        source_file_path: &FilePath,
+        context: &ModuleContext,
+        module_interfaces: &mut HashMap<FilePath, lang::ModuleInterface>,
+        visited_file_paths: &mut HashSet<FilePath>,
+    ) -> Result<lang::ModuleAnalysis, Box<dyn std::error::Error>> {
+        if !visited_file_paths.insert(source_file_path.clone()) {
+            return Err(BuildError::ModuleCircularDependency(source_file_path.clone()).into());
+        }
+
+        let module = self.module_parser.parse(
+            &self.read_source(source_file_path, context.documents)?,
+            source_file_path,
+        )?;
+        let package_directory_path = context.package_configuration.directory_path();
+
+        let mut imports = vec![];
+
+        for import in module.imports() {
+            let module_interface = match import.module_path() {
+                lang::UnresolvedModulePath::Internal(internal_module_path) => {
+                    let imported_file_path = self
+                        .file_path_resolver
+                        .resolve_source_file_path(package_directory_path, internal_module_path);
+
+                    if let Some(module_interface) = module_interfaces.get(&imported_file_path) {
+                        module_interface.clone()
+                    } else if self.file_system.exists(&imported_file_path)
+                        || context.documents.contains_key(&imported_file_path)
+                    {
+                        let module_interface = self
+                            .analyze_module(
+                                &imported_file_path,
+                                context,
+                                module_interfaces,
+                                visited_file_paths,
+                            )?
+                            .module_interface()
+                            .clone();
+
+                        module_interfaces.insert(imported_file_path, module_interface.clone());
+
+                        module_interface
+                    } else {
+                        return Err(self.module_not_found(import.module_path(), source_file_path));
+                    }
+                }
+                lang::UnresolvedModulePath::External(_) => context
+                    .external_module_interfaces
+                    .get(import.module_path())
+                    .ok_or_else(|| self.module_not_found(import.module_path(), source_file_path))?
+                    .clone(),
+            };
+
+            imports.push(lang::Import::with_alias_and_names(
+                module_interface,
+                true,
+                import.alias().map(String::from),
+                import.names().to_vec(),
+                import.source_information().clone(),
+            ));
+        }
+
+        visited_file_paths.remove(source_file_path);
+
+        let module_path = self.file_path_resolver.resolve_module_path(
+            &source_file_path.relative_to(package_directory_path),
+            context.package_configuration.package(),
+        );
+
+        Ok(lang::analyze(
+            &module.resolve(
+                module_path.clone(),
+                imports
+                    .into_iter()
+                    .chain(
+                        context
+                            .prelude_module_interfaces
+                            .iter()
+                            .map(|module_interface| {
+                                lang::Import::new(module_interface.clone(), false)
+                            }),
+                    )
+                    .collect(),
+            ),
+            if self.is_main_module(&module_path, context.package_configuration) {
+                self.compile_configuration.clone()
+            } else {
+                let mut configuration = self.compile_configuration.as_ref().clone();
+                configuration.main_module_configuration = None;
+                configuration.into()
+            },
+        )?)
+    }
+
+    fn module_not_found(
+        &self,
+        module_path: &lang::UnresolvedModulePath,
+        source_file_path: &FilePath,
+    ) -> Box<dyn std::error::Error> {
+        BuildError::ModuleNotFound {
+            module_path: module_path.clone(),
+            source_file_path: source_file_path.clone(),
+        }
+        .into()
+    }
+
+    fn is_main_module(
+        &self,
+        module_path: &lang::ModulePath,
+        package_configuration: &PackageConfiguration,
+    ) -> bool {
+        matches!(
+            package_configuration.build_configuration().target(),
+            Target::Application(_)
+        ) && module_path.components().collect::<Vec<&str>>()
+            == vec![self.file_path_configuration.main_file_basename]
+    }
+}
+
+struct ModuleContext<'a> {
+    documents: &'a HashMap<FilePath, String>,
+    package_configuration: &'a PackageConfiguration,
+    external_module_interfaces: &'a HashMap<lang::UnresolvedModulePath, lang::ModuleInterface>,
+    prelude_module_interfaces: &'a [lang::ModuleInterface],
+}
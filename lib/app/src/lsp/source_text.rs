@@ -0,0 +1,131 @@
+// Lines and characters are zero-based as in the language server protocol.
+
+pub fn find_word(source: &str, line: usize, character: usize) -> Option<&str> {
+    let (line, characters) = find_characters(source, line)?;
+    let index = character.min(characters.len());
+
+    let start = characters[..index]
+        .iter()
+        .rposition(|&(_, character)| !is_word_character(character))
+        .map(|position| position + 1)
+        .unwrap_or(0);
+    let end = characters[index..]
+        .iter()
+        .position(|&(_, character)| !is_word_character(character))
+        .map(|position| index + position)
+        .unwrap_or_else(|| characters.len());
+
+    if start == end {
+        None
+    } else {
+        Some(&line[byte_offset(line, &characters, start)..byte_offset(line, &characters, end)])
+    }
+}
+
+pub fn find_word_prefix(source: &str, line: usize, character: usize) -> &str {
+    if let Some((line, characters)) = find_characters(source, line) {
+        let index = character.min(characters.len());
+        let start = characters[..index]
+            .iter()
+            .rposition(|&(_, character)| !is_word_character(character))
+            .map(|position| position + 1)
+            .unwrap_or(0);
+
+        &line[byte_offset(line, &characters, start)..byte_offset(line, &characters, index)]
+    } else {
+        ""
+    }
+}
+
+// Characters in the protocol are counted in UTF-16 code units while columns in
+// source information are counted in characters.
+pub fn convert_from_utf16(source: &str, line: usize, character: usize) -> usize {
+    let mut units = 0;
+    let mut index = 0;
+
+    for line_character in source.lines().nth(line).unwrap_or_default().chars() {
+        if units >= character {
+            break;
+        }
+
+        units += line_character.len_utf16();
+        index += 1;
+    }
+
+    index + character.saturating_sub(units)
+}
+
+pub fn convert_to_utf16(source: &str, line: usize, character: usize) -> usize {
+    let line = source.lines().nth(line).unwrap_or_default();
+
+    line.chars()
+        .take(character)
+        .map(char::len_utf16)
+        .sum::<usize>()
+        + character.saturating_sub(line.chars().count())
+}
+
+fn find_characters(source: &str, line: usize) -> Option<(&str, Vec<(usize, char)>)> {
+    let line = source.lines().nth(line)?;
+
+    Some((line, line.char_indices().collect()))
+}
+
+fn byte_offset(line: &str, characters: &[(usize, char)], index: usize) -> usize {
+    characters
+        .get(index)
+        .map(|&(offset, _)| offset)
+        .unwrap_or_else(|| line.len())
+}
+
+fn is_word_character(character: char) -> bool {
+    character.is_alphanumeric() || character == '_' || character == '.'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_word_at_position() {
+        assert_eq!(find_word("foo bar", 0, 0), Some("foo"));
+        assert_eq!(find_word("foo bar", 0, 2), Some("foo"));
+        assert_eq!(find_word("foo bar", 0, 3), Some("foo"));
+        assert_eq!(find_word("foo bar", 0, 5), Some("bar"));
+        assert_eq!(find_word("foo bar", 0, 7), Some("bar"));
+        assert_eq!(find_word("x\nFoo.bar x", 1, 4), Some("Foo.bar"));
+    }
+
+    #[test]
+    fn find_no_word() {
+        assert_eq!(find_word("", 0, 0), None);
+        assert_eq!(find_word("( )", 0, 1), None);
+        assert_eq!(find_word("foo", 1, 0), None);
+    }
+
+    #[test]
+    fn find_word_prefix_at_position() {
+        assert_eq!(find_word_prefix("foo bar", 0, 6), "ba");
+        assert_eq!(find_word_prefix("foo Foo.", 0, 8), "Foo.");
+        assert_eq!(find_word_prefix("foo", 0, 0), "");
+        assert_eq!(find_word_prefix("foo", 1, 0), "");
+    }
+
+    #[test]
+    fn convert_characters_from_utf16() {
+        assert_eq!(convert_from_utf16("foo", 0, 2), 2);
+        assert_eq!(convert_from_utf16("\"😀\" x", 0, 5), 4);
+        assert_eq!(convert_from_utf16("x\né x", 1, 2), 2);
+        assert_eq!(convert_from_utf16("foo", 0, 5), 5);
+        assert_eq!(convert_from_utf16("foo", 1, 1), 1);
+    }
+
+    #[test]
+    fn convert_characters_to_utf16() {
+        assert_eq!(convert_to_utf16("foo", 0, 2), 2);
+        assert_eq!(convert_to_utf16("\"😀\" x", 0, 4), 5);
+        assert_eq!(convert_to_utf16("x\né x", 1, 2), 2);
+        assert_eq!(convert_to_utf16("foo", 0, 5), 5);
+        assert_eq!(convert_to_utf16("foo", 1, 1), 1);
+    }
+}
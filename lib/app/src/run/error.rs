@@ -0,0 +1,35 @@
+#[derive(Debug, PartialEq)]
+pub enum RunError {
+    LibraryNotRunnable,
+}
+
+impl RunError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::LibraryNotRunnable => "R0001",
+        }
+    }
+}
+
+impl std::error::Error for RunError {}
+
+impl std::fmt::Display for RunError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::LibraryNotRunnable => write!(formatter, "library packages cannot be run"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_library_not_runnable() {
+        assert_eq!(
+            format!("{}", RunError::LibraryNotRunnable),
+            "library packages cannot be run"
+        );
+    }
+}
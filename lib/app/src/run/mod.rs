@@ -0,0 +1,5 @@
+mod error;
+mod package_runner;
+
+pub use error::*;
+pub use package_runner::*;
@@ -0,0 +1,41 @@
+use super::error::RunError;
+use crate::{
+    build::{MainPackageBuilder, PackageConfigurationReader},
+    common::{FilePath, Target},
+    infra::ApplicationRunner,
+};
+
+pub struct PackageRunner<'a> {
+    package_configuration_reader: &'a PackageConfigurationReader<'a>,
+    main_package_builder: &'a MainPackageBuilder<'a>,
+    application_runner: &'a dyn ApplicationRunner,
+}
+
+impl<'a> PackageRunner<'a> {
+    pub fn new(
+        package_configuration_reader: &'a PackageConfigurationReader<'a>,
+        main_package_builder: &'a MainPackageBuilder<'a>,
+        application_runner: &'a dyn ApplicationRunner,
+    ) -> Self {
+        Self {
+            package_configuration_reader,
+            main_package_builder,
+            application_runner,
+        }
+    }
+
+    // It returns a status code of an application.
+    pub fn run(&self, arguments: &[String]) -> Result<Option<i32>, Box<dyn std::error::Error>> {
+        let package_configuration = self.package_configuration_reader.read(&FilePath::empty())?;
+
+        let application_target = match package_configuration.build_configuration().target() {
+            Target::Application(application_target) => application_target,
+            Target::Library => return Err(RunError::LibraryNotRunnable.into()),
+        };
+
+        self.main_package_builder.build()?;
+
+        self.application_runner
+            .run(&FilePath::new(&[application_target.name()]), arguments)
+    }
+}
@@ -0,0 +1,54 @@
+#[derive(Debug, PartialEq)]
+pub enum TestError {
+    SystemModuleNotFound {
+        system_module_name: String,
+        package_name: String,
+    },
+    TestsFailed,
+}
+
+impl TestError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::SystemModuleNotFound { .. } => "T0001",
+            Self::TestsFailed => "T0002",
+        }
+    }
+}
+
+impl std::error::Error for TestError {}
+
+impl std::fmt::Display for TestError {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::SystemModuleNotFound {
+                system_module_name,
+                package_name,
+            } => write!(
+                formatter,
+                "\"{}\" module not found in system package \"{}\"",
+                system_module_name, package_name
+            ),
+            Self::TestsFailed => write!(formatter, "tests failed"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_system_module_not_found() {
+        assert_eq!(
+            format!(
+                "{}",
+                TestError::SystemModuleNotFound {
+                    system_module_name: "Os".into(),
+                    package_name: "github.com/foo/os".into(),
+                }
+            ),
+            "\"Os\" module not found in system package \"github.com/foo/os\""
+        );
+    }
+}
@@ -0,0 +1,11 @@
+mod error;
+mod test_case;
+mod test_configuration;
+mod test_harness_generator;
+mod test_runner;
+
+pub use error::*;
+pub use test_case::*;
+pub use test_configuration::*;
+pub use test_harness_generator::*;
+pub use test_runner::*;
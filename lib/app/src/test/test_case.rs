@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TestCase {
+    module_path: lang::ModulePath,
+    name: String,
+    source_information: Arc<lang::debug::SourceInformation>,
+}
+
+impl TestCase {
+    pub fn new(
+        module_path: lang::ModulePath,
+        name: impl Into<String>,
+        source_information: Arc<lang::debug::SourceInformation>,
+    ) -> Self {
+        Self {
+            module_path,
+            name: name.into(),
+            source_information,
+        }
+    }
+
+    pub fn module_path(&self) -> &lang::ModulePath {
+        &self.module_path
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn source_information(&self) -> &Arc<lang::debug::SourceInformation> {
+        &self.source_information
+    }
+}
@@ -0,0 +1,10 @@
+use std::sync::Arc;
+
+pub struct TestConfiguration {
+    pub test_name_prefix: String,
+    pub main_module_configuration: Arc<lang::MainModuleConfiguration>,
+    pub system_module_name: String,
+    pub system_type_name: String,
+    pub write_function_name: String,
+    pub stdout_variable_name: String,
+}
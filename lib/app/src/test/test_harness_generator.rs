@@ -0,0 +1,349 @@
+use super::{test_case::TestCase, test_configuration::TestConfiguration};
+use crate::common::FilePathConfiguration;
+
+const REPORT_FUNCTION_NAME: &str = "_reportTest";
+const TEST_MODULE_ALIAS_PREFIX: &str = "Test";
+
+pub struct TestHarnessGenerator<'a> {
+    test_configuration: &'a TestConfiguration,
+    file_path_configuration: &'a FilePathConfiguration,
+}
+
+impl<'a> TestHarnessGenerator<'a> {
+    pub fn new(
+        test_configuration: &'a TestConfiguration,
+        file_path_configuration: &'a FilePathConfiguration,
+    ) -> Self {
+        Self {
+            test_configuration,
+            file_path_configuration,
+        }
+    }
+
+    pub fn is_test_module(&self, module_path: &lang::ModulePath) -> bool {
+        module_path
+            .components()
+            .last()
+            .map(|component| component.ends_with(self.file_path_configuration.test_module_suffix))
+            .unwrap_or(false)
+    }
+
+    // Tests are exported boolean variables in test modules. Failures are
+    // reported at their definitions.
+    pub fn find_test_cases(&self, module_interfaces: &[lang::ModuleInterface]) -> Vec<TestCase> {
+        module_interfaces
+            .iter()
+            .filter(|module_interface| self.is_test_module(module_interface.path()))
+            .flat_map(|module_interface| {
+                module_interface
+                    .exported_names()
+                    .iter()
+                    .filter(|name| name.starts_with(&self.test_configuration.test_name_prefix))
+                    .filter_map(move |name| {
+                        let qualified_name = module_interface.path().fully_qualify_name(name);
+
+                        match module_interface.variables().get(&qualified_name) {
+                            Some(lang::types::Type::Boolean(boolean)) => Some(TestCase::new(
+                                module_interface.path().clone(),
+                                name,
+                                module_interface
+                                    .variable_source_informations()
+                                    .get(&qualified_name)
+                                    .unwrap_or_else(|| boolean.source_information())
+                                    .clone(),
+                            )),
+                            _ => None,
+                        }
+                    })
+            })
+            .collect()
+    }
+
+    // A harness reports results of tests to stdout and exits with a non-zero
+    // status code if any of them fail.
+    pub fn generate(
+        &self,
+        system_module_interface: &lang::ModuleInterface,
+        test_cases: &[TestCase],
+    ) -> String {
+        let mut module_aliases = Vec::<&lang::ModulePath>::new();
+
+        for test_case in test_cases {
+            if !module_aliases.contains(&test_case.module_path()) {
+                module_aliases.push(test_case.module_path());
+            }
+        }
+
+        let system_module_name = &self.test_configuration.system_module_name;
+        let system_type = format!(
+            "{}.{}",
+            system_module_name, self.test_configuration.system_type_name
+        );
+        let write = format!(
+            "{}.{} os {}.{}",
+            system_module_name,
+            self.test_configuration.write_function_name,
+            system_module_name,
+            self.test_configuration.stdout_variable_name
+        );
+
+        let mut lines = vec![format!(
+            "import {} \"{}\"",
+            system_module_name,
+            system_module_interface.path().external_unresolved()
+        )];
+
+        for (index, module_path) in module_aliases.iter().enumerate() {
+            lines.push(format!(
+                "import {}{} \"{}\"",
+                TEST_MODULE_ALIAS_PREFIX,
+                index,
+                module_path.internal_unresolved()
+            ));
+        }
+
+        lines.extend(vec![
+            "".into(),
+            format!(
+                "{} : {} -> Boolean -> String -> String -> Number",
+                REPORT_FUNCTION_NAME, system_type
+            ),
+            format!(
+                "{} os passed passedMessage failedMessage =",
+                REPORT_FUNCTION_NAME
+            ),
+            "  if passed then".into(),
+            "    let".into(),
+            format!("      _ = {} passedMessage", write),
+            "    in".into(),
+            "      0".into(),
+            "  else".into(),
+            "    let".into(),
+            format!("      _ = {} failedMessage", write),
+            "    in".into(),
+            "      1".into(),
+            "".into(),
+            format!(
+                "{} : {} -> Number",
+                self.test_configuration
+                    .main_module_configuration
+                    .source_main_function_name,
+                system_type
+            ),
+            format!(
+                "{} os =",
+                self.test_configuration
+                    .main_module_configuration
+                    .source_main_function_name
+            ),
+            "  let".into(),
+        ]);
+
+        for (index, test_case) in test_cases.iter().enumerate() {
+            let name = format!(
+                "{}.{}",
+                test_case
+                    .module_path()
+                    .components()
+                    .collect::<Vec<_>>()
+                    .join("/"),
+                test_case.name()
+            );
+
+            lines.push(format!(
+                "    result{} = {} os {}{}.{} \"{}\" \"{}\"",
+                index,
+                REPORT_FUNCTION_NAME,
+                TEST_MODULE_ALIAS_PREFIX,
+                module_aliases
+                    .iter()
+                    .position(|&module_path| module_path == test_case.module_path())
+                    .unwrap(),
+                test_case.name(),
+                escape_string(&format!("PASS {}\n", name)),
+                escape_string(&format!(
+                    "FAIL {}{}\n",
+                    name,
+                    self.format_location(test_case.source_information())
+                )),
+            ));
+        }
+
+        lines.extend(vec![
+            format!(
+                "    failures = {}",
+                if test_cases.is_empty() {
+                    "0".into()
+                } else {
+                    (0..test_cases.len())
+                        .map(|index| format!("result{}", index))
+                        .collect::<Vec<_>>()
+                        .join(" + ")
+                }
+            ),
+            "  in".into(),
+            "    if failures > 0 then 1 else 0".into(),
+        ]);
+
+        lines.join("\n") + "\n"
+    }
+
+    fn format_location(&self, source_information: &lang::debug::SourceInformation) -> String {
+        if source_information.source_name().is_empty() {
+            "".into()
+        } else {
+            format!(
+                " at {}:{}:{}",
+                source_information.source_name(),
+                source_information.location().line_number(),
+                source_information.location().column_number()
+            )
+        }
+    }
+}
+
+fn escape_string(string: &str) -> String {
+    string
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lang::{
+        debug::{Location, SourceInformation},
+        types, ModuleInterface, ModulePath, Package,
+    };
+
+    const FILE_PATH_CONFIGURATION: FilePathConfiguration = FilePathConfiguration {
+        build_configuration_filename: "ein.json",
+        package_lock_filename: "ein.lock",
+        output_directory_name: ".ein",
+        source_file_extension: "ein",
+        main_file_basename: "Main",
+        test_module_suffix: "Test",
+    };
+
+    fn create_test_configuration() -> TestConfiguration {
+        TestConfiguration {
+            test_name_prefix: "test".into(),
+            main_module_configuration: lang::MainModuleConfiguration {
+                source_main_function_name: "_runTests".into(),
+                object_main_function_name: "_ein_main".into(),
+                main_function_type_name: "MainFunction".into(),
+            }
+            .into(),
+            system_module_name: "Os".into(),
+            system_type_name: "Os".into(),
+            write_function_name: "fdWrite".into(),
+            stdout_variable_name: "stdout".into(),
+        }
+    }
+
+    fn create_module_interface(components: &[&str], names: &[&str]) -> ModuleInterface {
+        let path = ModulePath::new(
+            Package::new("foo", ""),
+            components
+                .iter()
+                .map(|&component| component.into())
+                .collect(),
+        );
+
+        ModuleInterface::with_variable_source_informations(
+            path.clone(),
+            names.iter().map(|&name| name.into()).collect(),
+            Default::default(),
+            names
+                .iter()
+                .map(|name| {
+                    (
+                        path.fully_qualify_name(name),
+                        types::Boolean::new(SourceInformation::new(
+                            "FooTest.ein",
+                            Location::new(1, 9),
+                            "",
+                        ))
+                        .into(),
+                    )
+                })
+                .collect(),
+            Default::default(),
+            names
+                .iter()
+                .map(|name| {
+                    (
+                        path.fully_qualify_name(name),
+                        SourceInformation::new("FooTest.ein", Location::new(1, 1), "").into(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn find_test_cases() {
+        let test_configuration = create_test_configuration();
+        let generator = TestHarnessGenerator::new(&test_configuration, &FILE_PATH_CONFIGURATION);
+
+        assert_eq!(
+            generator
+                .find_test_cases(&[
+                    create_module_interface(&["FooTest"], &["testFoo", "foo"]),
+                    create_module_interface(&["Foo"], &["testFoo"]),
+                ])
+                .iter()
+                .map(|test_case| test_case.name())
+                .collect::<Vec<_>>(),
+            vec!["testFoo"]
+        );
+    }
+
+    #[test]
+    fn generate_parsable_harness() {
+        let test_configuration = create_test_configuration();
+        let generator = TestHarnessGenerator::new(&test_configuration, &FILE_PATH_CONFIGURATION);
+        let test_cases = generator.find_test_cases(&[
+            create_module_interface(&["FooTest"], &["testFoo", "testBar"]),
+            create_module_interface(&["bar", "BarTest"], &["testBaz"]),
+        ]);
+
+        let source = generator.generate(
+            &ModuleInterface::new(
+                ModulePath::new(Package::new("github.com/foo/os", ""), vec!["Os".into()]),
+                Default::default(),
+                Default::default(),
+                Default::default(),
+            ),
+            &test_cases,
+        );
+
+        assert_eq!(test_cases.len(), 3);
+        assert!(source.contains("import Os \"github.com/foo/os/Os\""));
+        assert!(source.contains("import Test1 \"/bar/BarTest\""));
+        assert!(source.contains("FAIL FooTest.testFoo at FooTest.ein:1:1\\n"));
+
+        lang::parse(&source, "").unwrap();
+    }
+
+    #[test]
+    fn generate_harness_without_tests() {
+        let test_configuration = create_test_configuration();
+
+        lang::parse(
+            &TestHarnessGenerator::new(&test_configuration, &FILE_PATH_CONFIGURATION).generate(
+                &ModuleInterface::new(
+                    ModulePath::new(Package::new("github.com/foo/os", ""), vec!["Os".into()]),
+                    Default::default(),
+                    Default::default(),
+                    Default::default(),
+                ),
+                &[],
+            ),
+            "",
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,189 @@
+use super::{
+    error::TestError, test_configuration::TestConfiguration,
+    test_harness_generator::TestHarnessGenerator,
+};
+use crate::{
+    build::{MainPackageBuilder, ModuleCompiler, ObjectCacheManager, PackageConfigurationReader},
+    common::{FilePath, FilePathConfiguration, PackageConfiguration, StaticFilePathManager},
+    infra::{ApplicationLinker, ApplicationRunner, FileSystem, Logger},
+};
+use std::collections::HashMap;
+
+const TEST_TARGET_NAME: &str = "test";
+
+pub struct TestRunner<'a> {
+    package_configuration_reader: &'a PackageConfigurationReader<'a>,
+    main_package_builder: &'a MainPackageBuilder<'a>,
+    module_compiler: &'a ModuleCompiler<'a>,
+    application_linker: &'a dyn ApplicationLinker,
+    application_runner: &'a dyn ApplicationRunner,
+    object_cache_manager: &'a ObjectCacheManager<'a>,
+    file_system: &'a dyn FileSystem,
+    logger: &'a dyn Logger,
+    static_file_path_manager: &'a StaticFilePathManager,
+    file_path_configuration: &'a FilePathConfiguration,
+    test_configuration: &'a TestConfiguration,
+}
+
+impl<'a> TestRunner<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        package_configuration_reader: &'a PackageConfigurationReader<'a>,
+        main_package_builder: &'a MainPackageBuilder<'a>,
+        module_compiler: &'a ModuleCompiler<'a>,
+        application_linker: &'a dyn ApplicationLinker,
+        application_runner: &'a dyn ApplicationRunner,
+        object_cache_manager: &'a ObjectCacheManager<'a>,
+        file_system: &'a dyn FileSystem,
+        logger: &'a dyn Logger,
+        static_file_path_manager: &'a StaticFilePathManager,
+        file_path_configuration: &'a FilePathConfiguration,
+        test_configuration: &'a TestConfiguration,
+    ) -> Self {
+        Self {
+            package_configuration_reader,
+            main_package_builder,
+            module_compiler,
+            application_linker,
+            application_runner,
+            object_cache_manager,
+            file_system,
+            logger,
+            static_file_path_manager,
+            file_path_configuration,
+            test_configuration,
+        }
+    }
+
+    pub fn run(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let package_configuration = self.package_configuration_reader.read(&FilePath::empty())?;
+        let application_modules = self
+            .main_package_builder
+            .build_application_modules(&package_configuration, true)?;
+
+        let test_harness_generator =
+            TestHarnessGenerator::new(self.test_configuration, self.file_path_configuration);
+        let test_cases =
+            test_harness_generator.find_test_cases(application_modules.module_interfaces());
+
+        if test_cases.is_empty() {
+            self.logger.log("no tests found")?;
+
+            return self.object_cache_manager.collect_garbage(TEST_TARGET_NAME);
+        }
+
+        let system_module_interface = application_modules
+            .system_module_interfaces()
+            .iter()
+            .find(|module_interface| {
+                module_interface.path().components().collect::<Vec<_>>()
+                    == vec![self.test_configuration.system_module_name.as_str()]
+            })
+            .ok_or_else(|| TestError::SystemModuleNotFound {
+                system_module_name: self.test_configuration.system_module_name.clone(),
+                package_name: application_modules.system_package().name().into(),
+            })?;
+
+        let harness_source_file_path = self
+            .static_file_path_manager
+            .test_harness_source_file_path();
+
+        self.file_system.write(
+            harness_source_file_path,
+            test_harness_generator
+                .generate(system_module_interface, &test_cases)
+                .as_bytes(),
+        )?;
+
+        let (harness_object_path, _) = self.module_compiler.compile_main_module(
+            harness_source_file_path,
+            &application_modules
+                .module_interfaces()
+                .iter()
+                .map(|module_interface| {
+                    (
+                        module_interface.path().internal_unresolved().into(),
+                        module_interface.clone(),
+                    )
+                })
+                .chain(vec![(
+                    system_module_interface.path().external_unresolved().into(),
+                    system_module_interface.clone(),
+                )])
+                .collect::<HashMap<_, _>>(),
+            application_modules.prelude_module_interfaces(),
+            &package_configuration,
+            self.test_configuration.main_module_configuration.clone(),
+        )?;
+
+        self.logger.log("linking test harness")?;
+
+        let harness_application_file_path = self
+            .static_file_path_manager
+            .test_harness_application_file_path();
+
+        self.application_linker.link(
+            &application_modules
+                .dependency_object_file_paths()
+                .iter()
+                .cloned()
+                .chain(self.filter_main_module_object_path(
+                    application_modules.object_file_paths(),
+                    application_modules.module_interfaces(),
+                    &package_configuration,
+                ))
+                .chain(vec![harness_object_path])
+                .collect::<Vec<_>>(),
+            harness_application_file_path,
+        )?;
+
+        self.object_cache_manager
+            .collect_garbage(TEST_TARGET_NAME)?;
+
+        self.logger
+            .log(&format!("running {} tests", test_cases.len()))?;
+
+        match self
+            .application_runner
+            .run(harness_application_file_path, &[])?
+        {
+            Some(0) => Ok(()),
+            _ => Err(TestError::TestsFailed.into()),
+        }
+    }
+
+    // A main module of an application package is not linked into a test
+    // harness as the harness has its own main function. Object files of
+    // modules are ordered in the same way as their interfaces and followed by
+    // an FFI object file if any.
+    fn filter_main_module_object_path(
+        &self,
+        module_object_paths: &[FilePath],
+        module_interfaces: &[lang::ModuleInterface],
+        package_configuration: &PackageConfiguration,
+    ) -> Vec<FilePath> {
+        if package_configuration
+            .build_configuration()
+            .target()
+            .as_application()
+            .is_none()
+        {
+            return module_object_paths.to_vec();
+        }
+
+        module_object_paths
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                module_interfaces
+                    .get(*index)
+                    .map(|module_interface| {
+                        module_interface.path().components().collect::<Vec<_>>()
+                            != vec![self.file_path_configuration.main_file_basename]
+                    })
+                    .unwrap_or(true)
+            })
+            .map(|(_, path)| path.clone())
+            .collect()
+    }
+}
@@ -0,0 +1,86 @@
+use std::fmt::{self, Debug, Formatter};
+
+// Type information of variant values which is generated by compilers
+#[repr(C)]
+pub struct TypeInformation {
+    clone: extern "C" fn(u64) -> u64,
+    drop: extern "C" fn(u64),
+}
+
+impl TypeInformation {
+    pub const fn new(clone: extern "C" fn(u64) -> u64, drop: extern "C" fn(u64)) -> Self {
+        Self { clone, drop }
+    }
+}
+
+// Values of type parameters and `Any` types are passed as variants.
+#[repr(C)]
+pub struct Any {
+    type_information: &'static TypeInformation,
+    payload: u64,
+}
+
+impl Any {
+    pub fn new(type_information: &'static TypeInformation, payload: u64) -> Self {
+        Self {
+            type_information,
+            payload,
+        }
+    }
+
+    pub fn type_information(&self) -> &'static TypeInformation {
+        self.type_information
+    }
+
+    pub fn payload(&self) -> u64 {
+        self.payload
+    }
+}
+
+impl Clone for Any {
+    fn clone(&self) -> Self {
+        Self {
+            type_information: self.type_information,
+            payload: (self.type_information.clone)(self.payload),
+        }
+    }
+}
+
+impl Drop for Any {
+    fn drop(&mut self) {
+        (self.type_information.drop)(self.payload);
+    }
+}
+
+impl Debug for Any {
+    fn fmt(&self, formatter: &mut Formatter) -> fmt::Result {
+        write!(formatter, "Any({:x})", self.payload)
+    }
+}
+
+impl PartialEq for Any {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.type_information, other.type_information) && self.payload == other.payload
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    extern "C" fn clone_number(payload: u64) -> u64 {
+        payload
+    }
+
+    extern "C" fn drop_number(_: u64) {}
+
+    pub static NUMBER_TYPE_INFORMATION: TypeInformation =
+        TypeInformation::new(clone_number, drop_number);
+
+    #[test]
+    fn clone() {
+        let any = Any::new(&NUMBER_TYPE_INFORMATION, 42);
+
+        assert_eq!(any.clone(), any);
+    }
+}
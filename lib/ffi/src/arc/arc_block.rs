@@ -88,7 +88,7 @@ impl ArcBlock {
             fence(Ordering::Acquire);
 
             unsafe {
-                drop_in_place(self.inner_pointer() as *mut T);
+                drop_in_place(self.ptr_mut() as *mut T);
 
                 // This layout is expected not to be used.
                 dealloc(
@@ -3,7 +3,12 @@ mod arc_buffer;
 
 use arc_block::*;
 pub use arc_buffer::*;
-use std::{alloc::Layout, marker::PhantomData, ops::Deref};
+use std::{
+    alloc::Layout,
+    marker::PhantomData,
+    ops::Deref,
+    ptr::{write, NonNull},
+};
 
 #[derive(Debug)]
 #[repr(C)]
@@ -14,15 +19,30 @@ pub struct Arc<T> {
 
 impl<T> Arc<T> {
     pub fn new(payload: T) -> Self {
-        let mut block = ArcBlock::new(Layout::new::<T>());
+        let mut arc = Self {
+            block: ArcBlock::new(Layout::new::<T>()),
+            phantom: PhantomData::default(),
+        };
+
+        unsafe { write(arc.ptr_mut(), payload) }
+
+        arc
+    }
 
-        unsafe {
-            *(block.ptr_mut() as *mut T) = payload;
+    // Blocks of zero-sized payloads are null.
+    fn ptr(&self) -> *const T {
+        if self.block.is_null() {
+            NonNull::dangling().as_ptr()
+        } else {
+            self.block.ptr() as *const T
         }
+    }
 
-        Self {
-            block,
-            phantom: PhantomData::default(),
+    fn ptr_mut(&mut self) -> *mut T {
+        if self.block.is_null() {
+            NonNull::dangling().as_ptr()
+        } else {
+            self.block.ptr_mut() as *mut T
         }
     }
 }
@@ -37,7 +57,7 @@ impl<T> Deref for Arc<T> {
     type Target = T;
 
     fn deref(&self) -> &T {
-        unsafe { &*(self.block.ptr() as *const T) }
+        unsafe { &*self.ptr() }
     }
 }
 
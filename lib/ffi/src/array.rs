@@ -0,0 +1,408 @@
+use super::{
+    any::{Any, TypeInformation},
+    arc::Arc,
+    number::Number,
+};
+use std::mem::{forget, transmute_copy, ManuallyDrop};
+
+// Arrays are passed to Ein as variants of this type.
+static TYPE_INFORMATION: TypeInformation = TypeInformation::new(clone_payload, drop_payload);
+
+extern "C" fn clone_payload(payload: u64) -> u64 {
+    Array::clone(&ManuallyDrop::new(Array::from_payload(payload))).into_payload()
+}
+
+extern "C" fn drop_payload(payload: u64) {
+    Array::from_payload(payload);
+}
+
+const NODE_BITS: usize = 5;
+const NODE_WIDTH: usize = 1 << NODE_BITS;
+const NODE_MASK: usize = NODE_WIDTH - 1;
+
+// Arrays are immutable and persistent. They are represented as tries whose
+// nodes have up to 32 children and are shared by reference counting, so that
+// updates and appends copy only paths from their roots to their leaves.
+// Elements are dropped only when the last references to their leaves are.
+#[repr(C)]
+#[derive(Debug)]
+pub struct Array {
+    inner: Arc<ArrayInner>,
+}
+
+#[derive(Debug)]
+struct ArrayInner {
+    length: usize,
+    shift: usize,
+    root: Arc<Node>,
+}
+
+#[derive(Clone, Debug)]
+enum Node {
+    Branch(Vec<Arc<Node>>),
+    Leaf(Vec<Any>),
+}
+
+impl Array {
+    pub fn empty() -> Self {
+        Self::new(0, 0, Node::Leaf(vec![]))
+    }
+
+    fn new(length: usize, shift: usize, root: Node) -> Self {
+        Self {
+            inner: ArrayInner {
+                length,
+                shift,
+                root: root.into(),
+            }
+            .into(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.inner.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Any> {
+        (0..self.len()).map(move |index| self.get_element(index))
+    }
+
+    // Indices start from 1.
+    pub fn get(&self, index: Number) -> Option<Any> {
+        self.get_index(index)
+            .map(|index| self.get_element(index).clone())
+    }
+
+    pub fn set(&self, index: Number, element: Any) -> Self {
+        if let Some(index) = self.get_index(index) {
+            Self::new(
+                self.len(),
+                self.inner.shift,
+                set_element(&self.inner.root, self.inner.shift, index, element),
+            )
+        } else {
+            self.clone()
+        }
+    }
+
+    pub fn push(&self, element: Any) -> Self {
+        let index = self.len();
+        let shift = self.inner.shift;
+
+        if index >> shift == NODE_WIDTH {
+            Self::new(
+                index + 1,
+                shift + NODE_BITS,
+                Node::Branch(vec![
+                    self.inner.root.clone(),
+                    create_path(shift, element).into(),
+                ]),
+            )
+        } else {
+            Self::new(
+                index + 1,
+                shift,
+                push_element(&self.inner.root, shift, index, element),
+            )
+        }
+    }
+
+    fn get_element(&self, index: usize) -> &Any {
+        let mut node = &*self.inner.root;
+        let mut shift = self.inner.shift;
+
+        loop {
+            match node {
+                Node::Branch(children) => {
+                    node = &children[(index >> shift) & NODE_MASK];
+                    shift -= NODE_BITS;
+                }
+                Node::Leaf(elements) => return &elements[index & NODE_MASK],
+            }
+        }
+    }
+
+    fn from_payload(payload: u64) -> Self {
+        unsafe { transmute_copy(&(payload as usize)) }
+    }
+
+    fn into_payload(self) -> u64 {
+        let payload = unsafe { transmute_copy::<_, usize>(&self) };
+
+        forget(self);
+
+        payload as u64
+    }
+
+    // Indices which are not integers, such as NaN, are invalid.
+    fn get_index(&self, index: Number) -> Option<usize> {
+        let index = f64::from(index);
+
+        if index >= 1.0 && index <= self.len() as f64 && index.fract() == 0.0 {
+            Some(index as usize - 1)
+        } else {
+            None
+        }
+    }
+}
+
+fn set_element(node: &Node, shift: usize, index: usize, element: Any) -> Node {
+    match node {
+        Node::Branch(children) => {
+            let mut children = children.clone();
+            let child_index = (index >> shift) & NODE_MASK;
+
+            children[child_index] =
+                set_element(&children[child_index], shift - NODE_BITS, index, element).into();
+
+            Node::Branch(children)
+        }
+        Node::Leaf(elements) => {
+            let mut elements = elements.clone();
+
+            elements[index & NODE_MASK] = element;
+
+            Node::Leaf(elements)
+        }
+    }
+}
+
+fn push_element(node: &Node, shift: usize, index: usize, element: Any) -> Node {
+    match node {
+        Node::Branch(children) => {
+            let mut children = children.clone();
+            let child_index = (index >> shift) & NODE_MASK;
+
+            if child_index < children.len() {
+                children[child_index] =
+                    push_element(&children[child_index], shift - NODE_BITS, index, element).into();
+            } else {
+                children.push(create_path(shift - NODE_BITS, element).into());
+            }
+
+            Node::Branch(children)
+        }
+        Node::Leaf(elements) => {
+            let mut elements = elements.clone();
+
+            elements.push(element);
+
+            Node::Leaf(elements)
+        }
+    }
+}
+
+fn create_path(shift: usize, element: Any) -> Node {
+    if shift == 0 {
+        Node::Leaf(vec![element])
+    } else {
+        Node::Branch(vec![create_path(shift - NODE_BITS, element).into()])
+    }
+}
+
+impl Clone for Array {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl Default for Array {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+impl PartialEq for Array {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl From<Vec<Any>> for Array {
+    fn from(elements: Vec<Any>) -> Self {
+        elements
+            .into_iter()
+            .fold(Self::empty(), |array, element| array.push(element))
+    }
+}
+
+impl From<Array> for Any {
+    fn from(array: Array) -> Self {
+        Any::new(&TYPE_INFORMATION, array.into_payload())
+    }
+}
+
+// Variants of other types are converted into empty arrays.
+impl From<Any> for Array {
+    fn from(any: Any) -> Self {
+        if std::ptr::eq(any.type_information(), &TYPE_INFORMATION) {
+            let payload = any.payload();
+
+            forget(any);
+
+            Self::from_payload(payload)
+        } else {
+            Self::empty()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::any::tests::NUMBER_TYPE_INFORMATION, *};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn create_element(payload: u64) -> Any {
+        Any::new(&NUMBER_TYPE_INFORMATION, payload)
+    }
+
+    #[test]
+    fn create_empty() {
+        assert!(Array::empty().is_empty());
+    }
+
+    #[test]
+    fn get_element() {
+        let array = Array::from(vec![create_element(1), create_element(2)]);
+
+        assert_eq!(array.get(1.0.into()), Some(create_element(1)));
+        assert_eq!(array.get(2.0.into()), Some(create_element(2)));
+    }
+
+    #[test]
+    fn get_element_out_of_bounds() {
+        let array = Array::from(vec![create_element(1)]);
+
+        assert_eq!(array.get(0.0.into()), None);
+        assert_eq!(array.get(2.0.into()), None);
+        assert_eq!(array.get(f64::NAN.into()), None);
+        assert_eq!(array.get(1.5.into()), None);
+    }
+
+    #[test]
+    fn set_element() {
+        let array = Array::from(vec![create_element(1), create_element(2)]);
+
+        assert_eq!(
+            array.set(2.0.into(), create_element(3)),
+            vec![create_element(1), create_element(3)].into()
+        );
+        assert_eq!(array, vec![create_element(1), create_element(2)].into());
+    }
+
+    #[test]
+    fn set_element_out_of_bounds() {
+        let array = Array::from(vec![create_element(1)]);
+
+        assert_eq!(array.set(2.0.into(), create_element(2)), array);
+    }
+
+    #[test]
+    fn push_element() {
+        assert_eq!(
+            Array::empty()
+                .push(create_element(1))
+                .push(create_element(2)),
+            vec![create_element(1), create_element(2)].into()
+        );
+    }
+
+    #[test]
+    fn convert_to_any() {
+        let array = Array::from(vec![create_element(1)]);
+
+        assert_eq!(Array::from(Any::from(array.clone())), array);
+    }
+
+    #[test]
+    fn clone_any() {
+        let array = Array::from(vec![create_element(1)]);
+        let any = Any::from(array.clone());
+
+        drop(any.clone());
+
+        assert_eq!(Array::from(any), array);
+    }
+
+    #[test]
+    fn convert_other_variant() {
+        assert_eq!(Array::from(create_element(1)), Array::empty());
+    }
+
+    #[test]
+    fn drop_elements_once() {
+        static DROP_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        extern "C" fn clone_element(payload: u64) -> u64 {
+            payload
+        }
+
+        extern "C" fn drop_element(_: u64) {
+            DROP_COUNT.fetch_add(1, Ordering::SeqCst);
+        }
+
+        static ELEMENT_TYPE_INFORMATION: TypeInformation =
+            TypeInformation::new(clone_element, drop_element);
+
+        let array = Array::from(vec![Any::new(&ELEMENT_TYPE_INFORMATION, 0)]);
+        let other = array.clone();
+
+        drop(array);
+        assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 0);
+
+        drop(other);
+        assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn drop_shared_array() {
+        let array = Array::from(vec![create_element(1)]);
+
+        drop(array.clone());
+
+        assert_eq!(array.get(1.0.into()), Some(create_element(1)));
+    }
+
+    #[test]
+    fn push_elements_over_nodes() {
+        let count = NODE_WIDTH * NODE_WIDTH + 1;
+        let array = (0..count).fold(Array::empty(), |array, index| {
+            array.push(create_element(index as u64))
+        });
+
+        assert_eq!(array.len(), count);
+
+        for index in 0..count {
+            assert_eq!(
+                array.get(((index + 1) as f64).into()),
+                Some(create_element(index as u64))
+            );
+        }
+    }
+
+    #[test]
+    fn set_elements_over_nodes() {
+        let count = NODE_WIDTH * NODE_WIDTH + 1;
+        let array = Array::from((0..count).map(|_| create_element(0)).collect::<Vec<_>>());
+        let other = (0..count).fold(array.clone(), |array, index| {
+            array.set(((index + 1) as f64).into(), create_element(index as u64))
+        });
+
+        for index in 0..count {
+            assert_eq!(
+                array.get(((index + 1) as f64).into()),
+                Some(create_element(0))
+            );
+            assert_eq!(
+                other.get(((index + 1) as f64).into()),
+                Some(create_element(index as u64))
+            );
+        }
+    }
+}
@@ -1,4 +1,6 @@
+mod any;
 mod arc;
+mod array;
 mod boolean;
 mod closure;
 pub mod cps;
@@ -6,7 +8,9 @@ mod none;
 mod number;
 mod string;
 
+pub use any::*;
 pub use arc::*;
+pub use array::*;
 pub use boolean::*;
 pub use closure::*;
 pub use none::*;
@@ -1,72 +1,257 @@
-use super::{command_runner::CommandRunner, file_path_converter::FilePathConverter};
+use super::{
+    command_runner::CommandRunner, error::InfrastructureError,
+    file_path_converter::FilePathConverter, toolchain::Toolchain,
+};
+use std::path::{Path, PathBuf};
+
+const MAIN_FUNCTION_NAME: &str = "main";
 
 pub struct ApplicationLinker<'a> {
     command_runner: &'a CommandRunner,
     file_path_converter: &'a FilePathConverter,
+    toolchain: &'a Toolchain<'a>,
+    jobs: usize,
+    profile: &'a app::Profile,
+    lto_directory_path: &'a app::FilePath,
 }
 
 impl<'a> ApplicationLinker<'a> {
     pub fn new(
         command_runner: &'a CommandRunner,
         file_path_converter: &'a FilePathConverter,
+        toolchain: &'a Toolchain<'a>,
+        jobs: usize,
+        profile: &'a app::Profile,
+        lto_directory_path: &'a app::FilePath,
     ) -> Self {
         Self {
             command_runner,
             file_path_converter,
+            toolchain,
+            jobs,
+            profile,
+            lto_directory_path,
         }
     }
+
+    fn optimization_option(&self) -> String {
+        format!("-O{}", self.profile.optimization_level())
+    }
+
+    // LLVM C API doesn't seem to support the tailcallopt pass directly.
+    // So we compile each bitcode file with the pass manually in order to
+    // optimize all tail calls.
+    fn compile_bitcode(
+        &self,
+        bitcode_paths: &[PathBuf],
+    ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+        let llc = self.toolchain.llc()?;
+
+        self.command_runner.run_all(
+            &mut bitcode_paths
+                .iter()
+                .map(|path| {
+                    let mut command = std::process::Command::new(&llc);
+
+                    // Tail calls are optimized regardless of optimization
+                    // levels as they are required for Ein programs to run in
+                    // constant stack space.
+                    command
+                        .arg(self.optimization_option())
+                        .arg("-tailcallopt")
+                        .arg("--relocation-model=pic")
+                        .arg("-filetype=obj")
+                        .arg(path);
+
+                    command
+                })
+                .collect::<Vec<_>>(),
+            self.jobs,
+        )?;
+
+        Ok(bitcode_paths
+            .iter()
+            .map(|path| path.with_extension("o"))
+            .collect())
+    }
+
+    // All bitcode files are merged into a module and optimized as a whole.
+    // Definitions not referenced by main functions or FFI objects are dropped
+    // by internalization.
+    fn compile_bitcode_with_lto(
+        &self,
+        bitcode_paths: &[PathBuf],
+        ffi_paths: &[PathBuf],
+        application_file_path: &Path,
+    ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+        if bitcode_paths.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let directory = self
+            .file_path_converter
+            .convert_to_os_path(self.lto_directory_path);
+
+        std::fs::create_dir_all(&directory).map_err(|source| {
+            InfrastructureError::CreateDirectory {
+                path: directory.clone(),
+                source,
+            }
+        })?;
+
+        let merged_bitcode_path = directory.join(format!(
+            "{}.lto.bc",
+            application_file_path
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+        ));
+        let optimized_bitcode_path = merged_bitcode_path.with_extension("opt.bc");
+
+        self.command_runner.run(
+            std::process::Command::new(self.toolchain.llvm_command("llvm-link")?)
+                .arg("-o")
+                .arg(&merged_bitcode_path)
+                .args(bitcode_paths),
+        )?;
+
+        self.command_runner.run(
+            std::process::Command::new(self.toolchain.llvm_command("opt")?)
+                .arg(format!(
+                    "-internalize-public-api-list={}",
+                    self.find_public_symbols(ffi_paths)?.join(",")
+                ))
+                .arg(format!(
+                    "-passes=internalize,globaldce,default<O{}>",
+                    self.profile.optimization_level()
+                ))
+                .arg("-o")
+                .arg(&optimized_bitcode_path)
+                .arg(&merged_bitcode_path),
+        )?;
+
+        self.compile_bitcode(&[optimized_bitcode_path])
+    }
+
+    // Symbols undefined in FFI objects are kept public as they might be
+    // defined in Ein modules.
+    fn find_public_symbols(
+        &self,
+        ffi_paths: &[PathBuf],
+    ) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let mut symbols = vec![MAIN_FUNCTION_NAME.into()];
+
+        if !ffi_paths.is_empty() {
+            symbols.extend(parse_undefined_symbols(
+                &self.command_runner.run(
+                    std::process::Command::new(self.toolchain.llvm_command("llvm-nm")?)
+                        .arg("--undefined-only")
+                        .arg("--format=posix")
+                        .args(ffi_paths),
+                )?,
+            ));
+        }
+
+        Ok(symbols)
+    }
 }
 
 impl<'a> app::ApplicationLinker for ApplicationLinker<'a> {
     fn link(
         &self,
         object_file_paths: &[app::FilePath],
-        application_name: &str,
+        application_file_path: &app::FilePath,
     ) -> Result<(), Box<dyn std::error::Error>> {
+        let application_file_path = self
+            .file_path_converter
+            .convert_to_os_path(application_file_path);
         let (bitcode_paths, ffi_paths) = object_file_paths
             .iter()
             .map(|path| self.file_path_converter.convert_to_os_path(path))
             .partition::<Vec<_>, _>(|path| path.extension() == Some(std::ffi::OsStr::new("bc")));
-        let llc = which::which("llc-13")
-            .or_else(|_| which::which("llc-12"))
-            .or_else(|_| which::which("llc-11"))
-            .or_else(|_| which::which("llc"))?;
-
-        for path in &bitcode_paths {
-            // LLVM C API doesn't seem to support the tailcallopt pass directly.
-            // So we compile each bitcode file with the pass manually in order
-            // to optimize all tail calls.
-            self.command_runner.run(
-                std::process::Command::new(&llc)
-                    .arg("-O3")
-                    .arg("-tailcallopt")
-                    .arg("--relocation-model=pic")
-                    .arg("-filetype=obj")
-                    .arg(path),
-            )?;
-        }
+
+        let object_paths = if self.profile.lto() {
+            self.compile_bitcode_with_lto(&bitcode_paths, &ffi_paths, &application_file_path)?
+        } else {
+            self.compile_bitcode(&bitcode_paths)?
+        };
+
+        let configuration = self.toolchain.configuration();
 
         self.command_runner.run(
-            std::process::Command::new("clang")
+            std::process::Command::new(self.toolchain.clang()?)
                 .arg("-Werror") // cspell:disable-line
                 .arg("-Wno-incompatible-pointer-types-discards-qualifiers") // cspell:disable-line
                 .arg("-Wno-override-module") // cspell:disable-line
                 .arg("-o")
-                .arg(
-                    self.file_path_converter
-                        .convert_to_os_path(&app::FilePath::new(&[application_name])),
-                )
-                .arg("-O3")
-                .args(bitcode_paths.iter().map(|path| {
-                    let mut path = path.clone();
-                    path.set_extension("o");
-                    path
-                }))
+                .arg(&application_file_path)
+                .arg(self.optimization_option())
+                .args(if self.profile.debug_information() {
+                    vec!["-g"]
+                } else {
+                    vec![]
+                })
+                .args(object_paths)
                 .args(ffi_paths)
+                .args(if configuration.static_linking() {
+                    vec!["-static"]
+                } else {
+                    vec![]
+                })
                 .arg("-ldl")
-                .arg("-lpthread"),
+                .arg("-lpthread")
+                .args(
+                    configuration
+                        .libraries()
+                        .iter()
+                        .map(|library| format!("-l{}", library)),
+                )
+                .args(configuration.link_arguments())
+                .args(self.profile.link_arguments()),
         )?;
 
         Ok(())
     }
 }
+
+// Outputs of llvm-nm in the POSIX format have lines of symbol names followed by
+// their types. Lines of archive members and defined symbols are ignored.
+fn parse_undefined_symbols(output: &str) -> Vec<String> {
+    output
+        .lines()
+        .filter_map(
+            |line| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                [symbol, "U", ..] => Some(symbol.to_string()),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_undefined_symbols_of_object() {
+        assert_eq!(
+            parse_undefined_symbols("foo U\nbar U\n"),
+            vec!["foo".to_string(), "bar".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_undefined_symbols_of_archive() {
+        assert_eq!(
+            parse_undefined_symbols(
+                "libfoo.a[foo.o]:\nfoo U\nbar T 0000000000000000 0000000000000010\n\nlibfoo.a[bar.o]:\nbaz U\n"
+            ),
+            vec!["foo".to_string(), "baz".to_string()]
+        );
+    }
+
+    #[test]
+    fn parse_no_undefined_symbols() {
+        assert_eq!(parse_undefined_symbols(""), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,29 @@
+use super::file_path_converter::FilePathConverter;
+
+pub struct ApplicationRunner<'a> {
+    file_path_converter: &'a FilePathConverter,
+}
+
+impl<'a> ApplicationRunner<'a> {
+    pub fn new(file_path_converter: &'a FilePathConverter) -> Self {
+        Self {
+            file_path_converter,
+        }
+    }
+}
+
+impl<'a> app::ApplicationRunner for ApplicationRunner<'a> {
+    fn run(
+        &self,
+        application_file_path: &app::FilePath,
+        arguments: &[String],
+    ) -> Result<Option<i32>, Box<dyn std::error::Error>> {
+        Ok(std::process::Command::new(
+            self.file_path_converter
+                .convert_to_os_path(application_file_path),
+        )
+        .args(arguments)
+        .status()?
+        .code())
+    }
+}
@@ -0,0 +1,98 @@
+use super::{
+    command_runner::CommandRunner, file_path_converter::FilePathConverter, toolchain::Toolchain,
+};
+
+pub struct BitCodeConverter<'a> {
+    command_runner: &'a CommandRunner,
+    file_path_converter: &'a FilePathConverter,
+    toolchain: &'a Toolchain<'a>,
+    profile: &'a app::Profile,
+}
+
+impl<'a> BitCodeConverter<'a> {
+    pub fn new(
+        command_runner: &'a CommandRunner,
+        file_path_converter: &'a FilePathConverter,
+        toolchain: &'a Toolchain<'a>,
+        profile: &'a app::Profile,
+    ) -> Self {
+        Self {
+            command_runner,
+            file_path_converter,
+            toolchain,
+            profile,
+        }
+    }
+
+    fn create_output_directory(
+        &self,
+        output_file_path: &std::path::Path,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(directory) = output_file_path.parent() {
+            std::fs::create_dir_all(directory).map_err(|source| {
+                super::error::InfrastructureError::CreateDirectory {
+                    path: directory.into(),
+                    source,
+                }
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> app::BitCodeConverter for BitCodeConverter<'a> {
+    fn convert_to_llvm_ir(
+        &self,
+        bit_code_file_path: &app::FilePath,
+        llvm_ir_file_path: &app::FilePath,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let llvm_ir_file_path = self
+            .file_path_converter
+            .convert_to_os_path(llvm_ir_file_path);
+        let llvm_dis = self.toolchain.llvm_command("llvm-dis")?;
+
+        self.create_output_directory(&llvm_ir_file_path)?;
+        self.command_runner.run(
+            std::process::Command::new(&llvm_dis)
+                .arg("-o")
+                .arg(&llvm_ir_file_path)
+                .arg(
+                    self.file_path_converter
+                        .convert_to_os_path(bit_code_file_path),
+                ),
+        )?;
+
+        Ok(())
+    }
+
+    fn convert_to_assembly(
+        &self,
+        bit_code_file_path: &app::FilePath,
+        assembly_file_path: &app::FilePath,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let assembly_file_path = self
+            .file_path_converter
+            .convert_to_os_path(assembly_file_path);
+        let llc = self.toolchain.llc()?;
+
+        self.create_output_directory(&assembly_file_path)?;
+        // Options are the same as the ones on linking so that emitted assembly
+        // matches code in applications.
+        self.command_runner.run(
+            std::process::Command::new(&llc)
+                .arg(format!("-O{}", self.profile.optimization_level()))
+                .arg("-tailcallopt")
+                .arg("--relocation-model=pic")
+                .arg("-filetype=asm")
+                .arg("-o")
+                .arg(&assembly_file_path)
+                .arg(
+                    self.file_path_converter
+                        .convert_to_os_path(bit_code_file_path),
+                ),
+        )?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use super::error::InfrastructureError;
-use std::io::Write;
+use std::{collections::VecDeque, io::Write};
 
 #[derive(Default)]
 pub struct CommandRunner {}
@@ -13,8 +13,64 @@ impl CommandRunner {
         &self,
         command: &mut std::process::Command,
     ) -> Result<String, Box<dyn std::error::Error>> {
-        let output = command.output()?;
+        Self::check_output(command.output()?)
+    }
+
+    // Commands are run concurrently up to a given number of jobs. Their
+    // outputs are checked in the same order as the commands. No command is
+    // started after a failure but all running ones are waited for.
+    pub fn run_all(
+        &self,
+        commands: &mut [std::process::Command],
+        jobs: usize,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let mut children = VecDeque::new();
+        let mut result = Ok(());
+
+        for command in commands {
+            if children.len() >= jobs.max(1) {
+                result = Self::wait(&mut children);
+
+                if result.is_err() {
+                    break;
+                }
+            }
+
+            match command
+                .stdout(std::process::Stdio::piped())
+                .stderr(std::process::Stdio::piped())
+                .spawn()
+            {
+                Ok(child) => children.push_back(child),
+                Err(error) => {
+                    result = Err(error.into());
+                    break;
+                }
+            }
+        }
 
+        while !children.is_empty() {
+            let child_result = Self::wait(&mut children);
+
+            if result.is_ok() {
+                result = child_result;
+            }
+        }
+
+        result
+    }
+
+    fn wait(
+        children: &mut VecDeque<std::process::Child>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(child) = children.pop_front() {
+            Self::check_output(child.wait_with_output()?)?;
+        }
+
+        Ok(())
+    }
+
+    fn check_output(output: std::process::Output) -> Result<String, Box<dyn std::error::Error>> {
         if output.status.success() {
             return Ok(String::from_utf8(output.stdout)?);
         }
@@ -28,3 +84,42 @@ impl CommandRunner {
         .into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shell_command(script: &str) -> std::process::Command {
+        let mut command = std::process::Command::new("sh");
+        command.arg("-c").arg(script);
+        command
+    }
+
+    #[test]
+    fn run_all_commands() {
+        CommandRunner::new()
+            .run_all(&mut [shell_command("true"), shell_command("true")], 2)
+            .unwrap();
+    }
+
+    #[test]
+    fn report_first_failure_of_commands() {
+        let error = CommandRunner::new()
+            .run_all(
+                &mut [
+                    shell_command("sleep 0.1; exit 1"),
+                    shell_command("exit 2"),
+                    shell_command("true"),
+                ],
+                2,
+            )
+            .unwrap_err();
+
+        assert!(matches!(
+            error.downcast_ref::<InfrastructureError>(),
+            Some(InfrastructureError::CommandExit {
+                status_code: Some(1)
+            })
+        ));
+    }
+}
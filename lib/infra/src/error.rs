@@ -9,6 +9,10 @@ pub enum InfrastructureError {
         path: std::path::PathBuf,
         source: std::io::Error,
     },
+    PackageVersionNotFound {
+        name: String,
+        version: String,
+    },
     ReadDirectory {
         path: std::path::PathBuf,
         source: std::io::Error,
@@ -21,20 +25,53 @@ pub enum InfrastructureError {
         path: std::path::PathBuf,
         source: std::io::Error,
     },
+    RemoveDirectory {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+    RemoveFile {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
+    RenameFile {
+        path: std::path::PathBuf,
+        source: std::io::Error,
+    },
     WriteFile {
         path: std::path::PathBuf,
         source: std::io::Error,
     },
 }
 
+impl InfrastructureError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::CommandExit { .. } => "I0001",
+            Self::CreateDirectory { .. } => "I0002",
+            Self::PackageVersionNotFound { .. } => "I0003",
+            Self::ReadDirectory { .. } => "I0004",
+            Self::ReadRepository { .. } => "I0005",
+            Self::ReadFile { .. } => "I0006",
+            Self::RemoveDirectory { .. } => "I0007",
+            Self::RemoveFile { .. } => "I0008",
+            Self::RenameFile { .. } => "I0009",
+            Self::WriteFile { .. } => "I0010",
+        }
+    }
+}
+
 impl Error for InfrastructureError {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match self {
             Self::CommandExit { status_code: _ } => None,
             Self::CreateDirectory { path: _, source } => Some(source),
+            Self::PackageVersionNotFound { .. } => None,
             Self::ReadDirectory { path: _, source } => Some(source),
             Self::ReadRepository { path: _, source } => Some(source.as_ref()),
             Self::ReadFile { path: _, source } => Some(source),
+            Self::RemoveDirectory { path: _, source } => Some(source),
+            Self::RemoveFile { path: _, source } => Some(source),
+            Self::RenameFile { path: _, source } => Some(source),
             Self::WriteFile { path: _, source } => Some(source),
         }
     }
@@ -54,6 +91,11 @@ impl Display for InfrastructureError {
                 "failed to create directory {}",
                 path.to_string_lossy()
             ),
+            Self::PackageVersionNotFound { name, version } => write!(
+                formatter,
+                "version \"{}\" not found in package \"{}\"",
+                version, name
+            ),
             Self::ReadDirectory { path, source: _ } => write!(
                 formatter,
                 "failed to read directory {}",
@@ -67,6 +109,25 @@ impl Display for InfrastructureError {
             Self::ReadFile { path, source: _ } => {
                 write!(formatter, "failed to read file {}", path.to_string_lossy())
             }
+            Self::RemoveDirectory { path, source: _ } => write!(
+                formatter,
+                "failed to remove directory {}",
+                path.to_string_lossy()
+            ),
+            Self::RemoveFile { path, source: _ } => {
+                write!(
+                    formatter,
+                    "failed to remove file {}",
+                    path.to_string_lossy()
+                )
+            }
+            Self::RenameFile { path, source: _ } => {
+                write!(
+                    formatter,
+                    "failed to rename file {}",
+                    path.to_string_lossy()
+                )
+            }
             Self::WriteFile { path, source: _ } => {
                 write!(formatter, "failed to write file {}", path.to_string_lossy())
             }
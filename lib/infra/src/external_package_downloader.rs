@@ -1,4 +1,7 @@
-use super::file_path_converter::FilePathConverter;
+use super::{error::InfrastructureError, file_path_converter::FilePathConverter};
+
+const DEFAULT_REMOTE_NAME: &str = "origin";
+const DEFAULT_SSH_USERNAME: &str = "git";
 
 pub struct ExternalPackageDownloader<'a> {
     file_path_converter: &'a FilePathConverter,
@@ -10,6 +13,60 @@ impl<'a> ExternalPackageDownloader<'a> {
             file_path_converter,
         }
     }
+
+    fn clone_repository(
+        &self,
+        url: &url::Url,
+        directory_path: &std::path::Path,
+    ) -> Result<git2::Repository, git2::Error> {
+        let mut callbacks = git2::RemoteCallbacks::new();
+        let mut attempted = false;
+
+        callbacks.credentials(move |_, username, allowed_types| {
+            authenticate(&mut attempted, username, allowed_types)
+        });
+
+        let mut fetch_options = git2::FetchOptions::new();
+        fetch_options.remote_callbacks(callbacks);
+
+        git2::build::RepoBuilder::new()
+            .fetch_options(fetch_options)
+            .clone(url.as_str(), directory_path)
+    }
+
+    // Versions are branch names, tag names, commit IDs, or semantic version
+    // requirements matched against tags.
+    fn resolve_version<'r>(
+        &self,
+        repository: &'r git2::Repository,
+        version: &str,
+    ) -> Result<Option<git2::Object<'r>>, git2::Error> {
+        for name in &[
+            version.to_owned(),
+            format!("{}/{}", DEFAULT_REMOTE_NAME, version),
+        ] {
+            if let Ok(reference) = repository.resolve_reference_from_short_name(name) {
+                return Ok(Some(reference.peel(git2::ObjectType::Commit)?));
+            }
+        }
+
+        if let Ok(object) = repository.revparse_single(version) {
+            return Ok(Some(object.peel(git2::ObjectType::Commit)?));
+        }
+
+        let tag_names = repository.tag_names(None)?;
+
+        Ok(
+            match resolve_version_requirement(tag_names.iter().flatten(), version) {
+                Some(tag_name) => Some(
+                    repository
+                        .resolve_reference_from_short_name(tag_name)?
+                        .peel(git2::ObjectType::Commit)?,
+                ),
+                None => None,
+            },
+        )
+    }
 }
 
 impl<'a> app::ExternalPackageDownloader for ExternalPackageDownloader<'a> {
@@ -20,16 +77,109 @@ impl<'a> app::ExternalPackageDownloader for ExternalPackageDownloader<'a> {
     ) -> Result<(), Box<dyn std::error::Error>> {
         let directory_path = self.file_path_converter.convert_to_os_path(directory_path);
 
-        let url = url::Url::parse(&["https://", external_package.name()].concat())?;
-        let repository = git2::Repository::clone(url.as_str(), &directory_path)?;
+        let url = match external_package.url() {
+            Some(url) => url.clone(),
+            None => url::Url::parse(&["https://", external_package.name()].concat())?,
+        };
+        let repository = self.clone_repository(&url, &directory_path)?;
 
-        repository.checkout_tree(
-            &repository
-                .resolve_reference_from_short_name(external_package.version())?
-                .peel(git2::ObjectType::Any)?,
-            None,
-        )?;
+        let object = self
+            .resolve_version(&repository, external_package.version())?
+            .ok_or_else(|| InfrastructureError::PackageVersionNotFound {
+                name: external_package.name().into(),
+                version: external_package.version().into(),
+            })?;
+
+        repository.checkout_tree(&object, None)?;
+        repository.set_head_detached(object.id())?;
 
         Ok(())
     }
 }
+
+// libgit2 calls a credential callback again whenever credentials are rejected.
+// Therefore, an SSH agent is tried only once so that clones fail instead of
+// retrying forever.
+fn authenticate(
+    attempted: &mut bool,
+    username: Option<&str>,
+    allowed_types: git2::CredentialType,
+) -> Result<git2::Cred, git2::Error> {
+    let username = username.unwrap_or(DEFAULT_SSH_USERNAME);
+
+    if allowed_types.contains(git2::CredentialType::USERNAME) {
+        git2::Cred::username(username)
+    } else if *attempted || !allowed_types.contains(git2::CredentialType::SSH_KEY) {
+        Err(git2::Error::from_str("authentication failed"))
+    } else {
+        *attempted = true;
+
+        git2::Cred::ssh_key_from_agent(username)
+    }
+}
+
+fn resolve_version_requirement<'t>(
+    tag_names: impl IntoIterator<Item = &'t str>,
+    requirement: &str,
+) -> Option<&'t str> {
+    let requirement = semver::VersionReq::parse(requirement).ok()?;
+
+    tag_names
+        .into_iter()
+        .filter_map(|tag_name| {
+            semver::Version::parse(tag_name.strip_prefix('v').unwrap_or(tag_name))
+                .ok()
+                .map(|version| (version, tag_name))
+        })
+        .filter(|(version, _)| requirement.matches(version))
+        .max_by(|(one, _), (other, _)| one.cmp(other))
+        .map(|(_, tag_name)| tag_name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn authenticate_with_ssh_agent_only_once() {
+        let mut attempted = false;
+
+        assert!(authenticate(&mut attempted, None, git2::CredentialType::SSH_KEY).is_ok());
+        assert!(authenticate(&mut attempted, None, git2::CredentialType::SSH_KEY).is_err());
+    }
+
+    #[test]
+    fn fail_to_authenticate_without_ssh_keys() {
+        assert!(authenticate(
+            &mut false,
+            Some("foo"),
+            git2::CredentialType::USER_PASS_PLAINTEXT
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn resolve_latest_matching_tag() {
+        assert_eq!(
+            resolve_version_requirement(vec!["v1.0.0", "v1.2.0", "v1.10.1", "v2.0.0"], "^1.2"),
+            Some("v1.10.1")
+        );
+    }
+
+    #[test]
+    fn resolve_tag_without_prefix() {
+        assert_eq!(
+            resolve_version_requirement(vec!["1.0.0", "1.1.0"], "~1.0"),
+            Some("1.0.0")
+        );
+    }
+
+    #[test]
+    fn fail_to_resolve_unmatched_requirement() {
+        assert_eq!(
+            resolve_version_requirement(vec!["v1.0.0", "foo"], ">=2"),
+            None
+        );
+        assert_eq!(resolve_version_requirement(vec!["v1.0.0"], "main"), None);
+    }
+}
@@ -1,20 +1,26 @@
 use super::{command_runner::CommandRunner, file_path_converter::FilePathConverter};
 
 const FFI_INITIALIZATION_SCRIPT: &str = "ein-ffi.sh";
+const TARGET_ENVIRONMENT_VARIABLE: &str = "EIN_TARGET";
+const CARGO_TARGET_ENVIRONMENT_VARIABLE: &str = "CARGO_BUILD_TARGET";
+const CARGO_TARGET_DIRECTORY: &str = "target";
 
 pub struct FfiPackageInitializer<'a> {
     command_runner: &'a CommandRunner,
     file_path_converter: &'a FilePathConverter,
+    target: Option<&'a str>,
 }
 
 impl<'a> FfiPackageInitializer<'a> {
     pub fn new(
         command_runner: &'a CommandRunner,
         file_path_converter: &'a FilePathConverter,
+        target: Option<&'a str>,
     ) -> Self {
         Self {
             command_runner,
             file_path_converter,
+            target,
         }
     }
 }
@@ -24,28 +30,54 @@ impl<'a> app::FfiPackageInitializer for FfiPackageInitializer<'a> {
         &self,
         directory_path: &app::FilePath,
     ) -> Result<Option<app::FilePath>, Box<dyn std::error::Error>> {
-        let stdout = self
-            .command_runner
-            .run(std::process::Command::new("sh").arg("-c").arg(format!(
-                "cd {} && if [ -r {script} ]; then ./{script}; fi",
-                self.file_path_converter
-                    .convert_to_os_path(directory_path)
-                    .to_string_lossy(),
-                script = FFI_INITIALIZATION_SCRIPT,
-            )))?;
+        let mut command = std::process::Command::new("sh");
+
+        command.arg("-c").arg(format!(
+            "cd {} && if [ -r {script} ]; then ./{script}; fi",
+            self.file_path_converter
+                .convert_to_os_path(directory_path)
+                .to_string_lossy(),
+            script = FFI_INITIALIZATION_SCRIPT,
+        ));
+
+        // Scripts build FFI libraries for a target given by an environment
+        // variable if any. Cargo builds libraries for the target as well even
+        // if scripts, such as ones of system packages, are not aware of it.
+        if let Some(target) = self.target {
+            command.env(TARGET_ENVIRONMENT_VARIABLE, target).env(
+                CARGO_TARGET_ENVIRONMENT_VARIABLE,
+                convert_to_cargo_target(target),
+            );
+        }
+
+        let stdout = self.command_runner.run(&mut command)?;
         let path_string = stdout.trim();
 
-        Ok(if path_string.is_empty() {
-            None
-        } else {
-            Some(
-                directory_path.join(
-                    &self
+        if path_string.is_empty() {
+            return Ok(None);
+        }
+
+        let path = directory_path.join(
+            &self
+                .file_path_converter
+                .convert_relative_to_file_path(path_string)?,
+        );
+
+        Ok(Some(
+            match self.target.and_then(|target| {
+                convert_to_cargo_target_path(&path, convert_to_cargo_target(target))
+            }) {
+                Some(target_path)
+                    if self
                         .file_path_converter
-                        .convert_relative_to_file_path(path_string)?,
-                ),
-            )
-        })
+                        .convert_to_os_path(&target_path)
+                        .exists() =>
+                {
+                    target_path
+                }
+                _ => path,
+            },
+        ))
     }
 
     fn is_ffi_used(&self, directory_path: &app::FilePath) -> bool {
@@ -56,3 +88,88 @@ impl<'a> app::FfiPackageInitializer for FfiPackageInitializer<'a> {
             .exists()
     }
 }
+
+// Rust names the `wasm32-wasi` target `wasm32-wasip1` since it removed the old
+// name in 1.84.
+fn convert_to_cargo_target(target: &str) -> &str {
+    match target {
+        "wasm32-wasi" => "wasm32-wasip1",
+        _ => target,
+    }
+}
+
+// Cargo writes libraries built for targets given explicitly into their
+// subdirectories, such as `target/wasm32-wasip1/release` for `target/release`.
+fn convert_to_cargo_target_path(path: &app::FilePath, target: &str) -> Option<app::FilePath> {
+    let components = path.components().collect::<Vec<_>>();
+    let index = components
+        .iter()
+        .rposition(|&component| component == CARGO_TARGET_DIRECTORY)?;
+
+    if components.get(index + 1) == Some(&target) {
+        return None;
+    }
+
+    Some(app::FilePath::new(
+        components[..=index]
+            .iter()
+            .copied()
+            .chain([target])
+            .chain(components[index + 1..].iter().copied())
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_wasi_target() {
+        assert_eq!(convert_to_cargo_target("wasm32-wasi"), "wasm32-wasip1");
+    }
+
+    #[test]
+    fn keep_other_target() {
+        assert_eq!(
+            convert_to_cargo_target("wasm32-unknown-unknown"),
+            "wasm32-unknown-unknown"
+        );
+    }
+
+    #[test]
+    fn convert_host_library_path() {
+        assert_eq!(
+            convert_to_cargo_target_path(
+                &app::FilePath::new(vec!["ffi", "target", "release", "libos.a"]),
+                "wasm32-wasip1"
+            ),
+            Some(app::FilePath::new(vec![
+                "ffi",
+                "target",
+                "wasm32-wasip1",
+                "release",
+                "libos.a"
+            ]))
+        );
+    }
+
+    #[test]
+    fn keep_target_library_path() {
+        assert_eq!(
+            convert_to_cargo_target_path(
+                &app::FilePath::new(vec!["ffi", "target", "wasm32-wasip1", "release", "libos.a"]),
+                "wasm32-wasip1"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn keep_library_path_outside_cargo_target_directory() {
+        assert_eq!(
+            convert_to_cargo_target_path(&app::FilePath::new(vec!["libfoo.a"]), "wasm32-wasip1"),
+            None
+        );
+    }
+}
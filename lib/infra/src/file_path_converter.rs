@@ -38,6 +38,8 @@ impl FilePathConverter {
                     std::path::Component::Normal(component) => {
                         Some(component.to_string_lossy().into())
                     }
+                    // Paths of local packages can be outside package directories.
+                    std::path::Component::ParentDir => Some("..".into()),
                     _ => None,
                 })
                 .collect::<Vec<String>>(),
@@ -55,6 +57,7 @@ impl FilePathConverter {
                     std::path::Component::Normal(component) => {
                         Some(component.to_string_lossy().into())
                     }
+                    std::path::Component::ParentDir => Some("..".into()),
                     _ => None,
                 })
                 .collect::<Vec<String>>(),
@@ -134,4 +134,48 @@ impl<'a> app::FileSystem for FileSystem<'a> {
 
         Ok(())
     }
+
+    fn remove_file(&self, file_path: &app::FilePath) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.file_path_converter.convert_to_os_path(file_path);
+
+        std::fs::remove_file(&path)
+            .map_err(|source| InfrastructureError::RemoveFile { path, source })?;
+
+        Ok(())
+    }
+
+    fn remove_directory(
+        &self,
+        directory_path: &app::FilePath,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.file_path_converter.convert_to_os_path(directory_path);
+
+        std::fs::remove_dir_all(&path)
+            .map_err(|source| InfrastructureError::RemoveDirectory { path, source })?;
+
+        Ok(())
+    }
+
+    fn rename(
+        &self,
+        from: &app::FilePath,
+        to: &app::FilePath,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let from = self.file_path_converter.convert_to_os_path(from);
+        let to = self.file_path_converter.convert_to_os_path(to);
+
+        if let Some(directory) = to.parent() {
+            std::fs::create_dir_all(directory).map_err(|source| {
+                InfrastructureError::CreateDirectory {
+                    path: directory.into(),
+                    source,
+                }
+            })?;
+        }
+
+        std::fs::rename(&from, &to)
+            .map_err(|source| InfrastructureError::RenameFile { path: from, source })?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,59 @@
+use super::file_path_converter::FilePathConverter;
+
+pub struct FileUriConverter<'a> {
+    file_path_converter: &'a FilePathConverter,
+}
+
+impl<'a> FileUriConverter<'a> {
+    pub fn new(file_path_converter: &'a FilePathConverter) -> Self {
+        Self {
+            file_path_converter,
+        }
+    }
+}
+
+impl<'a> app::FileUriConverter for FileUriConverter<'a> {
+    fn convert_to_file_path(&self, uri: &str) -> Result<app::FilePath, Box<dyn std::error::Error>> {
+        self.file_path_converter.convert_absolute_to_file_path(
+            url::Url::parse(uri)?
+                .to_file_path()
+                .map_err(|_| invalid_uri_error(uri))?,
+        )
+    }
+
+    fn convert_to_uri(
+        &self,
+        file_path: &app::FilePath,
+    ) -> Result<String, Box<dyn std::error::Error>> {
+        let path = self.file_path_converter.convert_to_os_path(file_path);
+
+        Ok(url::Url::from_file_path(&path)
+            .map_err(|_| invalid_uri_error(&path.to_string_lossy()))?
+            .into())
+    }
+}
+
+fn invalid_uri_error(uri: &str) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidInput,
+        format!("invalid file URI: {}", uri),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use app::FileUriConverter as _;
+
+    #[test]
+    fn convert_file_path_to_uri_and_back() {
+        let file_path_converter = FilePathConverter::new(std::env::current_dir().unwrap());
+        let converter = FileUriConverter::new(&file_path_converter);
+        let file_path = app::FilePath::new(vec!["foo", "Bar.ein"]);
+
+        let uri = converter.convert_to_uri(&file_path).unwrap();
+
+        assert!(uri.starts_with("file://"));
+        assert_eq!(converter.convert_to_file_path(&uri).unwrap(), file_path);
+    }
+}
@@ -1,4 +1,6 @@
 mod application_linker;
+mod application_runner;
+mod bit_code_converter;
 mod command_runner;
 mod error;
 mod external_package_downloader;
@@ -6,10 +8,17 @@ mod ffi_package_initializer;
 mod file_path_converter;
 mod file_path_displayer;
 mod file_system;
+mod file_uri_converter;
 mod logger;
 mod prelude_package_downloader;
+mod stdio_message_transport;
+mod toolchain;
+mod wasm_application_linker;
+mod wasm_application_runner;
 
 pub use application_linker::*;
+pub use application_runner::*;
+pub use bit_code_converter::*;
 pub use command_runner::*;
 pub use error::*;
 pub use external_package_downloader::*;
@@ -17,5 +26,10 @@ pub use ffi_package_initializer::*;
 pub use file_path_converter::*;
 pub use file_path_displayer::*;
 pub use file_system::*;
+pub use file_uri_converter::*;
 pub use logger::*;
 pub use prelude_package_downloader::*;
+pub use stdio_message_transport::*;
+pub use toolchain::*;
+pub use wasm_application_linker::*;
+pub use wasm_application_runner::*;
@@ -1,3 +1,4 @@
+use super::error::InfrastructureError;
 use std::io::Write;
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
 
@@ -11,19 +12,59 @@ impl Logger {
 
     pub fn log_error(
         &self,
-        error: &dyn std::error::Error,
+        error: &(dyn std::error::Error + 'static),
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Some(error) = error.downcast_ref::<lang::CompileError>() {
+            for error in error.errors() {
+                self.log_error_with_code(error, Some(error.code()))?;
+            }
+
+            Ok(())
+        } else if let Some(parse_error) = error.downcast_ref::<lang::ParseError>() {
+            self.log_error_with_code(error, Some(parse_error.code()))
+        } else if let Some(build_error) = error.downcast_ref::<app::BuildError>() {
+            self.log_error_with_code(error, Some(build_error.code()))
+        } else if let Some(format_error) = error.downcast_ref::<app::FormatError>() {
+            self.log_error_with_code(error, Some(format_error.code()))
+        } else if let Some(run_error) = error.downcast_ref::<app::RunError>() {
+            self.log_error_with_code(error, Some(run_error.code()))
+        } else if let Some(test_error) = error.downcast_ref::<app::TestError>() {
+            self.log_error_with_code(error, Some(test_error.code()))
+        } else if let Some(infrastructure_error) = error.downcast_ref::<InfrastructureError>() {
+            self.log_error_with_code(error, Some(infrastructure_error.code()))
+        } else {
+            self.log_error_with_code(error, None)
+        }
+    }
+
+    fn log_error_with_code(
+        &self,
+        error: &(dyn std::error::Error + 'static),
+        code: Option<&str>,
     ) -> Result<(), Box<dyn std::error::Error>> {
         let mut stderr = StandardStream::stderr(ColorChoice::Auto);
 
         stderr.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
         write!(&mut stderr, "error")?;
+
+        if let Some(code) = code {
+            write!(&mut stderr, "[{}]", code)?;
+        }
+
         stderr.set_color(ColorSpec::new().set_fg(None))?;
+        write!(&mut stderr, ":")?;
+
+        for (index, line) in format!("{}", error).trim().lines().enumerate() {
+            write!(&mut stderr, "{}", if index == 0 { " " } else { "  " })?;
 
-        writeln!(
-            &mut stderr,
-            ": {}",
-            format!("{}", error).replace("\n", "\n  ").trim()
-        )?;
+            if Self::is_underline(line) {
+                stderr.set_color(ColorSpec::new().set_fg(Some(Color::Red)))?;
+                writeln!(&mut stderr, "{}", line)?;
+                stderr.set_color(ColorSpec::new().set_fg(None))?;
+            } else {
+                writeln!(&mut stderr, "{}", line)?;
+            }
+        }
 
         if let Some(error) = error.source() {
             self.log_error(error)?;
@@ -31,6 +72,13 @@ impl Logger {
 
         Ok(())
     }
+
+    fn is_underline(line: &str) -> bool {
+        line.contains('^')
+            && line
+                .chars()
+                .all(|character| character.is_whitespace() || character == '^')
+    }
 }
 
 impl app::Logger for Logger {
@@ -0,0 +1,76 @@
+use std::{
+    io::{BufRead, Read, Write},
+    sync::Mutex,
+};
+
+const CONTENT_LENGTH_HEADER: &str = "Content-Length:";
+
+// Messages are framed by headers as specified in the language server protocol.
+pub struct StdioMessageTransport {
+    reader: Mutex<std::io::BufReader<std::io::Stdin>>,
+}
+
+impl StdioMessageTransport {
+    pub fn new() -> Self {
+        Self {
+            reader: std::io::BufReader::new(std::io::stdin()).into(),
+        }
+    }
+}
+
+impl Default for StdioMessageTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl app::MessageTransport for StdioMessageTransport {
+    fn read_message(&self) -> Result<Option<serde_json::Value>, Box<dyn std::error::Error>> {
+        let mut reader = self.reader.lock().unwrap();
+        let mut content_length = None;
+
+        loop {
+            let mut line = String::new();
+
+            if reader.read_line(&mut line)? == 0 {
+                return Ok(None);
+            }
+
+            let line = line.trim();
+
+            if line.is_empty() {
+                break;
+            } else if let Some(length) = line.strip_prefix(CONTENT_LENGTH_HEADER) {
+                content_length = Some(length.trim().parse::<usize>()?);
+            }
+        }
+
+        let mut content = vec![
+            0;
+            content_length.ok_or_else(|| std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                "content length header not found",
+            ))?
+        ];
+
+        reader.read_exact(&mut content)?;
+
+        Ok(Some(serde_json::from_slice(&content)?))
+    }
+
+    fn write_message(&self, message: &serde_json::Value) -> Result<(), Box<dyn std::error::Error>> {
+        let content = serde_json::to_string(message)?;
+        let mut stdout = std::io::stdout();
+
+        write!(
+            stdout,
+            "{} {}\r\n\r\n{}",
+            CONTENT_LENGTH_HEADER,
+            content.len(),
+            content
+        )?;
+        stdout.flush()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use super::{command_runner::CommandRunner, file_path_converter::FilePathConverter};
+use std::path::{Path, PathBuf};
+
+const SUPPORTED_LLVM_VERSIONS: &[u32] = &[13, 12, 11];
+const LLC_COMMAND_NAME: &str = "llc";
+const CLANG_COMMAND_NAME: &str = "clang";
+const LLC_ENVIRONMENT_VARIABLE: &str = "EIN_LLC";
+const CLANG_ENVIRONMENT_VARIABLE: &str = "EIN_CLANG";
+const WASI_SYSROOT_ENVIRONMENT_VARIABLE: &str = "EIN_WASI_SYSROOT";
+const DEFAULT_WASI_SYSROOT: &str = "/usr/share/wasi-sysroot";
+
+// Toolchains resolve paths of external commands in the following order.
+//
+// 1. Environment variables
+// 2. Toolchain configurations in package configuration files
+// 3. Versioned commands in `PATH`, such as `llc-13`
+//
+// Commands are resolved lazily so that sub-commands not generating code run
+// without LLVM installed. Relative paths in toolchain configurations are
+// resolved against package directories.
+pub struct Toolchain<'a> {
+    command_runner: &'a CommandRunner,
+    file_path_converter: &'a FilePathConverter,
+    configuration: &'a app::ToolchainConfiguration,
+}
+
+impl<'a> Toolchain<'a> {
+    pub fn new(
+        command_runner: &'a CommandRunner,
+        file_path_converter: &'a FilePathConverter,
+        configuration: &'a app::ToolchainConfiguration,
+    ) -> Self {
+        Self {
+            command_runner,
+            file_path_converter,
+            configuration,
+        }
+    }
+
+    pub fn configuration(&self) -> &app::ToolchainConfiguration {
+        self.configuration
+    }
+
+    pub fn llc(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        if let Some(path) = std::env::var_os(LLC_ENVIRONMENT_VARIABLE)
+            .map(PathBuf::from)
+            .map(Ok)
+            .or_else(|| {
+                self.configuration
+                    .llc()
+                    .map(|path| self.resolve_configured_path(path))
+            })
+        {
+            let path = Self::find_command(&path?)?;
+
+            return if self.is_supported(&path)? {
+                Ok(path)
+            } else {
+                Err(Self::llvm_not_found().into())
+            };
+        }
+
+        for version in SUPPORTED_LLVM_VERSIONS {
+            if let Ok(path) = which::which(format!("{}-{}", LLC_COMMAND_NAME, version)) {
+                return Ok(path);
+            }
+        }
+
+        match which::which(LLC_COMMAND_NAME) {
+            Ok(path) if self.is_supported(&path)? => Ok(path),
+            _ => Err(Self::llvm_not_found().into()),
+        }
+    }
+
+    // Other LLVM commands are looked up next to `llc` with the same version
+    // suffix, such as `opt-13` for `/usr/bin/llc-13`.
+    pub fn llvm_command(&self, name: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let llc = self.llc()?;
+        let name = format!(
+            "{}{}",
+            name,
+            llc.file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .trim_start_matches(LLC_COMMAND_NAME)
+        );
+        let path = llc.with_file_name(&name);
+
+        if path.is_file() {
+            Ok(path)
+        } else {
+            Self::find_command(Path::new(&name))
+        }
+    }
+
+    pub fn clang(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        Self::find_command(&match std::env::var_os(CLANG_ENVIRONMENT_VARIABLE) {
+            Some(path) => PathBuf::from(path),
+            None => match self.configuration.clang() {
+                Some(path) => self.resolve_configured_path(path)?,
+                None => CLANG_COMMAND_NAME.into(),
+            },
+        })
+    }
+
+    pub fn wasm_ld(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        self.llvm_command("wasm-ld")
+    }
+
+    // WASI sysroots provide C runtimes and libraries linked to WebAssembly
+    // applications.
+    pub fn wasi_sysroot(&self) -> PathBuf {
+        std::env::var_os(WASI_SYSROOT_ENVIRONMENT_VARIABLE)
+            .map(PathBuf::from)
+            .unwrap_or_else(|| DEFAULT_WASI_SYSROOT.into())
+    }
+
+    // Bare command names are searched in `PATH`.
+    fn resolve_configured_path(&self, path: &str) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let path = Path::new(path);
+
+        Ok(if path.is_absolute() || path.components().count() == 1 {
+            path.into()
+        } else {
+            self.file_path_converter.convert_to_os_path(
+                &self
+                    .file_path_converter
+                    .convert_relative_to_file_path(path)?,
+            )
+        })
+    }
+
+    fn find_command(path: &Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        Ok(
+            which::which(path).map_err(|_| app::BuildError::CommandNotFound {
+                command: path.to_string_lossy().into(),
+            })?,
+        )
+    }
+
+    fn is_supported(&self, llc: &std::path::Path) -> Result<bool, Box<dyn std::error::Error>> {
+        Ok(parse_llvm_version(
+            &self
+                .command_runner
+                .run(std::process::Command::new(llc).arg("--version"))?,
+        )
+        .map(|version| SUPPORTED_LLVM_VERSIONS.contains(&version))
+        .unwrap_or(false))
+    }
+
+    fn llvm_not_found() -> app::BuildError {
+        app::BuildError::LlvmNotFound {
+            supported_versions: SUPPORTED_LLVM_VERSIONS,
+        }
+    }
+}
+
+fn parse_llvm_version(output: &str) -> Option<u32> {
+    output
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("LLVM version "))?
+        .split('.')
+        .next()?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_version() {
+        assert_eq!(
+            parse_llvm_version(
+                "LLVM (http://llvm.org/):\n  LLVM version 13.0.1\n  Optimized build.\n"
+            ),
+            Some(13)
+        );
+    }
+
+    #[test]
+    fn fail_to_parse_version() {
+        assert_eq!(parse_llvm_version("foo"), None);
+    }
+
+    #[test]
+    fn resolve_configured_paths() {
+        let command_runner = CommandRunner::new();
+        let file_path_converter = FilePathConverter::new("/foo");
+        let configuration = Default::default();
+        let toolchain = Toolchain::new(&command_runner, &file_path_converter, &configuration);
+
+        assert_eq!(
+            toolchain.resolve_configured_path("llc-13").unwrap(),
+            PathBuf::from("llc-13")
+        );
+        assert_eq!(
+            toolchain.resolve_configured_path("/usr/bin/llc").unwrap(),
+            PathBuf::from("/usr/bin/llc")
+        );
+        assert_eq!(
+            toolchain.resolve_configured_path("./bin/llc").unwrap(),
+            PathBuf::from("/foo/bin/llc")
+        );
+    }
+
+    #[test]
+    fn fail_to_find_command() {
+        assert_eq!(
+            Toolchain::find_command(Path::new("/foo/bar"))
+                .unwrap_err()
+                .downcast_ref::<app::BuildError>(),
+            Some(&app::BuildError::CommandNotFound {
+                command: "/foo/bar".into()
+            })
+        );
+    }
+}
@@ -0,0 +1,131 @@
+use super::{
+    command_runner::CommandRunner, file_path_converter::FilePathConverter, toolchain::Toolchain,
+};
+use std::path::PathBuf;
+
+const WASI_TARGET_NAME: &str = "wasm32-wasi";
+
+// WebAssembly applications are linked by `wasm-ld` against a WASI sysroot
+// directly as `clang` requires its own sysroot configuration for the target.
+pub struct WasmApplicationLinker<'a> {
+    command_runner: &'a CommandRunner,
+    file_path_converter: &'a FilePathConverter,
+    toolchain: &'a Toolchain<'a>,
+    jobs: usize,
+    profile: &'a app::Profile,
+}
+
+impl<'a> WasmApplicationLinker<'a> {
+    pub fn new(
+        command_runner: &'a CommandRunner,
+        file_path_converter: &'a FilePathConverter,
+        toolchain: &'a Toolchain<'a>,
+        jobs: usize,
+        profile: &'a app::Profile,
+    ) -> Self {
+        Self {
+            command_runner,
+            file_path_converter,
+            toolchain,
+            jobs,
+            profile,
+        }
+    }
+
+    // Tail calls are compiled into the instructions of the tail call proposal
+    // so that Ein programs run in constant stack space.
+    fn compile_bitcode(
+        &self,
+        bitcode_paths: &[PathBuf],
+    ) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+        let llc = self.toolchain.llc()?;
+
+        self.command_runner.run_all(
+            &mut bitcode_paths
+                .iter()
+                .map(|path| {
+                    let mut command = std::process::Command::new(&llc);
+
+                    command
+                        .arg(format!("-O{}", self.profile.optimization_level()))
+                        .arg("-mattr=+tail-call")
+                        .arg("-filetype=obj")
+                        .arg(path);
+
+                    command
+                })
+                .collect::<Vec<_>>(),
+            self.jobs,
+        )?;
+
+        Ok(bitcode_paths
+            .iter()
+            .map(|path| path.with_extension("o"))
+            .collect())
+    }
+}
+
+impl<'a> app::ApplicationLinker for WasmApplicationLinker<'a> {
+    fn link(
+        &self,
+        object_file_paths: &[app::FilePath],
+        application_file_path: &app::FilePath,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (bitcode_paths, ffi_paths) = object_file_paths
+            .iter()
+            .map(|path| self.file_path_converter.convert_to_os_path(path))
+            .partition::<Vec<_>, _>(|path| path.extension() == Some(std::ffi::OsStr::new("bc")));
+        let configuration = self.toolchain.configuration();
+        let library_directory = self
+            .toolchain
+            .wasi_sysroot()
+            .join("lib")
+            .join(WASI_TARGET_NAME);
+
+        // Bitcode files are passed to the linker as they are on link-time
+        // optimization.
+        let (object_paths, lto_arguments) = if self.profile.lto() {
+            (
+                bitcode_paths,
+                vec![
+                    format!("--lto-O{}", self.profile.optimization_level()),
+                    "-mllvm".into(),
+                    "-mattr=+tail-call".into(),
+                ],
+            )
+        } else {
+            (self.compile_bitcode(&bitcode_paths)?, vec![])
+        };
+
+        self.command_runner.run(
+            std::process::Command::new(self.toolchain.wasm_ld()?)
+                .arg("-o")
+                .arg(
+                    self.file_path_converter
+                        .convert_to_os_path(application_file_path),
+                )
+                .args(if self.profile.debug_information() {
+                    vec![]
+                } else {
+                    vec!["--strip-debug"]
+                })
+                .args(lto_arguments)
+                .arg("-L")
+                .arg(&library_directory)
+                .arg(library_directory.join("crt1.o"))
+                .args(object_paths)
+                .args(ffi_paths)
+                .arg("-lc")
+                .args(
+                    configuration
+                        .libraries()
+                        .iter()
+                        .map(|library| format!("-l{}", library)),
+                )
+                .args(configuration.link_arguments())
+                .args(self.profile.link_arguments()),
+        )?;
+
+        Ok(())
+    }
+}
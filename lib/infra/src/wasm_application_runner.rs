@@ -0,0 +1,37 @@
+use super::file_path_converter::FilePathConverter;
+
+const WASMTIME_COMMAND: &str = "wasmtime";
+
+// WebAssembly applications are run by `wasmtime` with the tail call proposal
+// enabled as they are linked by `WasmApplicationLinker`.
+pub struct WasmApplicationRunner<'a> {
+    file_path_converter: &'a FilePathConverter,
+}
+
+impl<'a> WasmApplicationRunner<'a> {
+    pub fn new(file_path_converter: &'a FilePathConverter) -> Self {
+        Self {
+            file_path_converter,
+        }
+    }
+}
+
+impl<'a> app::ApplicationRunner for WasmApplicationRunner<'a> {
+    fn run(
+        &self,
+        application_file_path: &app::FilePath,
+        arguments: &[String],
+    ) -> Result<Option<i32>, Box<dyn std::error::Error>> {
+        Ok(std::process::Command::new(WASMTIME_COMMAND)
+            .arg("run")
+            .arg("-W")
+            .arg("tail-call=y")
+            .arg(
+                self.file_path_converter
+                    .convert_to_os_path(application_file_path),
+            )
+            .args(arguments)
+            .status()?
+            .code())
+    }
+}
@@ -0,0 +1,34 @@
+use std::{
+    process::Command,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// Build IDs distinguish compilers of the same version. Commit IDs are used
+// for clean source trees and build times otherwise.
+fn main() {
+    println!("cargo:rustc-env=EIN_BUILD_ID={}", build_id());
+}
+
+fn build_id() -> String {
+    match (
+        run_git(&["rev-parse", "HEAD"]),
+        run_git(&["status", "--porcelain"]),
+    ) {
+        (Some(commit_id), Some(status)) if status.is_empty() => commit_id,
+        _ => SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos()
+            .to_string(),
+    }
+}
+
+fn run_git(arguments: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(arguments).output().ok()?;
+
+    if output.status.success() {
+        Some(String::from_utf8(output.stdout).ok()?.trim().into())
+    } else {
+        None
+    }
+}
@@ -0,0 +1,28 @@
+use crate::debug::SourceInformation;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comment {
+    content: String,
+    source_information: Arc<SourceInformation>,
+}
+
+impl Comment {
+    pub fn new(
+        content: impl Into<String>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            content: content.into(),
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+}
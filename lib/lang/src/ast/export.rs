@@ -1,16 +1,28 @@
-use std::collections::HashSet;
+use crate::debug::SourceInformation;
+use std::{collections::HashSet, sync::Arc};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Export {
     names: HashSet<String>,
+    source_information: Arc<SourceInformation>,
 }
 
 impl Export {
-    pub fn new(names: HashSet<String>) -> Self {
-        Self { names }
+    pub fn new(
+        names: HashSet<String>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            names,
+            source_information: source_information.into(),
+        }
     }
 
     pub fn names(&self) -> &HashSet<String> {
         &self.names
     }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
 }
@@ -1,7 +1,7 @@
 use super::{
-    application::Application, boolean::Boolean, case::Case, if_::If, let_::Let,
-    let_error::LetError, list::List, list_case::ListCase, none::None, number::Number,
-    operation::Operation, record_construction::RecordConstruction,
+    application::Application, array::Array, boolean::Boolean, case::Case, if_::If, lambda::Lambda,
+    let_::Let, let_error::LetError, list::List, list_case::ListCase, map::Map, none::None,
+    number::Number, operation::Operation, record_construction::RecordConstruction,
     record_element_operation::RecordElementOperation, record_update::RecordUpdate,
     string::EinString, type_coercion::TypeCoercion, variable::Variable,
 };
@@ -11,13 +11,16 @@ use std::sync::Arc;
 #[derive(Clone, Debug, PartialEq)]
 pub enum Expression {
     Application(Application),
+    Array(Array),
     Boolean(Boolean),
     Case(Case),
     If(If),
+    Lambda(Lambda),
     Let(Let),
     LetError(LetError),
     List(List),
     ListCase(ListCase),
+    Map(Map),
     None(None),
     Number(Number),
     Operation(Operation),
@@ -33,6 +36,7 @@ impl Expression {
     pub fn source_information(&self) -> &Arc<SourceInformation> {
         match self {
             Self::Application(application) => application.source_information(),
+            Self::Array(array) => array.source_information(),
             Self::Boolean(boolean) => boolean.source_information(),
             Self::Case(case) => case.source_information(),
             Self::RecordConstruction(record_construction) => {
@@ -41,10 +45,12 @@ impl Expression {
             Self::RecordElementOperation(operation) => operation.source_information(),
             Self::RecordUpdate(record_update) => record_update.source_information(),
             Self::If(if_) => if_.source_information(),
+            Self::Lambda(lambda) => lambda.source_information(),
             Self::Let(let_) => let_.source_information(),
             Self::LetError(let_) => let_.source_information(),
             Self::List(list) => list.source_information(),
             Self::ListCase(case) => case.source_information(),
+            Self::Map(map) => map.source_information(),
             Self::Operation(operation) => operation.source_information(),
             Self::String(string) => string.source_information(),
             Self::TypeCoercion(coercion) => coercion.source_information(),
@@ -60,6 +66,7 @@ impl Expression {
     ) -> Result<Self, E> {
         let expression = match self {
             Self::Application(application) => application.transform_expressions(transform)?.into(),
+            Self::Array(array) => array.transform_expressions(transform)?.into(),
             Self::Case(case) => case.transform_expressions(transform)?.into(),
             Self::RecordConstruction(record_construction) => {
                 record_construction.transform_expressions(transform)?.into()
@@ -71,10 +78,12 @@ impl Expression {
                 record_update.transform_expressions(transform)?.into()
             }
             Self::If(if_) => if_.transform_expressions(transform)?.into(),
+            Self::Lambda(lambda) => lambda.transform_expressions(transform)?.into(),
             Self::Let(let_) => let_.transform_expressions(transform)?.into(),
             Self::LetError(let_) => let_.transform_expressions(transform)?.into(),
             Self::List(list) => list.transform_expressions(transform)?.into(),
             Self::ListCase(case) => case.transform_expressions(transform)?.into(),
+            Self::Map(map) => map.transform_expressions(transform)?.into(),
             Self::Operation(operation) => operation.transform_expressions(transform)?.into(),
             Self::TypeCoercion(coercion) => coercion.transform_expressions(transform)?.into(),
             Self::Boolean(_)
@@ -93,6 +102,7 @@ impl Expression {
     ) -> Result<Self, E> {
         Ok(match self {
             Self::Application(application) => application.transform_types(transform)?.into(),
+            Self::Array(array) => array.transform_types(transform)?.into(),
             Self::Case(case) => case.transform_types(transform)?.into(),
             Self::RecordConstruction(record_construction) => {
                 record_construction.transform_types(transform)?.into()
@@ -100,10 +110,12 @@ impl Expression {
             Self::RecordElementOperation(operation) => operation.transform_types(transform)?.into(),
             Self::RecordUpdate(record_update) => record_update.transform_types(transform)?.into(),
             Self::If(if_) => if_.transform_types(transform)?.into(),
+            Self::Lambda(lambda) => lambda.transform_types(transform)?.into(),
             Self::Let(let_) => let_.transform_types(transform)?.into(),
             Self::LetError(let_) => let_.transform_types(transform)?.into(),
             Self::List(list) => list.transform_types(transform)?.into(),
             Self::ListCase(case) => case.transform_types(transform)?.into(),
+            Self::Map(map) => map.transform_types(transform)?.into(),
             Self::Operation(operation) => operation.transform_types(transform)?.into(),
             Self::TypeCoercion(coercion) => coercion.transform_types(transform)?.into(),
             Self::Boolean(_)
@@ -121,6 +133,12 @@ impl From<Application> for Expression {
     }
 }
 
+impl From<Array> for Expression {
+    fn from(array: Array) -> Self {
+        Self::Array(array)
+    }
+}
+
 impl From<Boolean> for Expression {
     fn from(boolean: Boolean) -> Self {
         Self::Boolean(boolean)
@@ -163,6 +181,12 @@ impl From<If> for Expression {
     }
 }
 
+impl From<Lambda> for Expression {
+    fn from(lambda: Lambda) -> Self {
+        Self::Lambda(lambda)
+    }
+}
+
 impl From<Let> for Expression {
     fn from(let_: Let) -> Self {
         Self::Let(let_)
@@ -187,6 +211,12 @@ impl From<ListCase> for Expression {
     }
 }
 
+impl From<Map> for Expression {
+    fn from(map: Map) -> Self {
+        Self::Map(map)
+    }
+}
+
 impl From<None> for Expression {
     fn from(none: None) -> Self {
         Self::None(none)
@@ -1,9 +1,15 @@
 use super::module_interface::ModuleInterface;
+use crate::debug::SourceInformation;
+use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct Import {
     module_interface: ModuleInterface,
     qualified: bool,
+    alias: Option<String>,
+    names: Vec<String>,
+    // Implicit imports of prelude modules have no source information.
+    source_information: Option<Arc<SourceInformation>>,
 }
 
 impl Import {
@@ -11,6 +17,25 @@ impl Import {
         Self {
             module_interface,
             qualified,
+            alias: None,
+            names: vec![],
+            source_information: None,
+        }
+    }
+
+    pub fn with_alias_and_names(
+        module_interface: ModuleInterface,
+        qualified: bool,
+        alias: Option<String>,
+        names: Vec<String>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            module_interface,
+            qualified,
+            alias,
+            names,
+            source_information: Some(source_information.into()),
         }
     }
 
@@ -21,4 +46,23 @@ impl Import {
     pub fn qualified(&self) -> bool {
         self.qualified
     }
+
+    pub fn alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn source_information(&self) -> Option<&Arc<SourceInformation>> {
+        self.source_information.as_ref()
+    }
+
+    pub fn qualify_name(&self, name: &str) -> String {
+        match &self.alias {
+            Some(alias) => [alias, name].join("."),
+            None => self.module_interface.path().qualify_name(name),
+        }
+    }
 }
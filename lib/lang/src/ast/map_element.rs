@@ -0,0 +1,45 @@
+use super::expression::Expression;
+use crate::types::Type;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct MapElement {
+    key: Expression,
+    value: Expression,
+}
+
+impl MapElement {
+    pub fn new(key: impl Into<Expression>, value: impl Into<Expression>) -> Self {
+        Self {
+            key: key.into(),
+            value: value.into(),
+        }
+    }
+
+    pub fn key(&self) -> &Expression {
+        &self.key
+    }
+
+    pub fn value(&self) -> &Expression {
+        &self.value
+    }
+
+    pub fn transform_expressions<E>(
+        &self,
+        transform: &mut impl FnMut(&Expression) -> Result<Expression, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.key.transform_expressions(transform)?,
+            self.value.transform_expressions(transform)?,
+        ))
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.key.transform_types(transform)?,
+            self.value.transform_types(transform)?,
+        ))
+    }
+}
@@ -1,10 +1,12 @@
 mod alternative;
 mod application;
 mod arithmetic_operation;
+mod array;
 mod boolean;
 mod boolean_operation;
 mod calling_convention;
 mod case;
+mod comment;
 mod definition;
 mod equality_operation;
 mod export;
@@ -14,11 +16,14 @@ mod function_definition;
 mod if_;
 mod import;
 mod import_foreign;
+mod lambda;
 mod let_;
 mod let_error;
 mod list;
 mod list_case;
 mod list_element;
+mod map;
+mod map_element;
 mod module;
 mod module_interface;
 mod none;
@@ -40,10 +45,12 @@ mod variable_definition;
 pub use alternative::*;
 pub use application::*;
 pub use arithmetic_operation::*;
+pub use array::*;
 pub use boolean::*;
 pub use boolean_operation::*;
 pub use calling_convention::*;
 pub use case::*;
+pub use comment::*;
 pub use definition::*;
 pub use equality_operation::*;
 pub use export::*;
@@ -53,11 +60,14 @@ pub use function_definition::*;
 pub use if_::*;
 pub use import::*;
 pub use import_foreign::*;
+pub use lambda::*;
 pub use let_::*;
 pub use let_error::*;
 pub use list::*;
 pub use list_case::*;
 pub use list_element::*;
+pub use map::*;
+pub use map_element::*;
 pub use module::*;
 pub use module_interface::*;
 pub use none::*;
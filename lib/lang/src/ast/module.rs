@@ -40,8 +40,8 @@ impl Module {
     pub fn dummy() -> Self {
         Self::new(
             ModulePath::new(crate::package::Package::new("", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), crate::debug::SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), crate::debug::SourceInformation::dummy()),
             vec![],
             vec![],
             vec![],
@@ -53,8 +53,8 @@ impl Module {
     pub fn from_definitions(definitions: Vec<Definition>) -> Self {
         Self::new(
             ModulePath::new(crate::package::Package::new("", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), crate::debug::SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), crate::debug::SourceInformation::dummy()),
             vec![],
             vec![],
             vec![],
@@ -69,8 +69,8 @@ impl Module {
     ) -> Self {
         Self::new(
             ModulePath::new(crate::package::Package::new("", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), crate::debug::SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), crate::debug::SourceInformation::dummy()),
             vec![],
             vec![],
             type_definitions,
@@ -1,13 +1,23 @@
-use crate::{path::ModulePath, types::Type};
+use crate::{debug::SourceInformation, path::ModulePath, types::Type};
+use derivative::Derivative;
 use serde::{Deserialize, Serialize};
-use std::collections::{BTreeMap, BTreeSet};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 
-#[derive(Clone, Debug, Deserialize, Hash, PartialEq, Serialize)]
+#[derive(Clone, Debug, Derivative, Deserialize, Serialize)]
+#[derivative(Hash, PartialEq)]
 pub struct ModuleInterface {
     path: ModulePath,
     exported_names: BTreeSet<String>,  // Unqualified
     types: BTreeMap<String, Type>,     // Fully-qualified
     variables: BTreeMap<String, Type>, // Fully-qualified
+    #[serde(default)]
+    type_parameters: BTreeMap<String, Vec<String>>, // Fully-qualified
+    #[derivative(Hash = "ignore", PartialEq = "ignore")]
+    #[serde(default)]
+    variable_source_informations: BTreeMap<String, Arc<SourceInformation>>, // Fully-qualified
 }
 
 impl ModuleInterface {
@@ -16,12 +26,42 @@ impl ModuleInterface {
         exported_names: BTreeSet<String>,
         types: BTreeMap<String, Type>,
         variables: BTreeMap<String, Type>,
+    ) -> Self {
+        Self::with_type_parameters(path, exported_names, types, variables, Default::default())
+    }
+
+    pub fn with_type_parameters(
+        path: ModulePath,
+        exported_names: BTreeSet<String>,
+        types: BTreeMap<String, Type>,
+        variables: BTreeMap<String, Type>,
+        type_parameters: BTreeMap<String, Vec<String>>,
+    ) -> Self {
+        Self::with_variable_source_informations(
+            path,
+            exported_names,
+            types,
+            variables,
+            type_parameters,
+            Default::default(),
+        )
+    }
+
+    pub fn with_variable_source_informations(
+        path: ModulePath,
+        exported_names: BTreeSet<String>,
+        types: BTreeMap<String, Type>,
+        variables: BTreeMap<String, Type>,
+        type_parameters: BTreeMap<String, Vec<String>>,
+        variable_source_informations: BTreeMap<String, Arc<SourceInformation>>,
     ) -> Self {
         Self {
             path,
             exported_names,
             types,
             variables,
+            type_parameters,
+            variable_source_informations,
         }
     }
 
@@ -40,4 +80,14 @@ impl ModuleInterface {
     pub fn variables(&self) -> &BTreeMap<String, Type> {
         &self.variables
     }
+
+    pub fn type_parameters(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.type_parameters
+    }
+
+    // Source information is neither compared nor hashed so that moving
+    // definitions does not invalidate dependent modules.
+    pub fn variable_source_informations(&self) -> &BTreeMap<String, Arc<SourceInformation>> {
+        &self.variable_source_informations
+    }
 }
@@ -16,6 +16,26 @@ pub enum Operation {
 }
 
 impl Operation {
+    pub fn lhs(&self) -> &Expression {
+        match self {
+            Self::Arithmetic(operation) => operation.lhs(),
+            Self::Boolean(operation) => operation.lhs(),
+            Self::Equality(operation) => operation.lhs(),
+            Self::Order(operation) => operation.lhs(),
+            Self::Pipe(operation) => operation.lhs(),
+        }
+    }
+
+    pub fn rhs(&self) -> &Expression {
+        match self {
+            Self::Arithmetic(operation) => operation.rhs(),
+            Self::Boolean(operation) => operation.rhs(),
+            Self::Equality(operation) => operation.rhs(),
+            Self::Order(operation) => operation.rhs(),
+            Self::Pipe(operation) => operation.rhs(),
+        }
+    }
+
     pub fn source_information(&self) -> &Arc<SourceInformation> {
         match self {
             Self::Arithmetic(operation) => operation.source_information(),
@@ -1,16 +1,34 @@
-use crate::types::Type;
+use crate::{debug::SourceInformation, types::Type};
+use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct TypeDefinition {
     name: String,
+    type_parameters: Vec<String>,
     type_: Type,
+    source_information: Arc<SourceInformation>,
 }
 
 impl TypeDefinition {
-    pub fn new(name: impl Into<String>, type_: impl Into<Type>) -> Self {
+    pub fn new(
+        name: impl Into<String>,
+        type_: impl Into<Type>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self::with_type_parameters(name, vec![], type_, source_information)
+    }
+
+    pub fn with_type_parameters(
+        name: impl Into<String>,
+        type_parameters: Vec<String>,
+        type_: impl Into<Type>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
         Self {
             name: name.into(),
+            type_parameters,
             type_: type_.into(),
+            source_information: source_information.into(),
         }
     }
 
@@ -18,17 +36,27 @@ impl TypeDefinition {
         &self.name
     }
 
+    pub fn type_parameters(&self) -> &[String] {
+        &self.type_parameters
+    }
+
     pub fn type_(&self) -> &Type {
         &self.type_
     }
 
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+
     pub fn transform_types<E>(
         &self,
         transform: &mut impl FnMut(&Type) -> Result<Type, E>,
     ) -> Result<Self, E> {
-        Ok(Self::new(
+        Ok(Self::with_type_parameters(
             self.name.clone(),
+            self.type_parameters.clone(),
             self.type_.transform_types(transform)?,
+            self.source_information.clone(),
         ))
     }
 }
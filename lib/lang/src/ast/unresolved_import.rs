@@ -1,18 +1,49 @@
-use crate::path::UnresolvedModulePath;
+use crate::{debug::SourceInformation, path::UnresolvedModulePath};
+use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct UnresolvedImport {
     module_path: UnresolvedModulePath,
+    alias: Option<String>,
+    names: Vec<String>,
+    source_information: Arc<SourceInformation>,
 }
 
 impl UnresolvedImport {
-    pub fn new(module_path: impl Into<UnresolvedModulePath>) -> Self {
+    pub fn new(
+        module_path: impl Into<UnresolvedModulePath>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self::with_alias_and_names(module_path, None, vec![], source_information)
+    }
+
+    pub fn with_alias_and_names(
+        module_path: impl Into<UnresolvedModulePath>,
+        alias: Option<String>,
+        names: Vec<String>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
         Self {
             module_path: module_path.into(),
+            alias,
+            names,
+            source_information: source_information.into(),
         }
     }
 
     pub fn module_path(&self) -> &UnresolvedModulePath {
         &self.module_path
     }
+
+    pub fn alias(&self) -> Option<&str> {
+        self.alias.as_deref()
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
 }
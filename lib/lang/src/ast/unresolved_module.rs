@@ -49,8 +49,8 @@ impl UnresolvedModule {
     #[cfg(test)]
     pub fn from_definitions(definitions: Vec<Definition>) -> Self {
         Self::new(
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), crate::debug::SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), crate::debug::SourceInformation::dummy()),
             vec![],
             vec![],
             vec![],
@@ -58,6 +58,10 @@ impl UnresolvedModule {
         )
     }
 
+    pub fn type_definitions(&self) -> &[TypeDefinition] {
+        &self.type_definitions
+    }
+
     pub fn definitions(&self) -> &[Definition] {
         &self.definitions
     }
@@ -0,0 +1,37 @@
+#[cfg(test)]
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+#[cfg(test)]
+use std::sync::Arc;
+
+#[cfg(test)]
+pub static ARRAY_TYPE_CONFIGURATION: Lazy<Arc<ArrayTypeConfiguration>> = Lazy::new(|| {
+    ArrayTypeConfiguration {
+        array_type_name: "GenericArray".into(),
+        empty_array_variable_name: "emptyArray".into(),
+        push_function_name: "pushToArray".into(),
+    }
+    .into()
+});
+
+#[derive(Serialize)]
+pub struct ArrayTypeConfiguration {
+    pub array_type_name: String,
+    pub empty_array_variable_name: String,
+    pub push_function_name: String,
+}
+
+impl ArrayTypeConfiguration {
+    pub fn qualify(&self, names: &HashMap<String, String>) -> Self {
+        Self {
+            array_type_name: self.qualify_name(&self.array_type_name, names),
+            empty_array_variable_name: self.qualify_name(&self.empty_array_variable_name, names),
+            push_function_name: self.qualify_name(&self.push_function_name, names),
+        }
+    }
+
+    fn qualify_name(&self, name: &str, names: &HashMap<String, String>) -> String {
+        names.get(name).cloned().unwrap_or_else(|| name.into())
+    }
+}
@@ -0,0 +1,303 @@
+use super::{
+    error::{collect_results, CompileError},
+    reference_type_resolver::ReferenceTypeResolver,
+    type_canonicalizer::TypeCanonicalizer,
+    type_equality_checker::TypeEqualityChecker,
+};
+use crate::{ast::*, types::Type};
+use std::sync::Arc;
+
+// List case expressions are not checked because they always have both empty
+// and non-empty alternatives.
+pub struct CaseExhaustivenessChecker {
+    reference_type_resolver: Arc<ReferenceTypeResolver>,
+    type_canonicalizer: Arc<TypeCanonicalizer>,
+    type_equality_checker: Arc<TypeEqualityChecker>,
+}
+
+impl CaseExhaustivenessChecker {
+    pub fn new(
+        reference_type_resolver: Arc<ReferenceTypeResolver>,
+        type_canonicalizer: Arc<TypeCanonicalizer>,
+        type_equality_checker: Arc<TypeEqualityChecker>,
+    ) -> Self {
+        Self {
+            reference_type_resolver,
+            type_canonicalizer,
+            type_equality_checker,
+        }
+    }
+
+    pub fn check(&self, module: &Module) -> Result<(), CompileError> {
+        let mut results = vec![];
+
+        module.transform_expressions(&mut |expression| -> Result<_, CompileError> {
+            if let Expression::Case(case) = expression {
+                results.push(self.check_case(case));
+            }
+
+            Ok(expression.clone())
+        })?;
+
+        collect_results(results)?;
+
+        Ok(())
+    }
+
+    fn check_case(&self, case: &Case) -> Result<(), CompileError> {
+        let argument_type = self
+            .type_canonicalizer
+            .canonicalize(&self.reference_type_resolver.resolve(case.type_())?)?;
+
+        // `None` represents all types of values.
+        let mut uncovered_types = match &argument_type {
+            Type::Any(_) | Type::Parameter(_) => None,
+            Type::Union(union) => Some(union.types().iter().cloned().collect()),
+            // Invalid argument types are reported on compilation.
+            _ => return Ok(()),
+        };
+        let mut covered_types = vec![];
+
+        for alternative in case.alternatives() {
+            if uncovered_types.as_ref().map(Vec::is_empty).unwrap_or(false) {
+                return Err(CompileError::CaseAlternativeUnreachable(
+                    alternative.type_().source_information().clone(),
+                ));
+            }
+
+            let alternative_types = self.get_member_types(alternative.type_())?;
+
+            if self.is_default_alternative(&alternative_types)? {
+                uncovered_types = Some(vec![]);
+                continue;
+            }
+
+            let mut matched = false;
+
+            for type_ in &alternative_types {
+                if let Some(uncovered_types) = &mut uncovered_types {
+                    if let Some(index) = self.find_type(uncovered_types, type_)? {
+                        uncovered_types.remove(index);
+                        matched = true;
+                    }
+                } else if self.find_type(&covered_types, type_)?.is_none() {
+                    covered_types.push(type_.clone());
+                    matched = true;
+                }
+            }
+
+            if !matched {
+                return Err(CompileError::CaseAlternativeUnreachable(
+                    alternative.type_().source_information().clone(),
+                ));
+            }
+        }
+
+        match uncovered_types {
+            None => Err(CompileError::CaseNotExhaustive {
+                types: vec![argument_type],
+                source_information: case.source_information().clone(),
+            }),
+            Some(types) if !types.is_empty() => Err(CompileError::CaseNotExhaustive {
+                types,
+                source_information: case.source_information().clone(),
+            }),
+            Some(_) => Ok(()),
+        }
+    }
+
+    fn get_member_types(&self, type_: &Type) -> Result<Vec<Type>, CompileError> {
+        Ok(
+            match self
+                .type_canonicalizer
+                .canonicalize(&self.reference_type_resolver.resolve(type_)?)?
+            {
+                Type::Union(union) => union.types().iter().cloned().collect(),
+                type_ => vec![type_],
+            },
+        )
+    }
+
+    fn is_default_alternative(&self, types: &[Type]) -> Result<bool, CompileError> {
+        for type_ in types {
+            if self.reference_type_resolver.is_any(type_)?
+                || self.reference_type_resolver.is_parameter(type_)?
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    fn find_type(&self, types: &[Type], type_: &Type) -> Result<Option<usize>, CompileError> {
+        for (index, other) in types.iter().enumerate() {
+            if self.type_equality_checker.equal(type_, other)? {
+                return Ok(Some(index));
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::SourceInformation, types};
+    use pretty_assertions::assert_eq;
+
+    fn check_case(
+        argument_type: impl Into<Type>,
+        alternative_types: Vec<Type>,
+    ) -> Result<(), CompileError> {
+        let module = Module::from_definitions(vec![VariableDefinition::new(
+            "x",
+            Case::with_type(
+                argument_type,
+                "y",
+                Variable::new("z", SourceInformation::dummy()),
+                alternative_types
+                    .into_iter()
+                    .map(|type_| Alternative::new(type_, None::new(SourceInformation::dummy())))
+                    .collect(),
+                SourceInformation::dummy(),
+            ),
+            types::None::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+        let reference_type_resolver = ReferenceTypeResolver::new(&module);
+        let type_equality_checker = TypeEqualityChecker::new(reference_type_resolver.clone());
+
+        CaseExhaustivenessChecker::new(
+            reference_type_resolver.clone(),
+            TypeCanonicalizer::new(reference_type_resolver, type_equality_checker.clone()),
+            type_equality_checker,
+        )
+        .check(&module)
+    }
+
+    fn create_union_type() -> types::Union {
+        types::Union::new(
+            vec![
+                types::Number::new(SourceInformation::dummy()).into(),
+                types::None::new(SourceInformation::dummy()).into(),
+            ],
+            SourceInformation::dummy(),
+        )
+    }
+
+    #[test]
+    fn check_exhaustive_case_of_union() {
+        assert_eq!(
+            check_case(
+                create_union_type(),
+                vec![
+                    types::Number::new(SourceInformation::dummy()).into(),
+                    types::None::new(SourceInformation::dummy()).into(),
+                ],
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn check_exhaustive_case_with_union_alternative() {
+        assert_eq!(
+            check_case(create_union_type(), vec![create_union_type().into()]),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn check_exhaustive_case_of_any() {
+        assert_eq!(
+            check_case(
+                types::Any::new(SourceInformation::dummy()),
+                vec![
+                    types::Number::new(SourceInformation::dummy()).into(),
+                    types::Any::new(SourceInformation::dummy()).into(),
+                ],
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_check_case_with_missing_union_member() {
+        assert_eq!(
+            check_case(
+                create_union_type(),
+                vec![types::Number::new(SourceInformation::dummy()).into()],
+            ),
+            Err(CompileError::CaseNotExhaustive {
+                types: vec![types::None::new(SourceInformation::dummy()).into()],
+                source_information: SourceInformation::dummy().into(),
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_check_case_of_any_without_default_alternative() {
+        assert_eq!(
+            check_case(
+                types::Any::new(SourceInformation::dummy()),
+                vec![types::Number::new(SourceInformation::dummy()).into()],
+            ),
+            Err(CompileError::CaseNotExhaustive {
+                types: vec![types::Any::new(SourceInformation::dummy()).into()],
+                source_information: SourceInformation::dummy().into(),
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_check_case_with_alternative_after_default_alternative() {
+        assert_eq!(
+            check_case(
+                types::Any::new(SourceInformation::dummy()),
+                vec![
+                    types::Any::new(SourceInformation::dummy()).into(),
+                    types::Number::new(SourceInformation::dummy()).into(),
+                ],
+            ),
+            Err(CompileError::CaseAlternativeUnreachable(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_check_case_with_duplicate_alternatives() {
+        assert_eq!(
+            check_case(
+                create_union_type(),
+                vec![
+                    types::Number::new(SourceInformation::dummy()).into(),
+                    types::Number::new(SourceInformation::dummy()).into(),
+                    types::None::new(SourceInformation::dummy()).into(),
+                ],
+            ),
+            Err(CompileError::CaseAlternativeUnreachable(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_check_case_with_alternative_not_in_union() {
+        assert_eq!(
+            check_case(
+                create_union_type(),
+                vec![
+                    types::Boolean::new(SourceInformation::dummy()).into(),
+                    create_union_type().into(),
+                ],
+            ),
+            Err(CompileError::CaseAlternativeUnreachable(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+}
@@ -1,11 +1,14 @@
 use super::{
+    array_type_configuration::ArrayTypeConfiguration,
     error_type_configuration::ErrorTypeConfiguration,
     list_type_configuration::ListTypeConfiguration,
     main_module_configuration::MainModuleConfiguration,
+    map_type_configuration::MapTypeConfiguration,
     string_type_configuration::StringTypeConfiguration,
 };
 #[cfg(test)]
 use once_cell::sync::Lazy;
+use serde::Serialize;
 use std::{collections::HashMap, sync::Arc};
 
 #[cfg(test)]
@@ -14,33 +17,49 @@ pub static COMPILE_CONFIGURATION: Lazy<Arc<CompileConfiguration>> = Lazy::new(||
         malloc_function_name: "foo_malloc".into(),
         realloc_function_name: "foo_realloc".into(),
         free_function_name: "foo_free".into(),
+        array_type_configuration: super::array_type_configuration::ARRAY_TYPE_CONFIGURATION.clone(),
         list_type_configuration: super::list_type_configuration::LIST_TYPE_CONFIGURATION.clone(),
+        map_type_configuration: super::map_type_configuration::MAP_TYPE_CONFIGURATION.clone(),
         string_type_configuration: super::string_type_configuration::STRING_TYPE_CONFIGURATION
             .clone(),
         error_type_configuration: super::error_type_configuration::ERROR_TYPE_CONFIGURATION.clone(),
         main_module_configuration: None,
+        assertions: true,
+        debug_information: false,
+        target_triple: None,
     }
     .into()
 });
 
-#[derive(Clone)]
+#[derive(Clone, Serialize)]
 pub struct CompileConfiguration {
     pub malloc_function_name: String,
     pub realloc_function_name: String,
     pub free_function_name: String,
+    pub array_type_configuration: Arc<ArrayTypeConfiguration>,
     pub error_type_configuration: Arc<ErrorTypeConfiguration>,
     pub list_type_configuration: Arc<ListTypeConfiguration>,
+    pub map_type_configuration: Arc<MapTypeConfiguration>,
     pub string_type_configuration: Arc<StringTypeConfiguration>,
     pub main_module_configuration: Option<Arc<MainModuleConfiguration>>,
+    // Intermediate representations are verified on compilation if enabled.
+    pub assertions: bool,
+    // Source locations and function names are emitted as DWARF if enabled.
+    pub debug_information: bool,
+    // Modules are compiled for a host machine if no target triple is given.
+    pub target_triple: Option<String>,
 }
 
 impl CompileConfiguration {
     pub fn qualify(&self, names: &HashMap<String, String>) -> Self {
         let mut configuration = self.clone();
 
+        configuration.array_type_configuration =
+            self.array_type_configuration.qualify(names).into();
         configuration.error_type_configuration =
             self.error_type_configuration.qualify(names).into();
         configuration.list_type_configuration = self.list_type_configuration.qualify(names).into();
+        configuration.map_type_configuration = self.map_type_configuration.qualify(names).into();
         configuration.string_type_configuration =
             self.string_type_configuration.qualify(names).into();
         configuration.main_module_configuration = self
@@ -4,26 +4,157 @@ use std::{error::Error, fmt::Display, sync::Arc};
 #[derive(Debug, PartialEq)]
 pub enum CompileError {
     AnyEqualOperation(Arc<SourceInformation>),
+    CaseAlternativeUnreachable(Arc<SourceInformation>),
     CaseArgumentTypeInvalid(Arc<SourceInformation>),
+    CaseNotExhaustive {
+        types: Vec<types::Type>,
+        source_information: Arc<SourceInformation>,
+    },
     DuplicateNames(Arc<SourceInformation>, Arc<SourceInformation>),
     ExportedNameNotFound {
         name: String,
     },
     FunctionEqualOperation(Arc<SourceInformation>),
     FunctionExpected(Arc<SourceInformation>),
+    ImportedNameConflicted {
+        name: String,
+        source_information: Arc<SourceInformation>,
+    },
+    ImportedNameNotFound {
+        name: String,
+        source_information: Arc<SourceInformation>,
+    },
     MainFunctionNotFound(ModulePath),
+    MapKeyNotComparable(Arc<SourceInformation>),
+    Multiple(Vec<CompileError>),
+    PreludeNameConflicted {
+        name: String,
+        source_information: Arc<SourceInformation>,
+    },
+    RecordElementsNotMatched {
+        record_type: types::Record,
+        source_information: Arc<SourceInformation>,
+    },
     RecordElementNotFound {
         record_type: types::Record,
         name: String,
     },
     RecordEqualOperation(Arc<SourceInformation>),
+    RecordExpected(types::Type),
     EirFmmCompile(eir_fmm::CompileError),
+    TypeArgumentsNotMatched(Arc<SourceInformation>),
     TypeNotFound(types::Reference),
-    TypesNotMatched(Arc<SourceInformation>, Arc<SourceInformation>),
+    TypesNotMatched {
+        lower_type: types::Type,
+        upper_type: types::Type,
+        union_type: Option<types::Union>,
+    },
     TypeNotInferred(Arc<SourceInformation>),
     VariableNotFound(ast::Variable),
 }
 
+impl CompileError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::AnyEqualOperation(_) => "E0001",
+            Self::CaseArgumentTypeInvalid(_) => "E0002",
+            Self::DuplicateNames(_, _) => "E0003",
+            Self::ExportedNameNotFound { .. } => "E0004",
+            Self::FunctionEqualOperation(_) => "E0005",
+            Self::FunctionExpected(_) => "E0006",
+            Self::ImportedNameConflicted { .. } => "E0007",
+            Self::ImportedNameNotFound { .. } => "E0008",
+            Self::MainFunctionNotFound(_) => "E0009",
+            Self::RecordElementNotFound { .. } => "E0010",
+            Self::RecordEqualOperation(_) => "E0011",
+            Self::EirFmmCompile(_) => "E0012",
+            Self::TypeArgumentsNotMatched(_) => "E0013",
+            Self::TypeNotFound(_) => "E0014",
+            Self::TypesNotMatched { .. } => "E0015",
+            Self::TypeNotInferred(_) => "E0016",
+            Self::VariableNotFound(_) => "E0017",
+            Self::MapKeyNotComparable(_) => "E0018",
+            Self::CaseNotExhaustive { .. } => "E0019",
+            Self::CaseAlternativeUnreachable(_) => "E0020",
+            Self::Multiple(_) => "E0021",
+            Self::RecordExpected(_) => "E0022",
+            Self::RecordElementsNotMatched { .. } => "E0023",
+            Self::PreludeNameConflicted { .. } => "E0024",
+        }
+    }
+
+    pub fn source_information(&self) -> Option<&Arc<SourceInformation>> {
+        match self {
+            Self::AnyEqualOperation(source_information)
+            | Self::CaseAlternativeUnreachable(source_information)
+            | Self::CaseArgumentTypeInvalid(source_information)
+            | Self::CaseNotExhaustive {
+                source_information, ..
+            }
+            | Self::DuplicateNames(source_information, _)
+            | Self::FunctionEqualOperation(source_information)
+            | Self::FunctionExpected(source_information)
+            | Self::ImportedNameConflicted {
+                source_information, ..
+            }
+            | Self::ImportedNameNotFound {
+                source_information, ..
+            }
+            | Self::MapKeyNotComparable(source_information)
+            | Self::PreludeNameConflicted {
+                source_information, ..
+            }
+            | Self::RecordElementsNotMatched {
+                source_information, ..
+            }
+            | Self::RecordEqualOperation(source_information)
+            | Self::TypeArgumentsNotMatched(source_information)
+            | Self::TypeNotInferred(source_information) => Some(source_information),
+            Self::RecordElementNotFound { record_type, .. } => {
+                Some(record_type.source_information())
+            }
+            Self::RecordExpected(type_) => Some(type_.source_information()),
+            Self::TypeNotFound(reference) => Some(reference.source_information()),
+            Self::TypesNotMatched { lower_type, .. } => Some(lower_type.source_information()),
+            Self::VariableNotFound(variable) => Some(variable.source_information()),
+            Self::EirFmmCompile(_)
+            | Self::ExportedNameNotFound { .. }
+            | Self::MainFunctionNotFound(_)
+            | Self::Multiple(_) => None,
+        }
+    }
+
+    pub fn errors(&self) -> Vec<&CompileError> {
+        match self {
+            Self::Multiple(errors) => errors.iter().flat_map(|error| error.errors()).collect(),
+            _ => vec![self],
+        }
+    }
+}
+
+// Results of independent compilation steps are collected so that all of their
+// errors are reported at once.
+pub fn collect_results<T>(
+    results: impl IntoIterator<Item = Result<T, CompileError>>,
+) -> Result<Vec<T>, CompileError> {
+    let mut values = vec![];
+    let mut errors = vec![];
+
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(CompileError::Multiple(other_errors)) => errors.extend(other_errors),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    match errors.len() {
+        0 => Ok(values),
+        1 => Err(errors.remove(0)),
+        _ => Err(CompileError::Multiple(errors)),
+    }
+}
+
 impl Display for CompileError {
     fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         match self {
@@ -32,11 +163,29 @@ impl Display for CompileError {
                 "cannot compare Any type values\n{}",
                 source_information
             ),
+            Self::CaseAlternativeUnreachable(source_information) => write!(
+                formatter,
+                "unreachable case alternative\n{}",
+                source_information
+            ),
             Self::CaseArgumentTypeInvalid(source_information) => write!(
                 formatter,
                 "invalid argument type of case expression\n{}",
                 source_information
             ),
+            Self::CaseNotExhaustive {
+                types,
+                source_information,
+            } => write!(
+                formatter,
+                "case expression not exhaustive; missing alternatives for {}\n{}",
+                types
+                    .iter()
+                    .map(|type_| format!("{}", type_))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                source_information
+            ),
             Self::ExportedNameNotFound { name } => {
                 write!(formatter, "exported name \"{}\" not found", name)
             }
@@ -51,11 +200,58 @@ impl Display for CompileError {
             Self::FunctionExpected(source_information) => {
                 write!(formatter, "function expected\n{}", source_information)
             }
+            Self::ImportedNameConflicted {
+                name,
+                source_information,
+            } => write!(
+                formatter,
+                "imported name \"{}\" conflicts with local definition\n{}",
+                name, source_information
+            ),
+            Self::ImportedNameNotFound {
+                name,
+                source_information,
+            } => write!(
+                formatter,
+                "imported name \"{}\" not found\n{}",
+                name, source_information
+            ),
             Self::MainFunctionNotFound(path) => write!(
                 formatter,
                 "main function not found in main module {}",
                 &path
             ),
+            Self::MapKeyNotComparable(source_information) => write!(
+                formatter,
+                "map keys including functions or Any values are not comparable\n{}",
+                source_information
+            ),
+            Self::Multiple(errors) => write!(
+                formatter,
+                "{}",
+                errors
+                    .iter()
+                    .map(|error| format!("{}", error))
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            ),
+            Self::PreludeNameConflicted {
+                name,
+                source_information,
+            } => write!(
+                formatter,
+                "imported name \"{}\" conflicts with prelude name\n{}",
+                name, source_information
+            ),
+            Self::RecordElementsNotMatched {
+                record_type,
+                source_information,
+            } => write!(
+                formatter,
+                "elements not matched with record type {}\n{}",
+                types::Type::from(record_type.clone()),
+                source_information
+            ),
             Self::RecordElementNotFound { record_type, name } => write!(
                 formatter,
                 "element \"{}\" not found in record type\n{}",
@@ -67,9 +263,20 @@ impl Display for CompileError {
                 "cannot compare records including functions or Any values\n{}",
                 source_information
             ),
+            Self::RecordExpected(type_) => write!(
+                formatter,
+                "record type expected but got {}\n{}",
+                type_,
+                type_.source_information()
+            ),
             Self::EirFmmCompile(error) => {
                 write!(formatter, "failed to compile eir to fmm: {:?}", error)
             }
+            Self::TypeArgumentsNotMatched(source_information) => write!(
+                formatter,
+                "wrong number of type arguments\n{}",
+                source_information
+            ),
             Self::TypeNotFound(reference) => write!(
                 formatter,
                 "type \"{}\" not found\n{}",
@@ -79,11 +286,32 @@ impl Display for CompileError {
             Self::TypeNotInferred(source_information) => {
                 write!(formatter, "failed to infer type\n{}", source_information)
             }
-            Self::TypesNotMatched(lhs_source_information, rhs_source_information) => write!(
-                formatter,
-                "types not matched\n{}\n{}",
-                lhs_source_information, rhs_source_information
-            ),
+            Self::TypesNotMatched {
+                lower_type,
+                upper_type,
+                union_type,
+            } => {
+                write!(formatter, "types not matched; ")?;
+
+                if let Some(union_type) = union_type {
+                    write!(
+                        formatter,
+                        "member {} of union type {} ",
+                        lower_type,
+                        types::Type::from(union_type.clone())
+                    )?;
+                } else {
+                    write!(formatter, "{} ", lower_type)?;
+                }
+
+                write!(
+                    formatter,
+                    "is not a subtype of {}\n{}\n{}",
+                    upper_type,
+                    lower_type.source_information(),
+                    upper_type.source_information()
+                )
+            }
             Self::VariableNotFound(variable) => write!(
                 formatter,
                 "variable \"{}\" not found\n{}",
@@ -101,3 +329,67 @@ impl From<eir_fmm::CompileError> for CompileError {
         Self::EirFmmCompile(error)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_results_without_errors() {
+        assert_eq!(collect_results(vec![Ok(1), Ok(2)]), Ok(vec![1, 2]));
+    }
+
+    #[test]
+    fn collect_results_with_error() {
+        assert_eq!(
+            collect_results(vec![
+                Ok(1),
+                Err(CompileError::TypeNotInferred(
+                    SourceInformation::dummy().into()
+                ))
+            ]),
+            Err(CompileError::TypeNotInferred(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn collect_results_with_multiple_errors() {
+        assert_eq!(
+            collect_results::<()>(vec![
+                Err(CompileError::TypeNotInferred(
+                    SourceInformation::dummy().into()
+                )),
+                Err(CompileError::Multiple(vec![
+                    CompileError::AnyEqualOperation(SourceInformation::dummy().into()),
+                    CompileError::FunctionExpected(SourceInformation::dummy().into()),
+                ]))
+            ]),
+            Err(CompileError::Multiple(vec![
+                CompileError::TypeNotInferred(SourceInformation::dummy().into()),
+                CompileError::AnyEqualOperation(SourceInformation::dummy().into()),
+                CompileError::FunctionExpected(SourceInformation::dummy().into()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn display_types_not_matched_with_union_member() {
+        assert!(format!(
+            "{}",
+            CompileError::TypesNotMatched {
+                lower_type: types::Number::new(SourceInformation::dummy()).into(),
+                upper_type: types::None::new(SourceInformation::dummy()).into(),
+                union_type: Some(types::Union::new(
+                    vec![
+                        types::Number::new(SourceInformation::dummy()).into(),
+                        types::None::new(SourceInformation::dummy()).into(),
+                    ],
+                    SourceInformation::dummy(),
+                )),
+            }
+        )
+        .starts_with("types not matched; member Number of union type None | Number is not a subtype of None\n"));
+    }
+}
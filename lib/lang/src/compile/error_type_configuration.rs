@@ -1,5 +1,6 @@
 #[cfg(test)]
 use once_cell::sync::Lazy;
+use serde::Serialize;
 use std::collections::HashMap;
 #[cfg(test)]
 use std::sync::Arc;
@@ -12,6 +13,7 @@ pub static ERROR_TYPE_CONFIGURATION: Lazy<Arc<ErrorTypeConfiguration>> = Lazy::n
     .into()
 });
 
+#[derive(Serialize)]
 pub struct ErrorTypeConfiguration {
     pub error_type_name: String,
 }
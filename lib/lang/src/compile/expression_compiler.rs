@@ -2,23 +2,32 @@ use super::{
     error::CompileError,
     last_result_type_calculator::LastResultTypeCalculator,
     reference_type_resolver::ReferenceTypeResolver,
+    source_information_compiler::SourceInformationCompiler,
     string_type_configuration::StringTypeConfiguration,
     transform::{
-        BooleanOperationTransformer, EqualOperationTransformer, FunctionTypeCoercionTransformer,
-        LetErrorTransformer, ListCaseTransformer, ListLiteralTransformer,
-        ListTypeCoercionTransformer, NotEqualOperationTransformer,
+        ArrayLiteralTransformer, BooleanOperationTransformer, EqualOperationTransformer,
+        FunctionTypeCoercionTransformer, LambdaTransformer, LetErrorTransformer,
+        ListCaseTransformer, ListLiteralTransformer, ListTypeCoercionTransformer,
+        MapLiteralTransformer, NotEqualOperationTransformer,
     },
     type_compiler::TypeCompiler,
+    utilities,
     variable_compiler::VariableCompiler,
 };
-use crate::{ast::*, types::Type};
+use crate::{
+    ast::*,
+    debug::SourceInformation,
+    types::{self, Type},
+};
 use std::sync::Arc;
 
 pub struct ExpressionCompilerSet {
+    pub source_information_compiler: Arc<SourceInformationCompiler>,
     pub variable_compiler: Arc<VariableCompiler>,
 }
 
 pub struct ExpressionTransformerSet {
+    pub array_literal_transformer: Arc<ArrayLiteralTransformer>,
     pub equal_operation_transformer: Arc<EqualOperationTransformer>,
     pub not_equal_operation_transformer: Arc<NotEqualOperationTransformer>,
     pub list_literal_transformer: Arc<ListLiteralTransformer>,
@@ -27,6 +36,8 @@ pub struct ExpressionTransformerSet {
     pub list_type_coercion_transformer: Arc<ListTypeCoercionTransformer>,
     pub list_case_transformer: Arc<ListCaseTransformer>,
     pub let_error_transformer: Arc<LetErrorTransformer>,
+    pub lambda_transformer: Arc<LambdaTransformer>,
+    pub map_literal_transformer: Arc<MapLiteralTransformer>,
 }
 
 pub struct ExpressionCompiler {
@@ -60,23 +71,45 @@ impl ExpressionCompiler {
 
     pub fn compile(&self, expression: &Expression) -> Result<eir::ir::Expression, CompileError> {
         Ok(match expression {
-            Expression::Application(application) => eir::ir::FunctionApplication::new(
-                self.type_compiler
-                    .compile(application.type_())?
-                    .into_function()
-                    .unwrap(),
-                self.compile(application.function())?,
-                self.compile(application.argument())?,
-            )
-            .into(),
+            Expression::Application(application) => self
+                .expression_compiler_set
+                .source_information_compiler
+                .compile_expression(
+                    eir::ir::FunctionApplication::new(
+                        self.type_compiler
+                            .compile(application.type_())?
+                            .into_function()
+                            .unwrap(),
+                        self.compile(application.function())?,
+                        self.compile(application.argument())?,
+                    ),
+                    application.source_information(),
+                ),
+            Expression::Array(array) => self.compile(
+                &self
+                    .expression_transformer_set
+                    .array_literal_transformer
+                    .transform(array),
+            )?,
             Expression::Boolean(boolean) => boolean.value().into(),
             Expression::Case(case) => self.compile_case(case)?,
-            Expression::If(if_) => eir::ir::If::new(
-                self.compile(if_.condition())?,
-                self.compile(if_.then())?,
-                self.compile(if_.else_())?,
-            )
-            .into(),
+            Expression::If(if_) => self
+                .expression_compiler_set
+                .source_information_compiler
+                .compile_expression(
+                    eir::ir::If::new(
+                        self.compile(if_.condition())?,
+                        self.compile(if_.then())?,
+                        self.compile(if_.else_())?,
+                    ),
+                    if_.source_information(),
+                ),
+            Expression::Lambda(lambda) => self.compile(
+                &self
+                    .expression_transformer_set
+                    .lambda_transformer
+                    .transform(lambda),
+            )?,
             Expression::Let(let_) => self.compile_let(let_)?,
             Expression::LetError(let_) => self.compile_let_error(let_)?,
             Expression::None(_) => {
@@ -94,6 +127,12 @@ impl ExpressionCompiler {
                     .list_case_transformer
                     .transform(case)?,
             )?,
+            Expression::Map(map) => self.compile(
+                &self
+                    .expression_transformer_set
+                    .map_literal_transformer
+                    .transform(map)?,
+            )?,
             Expression::Number(number) => number.value().into(),
             Expression::Operation(operation) => match operation {
                 Operation::Arithmetic(operation) => eir::ir::ArithmeticOperation::new(
@@ -169,38 +208,129 @@ impl ExpressionCompiler {
                     .into(),
                 )?,
             },
-            Expression::RecordConstruction(record) => eir::ir::Record::new(
-                self.type_compiler
-                    .compile(record.type_())?
-                    .into_record()
-                    .unwrap(),
-                self.reference_type_resolver
+            Expression::RecordConstruction(record) => {
+                let record_type = self
+                    .reference_type_resolver
                     .resolve_to_record(record.type_())?
-                    .unwrap()
-                    .elements()
-                    .iter()
-                    .map(|element| self.compile(&record.elements()[element.name()]))
-                    .collect::<Result<_, _>>()?,
-            )
-            .into(),
-            Expression::RecordElementOperation(operation) => eir::ir::RecordElement::new(
-                self.type_compiler
-                    .compile(operation.type_())?
-                    .into_record()
-                    .unwrap(),
-                self.reference_type_resolver
+                    .unwrap();
+                let definition = self
+                    .reference_type_resolver
+                    .resolve_record_definition(&record_type)?;
+
+                if record_type.elements() != definition.elements() {
+                    // Elements of generic records are stored as their
+                    // uninstantiated types.
+                    self.compile(
+                        &RecordConstruction::new(
+                            definition.clone(),
+                            record
+                                .elements()
+                                .iter()
+                                .map(|(key, expression)| {
+                                    Ok((
+                                        key.clone(),
+                                        Self::coerce_type(
+                                            expression,
+                                            utilities::get_record_element(&record_type, key)?,
+                                            utilities::get_record_element(&definition, key)?,
+                                            record.source_information().clone(),
+                                        ),
+                                    ))
+                                })
+                                .collect::<Result<_, CompileError>>()?,
+                            record.source_information().clone(),
+                        )
+                        .into(),
+                    )?
+                } else {
+                    eir::ir::Record::new(
+                        self.type_compiler
+                            .compile(record.type_())?
+                            .into_record()
+                            .unwrap(),
+                        record_type
+                            .elements()
+                            .iter()
+                            .map(|element| self.compile(&record.elements()[element.name()]))
+                            .collect::<Result<_, _>>()?,
+                    )
+                    .into()
+                }
+            }
+            Expression::RecordElementOperation(operation) => {
+                let record_type = self
+                    .reference_type_resolver
                     .resolve_to_record(operation.type_())?
-                    .unwrap()
-                    .elements()
-                    .iter()
-                    .position(|element| element.name() == operation.element_name())
-                    .unwrap(),
-                self.compile(operation.argument())?,
-            )
-            .into(),
+                    .unwrap();
+                let definition = self
+                    .reference_type_resolver
+                    .resolve_record_definition(&record_type)?;
+
+                if record_type.elements() != definition.elements() {
+                    self.compile(&Self::coerce_type(
+                        &RecordElementOperation::new(
+                            definition.clone(),
+                            operation.element_name(),
+                            operation.argument().clone(),
+                            operation.source_information().clone(),
+                        )
+                        .into(),
+                        utilities::get_record_element(&definition, operation.element_name())?,
+                        utilities::get_record_element(&record_type, operation.element_name())?,
+                        operation.source_information().clone(),
+                    ))?
+                } else {
+                    eir::ir::RecordElement::new(
+                        self.type_compiler
+                            .compile(operation.type_())?
+                            .into_record()
+                            .unwrap(),
+                        record_type
+                            .elements()
+                            .iter()
+                            .position(|element| element.name() == operation.element_name())
+                            .unwrap(),
+                        self.compile(operation.argument())?,
+                    )
+                    .into()
+                }
+            }
             Expression::String(string) => eir::ir::ByteString::new(string.value()).into(),
             Expression::TypeCoercion(coercion) => {
-                if self.reference_type_resolver.is_list(coercion.from())?
+                if self
+                    .reference_type_resolver
+                    .resolve_to_record(coercion.from())?
+                    .is_some()
+                    && self
+                        .reference_type_resolver
+                        .resolve_to_record(coercion.to())?
+                        .is_some()
+                {
+                    // Records of the same name share their representation
+                    // regardless of type arguments.
+                    self.compile(coercion.argument())?
+                } else if self.reference_type_resolver.is_parameter(coercion.from())?
+                    && !self.reference_type_resolver.is_any(coercion.to())?
+                    && !self.reference_type_resolver.is_union(coercion.to())?
+                    && !self.reference_type_resolver.is_parameter(coercion.to())?
+                {
+                    let argument_name = "$arg";
+                    let source_information = coercion.source_information();
+
+                    self.compile(
+                        &Case::with_type(
+                            types::Any::new(source_information.clone()),
+                            argument_name,
+                            coercion.argument().clone(),
+                            vec![Alternative::new(
+                                coercion.to().clone(),
+                                Variable::new(argument_name, source_information.clone()),
+                            )],
+                            source_information.clone(),
+                        )
+                        .into(),
+                    )?
+                } else if self.reference_type_resolver.is_list(coercion.from())?
                     && self.reference_type_resolver.is_list(coercion.to())?
                 {
                     self.compile(
@@ -280,8 +410,12 @@ impl ExpressionCompiler {
                             ),
                         )
                         .into(),
-                        Type::Any(_) | Type::Union(_) => argument,
-                        Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => {
+                        Type::Any(_) | Type::Parameter(_) | Type::Union(_) => argument,
+                        Type::Array(_)
+                        | Type::Map(_)
+                        | Type::Reference(_)
+                        | Type::Unknown(_)
+                        | Type::Variable(_) => {
                             unreachable!()
                         }
                     }
@@ -307,27 +441,32 @@ impl ExpressionCompiler {
                             .unwrap();
 
                         eir::ir::LetRecursive::new(
-                            eir::ir::Definition::new(
-                                definition.name(),
-                                definition
-                                    .arguments()
-                                    .iter()
-                                    .zip(type_.arguments())
-                                    .map(|(name, type_)| {
-                                        Ok(eir::ir::Argument::new(
-                                            name.clone(),
-                                            self.type_compiler.compile(type_)?,
-                                        ))
-                                    })
-                                    .collect::<Result<_, CompileError>>()?,
-                                self.compile(definition.body())?,
-                                self.type_compiler.compile(
-                                    &self.last_result_type_calculator.calculate(
-                                        definition.type_(),
-                                        definition.arguments().len(),
-                                    )?,
-                                )?,
-                            ),
+                            self.expression_compiler_set
+                                .source_information_compiler
+                                .compile_definition(
+                                    eir::ir::Definition::new(
+                                        definition.name(),
+                                        definition
+                                            .arguments()
+                                            .iter()
+                                            .zip(type_.arguments())
+                                            .map(|(name, type_)| {
+                                                Ok(eir::ir::Argument::new(
+                                                    name.clone(),
+                                                    self.type_compiler.compile(type_)?,
+                                                ))
+                                            })
+                                            .collect::<Result<_, CompileError>>()?,
+                                        self.compile(definition.body())?,
+                                        self.type_compiler.compile(
+                                            &self.last_result_type_calculator.calculate(
+                                                definition.type_(),
+                                                definition.arguments().len(),
+                                            )?,
+                                        )?,
+                                    ),
+                                    definition.source_information(),
+                                ),
                             expression?,
                         )
                         .into()
@@ -355,6 +494,7 @@ impl ExpressionCompiler {
 
     fn compile_case(&self, case: &Case) -> Result<eir::ir::Expression, CompileError> {
         if !self.reference_type_resolver.is_any(case.type_())?
+            && !self.reference_type_resolver.is_parameter(case.type_())?
             && !self.reference_type_resolver.is_union(case.type_())?
         {
             return Err(CompileError::CaseArgumentTypeInvalid(
@@ -381,7 +521,11 @@ impl ExpressionCompiler {
                     .iter()
                     .map(|alternative| -> Result<_, CompileError> {
                         Ok(
-                            if self.reference_type_resolver.is_any(alternative.type_())? {
+                            if self.reference_type_resolver.is_any(alternative.type_())?
+                                || self
+                                    .reference_type_resolver
+                                    .is_parameter(alternative.type_())?
+                            {
                                 Some(eir::ir::DefaultAlternative::new(
                                     case.name(),
                                     self.compile(alternative.expression())?,
@@ -407,7 +551,7 @@ impl ExpressionCompiler {
     ) -> Result<Option<Vec<eir::ir::Alternative>>, CompileError> {
         Ok(
             match &self.reference_type_resolver.resolve(alternative.type_())? {
-                Type::Any(_) => None,
+                Type::Any(_) | Type::Parameter(_) => None,
                 Type::Boolean(_)
                 | Type::Function(_)
                 | Type::None(_)
@@ -459,13 +603,36 @@ impl ExpressionCompiler {
                         })
                         .collect::<Result<Vec<_>, _>>()?,
                 ),
-                Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => {
+                Type::Array(_)
+                | Type::Map(_)
+                | Type::Reference(_)
+                | Type::Unknown(_)
+                | Type::Variable(_) => {
                     unreachable!()
                 }
             },
         )
     }
 
+    fn coerce_type(
+        expression: &Expression,
+        from: &Type,
+        to: &Type,
+        source_information: Arc<SourceInformation>,
+    ) -> Expression {
+        if from == to {
+            expression.clone()
+        } else {
+            TypeCoercion::new(
+                expression.clone(),
+                from.clone(),
+                to.clone(),
+                source_information,
+            )
+            .into()
+        }
+    }
+
     fn compile_arithmetic_operator(operator: ArithmeticOperator) -> eir::ir::ArithmeticOperator {
         match operator {
             ArithmeticOperator::Add => eir::ir::ArithmeticOperator::Add,
@@ -489,8 +656,10 @@ impl ExpressionCompiler {
 mod tests {
     use super::{
         super::{
+            array_type_configuration::ARRAY_TYPE_CONFIGURATION,
             error_type_configuration::ERROR_TYPE_CONFIGURATION,
             list_type_configuration::LIST_TYPE_CONFIGURATION,
+            map_type_configuration::MAP_TYPE_CONFIGURATION,
             string_type_configuration::STRING_TYPE_CONFIGURATION,
             type_canonicalizer::TypeCanonicalizer,
             type_comparability_checker::TypeComparabilityChecker,
@@ -519,6 +688,8 @@ mod tests {
         .unwrap();
         let type_comparability_checker =
             TypeComparabilityChecker::new(reference_type_resolver.clone());
+        let array_literal_transformer =
+            ArrayLiteralTransformer::new(ARRAY_TYPE_CONFIGURATION.clone());
         let type_equality_checker = TypeEqualityChecker::new(reference_type_resolver.clone());
         let type_canonicalizer = TypeCanonicalizer::new(
             reference_type_resolver.clone(),
@@ -526,9 +697,15 @@ mod tests {
         );
         let equal_operation_transformer = EqualOperationTransformer::new(
             reference_type_resolver.clone(),
-            type_comparability_checker,
+            type_comparability_checker.clone(),
             type_equality_checker.clone(),
             LIST_TYPE_CONFIGURATION.clone(),
+            MAP_TYPE_CONFIGURATION.clone(),
+        );
+        let map_literal_transformer = MapLiteralTransformer::new(
+            equal_operation_transformer.clone(),
+            type_comparability_checker,
+            MAP_TYPE_CONFIGURATION.clone(),
         );
         let not_equal_operation_transformer = NotEqualOperationTransformer::new();
         let list_literal_transformer = ListLiteralTransformer::new(
@@ -555,11 +732,17 @@ mod tests {
             type_canonicalizer,
             ERROR_TYPE_CONFIGURATION.clone(),
         );
+        let lambda_transformer = LambdaTransformer::new();
 
         (
             ExpressionCompiler::new(
-                ExpressionCompilerSet { variable_compiler }.into(),
+                ExpressionCompilerSet {
+                    source_information_compiler: SourceInformationCompiler::new(false),
+                    variable_compiler,
+                }
+                .into(),
                 ExpressionTransformerSet {
+                    array_literal_transformer,
                     equal_operation_transformer,
                     not_equal_operation_transformer,
                     list_literal_transformer,
@@ -568,6 +751,8 @@ mod tests {
                     list_type_coercion_transformer,
                     list_case_transformer,
                     let_error_transformer,
+                    lambda_transformer,
+                    map_literal_transformer,
                 }
                 .into(),
                 reference_type_resolver,
@@ -1008,7 +1193,11 @@ mod tests {
         );
         let (expression_compiler, _) =
             create_expression_compiler(&Module::from_definitions_and_type_definitions(
-                vec![TypeDefinition::new("Foo", type_)],
+                vec![TypeDefinition::new(
+                    "Foo",
+                    type_,
+                    SourceInformation::dummy(),
+                )],
                 vec![],
             ));
 
@@ -41,6 +41,7 @@ impl ExpressionTypeExtractor {
                 .unwrap()
                 .result()
                 .clone(),
+            Expression::Array(array) => array.type_().clone(),
             Expression::Boolean(boolean) => {
                 types::Boolean::new(boolean.source_information().clone()).into()
             }
@@ -70,6 +71,7 @@ impl ExpressionTypeExtractor {
                 )
                 .into(),
             )?,
+            Expression::Lambda(lambda) => lambda.type_().clone(),
             Expression::Let(let_) => {
                 let mut variables = variables.clone();
 
@@ -126,6 +128,7 @@ impl ExpressionTypeExtractor {
                 )
                 .into(),
             )?,
+            Expression::Map(map) => map.type_().clone(),
             Expression::None(none) => types::None::new(none.source_information().clone()).into(),
             Expression::Number(number) => {
                 types::Number::new(number.source_information().clone()).into()
@@ -346,8 +349,8 @@ mod tests {
 
         let module = Module::new(
             ModulePath::new(Package::new("", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![Import::new(
                 ModuleInterface::new(
                     ModulePath::new(Package::new("m", ""), vec![]),
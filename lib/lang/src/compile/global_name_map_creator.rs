@@ -11,13 +11,20 @@ impl GlobalNameMapCreator {
             for name in import.module_interface().exported_names() {
                 names.insert(
                     if import.qualified() {
-                        import.module_interface().path().qualify_name(name)
+                        import.qualify_name(name)
                     } else {
                         name.into()
                     },
                     import.module_interface().path().fully_qualify_name(name),
                 );
             }
+
+            for name in import.names() {
+                names.insert(
+                    name.into(),
+                    import.module_interface().path().fully_qualify_name(name),
+                );
+            }
         }
 
         for declaration in module.import_foreigns() {
@@ -96,7 +103,8 @@ mod tests {
             GlobalNameMapCreator::create(&Module::from_definitions_and_type_definitions(
                 vec![TypeDefinition::new(
                     "x",
-                    types::None::new(SourceInformation::dummy())
+                    types::None::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
                 )],
                 vec![]
             ),),
@@ -112,8 +120,8 @@ mod tests {
         assert_eq!(
             GlobalNameMapCreator::create(&Module::new(
                 ModulePath::dummy(),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![Import::new(
                     ModuleInterface::new(
                         ModulePath::new(Package::new("p", ""), vec!["m".into()]),
@@ -144,8 +152,8 @@ mod tests {
         assert_eq!(
             GlobalNameMapCreator::create(&Module::new(
                 ModulePath::dummy(),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![Import::new(
                     ModuleInterface::new(
                         ModulePath::new(Package::new("p", ""), vec!["m".into()]),
@@ -171,13 +179,93 @@ mod tests {
         );
     }
 
+    #[test]
+    fn create_name_map_from_import_with_alias() {
+        assert_eq!(
+            GlobalNameMapCreator::create(&Module::new(
+                ModulePath::dummy(),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![Import::with_alias_and_names(
+                    ModuleInterface::new(
+                        ModulePath::new(Package::new("p", ""), vec!["m".into()]),
+                        vec!["x".into()].into_iter().collect(),
+                        Default::default(),
+                        vec![(
+                            "x".into(),
+                            types::None::new(SourceInformation::dummy()).into(),
+                        )]
+                        .into_iter()
+                        .collect(),
+                    ),
+                    true,
+                    Some("n".into()),
+                    vec![],
+                    SourceInformation::dummy(),
+                )],
+                vec![],
+                vec![],
+                vec![],
+            )),
+            vec![("n.x".into(), "p().m.x".into())]
+                .into_iter()
+                .collect::<HashMap<_, _>>()
+                .into()
+        );
+    }
+
+    #[test]
+    fn create_name_map_from_import_with_names() {
+        assert_eq!(
+            GlobalNameMapCreator::create(&Module::new(
+                ModulePath::dummy(),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![Import::with_alias_and_names(
+                    ModuleInterface::new(
+                        ModulePath::new(Package::new("p", ""), vec!["m".into()]),
+                        vec!["x".into(), "y".into()].into_iter().collect(),
+                        Default::default(),
+                        vec![
+                            (
+                                "x".into(),
+                                types::None::new(SourceInformation::dummy()).into(),
+                            ),
+                            (
+                                "y".into(),
+                                types::None::new(SourceInformation::dummy()).into(),
+                            )
+                        ]
+                        .into_iter()
+                        .collect(),
+                    ),
+                    true,
+                    None,
+                    vec!["x".into()],
+                    SourceInformation::dummy(),
+                )],
+                vec![],
+                vec![],
+                vec![],
+            )),
+            vec![
+                ("m.x".into(), "p().m.x".into()),
+                ("m.y".into(), "p().m.y".into()),
+                ("x".into(), "p().m.x".into())
+            ]
+            .into_iter()
+            .collect::<HashMap<_, _>>()
+            .into()
+        );
+    }
+
     #[test]
     fn create_name_map_from_import_foreigns() {
         assert_eq!(
             GlobalNameMapCreator::create(&Module::new(
                 ModulePath::dummy(),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![ImportForeign::new(
                     "foo",
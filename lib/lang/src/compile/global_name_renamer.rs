@@ -36,9 +36,11 @@ impl GlobalNameRenamer {
                 .type_definitions()
                 .iter()
                 .map(|type_definition| {
-                    TypeDefinition::new(
+                    TypeDefinition::with_type_parameters(
                         self.rename_name(type_definition.name(), &self.names),
+                        type_definition.type_parameters().to_vec(),
                         type_definition.type_().clone(),
+                        type_definition.source_information().clone(),
                     )
                 })
                 .collect(),
@@ -80,8 +82,9 @@ impl GlobalNameRenamer {
                     record.source_information().clone(),
                 )
                 .into(),
-                Type::Reference(reference) => types::Reference::new(
+                Type::Reference(reference) => types::Reference::with_arguments(
                     self.rename_name(reference.name(), &self.names),
+                    reference.arguments().to_vec(),
                     reference.source_information().clone(),
                 )
                 .into(),
@@ -137,6 +140,16 @@ impl GlobalNameRenamer {
                 application.source_information().clone(),
             )
             .into(),
+            Expression::Array(array) => Array::with_type(
+                array.type_().clone(),
+                array
+                    .elements()
+                    .iter()
+                    .map(|element| self.rename_expression(element, names))
+                    .collect(),
+                array.source_information().clone(),
+            )
+            .into(),
             Expression::Case(case) => Case::with_type(
                 case.type_().clone(),
                 case.name(),
@@ -166,6 +179,21 @@ impl GlobalNameRenamer {
                 if_.source_information().clone(),
             )
             .into(),
+            Expression::Lambda(lambda) => {
+                let mut names = names.clone();
+
+                for name in lambda.arguments() {
+                    names.remove(name);
+                }
+
+                Lambda::new(
+                    lambda.arguments().to_vec(),
+                    self.rename_expression(lambda.body(), &names),
+                    lambda.type_().clone(),
+                    lambda.source_information().clone(),
+                )
+                .into()
+            }
             Expression::Let(let_) => {
                 let mut names = names.clone();
                 let mut definitions = vec![];
@@ -242,6 +270,20 @@ impl GlobalNameRenamer {
                 case.source_information().clone(),
             )
             .into(),
+            Expression::Map(map) => Map::with_type(
+                map.type_().clone(),
+                map.elements()
+                    .iter()
+                    .map(|element| {
+                        MapElement::new(
+                            self.rename_expression(element.key(), names),
+                            self.rename_expression(element.value(), names),
+                        )
+                    })
+                    .collect(),
+                map.source_information().clone(),
+            )
+            .into(),
             Expression::Operation(operation) => match operation {
                 Operation::Arithmetic(operation) => ArithmeticOperation::new(
                     operation.operator(),
@@ -340,8 +382,8 @@ mod tests {
     fn rename_nothing() {
         let module = Module::new(
             ModulePath::new(Package::new("M", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![],
             vec![],
             vec![],
@@ -364,8 +406,8 @@ mod tests {
     fn rename_names_in_variable_definitions() {
         let module = Module::new(
             ModulePath::new(Package::new("M", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![],
             vec![],
             vec![],
@@ -388,8 +430,8 @@ mod tests {
             .rename(&module),
             Module::new(
                 ModulePath::new(Package::new("M", ""), vec![]),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![],
@@ -408,8 +450,11 @@ mod tests {
     fn do_not_rename_names_in_export_statements() {
         let module = Module::new(
             ModulePath::new(Package::new("M", ""), vec![]),
-            Export::new(vec!["x".into()].into_iter().collect()),
-            ExportForeign::new(Default::default()),
+            Export::new(
+                vec!["x".into()].into_iter().collect(),
+                SourceInformation::dummy(),
+            ),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![],
             vec![],
             vec![],
@@ -426,8 +471,11 @@ mod tests {
             .rename(&module),
             Module::new(
                 ModulePath::new(Package::new("M", ""), vec![]),
-                Export::new(vec!["x".into()].into_iter().collect()),
-                ExportForeign::new(Default::default()),
+                Export::new(
+                    vec!["x".into()].into_iter().collect(),
+                    SourceInformation::dummy()
+                ),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![],
@@ -440,13 +488,14 @@ mod tests {
     fn rename_names_in_type_definitions() {
         let module = Module::new(
             ModulePath::new(Package::new("M", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![],
             vec![],
             vec![TypeDefinition::new(
                 "x",
                 types::None::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
             )],
             vec![],
         );
@@ -461,13 +510,14 @@ mod tests {
             .rename(&module),
             Module::new(
                 ModulePath::new(Package::new("M", ""), vec![]),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![TypeDefinition::new(
                     "y",
                     types::None::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
                 )],
                 vec![],
             )
@@ -478,13 +528,14 @@ mod tests {
     fn rename_reference_types() {
         let module = Module::new(
             ModulePath::new(Package::new("M", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![],
             vec![],
             vec![TypeDefinition::new(
                 "x",
                 types::Reference::new("z", SourceInformation::dummy()),
+                SourceInformation::dummy(),
             )],
             vec![VariableDefinition::new(
                 "y",
@@ -505,13 +556,14 @@ mod tests {
             .rename(&module),
             Module::new(
                 ModulePath::new(Package::new("M", ""), vec![]),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![TypeDefinition::new(
                     "x",
                     types::Reference::new("v", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
                 )],
                 vec![VariableDefinition::new(
                     "y",
@@ -528,13 +580,14 @@ mod tests {
     fn rename_record_types() {
         let module = Module::new(
             ModulePath::new(Package::new("M", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![],
             vec![],
             vec![TypeDefinition::new(
                 "x",
                 types::Record::new("y", Default::default(), SourceInformation::dummy()),
+                SourceInformation::dummy(),
             )],
             vec![],
         );
@@ -549,13 +602,14 @@ mod tests {
             .rename(&module),
             Module::new(
                 ModulePath::new(Package::new("M", ""), vec![]),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![TypeDefinition::new(
                     "x",
                     types::Record::new("z", Default::default(), SourceInformation::dummy()),
+                    SourceInformation::dummy(),
                 )],
                 vec![],
             )
@@ -566,8 +620,8 @@ mod tests {
     fn do_not_rename_case_argument() {
         let module = Module::new(
             ModulePath::new(Package::new("M", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![],
             vec![],
             vec![],
@@ -598,8 +652,8 @@ mod tests {
             .rename(&module),
             Module::new(
                 ModulePath::new(Package::new("M", ""), vec![]),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![],
@@ -626,8 +680,8 @@ mod tests {
     fn rename_names_in_import_foreigns() {
         let module = Module::new(
             ModulePath::dummy(),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![],
             vec![ImportForeign::new(
                 "x",
@@ -650,8 +704,8 @@ mod tests {
             .rename(&module),
             Module::new(
                 ModulePath::dummy(),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![ImportForeign::new(
                     "y",
@@ -1,6 +1,9 @@
-use super::error::CompileError;
+use super::error::{collect_results, CompileError};
 use crate::{ast::*, debug::SourceInformation};
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 pub struct GlobalNameValidator {}
 
@@ -10,38 +13,105 @@ impl GlobalNameValidator {
     }
 
     pub fn validate(&self, module: &Module) -> Result<(), CompileError> {
-        let mut names = HashMap::<&str, Arc<SourceInformation>>::new();
+        let mut results = vec![];
+        let prelude_names = module
+            .imports()
+            .iter()
+            .filter(|import| !import.qualified())
+            .flat_map(|import| import.module_interface().exported_names())
+            .map(|name| name.as_str())
+            .collect::<HashSet<_>>();
+        let mut imported_names = HashMap::<&str, &Arc<SourceInformation>>::new();
 
-        for declaration in module.import_foreigns() {
-            if let Some(source_information) = names.get(declaration.name()) {
-                return Err(CompileError::DuplicateNames(
-                    source_information.clone(),
-                    declaration.source_information().clone(),
-                ));
+        for import in module.imports() {
+            // Only explicit imports have names imported unqualified.
+            if let Some(source_information) = import.source_information() {
+                for name in import.names() {
+                    if !import.module_interface().exported_names().contains(name) {
+                        results.push(Err(CompileError::ImportedNameNotFound {
+                            name: name.into(),
+                            source_information: source_information.clone(),
+                        }));
+                    }
+
+                    if prelude_names.contains(name.as_str()) {
+                        results.push(Err(CompileError::PreludeNameConflicted {
+                            name: name.into(),
+                            source_information: source_information.clone(),
+                        }));
+                    }
+
+                    if let Some(other_source_information) = imported_names.get(name.as_str()) {
+                        results.push(Err(CompileError::DuplicateNames(
+                            (*other_source_information).clone(),
+                            source_information.clone(),
+                        )));
+                    } else {
+                        imported_names.insert(name, source_information);
+                    }
+                }
             }
+        }
 
-            names.insert(declaration.name(), declaration.source_information().clone());
+        for type_definition in module.type_definitions() {
+            results.push(self.validate_imported_name(
+                type_definition.name(),
+                type_definition.type_().source_information(),
+                &imported_names,
+            ));
         }
 
-        for definition in module.definitions() {
-            if let Some(source_information) = names.get(definition.name()) {
-                return Err(CompileError::DuplicateNames(
+        let mut names = HashMap::<&str, Arc<SourceInformation>>::new();
+
+        for (name, source_information) in module
+            .import_foreigns()
+            .iter()
+            .map(|declaration| (declaration.name(), declaration.source_information()))
+            .chain(
+                module
+                    .definitions()
+                    .iter()
+                    .map(|definition| (definition.name(), definition.source_information())),
+            )
+        {
+            results.push(self.validate_imported_name(name, source_information, &imported_names));
+
+            if let Some(other_source_information) = names.get(name) {
+                results.push(Err(CompileError::DuplicateNames(
+                    other_source_information.clone(),
                     source_information.clone(),
-                    definition.source_information().clone(),
-                ));
+                )));
+            } else {
+                names.insert(name, source_information.clone());
             }
-
-            names.insert(definition.name(), definition.source_information().clone());
         }
 
+        collect_results(results)?;
+
         Ok(())
     }
+
+    fn validate_imported_name(
+        &self,
+        name: &str,
+        source_information: &Arc<SourceInformation>,
+        imported_names: &HashMap<&str, &Arc<SourceInformation>>,
+    ) -> Result<(), CompileError> {
+        if imported_names.contains_key(name) {
+            Err(CompileError::ImportedNameConflicted {
+                name: name.into(),
+                source_information: source_information.clone(),
+            })
+        } else {
+            Ok(())
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{path::ModulePath, types};
+    use crate::{package::Package, path::ModulePath, types};
 
     #[test]
     fn validate_duplicate_names() {
@@ -74,8 +144,8 @@ mod tests {
         assert_eq!(
             GlobalNameValidator::new().validate(&Module::new(
                 ModulePath::dummy(),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![ImportForeign::new(
                     "foo",
@@ -103,4 +173,214 @@ mod tests {
             ))
         );
     }
+
+    fn create_import(names: Vec<String>) -> Import {
+        Import::with_alias_and_names(
+            ModuleInterface::new(
+                ModulePath::new(Package::new("p", ""), vec!["m".into()]),
+                vec!["foo".into()].into_iter().collect(),
+                Default::default(),
+                vec![(
+                    "foo".into(),
+                    types::Number::new(SourceInformation::dummy()).into(),
+                )]
+                .into_iter()
+                .collect(),
+            ),
+            true,
+            None,
+            names,
+            SourceInformation::dummy(),
+        )
+    }
+
+    #[test]
+    fn validate_imported_names() {
+        assert_eq!(
+            GlobalNameValidator::new().validate(&Module::new(
+                ModulePath::dummy(),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![create_import(vec!["foo".into()])],
+                vec![],
+                vec![],
+                vec![VariableDefinition::new(
+                    "bar",
+                    Number::new(42.0, SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()]
+            )),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_imported_names_not_found() {
+        assert_eq!(
+            GlobalNameValidator::new().validate(&Module::new(
+                ModulePath::dummy(),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![create_import(vec!["bar".into()])],
+                vec![],
+                vec![],
+                vec![]
+            )),
+            Err(CompileError::ImportedNameNotFound {
+                name: "bar".into(),
+                source_information: SourceInformation::dummy().into()
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_duplicate_imported_names() {
+        assert_eq!(
+            GlobalNameValidator::new().validate(&Module::new(
+                ModulePath::dummy(),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![
+                    create_import(vec!["foo".into()]),
+                    create_import(vec!["foo".into()])
+                ],
+                vec![],
+                vec![],
+                vec![]
+            )),
+            Err(CompileError::DuplicateNames(
+                SourceInformation::dummy().into(),
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_imported_names_conflicted_with_prelude_names() {
+        assert_eq!(
+            GlobalNameValidator::new().validate(&Module::new(
+                ModulePath::dummy(),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![
+                    create_import(vec!["foo".into()]),
+                    Import::new(
+                        ModuleInterface::new(
+                            ModulePath::new(Package::new("prelude", ""), vec!["m".into()]),
+                            vec!["foo".into()].into_iter().collect(),
+                            Default::default(),
+                            vec![(
+                                "foo".into(),
+                                types::Number::new(SourceInformation::dummy()).into(),
+                            )]
+                            .into_iter()
+                            .collect(),
+                        ),
+                        false
+                    )
+                ],
+                vec![],
+                vec![],
+                vec![]
+            )),
+            Err(CompileError::PreludeNameConflicted {
+                name: "foo".into(),
+                source_information: SourceInformation::dummy().into()
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_imported_names_conflicted_with_definitions() {
+        assert_eq!(
+            GlobalNameValidator::new().validate(&Module::new(
+                ModulePath::dummy(),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![create_import(vec!["foo".into()])],
+                vec![],
+                vec![],
+                vec![VariableDefinition::new(
+                    "foo",
+                    Number::new(42.0, SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()]
+            )),
+            Err(CompileError::ImportedNameConflicted {
+                name: "foo".into(),
+                source_information: SourceInformation::dummy().into()
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_imported_names_conflicted_with_type_definitions() {
+        assert_eq!(
+            GlobalNameValidator::new().validate(&Module::new(
+                ModulePath::dummy(),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![create_import(vec!["foo".into()])],
+                vec![],
+                vec![TypeDefinition::new(
+                    "foo",
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )],
+                vec![]
+            )),
+            Err(CompileError::ImportedNameConflicted {
+                name: "foo".into(),
+                source_information: SourceInformation::dummy().into()
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_validate_with_multiple_errors() {
+        assert_eq!(
+            GlobalNameValidator::new().validate(&Module::new(
+                ModulePath::dummy(),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![create_import(vec!["foo".into()])],
+                vec![],
+                vec![],
+                vec![
+                    VariableDefinition::new(
+                        "foo",
+                        Number::new(42.0, SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    VariableDefinition::new(
+                        "foo",
+                        Number::new(42.0, SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ]
+            )),
+            Err(CompileError::Multiple(vec![
+                CompileError::ImportedNameConflicted {
+                    name: "foo".into(),
+                    source_information: SourceInformation::dummy().into()
+                },
+                CompileError::ImportedNameConflicted {
+                    name: "foo".into(),
+                    source_information: SourceInformation::dummy().into()
+                },
+                CompileError::DuplicateNames(
+                    SourceInformation::dummy().into(),
+                    SourceInformation::dummy().into()
+                ),
+            ]))
+        );
+    }
 }
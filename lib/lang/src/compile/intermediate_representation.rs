@@ -0,0 +1,9 @@
+// Intermediate representations are dumped in their debug formats on
+// compilation for debugging of compilers.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum IntermediateRepresentation {
+    Ast,
+    TypedAst,
+    Eir,
+    Fmm,
+}
@@ -1,5 +1,6 @@
 #[cfg(test)]
 use once_cell::sync::Lazy;
+use serde::Serialize;
 use std::collections::HashMap;
 #[cfg(test)]
 use std::sync::Arc;
@@ -21,6 +22,7 @@ pub static LIST_TYPE_CONFIGURATION: Lazy<Arc<ListTypeConfiguration>> = Lazy::new
     .into()
 });
 
+#[derive(Serialize)]
 pub struct ListTypeConfiguration {
     pub empty_list_variable_name: String,
     pub concatenate_function_name: String,
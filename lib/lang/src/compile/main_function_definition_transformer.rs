@@ -37,6 +37,7 @@ impl MainFunctionDefinitionTransformer {
                         .object_main_function_name
                         .clone()])
                     .collect(),
+                module.export_foreign().source_information().clone(),
             ),
             module.imports().to_vec(),
             module.import_foreigns().to_vec(),
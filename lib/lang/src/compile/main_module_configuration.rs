@@ -1,5 +1,7 @@
+use serde::Serialize;
 use std::collections::HashMap;
 
+#[derive(Serialize)]
 pub struct MainModuleConfiguration {
     pub source_main_function_name: String,
     pub object_main_function_name: String,
@@ -0,0 +1,50 @@
+#[cfg(test)]
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use std::collections::HashMap;
+#[cfg(test)]
+use std::sync::Arc;
+
+#[cfg(test)]
+pub static MAP_TYPE_CONFIGURATION: Lazy<Arc<MapTypeConfiguration>> = Lazy::new(|| {
+    MapTypeConfiguration {
+        map_type_name: "GenericMap".into(),
+        empty_map_function_name: "emptyMap".into(),
+        insert_function_name: "insertIntoMap".into(),
+        combine_hashes_function_name: "combineHashes".into(),
+        hash_list_function_name: "hashList".into(),
+        hash_number_function_name: "hashNumber".into(),
+        hash_string_function_name: "hashString".into(),
+    }
+    .into()
+});
+
+#[derive(Serialize)]
+pub struct MapTypeConfiguration {
+    pub map_type_name: String,
+    pub empty_map_function_name: String,
+    pub insert_function_name: String,
+    pub combine_hashes_function_name: String,
+    pub hash_list_function_name: String,
+    pub hash_number_function_name: String,
+    pub hash_string_function_name: String,
+}
+
+impl MapTypeConfiguration {
+    pub fn qualify(&self, names: &HashMap<String, String>) -> Self {
+        Self {
+            map_type_name: self.qualify_name(&self.map_type_name, names),
+            empty_map_function_name: self.qualify_name(&self.empty_map_function_name, names),
+            insert_function_name: self.qualify_name(&self.insert_function_name, names),
+            combine_hashes_function_name: self
+                .qualify_name(&self.combine_hashes_function_name, names),
+            hash_list_function_name: self.qualify_name(&self.hash_list_function_name, names),
+            hash_number_function_name: self.qualify_name(&self.hash_number_function_name, names),
+            hash_string_function_name: self.qualify_name(&self.hash_string_function_name, names),
+        }
+    }
+
+    fn qualify_name(&self, name: &str, names: &HashMap<String, String>) -> String {
+        names.get(name).cloned().unwrap_or_else(|| name.into())
+    }
+}
@@ -1,3 +1,5 @@
+mod array_type_configuration;
+mod case_exhaustiveness_checker;
 mod compile_configuration;
 mod error;
 mod error_type_configuration;
@@ -6,15 +8,19 @@ mod expression_type_extractor;
 mod global_name_map_creator;
 mod global_name_renamer;
 mod global_name_validator;
+mod intermediate_representation;
 mod last_result_type_calculator;
 mod list_type_configuration;
 mod main_function_definition_transformer;
 mod main_module_configuration;
+mod map_type_configuration;
+mod module_analysis;
 mod module_compiler;
 mod module_environment_creator;
 mod module_interface_compiler;
 mod name_generator;
 mod reference_type_resolver;
+mod source_information_compiler;
 mod string_type_configuration;
 mod transform;
 mod type_canonicalizer;
@@ -26,29 +32,39 @@ mod type_id_calculator;
 mod type_inference;
 mod utilities;
 mod variable_compiler;
+mod variable_definition_collector;
+mod variable_type_collector;
 
 use crate::ast::*;
+pub use array_type_configuration::ArrayTypeConfiguration;
+use case_exhaustiveness_checker::CaseExhaustivenessChecker;
 pub use compile_configuration::CompileConfiguration;
-use error::CompileError;
+pub use error::CompileError;
 pub use error_type_configuration::ErrorTypeConfiguration;
 use expression_compiler::{ExpressionCompiler, ExpressionCompilerSet, ExpressionTransformerSet};
 use global_name_map_creator::GlobalNameMapCreator;
 use global_name_renamer::GlobalNameRenamer;
 use global_name_validator::GlobalNameValidator;
+pub use intermediate_representation::IntermediateRepresentation;
 use last_result_type_calculator::LastResultTypeCalculator;
 pub use list_type_configuration::ListTypeConfiguration;
 use main_function_definition_transformer::MainFunctionDefinitionTransformer;
 pub use main_module_configuration::MainModuleConfiguration;
+pub use map_type_configuration::MapTypeConfiguration;
+pub use module_analysis::ModuleAnalysis;
 use module_compiler::ModuleCompiler;
+use module_environment_creator::ModuleEnvironmentCreator;
 use module_interface_compiler::ModuleInterfaceCompiler;
 use reference_type_resolver::ReferenceTypeResolver;
-use std::sync::Arc;
+use source_information_compiler::SourceInformationCompiler;
+use std::{collections::HashMap, fmt::Debug, sync::Arc};
 pub use string_type_configuration::StringTypeConfiguration;
 use transform::{
-    transform_before_name_qualification, transform_with_types, transform_without_types,
-    BooleanOperationTransformer, EqualOperationTransformer, FunctionTypeCoercionTransformer,
+    transform_before_name_qualification, transform_with_checked_types, transform_with_types,
+    transform_without_types, ArrayLiteralTransformer, BooleanOperationTransformer,
+    EqualOperationTransformer, FunctionTypeCoercionTransformer, LambdaTransformer,
     LetErrorTransformer, ListCaseTransformer, ListLiteralTransformer, ListTypeCoercionTransformer,
-    NotEqualOperationTransformer,
+    MapLiteralTransformer, NotEqualOperationTransformer,
 };
 use type_canonicalizer::TypeCanonicalizer;
 use type_comparability_checker::TypeComparabilityChecker;
@@ -58,30 +74,45 @@ use type_equality_checker::TypeEqualityChecker;
 use type_id_calculator::TypeIdCalculator;
 use type_inference::infer_types;
 use variable_compiler::VariableCompiler;
+use variable_definition_collector::VariableDefinitionCollector;
+use variable_type_collector::VariableTypeCollector;
 
 pub fn compile(
     module: &Module,
     configuration: Arc<CompileConfiguration>,
 ) -> Result<(Vec<u8>, ModuleInterface), CompileError> {
-    GlobalNameValidator::new().validate(module)?;
+    let (module_object_data, module_interface, _) =
+        compile_with_intermediate_representations(module, configuration, &[])?;
 
-    let module = transform_before_name_qualification(module)?;
+    Ok((module_object_data, module_interface))
+}
 
-    let module = if let Some(main_module_configuration) = &configuration.main_module_configuration {
-        MainFunctionDefinitionTransformer::new(main_module_configuration.clone())
-            .transform(&module)?
-    } else {
-        module
+// Intermediate representations of the given kinds are returned in addition to
+// compiled modules.
+#[allow(clippy::type_complexity)]
+pub fn compile_with_intermediate_representations(
+    module: &Module,
+    configuration: Arc<CompileConfiguration>,
+    kinds: &[IntermediateRepresentation],
+) -> Result<
+    (
+        Vec<u8>,
+        ModuleInterface,
+        Vec<(IntermediateRepresentation, String)>,
+    ),
+    CompileError,
+> {
+    let mut intermediate_representations = vec![];
+    let mut dump = |kind, value: &dyn Debug| {
+        if kinds.contains(&kind) {
+            intermediate_representations.push((kind, format!("{:#?}", value)));
+        }
     };
 
-    let global_names = GlobalNameMapCreator::create(&module);
-    let configuration = Arc::new(configuration.qualify(&global_names));
-    let module = GlobalNameRenamer::new(global_names.clone()).rename(&module);
-
-    let module = transform_with_types(
-        &infer_types(&transform_without_types(&module)?, configuration.clone())?,
-        configuration.clone(),
-    )?;
+    let (module, global_names, configuration) = infer_module_types(module, configuration)?;
+    dump(IntermediateRepresentation::Ast, &module);
+    let module = check_typed_module(&module, configuration.clone())?;
+    dump(IntermediateRepresentation::TypedAst, &module);
 
     let reference_type_resolver = ReferenceTypeResolver::new(&module);
     let type_comparability_checker = TypeComparabilityChecker::new(reference_type_resolver.clone());
@@ -90,6 +121,7 @@ pub fn compile(
         reference_type_resolver.clone(),
         type_equality_checker.clone(),
     );
+
     let last_result_type_calculator =
         LastResultTypeCalculator::new(reference_type_resolver.clone());
     let type_id_calculator = TypeIdCalculator::new(reference_type_resolver.clone());
@@ -105,12 +137,17 @@ pub fn compile(
     )?;
     let type_definition_compiler =
         TypeDefinitionCompiler::new(type_compiler.clone(), reference_type_resolver.clone());
+    let source_information_compiler =
+        SourceInformationCompiler::new(configuration.debug_information);
 
+    let array_literal_transformer =
+        ArrayLiteralTransformer::new(configuration.array_type_configuration.clone());
     let equal_operation_transformer = EqualOperationTransformer::new(
         reference_type_resolver.clone(),
-        type_comparability_checker,
+        type_comparability_checker.clone(),
         type_equality_checker.clone(),
         configuration.list_type_configuration.clone(),
+        configuration.map_type_configuration.clone(),
     );
     let not_equal_operation_transformer = NotEqualOperationTransformer::new();
     let list_literal_transformer = ListLiteralTransformer::new(
@@ -137,10 +174,21 @@ pub fn compile(
         type_canonicalizer,
         configuration.error_type_configuration.clone(),
     );
+    let lambda_transformer = LambdaTransformer::new();
+    let map_literal_transformer = MapLiteralTransformer::new(
+        equal_operation_transformer.clone(),
+        type_comparability_checker,
+        configuration.map_type_configuration.clone(),
+    );
 
     let expression_compiler = ExpressionCompiler::new(
-        ExpressionCompilerSet { variable_compiler }.into(),
+        ExpressionCompilerSet {
+            source_information_compiler: source_information_compiler.clone(),
+            variable_compiler,
+        }
+        .into(),
         ExpressionTransformerSet {
+            array_literal_transformer,
             equal_operation_transformer,
             not_equal_operation_transformer,
             list_literal_transformer,
@@ -149,6 +197,8 @@ pub fn compile(
             list_type_coercion_transformer,
             list_case_transformer,
             let_error_transformer,
+            lambda_transformer,
+            map_literal_transformer,
         }
         .into(),
         reference_type_resolver,
@@ -157,21 +207,26 @@ pub fn compile(
         configuration.string_type_configuration.clone(),
     );
 
+    let eir_module = ModuleCompiler::new(
+        expression_compiler,
+        type_compiler,
+        type_definition_compiler,
+        source_information_compiler,
+        global_names,
+    )
+    .compile(&module)?;
+    dump(IntermediateRepresentation::Eir, &eir_module);
+
     let fmm_module = fmm::analysis::transform_to_cps(
-        &eir_fmm::compile(
-            &ModuleCompiler::new(
-                expression_compiler,
-                type_compiler,
-                type_definition_compiler,
-                global_names,
-            )
-            .compile(&module)?,
-        )?,
+        &eir_fmm::compile(&eir_module)?,
         fmm::types::Record::new(vec![]),
     )
     .unwrap();
+    dump(IntermediateRepresentation::Fmm, &fmm_module);
 
-    fmm::analysis::check_types(&fmm_module).unwrap();
+    if configuration.assertions {
+        fmm::analysis::check_types(&fmm_module).unwrap();
+    }
 
     Ok((
         fmm_llvm::compile_to_bit_code(
@@ -181,10 +236,120 @@ pub fn compile(
                 reallocate_function_name: configuration.realloc_function_name.clone(),
                 free_function_name: configuration.free_function_name.clone(),
             },
-            None,
+            configuration.target_triple.as_deref(),
+            if configuration.debug_information {
+                Some(fmm_llvm::DebugInformation::new(
+                    module.path().to_string(),
+                    format!("ein {}", crate::VERSION),
+                ))
+            } else {
+                None
+            }
+            .as_ref(),
         )
         .unwrap(),
         ModuleInterfaceCompiler::new().compile(&module)?,
+        intermediate_representations,
+    ))
+}
+
+pub fn analyze(
+    module: &Module,
+    configuration: Arc<CompileConfiguration>,
+) -> Result<ModuleAnalysis, CompileError> {
+    let (module, _, _) = check_module(module, configuration)?;
+
+    Ok(ModuleAnalysis::new(
+        ModuleInterfaceCompiler::new().compile(&module)?,
+        VariableTypeCollector::new(
+            ReferenceTypeResolver::new(&module),
+            ModuleEnvironmentCreator::new(),
+        )
+        .collect(&module)?,
+        VariableDefinitionCollector::new().collect(&module),
+    ))
+}
+
+// Modules are checked without code generation. Therefore, errors detected only
+// on code generation are not reported.
+pub fn check(
+    module: &Module,
+    configuration: Arc<CompileConfiguration>,
+) -> Result<ModuleInterface, CompileError> {
+    let (module, _, _) = check_module(module, configuration)?;
+
+    ModuleInterfaceCompiler::new().compile(&module)
+}
+
+fn check_module(
+    module: &Module,
+    configuration: Arc<CompileConfiguration>,
+) -> Result<
+    (
+        Module,
+        Arc<HashMap<String, String>>,
+        Arc<CompileConfiguration>,
+    ),
+    CompileError,
+> {
+    let (module, global_names, configuration) = infer_module_types(module, configuration)?;
+    let module = check_typed_module(&module, configuration.clone())?;
+
+    Ok((module, global_names, configuration))
+}
+
+fn check_typed_module(
+    module: &Module,
+    configuration: Arc<CompileConfiguration>,
+) -> Result<Module, CompileError> {
+    let module = transform_with_types(module, configuration.clone())?;
+
+    let reference_type_resolver = ReferenceTypeResolver::new(&module);
+    let type_equality_checker = TypeEqualityChecker::new(reference_type_resolver.clone());
+
+    CaseExhaustivenessChecker::new(
+        reference_type_resolver.clone(),
+        TypeCanonicalizer::new(reference_type_resolver, type_equality_checker.clone()),
+        type_equality_checker,
+    )
+    .check(&module)?;
+
+    transform_with_checked_types(&module, configuration)
+}
+
+fn infer_module_types(
+    module: &Module,
+    configuration: Arc<CompileConfiguration>,
+) -> Result<
+    (
+        Module,
+        Arc<HashMap<String, String>>,
+        Arc<CompileConfiguration>,
+    ),
+    CompileError,
+> {
+    GlobalNameValidator::new().validate(module)?;
+
+    let module = transform_before_name_qualification(module)?;
+
+    let module = if let Some(main_module_configuration) = &configuration.main_module_configuration {
+        MainFunctionDefinitionTransformer::new(main_module_configuration.clone())
+            .transform(&module)?
+    } else {
+        module
+    };
+
+    let global_names = GlobalNameMapCreator::create(&module);
+    let configuration = Arc::new(configuration.qualify(&global_names));
+    let module = GlobalNameRenamer::new(global_names.clone()).rename(&module);
+
+    Ok((
+        infer_types(
+            &transform_without_types(&module, configuration.clone())?,
+            configuration.clone(),
+        )?,
+        global_names,
+        configuration,
     ))
 }
 
@@ -193,6 +358,44 @@ mod tests {
     use super::{compile_configuration::COMPILE_CONFIGURATION, *};
     use crate::{debug::*, path::ModulePath, types};
 
+    // Hash functions of records refer to hash functions in prelude modules.
+    fn create_hash_function_definitions() -> Vec<Definition> {
+        vec![
+            FunctionDefinition::new(
+                &COMPILE_CONFIGURATION
+                    .map_type_configuration
+                    .hash_number_function_name,
+                vec!["x".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into(),
+            FunctionDefinition::new(
+                &COMPILE_CONFIGURATION
+                    .map_type_configuration
+                    .combine_hashes_function_name,
+                vec!["x".into(), "y".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into(),
+        ]
+    }
+
     #[test]
     fn compile_constant_initialized_with_operation() {
         assert!(compile(
@@ -222,6 +425,189 @@ mod tests {
         .is_ok());
     }
 
+    #[test]
+    fn compile_with_intermediate_representations_of_module() {
+        let (_, _, intermediate_representations) = compile_with_intermediate_representations(
+            &Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                Number::new(42.0, SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()]),
+            COMPILE_CONFIGURATION.clone(),
+            &[
+                IntermediateRepresentation::TypedAst,
+                IntermediateRepresentation::Fmm,
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            intermediate_representations
+                .iter()
+                .map(|(kind, _)| *kind)
+                .collect::<Vec<_>>(),
+            vec![
+                IntermediateRepresentation::TypedAst,
+                IntermediateRepresentation::Fmm
+            ]
+        );
+    }
+
+    #[test]
+    fn analyze_variable_types() {
+        let source_information = Arc::new(SourceInformation::new("", Location::new(1, 7), ""));
+
+        let analysis = analyze(
+            &Module::from_definitions(vec![FunctionDefinition::new(
+                "f",
+                vec!["x".into()],
+                Variable::new("x", source_information.clone()),
+                types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()]),
+            COMPILE_CONFIGURATION.clone(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            analysis.variable_types(),
+            &[(
+                source_information,
+                types::Number::new(SourceInformation::dummy()).into()
+            )]
+        );
+    }
+
+    #[test]
+    fn check_module_interface() {
+        assert_eq!(
+            check(
+                &Module::from_definitions(vec![VariableDefinition::new(
+                    "x",
+                    Number::new(42.0, SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()]),
+                COMPILE_CONFIGURATION.clone(),
+            )
+            .unwrap()
+            .path(),
+            &ModulePath::dummy()
+        );
+    }
+
+    #[test]
+    fn fail_to_check_module_with_type_error() {
+        assert!(matches!(
+            check(
+                &Module::from_definitions(vec![VariableDefinition::new(
+                    "x",
+                    None::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()]),
+                COMPILE_CONFIGURATION.clone(),
+            ),
+            Err(CompileError::TypesNotMatched { .. })
+        ));
+    }
+
+    #[test]
+    fn fail_to_check_module_with_case_not_exhaustive() {
+        let error = check(
+            &Module::from_definitions(vec![
+                VariableDefinition::new(
+                    "x",
+                    None::new(SourceInformation::dummy()),
+                    types::Union::new(
+                        vec![
+                            types::Number::new(SourceInformation::dummy()).into(),
+                            types::None::new(SourceInformation::dummy()).into(),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                VariableDefinition::new(
+                    "y",
+                    Case::new(
+                        "z",
+                        Variable::new("x", SourceInformation::dummy()),
+                        vec![Alternative::new(
+                            types::Number::new(SourceInformation::dummy()),
+                            None::new(SourceInformation::dummy()),
+                        )],
+                        SourceInformation::dummy(),
+                    ),
+                    types::None::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ]),
+            COMPILE_CONFIGURATION.clone(),
+        )
+        .unwrap_err();
+
+        assert_eq!(error.code(), "E0019");
+        assert_eq!(
+            error,
+            CompileError::CaseNotExhaustive {
+                types: vec![types::None::new(SourceInformation::dummy()).into()],
+                source_information: SourceInformation::dummy().into(),
+            }
+        );
+        assert!(error
+            .to_string()
+            .starts_with("case expression not exhaustive; missing alternatives for None"));
+    }
+
+    #[test]
+    fn fail_to_check_module_with_case_of_any_not_exhaustive() {
+        assert_eq!(
+            check(
+                &Module::from_definitions(vec![
+                    VariableDefinition::new(
+                        "x",
+                        None::new(SourceInformation::dummy()),
+                        types::Any::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    VariableDefinition::new(
+                        "y",
+                        Case::new(
+                            "z",
+                            Variable::new("x", SourceInformation::dummy()),
+                            vec![Alternative::new(
+                                types::Number::new(SourceInformation::dummy()),
+                                None::new(SourceInformation::dummy()),
+                            )],
+                            SourceInformation::dummy(),
+                        ),
+                        types::None::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ]),
+                COMPILE_CONFIGURATION.clone(),
+            ),
+            Err(CompileError::CaseNotExhaustive {
+                types: vec![types::Any::new(SourceInformation::dummy()).into()],
+                source_information: SourceInformation::dummy().into(),
+            })
+        );
+    }
+
     #[test]
     fn compile_record_construction() {
         let reference_type = types::Reference::new("Foo", SourceInformation::dummy());
@@ -238,6 +624,7 @@ mod tests {
                         )],
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
                 )],
                 vec![VariableDefinition::new(
                     "x",
@@ -254,7 +641,10 @@ mod tests {
                     reference_type,
                     SourceInformation::dummy(),
                 )
-                .into()],
+                .into()]
+                .into_iter()
+                .chain(create_hash_function_definitions())
+                .collect(),
             ),
             COMPILE_CONFIGURATION.clone(),
         )
@@ -283,6 +673,7 @@ mod tests {
                         ],
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
                 )],
                 vec![VariableDefinition::new(
                     "x",
@@ -305,7 +696,10 @@ mod tests {
                     reference_type,
                     SourceInformation::dummy(),
                 )
-                .into()],
+                .into()]
+                .into_iter()
+                .chain(create_hash_function_definitions())
+                .collect(),
             ),
             COMPILE_CONFIGURATION.clone(),
         )
@@ -328,6 +722,7 @@ mod tests {
                         )],
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
                 )],
                 vec![VariableDefinition::new(
                     "x",
@@ -348,7 +743,10 @@ mod tests {
                     types::Number::new(SourceInformation::dummy()),
                     SourceInformation::dummy(),
                 )
-                .into()],
+                .into()]
+                .into_iter()
+                .chain(create_hash_function_definitions())
+                .collect(),
             ),
             COMPILE_CONFIGURATION.clone(),
         )
@@ -364,6 +762,7 @@ mod tests {
                 vec![TypeDefinition::new(
                     "Foo",
                     types::Record::new("Foo", Default::default(), SourceInformation::dummy()),
+                    SourceInformation::dummy(),
                 )],
                 vec![VariableDefinition::new(
                     "x",
@@ -392,6 +791,7 @@ mod tests {
                         )],
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
                 )],
                 vec![],
             ),
@@ -645,6 +1045,7 @@ mod tests {
                         ],
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
                 )],
                 vec![],
             ),
@@ -801,8 +1202,8 @@ mod tests {
 
             let module = Module::new(
                 ModulePath::new(Package::new("", ""), vec![]),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![Import::new(
                     ModuleInterface::new(
                         ModulePath::new(Package::new("m", ""), vec![]),
@@ -877,8 +1278,8 @@ mod tests {
 
             let module = Module::new(
                 ModulePath::new(Package::new("", ""), vec![]),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![Import::new(
                     ModuleInterface::new(
                         ModulePath::new(Package::new("m", ""), vec![]),
@@ -948,8 +1349,11 @@ mod tests {
     fn compile_export_foreigns() {
         let module = Module::new(
             ModulePath::dummy(),
-            Export::new(Default::default()),
-            ExportForeign::new(vec!["foo".into()].into_iter().collect()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(
+                vec!["foo".into()].into_iter().collect(),
+                SourceInformation::dummy(),
+            ),
             vec![],
             vec![],
             vec![],
@@ -0,0 +1,36 @@
+use crate::{ast::ModuleInterface, debug::SourceInformation, types::Type};
+use std::sync::Arc;
+
+// Analysis results are used by editors without generating any code.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModuleAnalysis {
+    module_interface: ModuleInterface,
+    variable_types: Vec<(Arc<SourceInformation>, Type)>,
+    variable_definitions: Vec<(Arc<SourceInformation>, Arc<SourceInformation>)>,
+}
+
+impl ModuleAnalysis {
+    pub fn new(
+        module_interface: ModuleInterface,
+        variable_types: Vec<(Arc<SourceInformation>, Type)>,
+        variable_definitions: Vec<(Arc<SourceInformation>, Arc<SourceInformation>)>,
+    ) -> Self {
+        Self {
+            module_interface,
+            variable_types,
+            variable_definitions,
+        }
+    }
+
+    pub fn module_interface(&self) -> &ModuleInterface {
+        &self.module_interface
+    }
+
+    pub fn variable_types(&self) -> &[(Arc<SourceInformation>, Type)] {
+        &self.variable_types
+    }
+
+    pub fn variable_definitions(&self) -> &[(Arc<SourceInformation>, Arc<SourceInformation>)] {
+        &self.variable_definitions
+    }
+}
@@ -1,5 +1,8 @@
 use super::{
-    error::CompileError, expression_compiler::ExpressionCompiler, type_compiler::TypeCompiler,
+    error::{collect_results, CompileError},
+    expression_compiler::ExpressionCompiler,
+    source_information_compiler::SourceInformationCompiler,
+    type_compiler::TypeCompiler,
     type_definition_compiler::TypeDefinitionCompiler,
 };
 use crate::ast::*;
@@ -9,6 +12,7 @@ pub struct ModuleCompiler {
     expression_compiler: Arc<ExpressionCompiler>,
     type_compiler: Arc<TypeCompiler>,
     type_definition_compiler: Arc<TypeDefinitionCompiler>,
+    source_information_compiler: Arc<SourceInformationCompiler>,
     global_names: Arc<HashMap<String, String>>,
 }
 
@@ -17,12 +21,14 @@ impl ModuleCompiler {
         expression_compiler: Arc<ExpressionCompiler>,
         type_compiler: Arc<TypeCompiler>,
         type_definition_compiler: Arc<TypeDefinitionCompiler>,
+        source_information_compiler: Arc<SourceInformationCompiler>,
         global_names: Arc<HashMap<String, String>>,
     ) -> Self {
         Self {
             expression_compiler,
             type_compiler,
             type_definition_compiler,
+            source_information_compiler,
             global_names,
         }
     }
@@ -88,10 +94,8 @@ impl ModuleCompiler {
                         })
                 })
                 .collect::<Result<Vec<_>, CompileError>>()?,
-            module
-                .definitions()
-                .iter()
-                .map(|definition| {
+            collect_results(module.definitions().iter().map(
+                |definition| -> Result<_, CompileError> {
                     Ok(match definition {
                         Definition::FunctionDefinition(function_definition) => {
                             vec![self.compile_function_definition(function_definition)?]
@@ -100,11 +104,11 @@ impl ModuleCompiler {
                             self.compile_variable_definition(variable_definition)?
                         }
                     })
-                })
-                .collect::<Result<Vec<_>, CompileError>>()?
-                .into_iter()
-                .flatten()
-                .collect(),
+                },
+            ))?
+            .into_iter()
+            .flatten()
+            .collect(),
         ))
     }
 
@@ -116,20 +120,23 @@ impl ModuleCompiler {
             .type_compiler
             .compile_function(function_definition.type_())?;
 
-        Ok(eir::ir::Definition::new(
-            function_definition.name(),
-            function_definition
-                .arguments()
-                .iter()
-                .zip(core_type.arguments())
-                .map(|(name, type_)| eir::ir::Argument::new(name.clone(), type_.clone()))
-                .collect::<Vec<_>>(),
-            self.expression_compiler
-                .compile(function_definition.body())?,
-            (0..function_definition.arguments().len())
-                .fold(core_type.into(), |type_: eir::types::Type, _| {
-                    type_.into_function().unwrap().result().clone()
-                }),
+        Ok(self.source_information_compiler.compile_definition(
+            eir::ir::Definition::new(
+                function_definition.name(),
+                function_definition
+                    .arguments()
+                    .iter()
+                    .zip(core_type.arguments())
+                    .map(|(name, type_)| eir::ir::Argument::new(name.clone(), type_.clone()))
+                    .collect::<Vec<_>>(),
+                self.expression_compiler
+                    .compile(function_definition.body())?,
+                (0..function_definition.arguments().len())
+                    .fold(core_type.into(), |type_: eir::types::Type, _| {
+                        type_.into_function().unwrap().result().clone()
+                    }),
+            ),
+            function_definition.source_information(),
         ))
     }
 
@@ -152,7 +159,13 @@ impl ModuleCompiler {
                     variable_definition.body(),
                     &core_type,
                 )?]
-            },
+            }
+            .into_iter()
+            .map(|definition| {
+                self.source_information_compiler
+                    .compile_definition(definition, variable_definition.source_information())
+            })
+            .collect(),
         )
     }
 
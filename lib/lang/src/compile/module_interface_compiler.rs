@@ -22,7 +22,7 @@ impl ModuleInterfaceCompiler {
         }) {
             Err(CompileError::ExportedNameNotFound { name: name.into() })
         } else {
-            Ok(ModuleInterface::new(
+            Ok(ModuleInterface::with_variable_source_informations(
                 module.path().clone(),
                 module.export().names().iter().cloned().collect(),
                 module
@@ -40,6 +40,27 @@ impl ModuleInterfaceCompiler {
                     .iter()
                     .map(|definition| (definition.name().into(), definition.type_().clone()))
                     .collect(),
+                module
+                    .type_definitions()
+                    .iter()
+                    .filter(|type_definition| !type_definition.type_parameters().is_empty())
+                    .map(|type_definition| {
+                        (
+                            type_definition.name().into(),
+                            type_definition.type_parameters().to_vec(),
+                        )
+                    })
+                    .collect(),
+                module
+                    .definitions()
+                    .iter()
+                    .map(|definition| {
+                        (
+                            definition.name().into(),
+                            definition.source_information().clone(),
+                        )
+                    })
+                    .collect(),
             ))
         }
     }
@@ -50,7 +71,7 @@ mod tests {
     use super::*;
     use crate::{
         ast::{Export, Number, VariableDefinition},
-        debug::SourceInformation,
+        debug::{Location, SourceInformation},
         package::Package,
         path::ModulePath,
         types,
@@ -75,8 +96,11 @@ mod tests {
         assert_eq!(
             ModuleInterfaceCompiler::new().compile(&Module::new(
                 ModulePath::new(Package::new("P", ""), vec!["M".into()]),
-                Export::new(vec!["x".into()].into_iter().collect()),
-                ExportForeign::new(Default::default()),
+                Export::new(
+                    vec!["x".into()].into_iter().collect(),
+                    SourceInformation::dummy()
+                ),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![],
@@ -102,13 +126,35 @@ mod tests {
         );
     }
 
+    #[test]
+    fn compile_variable_source_informations() {
+        assert_eq!(
+            ModuleInterfaceCompiler::new()
+                .compile(&Module::from_definitions(vec![VariableDefinition::new(
+                    "x",
+                    Number::new(42.0, SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::new("", Location::new(3, 1), ""),
+                )
+                .into()]))
+                .unwrap()
+                .variable_source_informations()["x"]
+                .location()
+                .line_number(),
+            3
+        );
+    }
+
     #[test]
     fn fail_to_compile_module_interface_due_to_missing_exported_name() {
         assert_eq!(
             ModuleInterfaceCompiler::new().compile(&Module::new(
                 ModulePath::new(Package::new("P", ""), vec!["M".into()]),
-                Export::new(vec!["x".into()].into_iter().collect()),
-                ExportForeign::new(Default::default()),
+                Export::new(
+                    vec!["x".into()].into_iter().collect(),
+                    SourceInformation::dummy()
+                ),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![],
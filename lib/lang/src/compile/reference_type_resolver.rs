@@ -7,7 +7,7 @@ use std::{collections::HashMap, sync::Arc};
 
 #[derive(Debug)]
 pub struct ReferenceTypeResolver {
-    environment: HashMap<String, Type>,
+    environment: HashMap<String, (Vec<String>, Type)>,
 }
 
 impl ReferenceTypeResolver {
@@ -17,16 +17,29 @@ impl ReferenceTypeResolver {
                 .imports()
                 .iter()
                 .flat_map(|import| {
-                    import
-                        .module_interface()
-                        .types()
-                        .iter()
-                        .map(|(name, type_)| (name.into(), type_.clone()))
+                    let module_interface = import.module_interface();
+
+                    module_interface.types().iter().map(move |(name, type_)| {
+                        (
+                            name.into(),
+                            (
+                                module_interface
+                                    .type_parameters()
+                                    .get(name)
+                                    .cloned()
+                                    .unwrap_or_default(),
+                                type_.clone(),
+                            ),
+                        )
+                    })
                 })
                 .chain(module.type_definitions().iter().map(|type_definition| {
                     (
                         type_definition.name().into(),
-                        type_definition.type_().clone(),
+                        (
+                            type_definition.type_parameters().to_vec(),
+                            type_definition.type_().clone(),
+                        ),
                     )
                 }))
                 .collect(),
@@ -35,22 +48,74 @@ impl ReferenceTypeResolver {
     }
 
     pub fn resolve_reference(&self, reference: &types::Reference) -> Result<Type, CompileError> {
+        let (parameters, type_) = self.resolve_definition(reference)?;
+
         self.resolve(
-            self.environment
-                .get(reference.name())
-                .ok_or_else(|| CompileError::TypeNotFound(reference.clone()))?,
+            &type_.substitute_parameters(
+                &parameters
+                    .iter()
+                    .cloned()
+                    .zip(self.resolve_type_arguments(reference)?)
+                    .collect(),
+            ),
         )
     }
 
+    pub fn resolve_type_arguments(
+        &self,
+        reference: &types::Reference,
+    ) -> Result<Vec<Type>, CompileError> {
+        let (parameters, _) = self.resolve_definition(reference)?;
+
+        if reference.arguments().len() == parameters.len() {
+            Ok(reference.arguments().to_vec())
+        } else {
+            Err(CompileError::TypeArgumentsNotMatched(
+                reference.source_information().clone(),
+            ))
+        }
+    }
+
+    pub fn resolve_type_parameters(
+        &self,
+        reference: &types::Reference,
+    ) -> Result<&[String], CompileError> {
+        Ok(self.resolve_definition(reference)?.0.as_slice())
+    }
+
+    // Resolve a record type into its definition with type parameters
+    // uninstantiated.
+    pub fn resolve_record_definition(
+        &self,
+        record: &types::Record,
+    ) -> Result<types::Record, CompileError> {
+        Ok(match self.environment.get(record.name()) {
+            Some((_, Type::Record(definition))) => definition.clone(),
+            _ => record.clone(),
+        })
+    }
+
+    fn resolve_definition(
+        &self,
+        reference: &types::Reference,
+    ) -> Result<&(Vec<String>, Type), CompileError> {
+        self.environment
+            .get(reference.name())
+            .ok_or_else(|| CompileError::TypeNotFound(reference.clone()))
+    }
+
     pub fn resolve(&self, type_: &Type) -> Result<Type, CompileError> {
         match type_ {
             Type::Reference(reference) => self.resolve_reference(reference),
             Type::Any(_)
+            | Type::Array(_)
             | Type::Boolean(_)
             | Type::Function(_)
             | Type::List(_)
+            | Type::Map(_)
             | Type::None(_)
             | Type::Number(_)
+            | Type::Parameter(_)
             | Type::Record(_)
             | Type::String(_)
             | Type::Union(_)
@@ -109,6 +174,10 @@ impl ReferenceTypeResolver {
         Ok(matches!(self.resolve(type_)?, Type::List(_)))
     }
 
+    pub fn is_parameter(&self, type_: &Type) -> Result<bool, CompileError> {
+        Ok(matches!(self.resolve(type_)?, Type::Parameter(_)))
+    }
+
     pub fn is_union(&self, type_: &Type) -> Result<bool, CompileError> {
         Ok(matches!(self.resolve(type_)?, Type::Union(_)))
     }
@@ -133,8 +202,8 @@ mod tests {
         assert_eq!(
             ReferenceTypeResolver::new(&Module::new(
                 ModulePath::new(Package::new("", ""), vec![]),
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![Import::new(
                     ModuleInterface::new(
                         ModulePath::new(Package::new("Foo", ""), vec![]),
@@ -157,4 +226,86 @@ mod tests {
             Ok(types::Number::new(SourceInformation::dummy()).into())
         );
     }
+
+    #[test]
+    fn resolve_type_with_type_arguments() {
+        assert_eq!(
+            ReferenceTypeResolver::new(&Module::from_definitions_and_type_definitions(
+                vec![TypeDefinition::with_type_parameters(
+                    "Foo",
+                    vec!["a".into()],
+                    types::List::new(
+                        types::Parameter::new("a", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )],
+                vec![],
+            ))
+            .resolve(
+                &types::Reference::with_arguments(
+                    "Foo",
+                    vec![types::Number::new(SourceInformation::dummy()).into()],
+                    SourceInformation::dummy()
+                )
+                .into()
+            ),
+            Ok(types::List::new(
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into())
+        );
+    }
+
+    #[test]
+    fn fail_to_resolve_type_with_missing_type_arguments() {
+        assert_eq!(
+            ReferenceTypeResolver::new(&Module::from_definitions_and_type_definitions(
+                vec![TypeDefinition::with_type_parameters(
+                    "Foo",
+                    vec!["a".into()],
+                    types::List::new(
+                        types::Parameter::new("a", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )],
+                vec![],
+            ))
+            .resolve(&types::Reference::new("Foo", SourceInformation::dummy()).into()),
+            Err(CompileError::TypeArgumentsNotMatched(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_resolve_type_with_wrong_number_of_type_arguments() {
+        assert_eq!(
+            ReferenceTypeResolver::new(&Module::from_definitions_and_type_definitions(
+                vec![TypeDefinition::with_type_parameters(
+                    "Foo",
+                    vec!["a".into()],
+                    types::Parameter::new("a", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )],
+                vec![],
+            ))
+            .resolve(
+                &types::Reference::with_arguments(
+                    "Foo",
+                    vec![
+                        types::Number::new(SourceInformation::dummy()).into(),
+                        types::None::new(SourceInformation::dummy()).into()
+                    ],
+                    SourceInformation::dummy()
+                )
+                .into()
+            ),
+            Err(CompileError::TypeArgumentsNotMatched(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
 }
@@ -0,0 +1,81 @@
+use crate::debug::SourceInformation;
+use std::sync::Arc;
+
+// Source information is attached to intermediate representations only if debug
+// information is enabled so that builds without it are not affected.
+pub struct SourceInformationCompiler {
+    debug_information: bool,
+}
+
+impl SourceInformationCompiler {
+    pub fn new(debug_information: bool) -> Arc<Self> {
+        Self { debug_information }.into()
+    }
+
+    pub fn compile(
+        &self,
+        source_information: &SourceInformation,
+    ) -> Option<eir::ir::SourceInformation> {
+        if self.debug_information {
+            Some(eir::ir::SourceInformation::new(
+                source_information.source_name(),
+                source_information.location().line_number(),
+                source_information.location().column_number(),
+            ))
+        } else {
+            None
+        }
+    }
+
+    pub fn compile_expression(
+        &self,
+        expression: impl Into<eir::ir::Expression>,
+        source_information: &SourceInformation,
+    ) -> eir::ir::Expression {
+        let expression = expression.into();
+
+        if let Some(source_information) = self.compile(source_information) {
+            eir::ir::Located::new(source_information, expression).into()
+        } else {
+            expression
+        }
+    }
+
+    pub fn compile_definition(
+        &self,
+        definition: eir::ir::Definition,
+        source_information: &SourceInformation,
+    ) -> eir::ir::Definition {
+        if let Some(source_information) = self.compile(source_information) {
+            definition.with_source_information(source_information)
+        } else {
+            definition
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debug::Location;
+
+    #[test]
+    fn compile_expression_without_debug_information() {
+        assert_eq!(
+            SourceInformationCompiler::new(false)
+                .compile_expression(42.0, &SourceInformation::dummy()),
+            eir::ir::Expression::from(42.0)
+        );
+    }
+
+    #[test]
+    fn compile_expression_with_debug_information() {
+        assert_eq!(
+            SourceInformationCompiler::new(true).compile_expression(
+                42.0,
+                &SourceInformation::new("Foo.ein", Location::new(2, 3), "x = 42")
+            ),
+            eir::ir::Located::new(eir::ir::SourceInformation::new("Foo.ein", 2, 3), 42.0).into()
+        );
+    }
+}
@@ -1,5 +1,6 @@
 #[cfg(test)]
 use once_cell::sync::Lazy;
+use serde::Serialize;
 use std::collections::HashMap;
 #[cfg(test)]
 use std::sync::Arc;
@@ -12,6 +13,7 @@ pub static STRING_TYPE_CONFIGURATION: Lazy<Arc<StringTypeConfiguration>> = Lazy:
     .into()
 });
 
+#[derive(Serialize)]
 pub struct StringTypeConfiguration {
     pub equal_function_name: String,
 }
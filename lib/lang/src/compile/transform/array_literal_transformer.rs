@@ -0,0 +1,76 @@
+use super::super::{array_type_configuration::ArrayTypeConfiguration, utilities};
+use crate::{ast::*, debug::*, types};
+use std::sync::Arc;
+
+pub struct ArrayLiteralTransformer {
+    configuration: Arc<ArrayTypeConfiguration>,
+}
+
+impl ArrayLiteralTransformer {
+    pub fn new(configuration: Arc<ArrayTypeConfiguration>) -> Arc<Self> {
+        Self { configuration }.into()
+    }
+
+    pub fn transform(&self, array: &Array) -> Expression {
+        let source_information = array.source_information();
+        let element_type = utilities::get_array_element_type(array.type_());
+
+        array.elements().iter().fold(
+            Variable::new(
+                &self.configuration.empty_array_variable_name,
+                source_information.clone(),
+            )
+            .into(),
+            |expression, element| {
+                self.create_push(
+                    array.type_(),
+                    expression,
+                    TypeCoercion::new(
+                        element.clone(),
+                        element_type.clone(),
+                        types::Any::new(source_information.clone()),
+                        source_information.clone(),
+                    )
+                    .into(),
+                    source_information,
+                )
+            },
+        )
+    }
+
+    fn create_push(
+        &self,
+        array_type: &types::Type,
+        array: Expression,
+        element: Expression,
+        source_information: &Arc<SourceInformation>,
+    ) -> Expression {
+        Application::with_type(
+            types::Function::new(
+                types::Any::new(source_information.clone()),
+                array_type.clone(),
+                source_information.clone(),
+            ),
+            Application::with_type(
+                types::Function::new(
+                    array_type.clone(),
+                    types::Function::new(
+                        types::Any::new(source_information.clone()),
+                        array_type.clone(),
+                        source_information.clone(),
+                    ),
+                    source_information.clone(),
+                ),
+                Variable::new(
+                    &self.configuration.push_function_name,
+                    source_information.clone(),
+                ),
+                array,
+                source_information.clone(),
+            ),
+            element,
+            source_information.clone(),
+        )
+        .into()
+    }
+}
@@ -0,0 +1,127 @@
+use super::super::{array_type_configuration::ArrayTypeConfiguration, error::CompileError};
+use crate::{
+    ast::*,
+    debug::SourceInformation,
+    types::{self, Type},
+};
+use std::sync::Arc;
+
+// Array types are represented as generic record types defined in the prelude.
+pub struct ArrayTypeTransformer {
+    configuration: Arc<ArrayTypeConfiguration>,
+}
+
+impl ArrayTypeTransformer {
+    pub fn new(configuration: Arc<ArrayTypeConfiguration>) -> Self {
+        Self { configuration }
+    }
+
+    pub fn transform(&self, module: &Module) -> Result<Module, CompileError> {
+        module
+            .transform_types(&mut |type_| -> Result<_, CompileError> {
+                Ok(match type_ {
+                    Type::Array(array) => self
+                        .create_array_type(array.element(), array.source_information())
+                        .into(),
+                    _ => type_.clone(),
+                })
+            })?
+            .transform_expressions(&mut |expression| -> Result<_, CompileError> {
+                Ok(match expression {
+                    Expression::Array(array) => match array.type_() {
+                        Type::Unknown(_) => Array::with_type(
+                            self.create_array_type(
+                                &types::Unknown::new(array.source_information().clone()).into(),
+                                array.source_information(),
+                            ),
+                            array.elements().to_vec(),
+                            array.source_information().clone(),
+                        )
+                        .into(),
+                        _ => expression.clone(),
+                    },
+                    _ => expression.clone(),
+                })
+            })
+    }
+
+    fn create_array_type(
+        &self,
+        element: &Type,
+        source_information: &Arc<SourceInformation>,
+    ) -> types::Reference {
+        types::Reference::with_arguments(
+            &self.configuration.array_type_name,
+            vec![element.clone()],
+            source_information.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::super::array_type_configuration::ARRAY_TYPE_CONFIGURATION, *};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transform_array_types() {
+        let create_module = |type_: Type| {
+            Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                Variable::new("y", SourceInformation::dummy()),
+                type_,
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert_eq!(
+            ArrayTypeTransformer::new(ARRAY_TYPE_CONFIGURATION.clone()).transform(&create_module(
+                types::Array::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            )),
+            Ok(create_module(
+                types::Reference::with_arguments(
+                    "GenericArray",
+                    vec![types::Number::new(SourceInformation::dummy()).into()],
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn transform_array_literals() {
+        let create_module = |expression: Expression| {
+            Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                expression,
+                types::Unknown::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert_eq!(
+            ArrayTypeTransformer::new(ARRAY_TYPE_CONFIGURATION.clone()).transform(&create_module(
+                Array::new(vec![], SourceInformation::dummy()).into()
+            )),
+            Ok(create_module(
+                Array::with_type(
+                    types::Reference::with_arguments(
+                        "GenericArray",
+                        vec![types::Unknown::new(SourceInformation::dummy()).into()],
+                        SourceInformation::dummy()
+                    ),
+                    vec![],
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+    }
+}
@@ -64,12 +64,20 @@ mod tests {
         assert_eq!(
             ElementlessRecordTransformer::new().transform(
                 &Module::from_definitions_and_type_definitions(
-                    vec![TypeDefinition::new("Foo", record_type.clone())],
+                    vec![TypeDefinition::new(
+                        "Foo",
+                        record_type.clone(),
+                        SourceInformation::dummy()
+                    )],
                     vec![]
                 )
             ),
             Module::from_definitions_and_type_definitions(
-                vec![TypeDefinition::new("Foo", record_type.clone())],
+                vec![TypeDefinition::new(
+                    "Foo",
+                    record_type.clone(),
+                    SourceInformation::dummy()
+                )],
                 vec![VariableDefinition::new(
                     "Foo",
                     RecordConstruction::new(
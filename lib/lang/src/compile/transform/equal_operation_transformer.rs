@@ -1,9 +1,10 @@
 use super::{
     super::{
         error::CompileError, list_type_configuration::ListTypeConfiguration,
-        name_generator::NameGenerator, reference_type_resolver::ReferenceTypeResolver,
+        map_type_configuration::MapTypeConfiguration, name_generator::NameGenerator,
+        reference_type_resolver::ReferenceTypeResolver,
         type_comparability_checker::TypeComparabilityChecker,
-        type_equality_checker::TypeEqualityChecker,
+        type_equality_checker::TypeEqualityChecker, utilities::get_any_list_type,
     },
     utilities,
 };
@@ -20,6 +21,7 @@ pub struct EqualOperationTransformer {
     type_comparability_checker: Arc<TypeComparabilityChecker>,
     type_equality_checker: Arc<TypeEqualityChecker>,
     list_type_configuration: Arc<ListTypeConfiguration>,
+    map_type_configuration: Arc<MapTypeConfiguration>,
 }
 
 impl EqualOperationTransformer {
@@ -28,6 +30,7 @@ impl EqualOperationTransformer {
         type_comparability_checker: Arc<TypeComparabilityChecker>,
         type_equality_checker: Arc<TypeEqualityChecker>,
         list_type_configuration: Arc<ListTypeConfiguration>,
+        map_type_configuration: Arc<MapTypeConfiguration>,
     ) -> Arc<Self> {
         Self {
             name_generator: NameGenerator::new("equal_operation_argument_"),
@@ -35,6 +38,7 @@ impl EqualOperationTransformer {
             type_comparability_checker,
             type_equality_checker,
             list_type_configuration,
+            map_type_configuration,
         }
         .into()
     }
@@ -60,7 +64,9 @@ impl EqualOperationTransformer {
         source_information: Arc<SourceInformation>,
     ) -> Result<Expression, CompileError> {
         Ok(match self.reference_type_resolver.resolve(type_)? {
-            Type::Any(_) => return Err(CompileError::AnyEqualOperation(source_information)),
+            Type::Any(_) | Type::Parameter(_) => {
+                return Err(CompileError::AnyEqualOperation(source_information))
+            }
             Type::Boolean(_) => If::new(
                 lhs.clone(),
                 If::new(
@@ -83,10 +89,8 @@ impl EqualOperationTransformer {
             }
             Type::List(list_type) => {
                 let element_type = list_type.element();
-                let any_list_type = types::Reference::new(
-                    &self.list_type_configuration.list_type_name,
-                    source_information.clone(),
-                );
+                let any_list_type =
+                    get_any_list_type(&self.list_type_configuration, &source_information);
 
                 Let::new(
                     vec![FunctionDefinition::new(
@@ -311,7 +315,224 @@ impl EqualOperationTransformer {
                 )
                 .into()
             }
-            Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => unreachable!(),
+            Type::Array(_)
+            | Type::Map(_)
+            | Type::Reference(_)
+            | Type::Unknown(_)
+            | Type::Variable(_) => {
+                unreachable!()
+            }
         })
     }
+
+    // Hashes are consistent with equality. Values equal to each other have the
+    // same hashes.
+    pub fn transform_hash(
+        &self,
+        type_: &Type,
+        expression: &Expression,
+        source_information: Arc<SourceInformation>,
+    ) -> Result<Expression, CompileError> {
+        Ok(match self.reference_type_resolver.resolve(type_)? {
+            Type::Any(_) | Type::Parameter(_) => {
+                return Err(CompileError::AnyEqualOperation(source_information))
+            }
+            Type::Boolean(_) => If::new(
+                expression.clone(),
+                Number::new(1.0, source_information.clone()),
+                Number::new(0.0, source_information.clone()),
+                source_information,
+            )
+            .into(),
+            Type::Function(_) => {
+                return Err(CompileError::FunctionEqualOperation(source_information))
+            }
+            Type::List(list_type) => {
+                let element_type = list_type.element();
+                let any_list_type =
+                    get_any_list_type(&self.list_type_configuration, &source_information);
+                let hash_function_type = types::Function::new(
+                    types::Any::new(source_information.clone()),
+                    types::Number::new(source_information.clone()),
+                    source_information.clone(),
+                );
+
+                Let::new(
+                    vec![FunctionDefinition::new(
+                        "$hashElement",
+                        vec!["element".into()],
+                        Case::with_type(
+                            types::Any::new(source_information.clone()),
+                            "element",
+                            Variable::new("element", source_information.clone()),
+                            vec![
+                                Alternative::new(
+                                    element_type.clone(),
+                                    self.transform_hash(
+                                        element_type,
+                                        &Variable::new("element", source_information.clone())
+                                            .into(),
+                                        source_information.clone(),
+                                    )?,
+                                ),
+                                Alternative::new(
+                                    types::Any::new(source_information.clone()),
+                                    Number::new(0.0, source_information.clone()),
+                                ),
+                            ],
+                            source_information.clone(),
+                        ),
+                        hash_function_type.clone(),
+                        source_information.clone(),
+                    )
+                    .into()],
+                    Application::with_type(
+                        types::Function::new(
+                            any_list_type.clone(),
+                            types::Number::new(source_information.clone()),
+                            source_information.clone(),
+                        ),
+                        Application::with_type(
+                            types::Function::new(
+                                hash_function_type,
+                                types::Function::new(
+                                    any_list_type,
+                                    types::Number::new(source_information.clone()),
+                                    source_information.clone(),
+                                ),
+                                source_information.clone(),
+                            ),
+                            Variable::new(
+                                &self.map_type_configuration.hash_list_function_name,
+                                source_information.clone(),
+                            ),
+                            Variable::new("$hashElement", source_information.clone()),
+                            source_information.clone(),
+                        ),
+                        expression.clone(),
+                        source_information.clone(),
+                    ),
+                    source_information,
+                )
+                .into()
+            }
+            Type::None(_) => Number::new(0.0, source_information).into(),
+            Type::Number(_) => self.create_hash_function_application(
+                &self.map_type_configuration.hash_number_function_name,
+                types::Number::new(source_information.clone()),
+                expression,
+                source_information,
+            ),
+            Type::Record(record) => {
+                if self.type_comparability_checker.check(type_)? {
+                    self.create_hash_function_application(
+                        &utilities::get_record_hash_function_name(&record),
+                        record.clone(),
+                        expression,
+                        source_information,
+                    )
+                } else {
+                    return Err(CompileError::RecordEqualOperation(source_information));
+                }
+            }
+            Type::String(_) => self.create_hash_function_application(
+                &self.map_type_configuration.hash_string_function_name,
+                types::EinString::new(source_information.clone()),
+                expression,
+                source_information,
+            ),
+            Type::Union(union) => {
+                let name = self.name_generator.generate();
+
+                Case::with_type(
+                    union.clone(),
+                    &name,
+                    expression.clone(),
+                    union
+                        .types()
+                        .iter()
+                        .enumerate()
+                        .map(|(index, type_)| {
+                            Ok(Alternative::new(
+                                type_.clone(),
+                                self.create_combined_hash(
+                                    Number::new(index as f64, source_information.clone()).into(),
+                                    self.transform_hash(
+                                        type_,
+                                        &Variable::new(&name, source_information.clone()).into(),
+                                        source_information.clone(),
+                                    )?,
+                                    source_information.clone(),
+                                ),
+                            ))
+                        })
+                        .collect::<Result<_, CompileError>>()?,
+                    source_information,
+                )
+                .into()
+            }
+            Type::Array(_)
+            | Type::Map(_)
+            | Type::Reference(_)
+            | Type::Unknown(_)
+            | Type::Variable(_) => {
+                unreachable!()
+            }
+        })
+    }
+
+    pub fn create_combined_hash(
+        &self,
+        lhs: Expression,
+        rhs: Expression,
+        source_information: Arc<SourceInformation>,
+    ) -> Expression {
+        Application::with_type(
+            types::Function::new(
+                types::Number::new(source_information.clone()),
+                types::Number::new(source_information.clone()),
+                source_information.clone(),
+            ),
+            Application::with_type(
+                types::Function::new(
+                    types::Number::new(source_information.clone()),
+                    types::Function::new(
+                        types::Number::new(source_information.clone()),
+                        types::Number::new(source_information.clone()),
+                        source_information.clone(),
+                    ),
+                    source_information.clone(),
+                ),
+                Variable::new(
+                    &self.map_type_configuration.combine_hashes_function_name,
+                    source_information.clone(),
+                ),
+                lhs,
+                source_information.clone(),
+            ),
+            rhs,
+            source_information,
+        )
+        .into()
+    }
+
+    fn create_hash_function_application(
+        &self,
+        function_name: &str,
+        argument_type: impl Into<Type>,
+        expression: &Expression,
+        source_information: Arc<SourceInformation>,
+    ) -> Expression {
+        Application::with_type(
+            types::Function::new(
+                argument_type,
+                types::Number::new(source_information.clone()),
+                source_information.clone(),
+            ),
+            Variable::new(function_name, source_information.clone()),
+            expression.clone(),
+            source_information,
+        )
+        .into()
+    }
 }
@@ -118,6 +118,9 @@ impl FunctionTypeCoercionTransformer {
                     )
                     .into()
                 }
+                (Type::Parameter(_), Type::Function(_)) => {
+                    self.coerce_type(argument, from_type, to_type, source_information)?
+                }
                 (_, Type::Function(_)) => unreachable!(),
                 _ => self.coerce_type(argument, from_type, to_type, source_information)?,
             }
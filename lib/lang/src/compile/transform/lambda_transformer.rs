@@ -0,0 +1,75 @@
+use super::super::name_generator::NameGenerator;
+use crate::ast::*;
+use std::sync::Arc;
+
+/// LambdaTransformer converts lambda expressions into local function
+/// definitions which capture their free variables as closures.
+pub struct LambdaTransformer {
+    name_generator: NameGenerator,
+}
+
+impl LambdaTransformer {
+    pub fn new() -> Arc<Self> {
+        Self {
+            name_generator: NameGenerator::new("$lambda_"),
+        }
+        .into()
+    }
+
+    pub fn transform(&self, lambda: &Lambda) -> Expression {
+        let source_information = lambda.source_information();
+        let name = self.name_generator.generate();
+
+        Let::new(
+            vec![FunctionDefinition::new(
+                &name,
+                lambda.arguments().to_vec(),
+                lambda.body().clone(),
+                lambda.type_().clone(),
+                source_information.clone(),
+            )
+            .into()],
+            Variable::new(name, source_information.clone()),
+            source_information.clone(),
+        )
+        .into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::SourceInformation, types};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transform_lambda() {
+        let type_ = types::Function::new(
+            types::Number::new(SourceInformation::dummy()),
+            types::Number::new(SourceInformation::dummy()),
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            LambdaTransformer::new().transform(&Lambda::new(
+                vec!["x".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                type_.clone(),
+                SourceInformation::dummy(),
+            )),
+            Let::new(
+                vec![FunctionDefinition::new(
+                    "$lambda_0",
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    type_,
+                    SourceInformation::dummy(),
+                )
+                .into()],
+                Variable::new("$lambda_0", SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()
+        );
+    }
+}
@@ -118,8 +118,8 @@ mod tests {
     static MODULE: Lazy<Module> = Lazy::new(|| {
         Module::new(
             ModulePath::new(Package::new("", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![Import::new(
                 ModuleInterface::new(
                     ModulePath::new(Package::new("m", ""), vec![]),
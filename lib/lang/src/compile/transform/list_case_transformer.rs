@@ -1,6 +1,9 @@
 use super::super::{
-    error::CompileError, list_type_configuration::ListTypeConfiguration,
-    name_generator::NameGenerator, reference_type_resolver::ReferenceTypeResolver,
+    error::CompileError,
+    list_type_configuration::ListTypeConfiguration,
+    name_generator::NameGenerator,
+    reference_type_resolver::ReferenceTypeResolver,
+    utilities::{get_any_first_rest_type, get_any_list_type},
 };
 use crate::{ast::*, types};
 use std::sync::Arc;
@@ -28,14 +31,8 @@ impl ListCaseTransformer {
 
     pub fn transform(&self, case: &ListCase) -> Result<Expression, CompileError> {
         let source_information = case.source_information();
-        let any_list_type = types::Reference::new(
-            &self.configuration.list_type_name,
-            source_information.clone(),
-        );
-        let first_rest_type = types::Reference::new(
-            &self.configuration.first_rest_type_name,
-            source_information.clone(),
-        );
+        let any_list_type = get_any_list_type(&self.configuration, source_information);
+        let first_rest_type = get_any_first_rest_type(&self.configuration, source_information);
         let none_type = types::None::new(source_information.clone());
         let element_type = self
             .reference_type_resolver
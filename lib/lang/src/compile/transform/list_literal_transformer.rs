@@ -1,6 +1,6 @@
 use super::super::{
     error::CompileError, list_type_configuration::ListTypeConfiguration,
-    reference_type_resolver::ReferenceTypeResolver,
+    reference_type_resolver::ReferenceTypeResolver, utilities::get_any_list_type,
 };
 use crate::{ast::*, debug::*, types};
 use std::sync::Arc;
@@ -40,10 +40,7 @@ impl ListLiteralTransformer {
         source_information: &Arc<SourceInformation>,
     ) -> Expression {
         let rest_expression = || self.transform_list(type_, &elements[1..], source_information);
-        let any_list_type = types::Reference::new(
-            self.configuration.list_type_name.clone(),
-            source_information.clone(),
-        );
+        let any_list_type = get_any_list_type(&self.configuration, source_information);
 
         match elements {
             [] => Variable::new(
@@ -1,7 +1,7 @@
 use super::super::{
     error::CompileError, list_type_configuration::ListTypeConfiguration,
     name_generator::NameGenerator, reference_type_resolver::ReferenceTypeResolver,
-    type_equality_checker::TypeEqualityChecker,
+    type_equality_checker::TypeEqualityChecker, utilities::get_any_list_type,
 };
 use crate::{
     ast::*,
@@ -55,10 +55,7 @@ impl ListTypeCoercionTransformer {
             .resolve_to_list(coercion.to())?
             .unwrap();
         let source_information = coercion.source_information().clone();
-        let any_list_type = types::Reference::new(
-            &self.list_type_configuration.list_type_name,
-            source_information.clone(),
-        );
+        let any_list_type = get_any_list_type(&self.list_type_configuration, &source_information);
 
         // Re-tag elements if needed.
         Ok(Let::new(
@@ -0,0 +1,388 @@
+use super::{
+    super::{
+        error::CompileError, map_type_configuration::MapTypeConfiguration,
+        type_comparability_checker::TypeComparabilityChecker, utilities,
+    },
+    equal_operation_transformer::EqualOperationTransformer,
+};
+use crate::{
+    ast::*,
+    debug::*,
+    types::{self, Type},
+};
+use std::sync::Arc;
+
+pub struct MapLiteralTransformer {
+    equal_operation_transformer: Arc<EqualOperationTransformer>,
+    type_comparability_checker: Arc<TypeComparabilityChecker>,
+    configuration: Arc<MapTypeConfiguration>,
+}
+
+impl MapLiteralTransformer {
+    pub fn new(
+        equal_operation_transformer: Arc<EqualOperationTransformer>,
+        type_comparability_checker: Arc<TypeComparabilityChecker>,
+        configuration: Arc<MapTypeConfiguration>,
+    ) -> Arc<Self> {
+        Self {
+            equal_operation_transformer,
+            type_comparability_checker,
+            configuration,
+        }
+        .into()
+    }
+
+    pub fn transform(&self, map: &Map) -> Result<Expression, CompileError> {
+        let source_information = map.source_information();
+        let (key_type, value_type) = utilities::get_map_element_types(map.type_());
+
+        if !self.type_comparability_checker.check(key_type)? {
+            return Err(CompileError::MapKeyNotComparable(
+                source_information.clone(),
+            ));
+        }
+
+        Ok(Let::new(
+            vec![
+                self.create_hash_function_definition(key_type, source_information)?
+                    .into(),
+                self.create_equal_function_definition(key_type, source_information)
+                    .into(),
+            ],
+            map.elements().iter().fold(
+                self.create_empty_map(map.type_(), source_information),
+                |expression, element| {
+                    self.create_insertion(
+                        map.type_(),
+                        expression,
+                        TypeCoercion::new(
+                            element.key().clone(),
+                            key_type.clone(),
+                            types::Any::new(source_information.clone()),
+                            source_information.clone(),
+                        )
+                        .into(),
+                        TypeCoercion::new(
+                            element.value().clone(),
+                            value_type.clone(),
+                            types::Any::new(source_information.clone()),
+                            source_information.clone(),
+                        )
+                        .into(),
+                        source_information,
+                    )
+                },
+            ),
+            source_information.clone(),
+        )
+        .into())
+    }
+
+    fn create_hash_function_definition(
+        &self,
+        key_type: &Type,
+        source_information: &Arc<SourceInformation>,
+    ) -> Result<FunctionDefinition, CompileError> {
+        Ok(FunctionDefinition::new(
+            "$hashKey",
+            vec!["key".into()],
+            Case::with_type(
+                types::Any::new(source_information.clone()),
+                "key",
+                Variable::new("key", source_information.clone()),
+                vec![
+                    Alternative::new(
+                        key_type.clone(),
+                        self.equal_operation_transformer.transform_hash(
+                            key_type,
+                            &Variable::new("key", source_information.clone()).into(),
+                            source_information.clone(),
+                        )?,
+                    ),
+                    Alternative::new(
+                        types::Any::new(source_information.clone()),
+                        Number::new(0.0, source_information.clone()),
+                    ),
+                ],
+                source_information.clone(),
+            ),
+            self.create_hash_function_type(source_information),
+            source_information.clone(),
+        ))
+    }
+
+    fn create_equal_function_definition(
+        &self,
+        key_type: &Type,
+        source_information: &Arc<SourceInformation>,
+    ) -> FunctionDefinition {
+        FunctionDefinition::new(
+            "$equalKeys",
+            vec!["lhs".into(), "rhs".into()],
+            Case::with_type(
+                types::Any::new(source_information.clone()),
+                "lhs",
+                Variable::new("lhs", source_information.clone()),
+                vec![
+                    Alternative::new(
+                        key_type.clone(),
+                        Case::with_type(
+                            types::Any::new(source_information.clone()),
+                            "rhs",
+                            Variable::new("rhs", source_information.clone()),
+                            vec![
+                                Alternative::new(
+                                    key_type.clone(),
+                                    EqualityOperation::with_type(
+                                        key_type.clone(),
+                                        EqualityOperator::Equal,
+                                        Variable::new("lhs", source_information.clone()),
+                                        Variable::new("rhs", source_information.clone()),
+                                        source_information.clone(),
+                                    ),
+                                ),
+                                Alternative::new(
+                                    types::Any::new(source_information.clone()),
+                                    Boolean::new(false, source_information.clone()),
+                                ),
+                            ],
+                            source_information.clone(),
+                        ),
+                    ),
+                    Alternative::new(
+                        types::Any::new(source_information.clone()),
+                        Boolean::new(false, source_information.clone()),
+                    ),
+                ],
+                source_information.clone(),
+            ),
+            self.create_equal_function_type(source_information),
+            source_information.clone(),
+        )
+    }
+
+    fn create_empty_map(
+        &self,
+        map_type: &Type,
+        source_information: &Arc<SourceInformation>,
+    ) -> Expression {
+        Application::with_type(
+            types::Function::new(
+                self.create_equal_function_type(source_information),
+                map_type.clone(),
+                source_information.clone(),
+            ),
+            Application::with_type(
+                types::Function::new(
+                    self.create_hash_function_type(source_information),
+                    types::Function::new(
+                        self.create_equal_function_type(source_information),
+                        map_type.clone(),
+                        source_information.clone(),
+                    ),
+                    source_information.clone(),
+                ),
+                Variable::new(
+                    &self.configuration.empty_map_function_name,
+                    source_information.clone(),
+                ),
+                Variable::new("$hashKey", source_information.clone()),
+                source_information.clone(),
+            ),
+            Variable::new("$equalKeys", source_information.clone()),
+            source_information.clone(),
+        )
+        .into()
+    }
+
+    fn create_insertion(
+        &self,
+        map_type: &Type,
+        map: Expression,
+        key: Expression,
+        value: Expression,
+        source_information: &Arc<SourceInformation>,
+    ) -> Expression {
+        Application::with_type(
+            types::Function::new(
+                types::Any::new(source_information.clone()),
+                map_type.clone(),
+                source_information.clone(),
+            ),
+            Application::with_type(
+                types::Function::new(
+                    types::Any::new(source_information.clone()),
+                    types::Function::new(
+                        types::Any::new(source_information.clone()),
+                        map_type.clone(),
+                        source_information.clone(),
+                    ),
+                    source_information.clone(),
+                ),
+                Application::with_type(
+                    types::Function::new(
+                        map_type.clone(),
+                        types::Function::new(
+                            types::Any::new(source_information.clone()),
+                            types::Function::new(
+                                types::Any::new(source_information.clone()),
+                                map_type.clone(),
+                                source_information.clone(),
+                            ),
+                            source_information.clone(),
+                        ),
+                        source_information.clone(),
+                    ),
+                    Variable::new(
+                        &self.configuration.insert_function_name,
+                        source_information.clone(),
+                    ),
+                    map,
+                    source_information.clone(),
+                ),
+                key,
+                source_information.clone(),
+            ),
+            value,
+            source_information.clone(),
+        )
+        .into()
+    }
+
+    fn create_hash_function_type(
+        &self,
+        source_information: &Arc<SourceInformation>,
+    ) -> types::Function {
+        types::Function::new(
+            types::Any::new(source_information.clone()),
+            types::Number::new(source_information.clone()),
+            source_information.clone(),
+        )
+    }
+
+    fn create_equal_function_type(
+        &self,
+        source_information: &Arc<SourceInformation>,
+    ) -> types::Function {
+        types::Function::new(
+            types::Any::new(source_information.clone()),
+            types::Function::new(
+                types::Any::new(source_information.clone()),
+                types::Boolean::new(source_information.clone()),
+                source_information.clone(),
+            ),
+            source_information.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        super::super::{
+            list_type_configuration::LIST_TYPE_CONFIGURATION,
+            map_type_configuration::MAP_TYPE_CONFIGURATION,
+            reference_type_resolver::ReferenceTypeResolver,
+            type_equality_checker::TypeEqualityChecker,
+        },
+        *,
+    };
+
+    fn create_map_literal_transformer(module: &Module) -> Arc<MapLiteralTransformer> {
+        let reference_type_resolver = ReferenceTypeResolver::new(module);
+        let type_comparability_checker =
+            TypeComparabilityChecker::new(reference_type_resolver.clone());
+
+        MapLiteralTransformer::new(
+            EqualOperationTransformer::new(
+                reference_type_resolver.clone(),
+                type_comparability_checker.clone(),
+                TypeEqualityChecker::new(reference_type_resolver),
+                LIST_TYPE_CONFIGURATION.clone(),
+                MAP_TYPE_CONFIGURATION.clone(),
+            ),
+            type_comparability_checker,
+            MAP_TYPE_CONFIGURATION.clone(),
+        )
+    }
+
+    fn create_map_type(key_type: impl Into<Type>) -> Type {
+        types::Reference::with_arguments(
+            &MAP_TYPE_CONFIGURATION.map_type_name,
+            vec![
+                key_type.into(),
+                types::Number::new(SourceInformation::dummy()).into(),
+            ],
+            SourceInformation::dummy(),
+        )
+        .into()
+    }
+
+    #[test]
+    fn transform_map_with_number_keys() {
+        assert!(matches!(
+            create_map_literal_transformer(&Module::dummy()).transform(&Map::with_type(
+                create_map_type(types::Number::new(SourceInformation::dummy())),
+                vec![MapElement::new(
+                    Number::new(1.0, SourceInformation::dummy()),
+                    Number::new(2.0, SourceInformation::dummy()),
+                )],
+                SourceInformation::dummy(),
+            )),
+            Ok(Expression::Let(_))
+        ));
+    }
+
+    #[test]
+    fn transform_map_with_recursive_record_keys() {
+        let record_type = types::Record::new(
+            "Foo",
+            vec![types::RecordElement::new(
+                "foo",
+                types::Union::new(
+                    vec![
+                        types::Reference::new("Foo", SourceInformation::dummy()).into(),
+                        types::None::new(SourceInformation::dummy()).into(),
+                    ],
+                    SourceInformation::dummy(),
+                ),
+            )],
+            SourceInformation::dummy(),
+        );
+
+        assert!(matches!(
+            create_map_literal_transformer(&Module::from_definitions_and_type_definitions(
+                vec![TypeDefinition::new(
+                    "Foo",
+                    record_type.clone(),
+                    SourceInformation::dummy()
+                )],
+                vec![],
+            ))
+            .transform(&Map::with_type(
+                create_map_type(record_type),
+                vec![],
+                SourceInformation::dummy(),
+            )),
+            Ok(Expression::Let(_))
+        ));
+    }
+
+    #[test]
+    fn fail_to_transform_map_with_function_keys() {
+        assert_eq!(
+            create_map_literal_transformer(&Module::dummy()).transform(&Map::with_type(
+                create_map_type(types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )),
+                vec![],
+                SourceInformation::dummy(),
+            )),
+            Err(CompileError::MapKeyNotComparable(
+                SourceInformation::dummy().into()
+            ))
+        );
+    }
+}
@@ -0,0 +1,148 @@
+use super::super::{error::CompileError, map_type_configuration::MapTypeConfiguration};
+use crate::{
+    ast::*,
+    debug::SourceInformation,
+    types::{self, Type},
+};
+use std::sync::Arc;
+
+// Map types are represented as generic record types defined in the prelude.
+pub struct MapTypeTransformer {
+    configuration: Arc<MapTypeConfiguration>,
+}
+
+impl MapTypeTransformer {
+    pub fn new(configuration: Arc<MapTypeConfiguration>) -> Self {
+        Self { configuration }
+    }
+
+    pub fn transform(&self, module: &Module) -> Result<Module, CompileError> {
+        module
+            .transform_types(&mut |type_| -> Result<_, CompileError> {
+                Ok(match type_ {
+                    Type::Map(map) => self
+                        .create_map_type(map.key(), map.value(), map.source_information())
+                        .into(),
+                    _ => type_.clone(),
+                })
+            })?
+            .transform_expressions(&mut |expression| -> Result<_, CompileError> {
+                Ok(match expression {
+                    Expression::Map(map) => match map.type_() {
+                        Type::Unknown(_) => Map::with_type(
+                            self.create_map_type(
+                                &types::Unknown::new(map.source_information().clone()).into(),
+                                &types::Unknown::new(map.source_information().clone()).into(),
+                                map.source_information(),
+                            ),
+                            map.elements().to_vec(),
+                            map.source_information().clone(),
+                        )
+                        .into(),
+                        _ => expression.clone(),
+                    },
+                    _ => expression.clone(),
+                })
+            })
+    }
+
+    fn create_map_type(
+        &self,
+        key: &Type,
+        value: &Type,
+        source_information: &Arc<SourceInformation>,
+    ) -> types::Reference {
+        types::Reference::with_arguments(
+            &self.configuration.map_type_name,
+            vec![key.clone(), value.clone()],
+            source_information.clone(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::super::map_type_configuration::MAP_TYPE_CONFIGURATION, *};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn transform_map_types() {
+        let create_module = |type_: Type| {
+            Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                Variable::new("y", SourceInformation::dummy()),
+                type_,
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert_eq!(
+            MapTypeTransformer::new(MAP_TYPE_CONFIGURATION.clone()).transform(&create_module(
+                types::Map::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Map::new(
+                        types::EinString::new(SourceInformation::dummy()),
+                        types::None::new(SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            )),
+            Ok(create_module(
+                types::Reference::with_arguments(
+                    "GenericMap",
+                    vec![
+                        types::Number::new(SourceInformation::dummy()).into(),
+                        types::Reference::with_arguments(
+                            "GenericMap",
+                            vec![
+                                types::EinString::new(SourceInformation::dummy()).into(),
+                                types::None::new(SourceInformation::dummy()).into()
+                            ],
+                            SourceInformation::dummy()
+                        )
+                        .into()
+                    ],
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn transform_map_literals() {
+        let create_module = |expression: Expression| {
+            Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                expression,
+                types::Unknown::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])
+        };
+
+        assert_eq!(
+            MapTypeTransformer::new(MAP_TYPE_CONFIGURATION.clone()).transform(&create_module(
+                Map::new(vec![], SourceInformation::dummy()).into()
+            )),
+            Ok(create_module(
+                Map::with_type(
+                    types::Reference::with_arguments(
+                        "GenericMap",
+                        vec![
+                            types::Unknown::new(SourceInformation::dummy()).into(),
+                            types::Unknown::new(SourceInformation::dummy()).into()
+                        ],
+                        SourceInformation::dummy()
+                    ),
+                    vec![],
+                    SourceInformation::dummy()
+                )
+                .into()
+            ))
+        );
+    }
+}
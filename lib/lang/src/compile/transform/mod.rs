@@ -1,14 +1,20 @@
+mod array_literal_transformer;
+mod array_type_transformer;
 mod boolean_operation_transformer;
 mod elementless_record_transformer;
 mod equal_operation_transformer;
 mod function_type_coercion_transformer;
+mod lambda_transformer;
 mod let_error_transformer;
 mod list_case_transformer;
 mod list_literal_transformer;
 mod list_type_coercion_transformer;
+mod map_literal_transformer;
+mod map_type_transformer;
 mod not_equal_operation_transformer;
 mod record_element_function_transformer;
 mod record_equal_function_transformer;
+mod record_hash_function_transformer;
 mod record_update_transformer;
 mod type_coercion_transformer;
 mod typed_meta_transformer;
@@ -24,17 +30,23 @@ use super::{
     type_equality_checker::TypeEqualityChecker,
 };
 use crate::ast::*;
+pub use array_literal_transformer::ArrayLiteralTransformer;
+use array_type_transformer::ArrayTypeTransformer;
 pub use boolean_operation_transformer::BooleanOperationTransformer;
 use elementless_record_transformer::ElementlessRecordTransformer;
 pub use equal_operation_transformer::EqualOperationTransformer;
 pub use function_type_coercion_transformer::FunctionTypeCoercionTransformer;
+pub use lambda_transformer::LambdaTransformer;
 pub use let_error_transformer::LetErrorTransformer;
 pub use list_case_transformer::ListCaseTransformer;
 pub use list_literal_transformer::ListLiteralTransformer;
 pub use list_type_coercion_transformer::ListTypeCoercionTransformer;
+pub use map_literal_transformer::MapLiteralTransformer;
+use map_type_transformer::MapTypeTransformer;
 pub use not_equal_operation_transformer::NotEqualOperationTransformer;
 use record_element_function_transformer::RecordElementFunctionTransformer;
 use record_equal_function_transformer::RecordEqualFunctionTransformer;
+use record_hash_function_transformer::RecordHashFunctionTransformer;
 use record_update_transformer::RecordUpdateTransformer;
 use std::sync::Arc;
 use type_coercion_transformer::TypeCoercionTransformer;
@@ -52,8 +64,16 @@ pub fn transform_before_name_qualification(module: &Module) -> Result<Module, Co
     Ok(module)
 }
 
-pub fn transform_without_types(module: &Module) -> Result<Module, CompileError> {
-    RecordUpdateTransformer::new().transform(module)
+pub fn transform_without_types(
+    module: &Module,
+    compile_configuration: Arc<CompileConfiguration>,
+) -> Result<Module, CompileError> {
+    let module = ArrayTypeTransformer::new(compile_configuration.array_type_configuration.clone())
+        .transform(module)?;
+    let module = MapTypeTransformer::new(compile_configuration.map_type_configuration.clone())
+        .transform(&module)?;
+
+    RecordUpdateTransformer::new().transform(&module)
 }
 
 pub fn transform_with_types(
@@ -90,6 +110,28 @@ pub fn transform_with_types(
     type_coercion_transformer.transform(module)
 }
 
+// Hash functions of records are generated after type inference as they apply
+// list functions in prelude modules to built-in list types.
+pub fn transform_with_checked_types(
+    module: &Module,
+    compile_configuration: Arc<CompileConfiguration>,
+) -> Result<Module, CompileError> {
+    let reference_type_resolver = ReferenceTypeResolver::new(module);
+    let type_comparability_checker = TypeComparabilityChecker::new(reference_type_resolver.clone());
+
+    RecordHashFunctionTransformer::new(
+        type_comparability_checker.clone(),
+        EqualOperationTransformer::new(
+            reference_type_resolver.clone(),
+            type_comparability_checker,
+            TypeEqualityChecker::new(reference_type_resolver),
+            compile_configuration.list_type_configuration.clone(),
+            compile_configuration.map_type_configuration.clone(),
+        ),
+    )
+    .transform(module)
+}
+
 #[cfg(test)]
 mod tests {
     use super::{super::compile_configuration::COMPILE_CONFIGURATION, *};
@@ -407,7 +449,8 @@ mod tests {
                             "Foo",
                             vec![types::RecordElement::new("foo", union_type)],
                             SourceInformation::dummy()
-                        )
+                        ),
+                        SourceInformation::dummy()
                     )],
                     vec![VariableDefinition::new(
                         "x",
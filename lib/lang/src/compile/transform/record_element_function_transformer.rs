@@ -28,6 +28,8 @@ impl RecordElementFunctionTransformer {
                         .iter()
                         .map(|type_definition| {
                             if let Type::Record(record_type) = type_definition.type_() {
+                                let type_ = Self::create_record_type(type_definition, record_type);
+
                                 record_type
                                     .elements()
                                     .iter()
@@ -42,13 +44,13 @@ impl RecordElementFunctionTransformer {
                                             ),
                                             vec!["record".into()],
                                             RecordElementOperation::new(
-                                                type_definition.type_().clone(),
+                                                type_.clone(),
                                                 element.name(),
                                                 Variable::new("record", source_information.clone()),
                                                 source_information.clone(),
                                             ),
                                             types::Function::new(
-                                                type_definition.type_().clone(),
+                                                type_.clone(),
                                                 element.type_().clone(),
                                                 source_information.clone(),
                                             ),
@@ -66,6 +68,25 @@ impl RecordElementFunctionTransformer {
                 .collect(),
         )
     }
+
+    fn create_record_type(type_definition: &TypeDefinition, record_type: &types::Record) -> Type {
+        if type_definition.type_parameters().is_empty() {
+            record_type.clone().into()
+        } else {
+            let source_information = record_type.source_information();
+
+            types::Reference::with_arguments(
+                type_definition.name(),
+                type_definition
+                    .type_parameters()
+                    .iter()
+                    .map(|name| types::Parameter::new(name, source_information.clone()).into())
+                    .collect(),
+                source_information.clone(),
+            )
+            .into()
+        }
+    }
 }
 
 #[cfg(test)]
@@ -88,12 +109,20 @@ mod tests {
         assert_eq!(
             RecordElementFunctionTransformer::new().transform(
                 &Module::from_definitions_and_type_definitions(
-                    vec![TypeDefinition::new("Foo", record_type.clone())],
+                    vec![TypeDefinition::new(
+                        "Foo",
+                        record_type.clone(),
+                        SourceInformation::dummy()
+                    )],
                     vec![]
                 )
             ),
             Module::from_definitions_and_type_definitions(
-                vec![TypeDefinition::new("Foo", record_type.clone())],
+                vec![TypeDefinition::new(
+                    "Foo",
+                    record_type.clone(),
+                    SourceInformation::dummy()
+                )],
                 vec![FunctionDefinition::new(
                     "Foo.foo",
                     vec!["record".into()],
@@ -114,4 +143,60 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn transform_record_element_functions_with_type_parameters() {
+        let record_type = types::Record::new(
+            "Foo",
+            vec![types::RecordElement::new(
+                "foo",
+                types::Parameter::new("a", SourceInformation::dummy()),
+            )],
+            SourceInformation::dummy(),
+        );
+        let reference_type = types::Reference::with_arguments(
+            "Foo",
+            vec![types::Parameter::new("a", SourceInformation::dummy()).into()],
+            SourceInformation::dummy(),
+        );
+
+        assert_eq!(
+            RecordElementFunctionTransformer::new().transform(
+                &Module::from_definitions_and_type_definitions(
+                    vec![TypeDefinition::with_type_parameters(
+                        "Foo",
+                        vec!["a".into()],
+                        record_type.clone(),
+                        SourceInformation::dummy()
+                    )],
+                    vec![]
+                )
+            ),
+            Module::from_definitions_and_type_definitions(
+                vec![TypeDefinition::with_type_parameters(
+                    "Foo",
+                    vec!["a".into()],
+                    record_type,
+                    SourceInformation::dummy()
+                )],
+                vec![FunctionDefinition::new(
+                    "Foo.foo",
+                    vec!["record".into()],
+                    RecordElementOperation::new(
+                        reference_type.clone(),
+                        "foo",
+                        Variable::new("record", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Function::new(
+                        reference_type,
+                        types::Parameter::new("a", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into()]
+            )
+        );
+    }
 }
@@ -0,0 +1,95 @@
+use super::{
+    super::{error::CompileError, type_comparability_checker::TypeComparabilityChecker},
+    equal_operation_transformer::EqualOperationTransformer,
+    utilities,
+};
+use crate::{
+    ast::*,
+    types::{self, Type},
+};
+use std::sync::Arc;
+
+pub struct RecordHashFunctionTransformer {
+    type_comparability_checker: Arc<TypeComparabilityChecker>,
+    equal_operation_transformer: Arc<EqualOperationTransformer>,
+}
+
+impl RecordHashFunctionTransformer {
+    pub fn new(
+        type_comparability_checker: Arc<TypeComparabilityChecker>,
+        equal_operation_transformer: Arc<EqualOperationTransformer>,
+    ) -> Self {
+        Self {
+            type_comparability_checker,
+            equal_operation_transformer,
+        }
+    }
+
+    pub fn transform(&self, module: &Module) -> Result<Module, CompileError> {
+        let mut hash_function_definitions = vec![];
+
+        for type_definition in module.type_definitions() {
+            if let Type::Record(record_type) = type_definition.type_() {
+                if self
+                    .type_comparability_checker
+                    .check(type_definition.type_())?
+                {
+                    hash_function_definitions.push(self.create_record_hash_function(record_type)?);
+                }
+            }
+        }
+
+        Ok(Module::new(
+            module.path().clone(),
+            module.export().clone(),
+            module.export_foreign().clone(),
+            module.imports().to_vec(),
+            module.import_foreigns().to_vec(),
+            module.type_definitions().to_vec(),
+            module
+                .definitions()
+                .iter()
+                .cloned()
+                .chain(hash_function_definitions.into_iter().map(Definition::from))
+                .collect(),
+        ))
+    }
+
+    fn create_record_hash_function(
+        &self,
+        record_type: &types::Record,
+    ) -> Result<FunctionDefinition, CompileError> {
+        let source_information = record_type.source_information();
+        let mut expression: Expression = Number::new(0.0, source_information.clone()).into();
+
+        for element in record_type.elements() {
+            expression = self.equal_operation_transformer.create_combined_hash(
+                expression,
+                self.equal_operation_transformer.transform_hash(
+                    element.type_(),
+                    &RecordElementOperation::new(
+                        record_type.clone(),
+                        element.name(),
+                        Variable::new("record", source_information.clone()),
+                        source_information.clone(),
+                    )
+                    .into(),
+                    source_information.clone(),
+                )?,
+                source_information.clone(),
+            );
+        }
+
+        Ok(FunctionDefinition::new(
+            utilities::get_record_hash_function_name(record_type),
+            vec!["record".into()],
+            expression,
+            types::Function::new(
+                record_type.clone(),
+                types::Number::new(source_information.clone()),
+                source_information.clone(),
+            ),
+            source_information.clone(),
+        ))
+    }
+}
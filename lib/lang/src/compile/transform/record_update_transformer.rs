@@ -2,7 +2,10 @@ use super::super::{
     error::CompileError, name_generator::NameGenerator,
     reference_type_resolver::ReferenceTypeResolver,
 };
-use crate::ast::*;
+use crate::{
+    ast::*,
+    types::{self, Type},
+};
 
 pub struct RecordUpdateTransformer {
     name_generator: NameGenerator,
@@ -20,9 +23,9 @@ impl RecordUpdateTransformer {
 
         module.transform_expressions(&mut |expression| -> Result<Expression, CompileError> {
             if let Expression::RecordUpdate(record_update) = expression {
-                let record_type = reference_type_resolver
-                    .resolve_to_record(record_update.type_())?
-                    .unwrap();
+                let type_ =
+                    Self::instantiate_type(record_update.type_(), &reference_type_resolver)?;
+                let record_type = reference_type_resolver.resolve_to_record(&type_)?.unwrap();
                 let source_information = record_update.source_information();
                 let name = self.name_generator.generate();
 
@@ -30,7 +33,7 @@ impl RecordUpdateTransformer {
                     vec![VariableDefinition::new(
                         &name,
                         record_update.argument().clone(),
-                        record_update.type_().clone(),
+                        type_,
                         source_information.clone(),
                     )
                     .into()],
@@ -63,6 +66,28 @@ impl RecordUpdateTransformer {
             }
         })
     }
+
+    // Type arguments of generic record types are inferred later.
+    fn instantiate_type(
+        type_: &Type,
+        reference_type_resolver: &ReferenceTypeResolver,
+    ) -> Result<Type, CompileError> {
+        Ok(match type_ {
+            Type::Reference(reference) if reference.arguments().is_empty() => {
+                types::Reference::with_arguments(
+                    reference.name(),
+                    reference_type_resolver
+                        .resolve_type_parameters(reference)?
+                        .iter()
+                        .map(|_| types::Unknown::new(reference.source_information().clone()).into())
+                        .collect(),
+                    reference.source_information().clone(),
+                )
+                .into()
+            }
+            _ => type_.clone(),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -86,7 +111,11 @@ mod tests {
         assert_eq!(
             RecordUpdateTransformer::new().transform(
                 &Module::from_definitions_and_type_definitions(
-                    vec![TypeDefinition::new("Foo", record_type.clone())],
+                    vec![TypeDefinition::new(
+                        "Foo",
+                        record_type.clone(),
+                        SourceInformation::dummy()
+                    )],
                     vec![VariableDefinition::new(
                         "x",
                         RecordUpdate::new(
@@ -104,7 +133,11 @@ mod tests {
                 )
             ),
             Ok(Module::from_definitions_and_type_definitions(
-                vec![TypeDefinition::new("Foo", record_type)],
+                vec![TypeDefinition::new(
+                    "Foo",
+                    record_type,
+                    SourceInformation::dummy()
+                )],
                 vec![VariableDefinition::new(
                     "x",
                     Let::new(
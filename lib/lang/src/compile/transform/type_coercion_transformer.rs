@@ -147,6 +147,27 @@ impl TypedTransformer for TypeCoercionTransformer {
                 )
                 .into()
             }
+            Expression::Array(array) => {
+                let element_type = utilities::get_array_element_type(array.type_());
+
+                Array::with_type(
+                    array.type_().clone(),
+                    array
+                        .elements()
+                        .iter()
+                        .map(|element| {
+                            self.coerce_type(
+                                element,
+                                element_type,
+                                element.source_information().clone(),
+                                variables,
+                            )
+                        })
+                        .collect::<Result<Vec<_>, CompileError>>()?,
+                    array.source_information().clone(),
+                )
+                .into()
+            }
             Expression::Case(case) => {
                 let result_type = self
                     .expression_type_extractor
@@ -183,6 +204,33 @@ impl TypedTransformer for TypeCoercionTransformer {
                 )
                 .into()
             }
+            Expression::Lambda(lambda) => {
+                let mut variables = variables.clone();
+
+                for (name, type_) in lambda.arguments().iter().zip(
+                    self.reference_type_resolver
+                        .resolve_to_function(lambda.type_())?
+                        .unwrap()
+                        .arguments(),
+                ) {
+                    variables.insert(name.into(), type_.clone());
+                }
+
+                Lambda::new(
+                    lambda.arguments().to_vec(),
+                    self.coerce_type(
+                        lambda.body(),
+                        &self
+                            .last_result_type_calculator
+                            .calculate(lambda.type_(), lambda.arguments().len())?,
+                        lambda.source_information().clone(),
+                        &variables,
+                    )?,
+                    lambda.type_().clone(),
+                    lambda.source_information().clone(),
+                )
+                .into()
+            }
             Expression::If(if_) => {
                 let result_type = self
                     .expression_type_extractor
@@ -312,6 +360,34 @@ impl TypedTransformer for TypeCoercionTransformer {
                 )
                 .into()
             }
+            Expression::Map(map) => {
+                let (key_type, value_type) = utilities::get_map_element_types(map.type_());
+
+                Map::with_type(
+                    map.type_().clone(),
+                    map.elements()
+                        .iter()
+                        .map(|element| {
+                            Ok(MapElement::new(
+                                self.coerce_type(
+                                    element.key(),
+                                    key_type,
+                                    element.key().source_information().clone(),
+                                    variables,
+                                )?,
+                                self.coerce_type(
+                                    element.value(),
+                                    value_type,
+                                    element.value().source_information().clone(),
+                                    variables,
+                                )?,
+                            ))
+                        })
+                        .collect::<Result<Vec<_>, CompileError>>()?,
+                    map.source_information().clone(),
+                )
+                .into()
+            }
             Expression::Operation(operation) => match operation {
                 Operation::Equality(operation) => {
                     let argument_type = self.type_canonicalizer.canonicalize(
@@ -384,8 +460,9 @@ impl TypedTransformer for TypeCoercionTransformer {
             | Expression::Number(_)
             | Expression::RecordElementOperation(_)
             | Expression::String(_)
+            | Expression::TypeCoercion(_)
             | Expression::Variable(_) => expression.clone(),
-            Expression::RecordUpdate(_) | Expression::TypeCoercion(_) => unreachable!(),
+            Expression::RecordUpdate(_) => unreachable!(),
         })
     }
 }
@@ -132,6 +132,16 @@ impl<D: TypedTransformer> TypedMetaTransformer<D> {
                 application.source_information().clone(),
             )
             .into(),
+            Expression::Array(array) => Array::with_type(
+                array.type_().clone(),
+                array
+                    .elements()
+                    .iter()
+                    .map(|element| self.transform_expression(element, variables))
+                    .collect::<Result<Vec<_>, CompileError>>()?,
+                array.source_information().clone(),
+            )
+            .into(),
             Expression::Case(case) => Case::with_type(
                 case.type_().clone(),
                 case.name(),
@@ -159,6 +169,26 @@ impl<D: TypedTransformer> TypedMetaTransformer<D> {
                 if_.source_information().clone(),
             )
             .into(),
+            Expression::Lambda(lambda) => {
+                let mut variables = variables.clone();
+
+                for (name, type_) in lambda.arguments().iter().zip(
+                    self.reference_type_resolver
+                        .resolve_to_function(lambda.type_())?
+                        .unwrap()
+                        .arguments(),
+                ) {
+                    variables.insert(name.into(), type_.clone());
+                }
+
+                Lambda::new(
+                    lambda.arguments().to_vec(),
+                    self.transform_expression(lambda.body(), &variables)?,
+                    lambda.type_().clone(),
+                    lambda.source_information().clone(),
+                )
+                .into()
+            }
             Expression::Let(let_) => {
                 let mut variables = variables.clone();
                 let mut definitions = vec![];
@@ -256,6 +286,20 @@ impl<D: TypedTransformer> TypedMetaTransformer<D> {
                 case.source_information().clone(),
             )
             .into(),
+            Expression::Map(map) => Map::with_type(
+                map.type_().clone(),
+                map.elements()
+                    .iter()
+                    .map(|element| {
+                        Ok(MapElement::new(
+                            self.transform_expression(element.key(), variables)?,
+                            self.transform_expression(element.value(), variables)?,
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, CompileError>>()?,
+                map.source_information().clone(),
+            )
+            .into(),
             Expression::Operation(operation) => match operation {
                 Operation::Arithmetic(operation) => ArithmeticOperation::new(
                     operation.operator(),
@@ -316,12 +360,19 @@ impl<D: TypedTransformer> TypedMetaTransformer<D> {
                 operation.source_information().clone(),
             )
             .into(),
+            Expression::TypeCoercion(coercion) => TypeCoercion::new(
+                self.transform_expression(coercion.argument(), variables)?,
+                coercion.from().clone(),
+                coercion.to().clone(),
+                coercion.source_information().clone(),
+            )
+            .into(),
             Expression::Boolean(_)
             | Expression::None(_)
             | Expression::Number(_)
             | Expression::String(_)
             | Expression::Variable(_) => expression.clone(),
-            Expression::RecordUpdate(_) | Expression::TypeCoercion(_) => unreachable!(),
+            Expression::RecordUpdate(_) => unreachable!(),
         };
 
         self.component_transformer
@@ -3,3 +3,7 @@ use crate::types;
 pub fn get_record_equal_function_name(record_type: &types::Record) -> String {
     format!("{}.$equal", record_type.name())
 }
+
+pub fn get_record_hash_function_name(record_type: &types::Record) -> String {
+    format!("{}.$hash", record_type.name())
+}
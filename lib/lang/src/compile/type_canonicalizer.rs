@@ -309,6 +309,7 @@ mod tests {
                 vec![TypeDefinition::new(
                     "Foo",
                     types::Boolean::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
                 )],
                 vec![],
             ));
@@ -344,6 +345,7 @@ mod tests {
                 vec![TypeDefinition::new(
                     "Foo",
                     types::Boolean::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
                 )],
                 vec![],
             ));
@@ -30,6 +30,7 @@ impl TypeComparabilityChecker {
             Type::List(list) => self.check_with_cache(list.element(), record_names)?,
             Type::None(_) => true,
             Type::Number(_) => true,
+            Type::Parameter(_) => false,
             Type::Record(record) => {
                 if record_names.contains(record.name()) {
                     true
@@ -38,7 +39,8 @@ impl TypeComparabilityChecker {
 
                     record_names.insert(record.name().into());
 
-                    record
+                    self.reference_type_resolver
+                        .resolve_record_definition(record)?
                         .elements()
                         .iter()
                         .map(|element| self.check_with_cache(element.type_(), &record_names))
@@ -59,7 +61,7 @@ impl TypeComparabilityChecker {
                 .collect::<Result<Vec<_>, _>>()?
                 .into_iter()
                 .all(|flag| flag),
-            Type::Unknown(_) | Type::Variable(_) => unreachable!(),
+            Type::Array(_) | Type::Map(_) | Type::Unknown(_) | Type::Variable(_) => unreachable!(),
         })
     }
 }
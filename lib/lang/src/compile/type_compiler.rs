@@ -40,11 +40,12 @@ impl TypeCompiler {
             Type::List(_) => self.compile_any_list().into(),
             Type::None(_) => self.compile_none().into(),
             Type::Number(_) => eir::types::Type::Number,
+            Type::Parameter(_) => self.compile_any(),
             Type::Record(record) => eir::types::Record::new(record.name()).into(),
             Type::Reference(reference) => self.compile_reference(reference)?,
             Type::String(_) => self.compile_string(),
             Type::Union(_) => self.compile_union(),
-            Type::Unknown(_) | Type::Variable(_) => unreachable!(),
+            Type::Array(_) | Type::Map(_) | Type::Unknown(_) | Type::Variable(_) => unreachable!(),
         })
     }
 
@@ -78,7 +79,8 @@ impl TypeCompiler {
         record: &types::Record,
     ) -> Result<eir::types::RecordBody, CompileError> {
         Ok(eir::types::RecordBody::new(
-            record
+            self.reference_type_resolver
+                .resolve_record_definition(record)?
                 .elements()
                 .iter()
                 .map(|element| self.compile(element.type_()))
@@ -162,6 +164,7 @@ mod tests {
                 vec![TypeDefinition::new(
                     "Foo",
                     types::Record::new("Bar", Default::default(), SourceInformation::dummy()),
+                    SourceInformation::dummy(),
                 )],
                 vec![],
             ));
@@ -214,6 +217,7 @@ mod tests {
                         Default::default(),
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
                 )],
                 vec![],
             ));
@@ -90,8 +90,15 @@ impl TypeDefinitionCompiler {
             | Type::Function(_)
             | Type::None(_)
             | Type::Number(_)
+            | Type::Parameter(_)
             | Type::String(_) => vec![],
-            Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => unreachable!(),
+            Type::Array(_)
+            | Type::Map(_)
+            | Type::Reference(_)
+            | Type::Unknown(_)
+            | Type::Variable(_) => {
+                unreachable!()
+            }
         })
     }
 }
@@ -19,7 +19,16 @@ impl TypeEqualityChecker {
         let other = self.reference_type_resolver.resolve(other)?;
 
         let value = match (&one, &other) {
-            (Type::Record(one), Type::Record(other)) => one.name() == other.name(),
+            (Type::Record(one), Type::Record(other)) => {
+                one.name() == other.name()
+                    && (one.elements() == other.elements()
+                        || Type::from(
+                            self.reference_type_resolver
+                                .resolve_record_definition(one)?,
+                        )
+                        .parameters()
+                        .is_empty())
+            }
             (Type::Union(one), Type::Union(other)) => {
                 one.types().len() == other.types().len()
                     && one
@@ -49,7 +58,11 @@ mod tests {
         let reference_type = types::Reference::new("Foo", SourceInformation::dummy());
 
         let module = Module::with_definitions_and_type_definitions(
-            vec![TypeDefinition::new("Foo", record_type.clone())],
+            vec![TypeDefinition::new(
+                "Foo",
+                record_type.clone(),
+                SourceInformation::dummy(),
+            )],
             vec![],
         );
         let reference_type_resolver = ReferenceTypeResolver::new(&module);
@@ -29,7 +29,8 @@ impl TypeIdCalculator {
 
     fn calculate_type_id(&self, type_: &Type) -> Result<String, CompileError> {
         Ok(match self.reference_type_resolver.resolve(type_)? {
-            Type::Any(_) => "Any".into(),
+            // Type parameters are represented in the same way as Any.
+            Type::Any(_) | Type::Parameter(_) => "Any".into(),
             Type::Boolean(_) => "Boolean".into(),
             Type::Function(function) => format!(
                 "({}->{})",
@@ -50,7 +51,13 @@ impl TypeIdCalculator {
                     .collect::<Result<Vec<_>, _>>()?
                     .join("|")
             ),
-            Type::Reference(_) | Type::Unknown(_) | Type::Variable(_) => unreachable!(),
+            Type::Array(_)
+            | Type::Map(_)
+            | Type::Reference(_)
+            | Type::Unknown(_)
+            | Type::Variable(_) => {
+                unreachable!()
+            }
         })
     }
 }
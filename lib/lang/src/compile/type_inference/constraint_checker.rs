@@ -6,7 +6,7 @@ use super::{
     subsumption_set::SubsumptionSet,
     variable_substitutor::VariableSubstitutor,
 };
-use crate::types::Type;
+use crate::types::{self, Type};
 use std::sync::Arc;
 
 pub struct ConstraintChecker {
@@ -30,11 +30,37 @@ impl ConstraintChecker {
 
     pub fn check(&self, mut subsumption_set: SubsumptionSet) -> Result<(), CompileError> {
         while let Some((lower, upper)) = subsumption_set.remove() {
+            let union_type = subsumption_set.get_union_type(&lower, &upper).cloned();
+
             match (
                 self.variable_substitutor.substitute(&lower)?,
                 self.variable_substitutor.substitute(&upper)?,
             ) {
                 (_, Type::Any(_)) => {}
+                (Type::Reference(one), Type::Reference(other)) if one.name() == other.name() => {
+                    if self
+                        .reference_type_resolver
+                        .resolve_to_record(&one.clone().into())?
+                        .is_some()
+                    {
+                        for (one, other) in self
+                            .reference_type_resolver
+                            .resolve_type_arguments(&one)?
+                            .into_iter()
+                            .zip(
+                                self.reference_type_resolver
+                                    .resolve_type_arguments(&other)?,
+                            )
+                        {
+                            subsumption_set.add(one, other);
+                        }
+                    } else {
+                        subsumption_set.add(
+                            self.reference_type_resolver.resolve_reference(&one)?,
+                            other.into(),
+                        );
+                    }
+                }
                 (Type::Reference(reference), upper) => subsumption_set.add(
                     self.reference_type_resolver.resolve_reference(&reference)?,
                     upper.clone(),
@@ -52,7 +78,7 @@ impl ConstraintChecker {
                 }
                 (Type::Union(one), other) => {
                     for type_ in one.types() {
-                        subsumption_set.add(type_.clone(), other.clone());
+                        subsumption_set.add_union_member(type_, &other, &one);
                     }
                 }
                 (lower, Type::Union(union)) => {
@@ -64,33 +90,55 @@ impl ConstraintChecker {
                         .into_iter()
                         .any(|value| value)
                     {
-                        return Err(CompileError::TypesNotMatched(
-                            lower.source_information().clone(),
-                            union.source_information().clone(),
-                        ));
+                        return Err(self.create_types_not_matched_error(
+                            lower,
+                            union.into(),
+                            union_type,
+                        )?);
                     }
                 }
                 (Type::Boolean(_), Type::Boolean(_)) => {}
                 (Type::None(_), Type::None(_)) => {}
                 (Type::Number(_), Type::Number(_)) => {}
                 (Type::String(_), Type::String(_)) => {}
+                (Type::Parameter(one), Type::Parameter(other)) if one.name() == other.name() => {}
                 (Type::Record(one), Type::Record(other)) => {
                     if one.name() != other.name() {
-                        return Err(CompileError::TypesNotMatched(
-                            one.source_information().clone(),
-                            other.source_information().clone(),
-                        ));
+                        return Err(self.create_types_not_matched_error(
+                            one.into(),
+                            other.into(),
+                            union_type,
+                        )?);
                     }
                 }
                 (one, other) => {
-                    return Err(CompileError::TypesNotMatched(
-                        one.source_information().clone(),
-                        other.source_information().clone(),
-                    ));
+                    return Err(self.create_types_not_matched_error(one, other, union_type)?);
                 }
             }
         }
 
         Ok(())
     }
+    fn create_types_not_matched_error(
+        &self,
+        lower_type: Type,
+        upper_type: Type,
+        union_type: Option<types::Union>,
+    ) -> Result<CompileError, CompileError> {
+        // Union types are recorded before variable substitution.
+        let union_type = if let Some(union_type) = union_type {
+            match self.variable_substitutor.substitute(&union_type.into())? {
+                Type::Union(union_type) => Some(union_type),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        Ok(CompileError::TypesNotMatched {
+            lower_type,
+            upper_type,
+            union_type,
+        })
+    }
 }
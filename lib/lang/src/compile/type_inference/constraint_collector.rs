@@ -1,20 +1,20 @@
 use super::{
     super::{
         error::CompileError, error_type_configuration::ErrorTypeConfiguration,
-        module_environment_creator::ModuleEnvironmentCreator,
         reference_type_resolver::ReferenceTypeResolver, utilities,
     },
     subsumption_set::SubsumptionSet,
 };
 use crate::{
     ast::*,
+    debug::SourceInformation,
     types::{self, Type},
 };
 use std::{collections::HashMap, sync::Arc};
 
+#[derive(Clone)]
 pub struct ConstraintCollector {
     reference_type_resolver: Arc<ReferenceTypeResolver>,
-    module_environment_creator: Arc<ModuleEnvironmentCreator>,
     solved_subsumption_set: SubsumptionSet,
     checked_subsumption_set: SubsumptionSet,
     error_type_configuration: Arc<ErrorTypeConfiguration>,
@@ -23,12 +23,10 @@ pub struct ConstraintCollector {
 impl ConstraintCollector {
     pub fn new(
         reference_type_resolver: Arc<ReferenceTypeResolver>,
-        module_environment_creator: Arc<ModuleEnvironmentCreator>,
         error_type_configuration: Arc<ErrorTypeConfiguration>,
     ) -> Self {
         Self {
             reference_type_resolver,
-            module_environment_creator,
             solved_subsumption_set: SubsumptionSet::new(),
             checked_subsumption_set: SubsumptionSet::new(),
             error_type_configuration,
@@ -37,20 +35,17 @@ impl ConstraintCollector {
 
     pub fn collect(
         mut self,
-        module: &Module,
+        definition: &Definition,
+        variables: &HashMap<String, Type>,
     ) -> Result<(SubsumptionSet, SubsumptionSet), CompileError> {
-        let variables = self.module_environment_creator.create(module);
-
-        for definition in module.definitions() {
-            match definition {
-                Definition::FunctionDefinition(function_definition) => {
-                    self.infer_function_definition(function_definition, &variables)?;
-                }
-                Definition::VariableDefinition(variable_definition) => {
-                    self.infer_variable_definition(variable_definition, &variables)?;
-                }
-            };
-        }
+        match definition {
+            Definition::FunctionDefinition(function_definition) => {
+                self.infer_function_definition(function_definition, variables)?;
+            }
+            Definition::VariableDefinition(variable_definition) => {
+                self.infer_variable_definition(variable_definition, variables)?;
+            }
+        };
 
         Ok((self.solved_subsumption_set, self.checked_subsumption_set))
     }
@@ -60,11 +55,27 @@ impl ConstraintCollector {
         function_definition: &FunctionDefinition,
         variables: &HashMap<String, Type>,
     ) -> Result<(), CompileError> {
-        let source_information = function_definition.source_information();
+        self.infer_function(
+            function_definition.arguments(),
+            function_definition.body(),
+            function_definition.type_(),
+            function_definition.source_information(),
+            variables,
+        )
+    }
+
+    fn infer_function(
+        &mut self,
+        arguments: &[String],
+        body: &Expression,
+        type_: &Type,
+        source_information: &Arc<SourceInformation>,
+        variables: &HashMap<String, Type>,
+    ) -> Result<(), CompileError> {
         let mut variables = variables.clone();
-        let mut type_ = function_definition.type_().clone();
+        let mut type_ = type_.clone();
 
-        for argument_name in function_definition.arguments() {
+        for argument_name in arguments {
             let argument_type: Type = types::Variable::new(source_information.clone()).into();
             let result_type: Type = types::Variable::new(source_information.clone()).into();
 
@@ -82,7 +93,7 @@ impl ConstraintCollector {
             type_ = result_type;
         }
 
-        let body_type = self.infer_expression(function_definition.body(), &variables)?;
+        let body_type = self.infer_expression(body, &variables)?;
         self.solved_subsumption_set.add(body_type, type_);
 
         Ok(())
@@ -125,13 +136,25 @@ impl ConstraintCollector {
 
                 Ok(result.into())
             }
+            Expression::Array(array) => {
+                let element_type = utilities::get_array_element_type(array.type_());
+
+                for element in array.elements() {
+                    let type_ = self.infer_expression(element, variables)?;
+                    self.solved_subsumption_set.add(type_, element_type.clone());
+                }
+
+                Ok(array.type_().clone())
+            }
             Expression::Boolean(boolean) => {
                 Ok(types::Boolean::new(boolean.source_information().clone()).into())
             }
             Expression::Case(case) => {
                 let argument = self.infer_expression(case.argument(), variables)?;
 
-                self.checked_subsumption_set
+                // Alternatives are not required to cover argument types here so
+                // that missing ones are reported by exhaustiveness checks.
+                self.solved_subsumption_set
                     .add(argument.clone(), case.type_().clone());
                 self.checked_subsumption_set
                     .add(case.type_().clone(), argument);
@@ -168,6 +191,17 @@ impl ConstraintCollector {
 
                 Ok(result.into())
             }
+            Expression::Lambda(lambda) => {
+                self.infer_function(
+                    lambda.arguments(),
+                    lambda.body(),
+                    lambda.type_(),
+                    lambda.source_information(),
+                    variables,
+                )?;
+
+                Ok(lambda.type_().clone())
+            }
             Expression::Let(let_) => {
                 let mut variables = variables.clone();
 
@@ -272,6 +306,19 @@ impl ConstraintCollector {
 
                 Ok(result.into())
             }
+            Expression::Map(map) => {
+                let (key_type, value_type) = utilities::get_map_element_types(map.type_());
+
+                for element in map.elements() {
+                    let type_ = self.infer_expression(element.key(), variables)?;
+                    self.solved_subsumption_set.add(type_, key_type.clone());
+
+                    let type_ = self.infer_expression(element.value(), variables)?;
+                    self.solved_subsumption_set.add(type_, value_type.clone());
+                }
+
+                Ok(map.type_().clone())
+            }
             Expression::None(none) => {
                 Ok(types::None::new(none.source_information().clone()).into())
             }
@@ -335,18 +382,13 @@ impl ConstraintCollector {
                 let record_type = self
                     .reference_type_resolver
                     .resolve_to_record(construction.type_())?
-                    .ok_or_else(|| {
-                        CompileError::TypesNotMatched(
-                            construction.source_information().clone(),
-                            construction.type_().source_information().clone(),
-                        )
-                    })?;
+                    .ok_or_else(|| CompileError::RecordExpected(construction.type_().clone()))?;
 
                 if construction.elements().len() != record_type.elements().len() {
-                    return Err(CompileError::TypesNotMatched(
-                        construction.source_information().clone(),
-                        record_type.source_information().clone(),
-                    ));
+                    return Err(CompileError::RecordElementsNotMatched {
+                        record_type,
+                        source_information: construction.source_information().clone(),
+                    });
                 }
 
                 for (key, expression) in construction.elements() {
@@ -364,12 +406,7 @@ impl ConstraintCollector {
                 let record_type = self
                     .reference_type_resolver
                     .resolve_to_record(operation.type_())?
-                    .ok_or_else(|| {
-                        CompileError::TypesNotMatched(
-                            operation.source_information().clone(),
-                            operation.type_().source_information().clone(),
-                        )
-                    })?;
+                    .ok_or_else(|| CompileError::RecordExpected(operation.type_().clone()))?;
 
                 let argument = self.infer_expression(operation.argument(), variables)?;
                 self.solved_subsumption_set
@@ -384,7 +421,14 @@ impl ConstraintCollector {
                 .get(variable.name())
                 .cloned()
                 .ok_or_else(|| CompileError::VariableNotFound(variable.clone())),
-            Expression::RecordUpdate(_) | Expression::TypeCoercion(_) => unreachable!(),
+            Expression::TypeCoercion(coercion) => {
+                let argument = self.infer_expression(coercion.argument(), variables)?;
+                self.solved_subsumption_set
+                    .add(argument, coercion.from().clone());
+
+                Ok(coercion.to().clone())
+            }
+            Expression::RecordUpdate(_) => unreachable!(),
         }
     }
 }
@@ -4,7 +4,7 @@ use super::{
     variable_constraint::VariableConstraint,
 };
 use crate::types::{self, Type};
-use std::sync::Arc;
+use std::{collections::BTreeSet, sync::Arc};
 
 pub struct ConstraintConverter {
     intersection_type_calculator: Arc<IntersectionTypeCalculator>,
@@ -24,9 +24,13 @@ impl ConstraintConverter {
     }
 
     pub fn convert(&self, constraint: &VariableConstraint) -> Result<Type, CompileError> {
+        // Variables bounded by other variables are bounded by their concrete
+        // types too. So we use variable bounds only if no others exist.
+        let (lower_types, variable_lower_types) = Self::partition(constraint.lower_types());
+        let (upper_types, variable_upper_types) = Self::partition(constraint.upper_types());
+
         Ok(
-            if constraint
-                .lower_types()
+            if lower_types
                 .iter()
                 .map(|type_| self.reference_type_resolver.is_any(type_))
                 .collect::<Result<Vec<bool>, _>>()?
@@ -34,15 +38,19 @@ impl ConstraintConverter {
                 .any(|ok| ok)
             {
                 types::Any::new(constraint.source_information().clone()).into()
-            } else if !constraint.lower_types().is_empty() {
+            } else if !lower_types.is_empty() {
+                types::Union::new(lower_types, constraint.source_information().clone()).into()
+            } else if !upper_types.is_empty() {
+                self.intersection_type_calculator.calculate(&upper_types)?
+            } else if !variable_lower_types.is_empty() {
                 types::Union::new(
-                    constraint.lower_types().iter().cloned().collect(),
+                    variable_lower_types,
                     constraint.source_information().clone(),
                 )
                 .into()
-            } else if !constraint.upper_types().is_empty() {
+            } else if !variable_upper_types.is_empty() {
                 self.intersection_type_calculator
-                    .calculate(&constraint.upper_types().iter().cloned().collect::<Vec<_>>())?
+                    .calculate(&variable_upper_types)?
             } else {
                 return Err(CompileError::TypeNotInferred(
                     constraint.source_information().clone(),
@@ -50,4 +58,11 @@ impl ConstraintConverter {
             },
         )
     }
+
+    fn partition(types: &BTreeSet<Type>) -> (Vec<Type>, Vec<Type>) {
+        types
+            .iter()
+            .cloned()
+            .partition(|type_| !matches!(type_, Type::Variable(_)))
+    }
 }
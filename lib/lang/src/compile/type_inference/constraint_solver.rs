@@ -44,6 +44,16 @@ impl ConstraintSolver {
                         solved_subsumption_set.add(lower.clone(), type_.clone());
                     }
 
+                    // Bounds are propagated transitively between variables so
+                    // that every variable is bounded by concrete types.
+                    if let Type::Variable(lower) = &lower {
+                        for type_ in constraint_set.get_lower_types(lower.id()) {
+                            solved_subsumption_set.add(type_.clone(), variable.clone());
+                        }
+
+                        constraint_set.add_upper_type(lower, &variable.clone().into());
+                    }
+
                     constraint_set.add_lower_type(&variable, &lower);
                 }
                 (Type::Variable(variable), upper) => {
@@ -53,6 +63,70 @@ impl ConstraintSolver {
 
                     constraint_set.add_upper_type(&variable, &upper);
                 }
+                (Type::Reference(one), Type::Reference(other)) if one.name() == other.name() => {
+                    if self
+                        .reference_type_resolver
+                        .resolve_to_record(&one.clone().into())?
+                        .is_some()
+                    {
+                        // Type arguments of record types are covariant.
+                        for (one, other) in self
+                            .reference_type_resolver
+                            .resolve_type_arguments(&one)?
+                            .into_iter()
+                            .zip(
+                                self.reference_type_resolver
+                                    .resolve_type_arguments(&other)?,
+                            )
+                        {
+                            solved_subsumption_set.add(one, other);
+                        }
+                    } else {
+                        solved_subsumption_set.add(
+                            self.reference_type_resolver.resolve_reference(&one)?,
+                            other.into(),
+                        );
+                    }
+                }
+                (lower, Type::Union(union)) => {
+                    // Type arguments of generic record types in union types are
+                    // constrained by the same record types in lower types.
+                    for lower in match &lower {
+                        Type::Union(lower) => lower.types().iter().collect(),
+                        _ => vec![&lower],
+                    } {
+                        if let Type::Reference(lower) = lower {
+                            for upper in union.types() {
+                                if let Type::Reference(upper) = upper {
+                                    if lower.name() == upper.name() {
+                                        solved_subsumption_set.add(lower.clone(), upper.clone());
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    if let Type::Reference(reference) = &lower {
+                        solved_subsumption_set.add(
+                            self.reference_type_resolver.resolve_reference(reference)?,
+                            union,
+                        );
+                    } else {
+                        self.add_checked_subsumption(
+                            &solved_subsumption_set,
+                            checked_subsumption_set,
+                            lower,
+                            union.into(),
+                        );
+                    }
+                }
+                // Upper types are resolved first to keep type arguments of lower
+                // record types for the union types above.
+                (lower @ Type::Reference(_), Type::Reference(reference)) => solved_subsumption_set
+                    .add(
+                        lower,
+                        self.reference_type_resolver.resolve_reference(&reference)?,
+                    ),
                 (Type::Reference(reference), upper) => solved_subsumption_set.add(
                     self.reference_type_resolver.resolve_reference(&reference)?,
                     upper.clone(),
@@ -61,6 +135,11 @@ impl ConstraintSolver {
                     lower.clone(),
                     self.reference_type_resolver.resolve_reference(&reference)?,
                 ),
+                (Type::Union(union), upper) => {
+                    for type_ in union.types() {
+                        solved_subsumption_set.add_union_member(type_, &upper, &union);
+                    }
+                }
                 (Type::Function(one), Type::Function(other)) => {
                     solved_subsumption_set.add(other.argument().clone(), one.argument().clone());
                     solved_subsumption_set.add(one.result().clone(), other.result().clone());
@@ -68,13 +147,32 @@ impl ConstraintSolver {
                 (Type::List(one), Type::List(other)) => {
                     solved_subsumption_set.add(one.element().clone(), other.element().clone());
                 }
-                subsumption => checked_subsumption_set.add(subsumption.0, subsumption.1),
+                (lower, upper) => self.add_checked_subsumption(
+                    &solved_subsumption_set,
+                    checked_subsumption_set,
+                    lower,
+                    upper,
+                ),
             }
         }
 
         self.convert_to_substitutions(&constraint_set)
     }
 
+    fn add_checked_subsumption(
+        &self,
+        solved_subsumption_set: &SubsumptionSet,
+        checked_subsumption_set: &mut SubsumptionSet,
+        lower: Type,
+        upper: Type,
+    ) {
+        if let Some(union) = solved_subsumption_set.get_union_type(&lower, &upper) {
+            checked_subsumption_set.add_union_member(&lower, &upper, union);
+        } else {
+            checked_subsumption_set.add(lower, upper);
+        }
+    }
+
     fn convert_to_substitutions(
         &self,
         constraint_set: &VariableConstraintSet,
@@ -106,11 +204,14 @@ impl ConstraintSolver {
         for type_ in substitutions.values() {
             type_.transform_types(&mut |type_| match type_ {
                 Type::Any(_)
+                | Type::Array(_)
                 | Type::Boolean(_)
                 | Type::Function(_)
                 | Type::List(_)
+                | Type::Map(_)
                 | Type::None(_)
                 | Type::Number(_)
+                | Type::Parameter(_)
                 | Type::Record(_)
                 | Type::Reference(_)
                 | Type::String(_)
@@ -45,6 +45,7 @@ impl IntersectionTypeCalculator {
                 | (Type::List(_), _)
                 | (Type::None(_), _)
                 | (Type::Number(_), _)
+                | (Type::Parameter(_), _)
                 | (Type::Record(_), _)
                 | (Type::String(_), _) => one.clone(),
                 (Type::Union(one), Type::Union(other)) => self.type_canonicalizer.canonicalize(
@@ -73,7 +74,11 @@ impl IntersectionTypeCalculator {
                     .into(),
                 )?,
                 (Type::Union(_), _) => self.intersect_types(other, one)?,
-                (Type::Reference(_), _) | (Type::Unknown(_), _) | (Type::Variable(_), _) => {
+                (Type::Array(_), _)
+                | (Type::Map(_), _)
+                | (Type::Reference(_), _)
+                | (Type::Unknown(_), _)
+                | (Type::Variable(_), _) => {
                     unreachable!()
                 }
             },
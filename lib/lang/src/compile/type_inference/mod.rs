@@ -5,6 +5,7 @@ mod constraint_solver;
 mod intersection_type_calculator;
 mod subsumption_set;
 mod type_inferrer;
+mod type_instantiator;
 mod variable_constraint;
 mod variable_constraint_set;
 mod variable_substitutor;
@@ -22,6 +23,7 @@ use constraint_solver::ConstraintSolver;
 use intersection_type_calculator::IntersectionTypeCalculator;
 use std::sync::Arc;
 use type_inferrer::TypeInferrer;
+use type_instantiator::TypeInstantiator;
 
 pub fn infer_types(
     module: &Module,
@@ -43,9 +45,12 @@ pub fn infer_types(
         reference_type_resolver.clone(),
     );
     let module_environment_creator = ModuleEnvironmentCreator::new();
+    let type_instantiator = TypeInstantiator::new(
+        reference_type_resolver.clone(),
+        module_environment_creator.clone(),
+    );
     let constraint_collector = ConstraintCollector::new(
         reference_type_resolver.clone(),
-        module_environment_creator,
         compile_configuration.error_type_configuration.clone(),
     );
     let constraint_solver =
@@ -53,8 +58,10 @@ pub fn infer_types(
 
     TypeInferrer::new(
         reference_type_resolver,
+        module_environment_creator,
         type_equality_checker,
         type_canonicalizer,
+        type_instantiator,
         constraint_collector,
         constraint_solver,
     )
@@ -132,10 +139,16 @@ mod tests {
 
         assert_eq!(
             infer_types(&module),
-            Err(CompileError::TypesNotMatched(
-                SourceInformation::dummy().into(),
-                SourceInformation::dummy().into()
-            ))
+            Err(CompileError::TypesNotMatched {
+                lower_type: types::Number::new(SourceInformation::dummy()).into(),
+                upper_type: types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                union_type: None,
+            })
         );
     }
 
@@ -178,13 +191,44 @@ mod tests {
 
         assert_eq!(
             infer_types(&module),
-            Err(CompileError::TypesNotMatched(
-                SourceInformation::dummy().into(),
-                SourceInformation::dummy().into()
-            ))
+            Err(CompileError::TypesNotMatched {
+                lower_type: types::Number::new(SourceInformation::dummy()).into(),
+                upper_type: types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                union_type: None,
+            })
         );
     }
 
+    #[test]
+    fn fail_to_infer_types_of_multiple_definitions() {
+        let module = Module::from_definitions(vec![
+            VariableDefinition::new(
+                "x",
+                Number::new(42.0, SourceInformation::dummy()),
+                types::None::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into(),
+            VariableDefinition::new(
+                "y",
+                None::new(SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into(),
+        ]);
+
+        assert!(matches!(
+            infer_types(&module),
+            Err(CompileError::Multiple(errors)) if errors.len() == 2
+        ));
+    }
+
     #[test]
     fn infer_types_of_applications() {
         let module = Module::from_definitions(vec![
@@ -250,10 +294,16 @@ mod tests {
 
         assert_eq!(
             infer_types(&module),
-            Err(CompileError::TypesNotMatched(
-                SourceInformation::dummy().into(),
-                SourceInformation::dummy().into()
-            ))
+            Err(CompileError::TypesNotMatched {
+                lower_type: types::Number::new(SourceInformation::dummy()).into(),
+                upper_type: types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                union_type: None,
+            })
         );
     }
 
@@ -306,10 +356,16 @@ mod tests {
 
         assert_eq!(
             infer_types(&module),
-            Err(CompileError::TypesNotMatched(
-                SourceInformation::dummy().into(),
-                SourceInformation::dummy().into()
-            ))
+            Err(CompileError::TypesNotMatched {
+                lower_type: types::Number::new(SourceInformation::dummy()).into(),
+                upper_type: types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                union_type: None,
+            })
         );
     }
 
@@ -380,10 +436,16 @@ mod tests {
 
         assert_eq!(
             infer_types(&module),
-            Err(CompileError::TypesNotMatched(
-                SourceInformation::dummy().into(),
-                SourceInformation::dummy().into()
-            ))
+            Err(CompileError::TypesNotMatched {
+                lower_type: types::Number::new(SourceInformation::dummy()).into(),
+                upper_type: types::Function::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                union_type: None,
+            })
         );
     }
 
@@ -443,8 +505,8 @@ mod tests {
 
         let module = Module::new(
             ModulePath::new(Package::new("", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![Import::new(
                 ModuleInterface::new(
                     ModulePath::new(Package::new("m", ""), vec![]),
@@ -520,8 +582,8 @@ mod tests {
     fn infer_types_of_variables_with_imports() {
         let module = Module::new(
             ModulePath::new(Package::new("", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![Import::new(
                 ModuleInterface::new(
                     ModulePath::new(Package::new("m", ""), vec![]),
@@ -553,8 +615,8 @@ mod tests {
     fn infer_types_of_import_foreigns() {
         let module = Module::new(
             ModulePath::new(Package::new("", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![],
             vec![ImportForeign::new(
                 "f",
@@ -795,6 +857,7 @@ mod tests {
             vec![TypeDefinition::new(
                 "Foo",
                 types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
             )],
             vec![VariableDefinition::new(
                 "x",
@@ -831,8 +894,8 @@ mod tests {
     fn infer_types_with_imported_reference_types() {
         let module = Module::new(
             ModulePath::new(Package::new("", ""), vec![]),
-            Export::new(Default::default()),
-            ExportForeign::new(Default::default()),
+            Export::new(Default::default(), SourceInformation::dummy()),
+            ExportForeign::new(Default::default(), SourceInformation::dummy()),
             vec![Import::new(
                 ModuleInterface::new(
                     ModulePath::new(Package::new("P", ""), vec![]),
@@ -871,6 +934,7 @@ mod tests {
                     types::Number::new(SourceInformation::dummy()),
                     SourceInformation::dummy(),
                 ),
+                SourceInformation::dummy(),
             )],
             vec![
                 FunctionDefinition::new(
@@ -948,6 +1012,378 @@ mod tests {
         assert_debug_snapshot!(infer_types(&module));
     }
 
+    #[test]
+    fn infer_types_of_lambdas() {
+        let module = Module::from_definitions(vec![
+            FunctionDefinition::new(
+                "f",
+                vec!["g".into()],
+                Application::new(
+                    Variable::new("g", SourceInformation::dummy()),
+                    Number::new(42.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Function::new(
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into(),
+            VariableDefinition::new(
+                "x",
+                Application::new(
+                    Variable::new("f", SourceInformation::dummy()),
+                    Lambda::new(
+                        vec!["y".into()],
+                        ArithmeticOperation::new(
+                            ArithmeticOperator::Add,
+                            Variable::new("y", SourceInformation::dummy()),
+                            Number::new(1.0, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Unknown::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into(),
+        ]);
+
+        assert!(infer_types(&module).is_ok());
+    }
+
+    #[test]
+    fn fail_to_infer_types_of_lambdas() {
+        let module = Module::from_definitions(vec![VariableDefinition::new(
+            "x",
+            Lambda::new(
+                vec!["y".into()],
+                ArithmeticOperation::new(
+                    ArithmeticOperator::Add,
+                    Variable::new("y", SourceInformation::dummy()),
+                    Number::new(1.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::Unknown::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            types::Function::new(
+                types::None::new(SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            SourceInformation::dummy(),
+        )
+        .into()]);
+
+        assert_eq!(
+            infer_types(&module),
+            Err(CompileError::TypesNotMatched {
+                lower_type: types::None::new(SourceInformation::dummy()).into(),
+                upper_type: types::Number::new(SourceInformation::dummy()).into(),
+                union_type: None,
+            })
+        );
+    }
+
+    mod type_parameters {
+        use super::*;
+        use pretty_assertions::assert_eq;
+
+        fn create_identity_function_definition() -> Definition {
+            FunctionDefinition::new(
+                "id",
+                vec!["x".into()],
+                Variable::new("x", SourceInformation::dummy()),
+                types::Function::new(
+                    types::Parameter::new("a", SourceInformation::dummy()),
+                    types::Parameter::new("a", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+            .into()
+        }
+
+        fn create_box_type_definition() -> TypeDefinition {
+            TypeDefinition::with_type_parameters(
+                "Box",
+                vec!["a".into()],
+                types::Record::new(
+                    "Box",
+                    vec![types::RecordElement::new(
+                        "value",
+                        types::Parameter::new("a", SourceInformation::dummy()),
+                    )],
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )
+        }
+
+        #[test]
+        fn infer_types_of_applications_of_generic_functions() {
+            assert!(infer_types(&Module::from_definitions(vec![
+                create_identity_function_definition(),
+                VariableDefinition::new(
+                    "x",
+                    Application::new(
+                        Variable::new("id", SourceInformation::dummy()),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                VariableDefinition::new(
+                    "y",
+                    Application::new(
+                        Variable::new("id", SourceInformation::dummy()),
+                        None::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::None::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ]))
+            .is_ok());
+        }
+
+        #[test]
+        fn fail_to_infer_types_of_applications_of_generic_functions() {
+            assert_eq!(
+                infer_types(&Module::from_definitions(vec![
+                    create_identity_function_definition(),
+                    VariableDefinition::new(
+                        "x",
+                        Application::new(
+                            Variable::new("id", SourceInformation::dummy()),
+                            Number::new(42.0, SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::None::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ])),
+                Err(CompileError::TypesNotMatched {
+                    lower_type: types::Number::new(SourceInformation::dummy()).into(),
+                    upper_type: types::None::new(SourceInformation::dummy()).into(),
+                    union_type: None,
+                })
+            );
+        }
+
+        #[test]
+        fn fail_to_infer_types_of_generic_functions_returning_concrete_values() {
+            assert_eq!(
+                infer_types(&Module::from_definitions(vec![FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Number::new(42.0, SourceInformation::dummy()),
+                    types::Function::new(
+                        types::Parameter::new("a", SourceInformation::dummy()),
+                        types::Parameter::new("a", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                )
+                .into()])),
+                Err(CompileError::TypesNotMatched {
+                    lower_type: types::Number::new(SourceInformation::dummy()).into(),
+                    upper_type: types::Parameter::new("a", SourceInformation::dummy()).into(),
+                    union_type: None,
+                })
+            );
+        }
+
+        #[test]
+        fn infer_types_of_generic_records() {
+            assert!(infer_types(&Module::from_definitions_and_type_definitions(
+                vec![create_box_type_definition()],
+                vec![VariableDefinition::new(
+                    "x",
+                    RecordElementOperation::new(
+                        types::Reference::new("Box", SourceInformation::dummy()),
+                        "value",
+                        RecordConstruction::new(
+                            types::Reference::new("Box", SourceInformation::dummy()),
+                            vec![(
+                                "value".into(),
+                                Number::new(42.0, SourceInformation::dummy()).into()
+                            )]
+                            .into_iter()
+                            .collect(),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()],
+            ))
+            .is_ok());
+        }
+
+        #[test]
+        fn infer_types_of_generic_records_in_union_types() {
+            let parameter =
+                || -> Type { types::Parameter::new("a", SourceInformation::dummy()).into() };
+            let reference = |name: &str| {
+                types::Reference::with_arguments(
+                    name,
+                    vec![parameter()],
+                    SourceInformation::dummy(),
+                )
+            };
+
+            assert!(infer_types(&Module::from_definitions_and_type_definitions(
+                vec![
+                    TypeDefinition::with_type_parameters(
+                        "Tree",
+                        vec!["a".into()],
+                        types::Union::new(
+                            vec![
+                                reference("Branch").into(),
+                                reference("Leaf").into(),
+                                types::None::new(SourceInformation::dummy()).into(),
+                            ],
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    TypeDefinition::with_type_parameters(
+                        "Branch",
+                        vec!["a".into()],
+                        types::Record::new(
+                            "Branch",
+                            vec![types::RecordElement::new("tree", reference("Tree"))],
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    TypeDefinition::with_type_parameters(
+                        "Leaf",
+                        vec!["a".into()],
+                        types::Record::new(
+                            "Leaf",
+                            vec![types::RecordElement::new("value", parameter())],
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                ],
+                vec![
+                    VariableDefinition::new(
+                        "emptyBranch",
+                        RecordConstruction::new(
+                            types::Reference::new("Branch", SourceInformation::dummy()),
+                            vec![("tree".into(), None::new(SourceInformation::dummy()).into())]
+                                .into_iter()
+                                .collect(),
+                            SourceInformation::dummy(),
+                        ),
+                        reference("Branch"),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    FunctionDefinition::new(
+                        "updateBranch",
+                        vec!["branch".into(), "tree".into()],
+                        RecordConstruction::new(
+                            types::Reference::new("Branch", SourceInformation::dummy()),
+                            vec![(
+                                "tree".into(),
+                                Variable::new("tree", SourceInformation::dummy()).into()
+                            )]
+                            .into_iter()
+                            .collect(),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Function::new(
+                            reference("Branch"),
+                            types::Function::new(
+                                reference("Tree"),
+                                reference("Branch"),
+                                SourceInformation::dummy(),
+                            ),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                    FunctionDefinition::new(
+                        "f",
+                        vec!["leaf".into()],
+                        Application::new(
+                            Application::new(
+                                Variable::new("updateBranch", SourceInformation::dummy()),
+                                Variable::new("emptyBranch", SourceInformation::dummy()),
+                                SourceInformation::dummy(),
+                            ),
+                            Variable::new("leaf", SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Function::new(
+                            reference("Leaf"),
+                            reference("Tree"),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into(),
+                ],
+            ))
+            .is_ok());
+        }
+
+        #[test]
+        fn fail_to_infer_types_of_generic_records_with_wrong_type_arguments() {
+            assert_eq!(
+                infer_types(&Module::from_definitions_and_type_definitions(
+                    vec![create_box_type_definition()],
+                    vec![VariableDefinition::new(
+                        "x",
+                        RecordConstruction::new(
+                            types::Reference::new("Box", SourceInformation::dummy()),
+                            vec![(
+                                "value".into(),
+                                Number::new(42.0, SourceInformation::dummy()).into()
+                            )]
+                            .into_iter()
+                            .collect(),
+                            SourceInformation::dummy(),
+                        ),
+                        types::Reference::with_arguments(
+                            "Box",
+                            vec![types::None::new(SourceInformation::dummy()).into()],
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                )),
+                Err(CompileError::TypesNotMatched {
+                    lower_type: types::Number::new(SourceInformation::dummy()).into(),
+                    upper_type: types::None::new(SourceInformation::dummy()).into(),
+                    union_type: None,
+                })
+            );
+        }
+    }
+
     mod if_ {
         use super::*;
         use pretty_assertions::assert_eq;
@@ -985,10 +1421,11 @@ mod tests {
             .into()]);
             assert_eq!(
                 infer_types(&module),
-                Err(CompileError::TypesNotMatched(
-                    SourceInformation::dummy().into(),
-                    SourceInformation::dummy().into()
-                ))
+                Err(CompileError::TypesNotMatched {
+                    lower_type: types::None::new(SourceInformation::dummy()).into(),
+                    upper_type: types::Boolean::new(SourceInformation::dummy()).into(),
+                    union_type: None,
+                })
             );
         }
 
@@ -1008,10 +1445,11 @@ mod tests {
             .into()]);
             assert_eq!(
                 infer_types(&module),
-                Err(CompileError::TypesNotMatched(
-                    SourceInformation::dummy().into(),
-                    SourceInformation::dummy().into()
-                ))
+                Err(CompileError::TypesNotMatched {
+                    lower_type: types::Boolean::new(SourceInformation::dummy()).into(),
+                    upper_type: types::None::new(SourceInformation::dummy()).into(),
+                    union_type: None,
+                })
             );
         }
 
@@ -1231,8 +1669,8 @@ mod tests {
         }
 
         #[test]
-        fn fail_to_infer_type_with_alternatives_not_exhaustive_with_union() {
-            assert_debug_snapshot!(infer_types(&Module::from_definitions(vec![
+        fn infer_types_of_case_expressions_not_exhaustive_with_union() {
+            assert!(infer_types(&Module::from_definitions(vec![
                 VariableDefinition::new(
                     "x",
                     None::new(SourceInformation::dummy()),
@@ -1261,12 +1699,13 @@ mod tests {
                     SourceInformation::dummy(),
                 )
                 .into()
-            ])));
+            ]))
+            .is_ok());
         }
 
         #[test]
-        fn fail_to_infer_type_with_alternatives_not_exhaustive_with_any() {
-            assert_debug_snapshot!(infer_types(&Module::from_definitions(vec![
+        fn infer_types_of_case_expressions_not_exhaustive_with_any() {
+            assert!(infer_types(&Module::from_definitions(vec![
                 VariableDefinition::new(
                     "x",
                     None::new(SourceInformation::dummy()),
@@ -1289,7 +1728,8 @@ mod tests {
                     SourceInformation::dummy(),
                 )
                 .into()
-            ])));
+            ]))
+            .is_ok());
         }
 
         #[test]
@@ -1370,7 +1810,11 @@ mod tests {
             let reference_type = types::Reference::new("Foo", SourceInformation::dummy());
 
             assert_debug_snapshot!(infer_types(&Module::from_definitions_and_type_definitions(
-                vec![TypeDefinition::new("Foo", record_type)],
+                vec![TypeDefinition::new(
+                    "Foo",
+                    record_type,
+                    SourceInformation::dummy()
+                )],
                 vec![VariableDefinition::new(
                     "x",
                     RecordConstruction::new(
@@ -1398,7 +1842,11 @@ mod tests {
             let reference_type = types::Reference::new("Foo", SourceInformation::dummy());
 
             assert_debug_snapshot!(infer_types(&Module::from_definitions_and_type_definitions(
-                vec![TypeDefinition::new("Foo", record_type)],
+                vec![TypeDefinition::new(
+                    "Foo",
+                    record_type,
+                    SourceInformation::dummy()
+                )],
                 vec![VariableDefinition::new(
                     "x",
                     RecordConstruction::new(
@@ -1425,7 +1873,11 @@ mod tests {
             let reference_type = types::Reference::new("Foo", SourceInformation::dummy());
 
             let module = Module::from_definitions_and_type_definitions(
-                vec![TypeDefinition::new("Foo", record_type)],
+                vec![TypeDefinition::new(
+                    "Foo",
+                    record_type.clone(),
+                    SourceInformation::dummy(),
+                )],
                 vec![VariableDefinition::new(
                     "x",
                     RecordConstruction::new(
@@ -1445,10 +1897,10 @@ mod tests {
             );
             assert_eq!(
                 infer_types(&module),
-                Err(CompileError::TypesNotMatched(
-                    SourceInformation::dummy().into(),
-                    SourceInformation::dummy().into()
-                ))
+                Err(CompileError::RecordElementsNotMatched {
+                    record_type,
+                    source_information: SourceInformation::dummy().into(),
+                })
             );
         }
 
@@ -1465,7 +1917,11 @@ mod tests {
             let reference_type = types::Reference::new("Foo", SourceInformation::dummy());
 
             let module = Module::from_definitions_and_type_definitions(
-                vec![TypeDefinition::new("Foo", record_type)],
+                vec![TypeDefinition::new(
+                    "Foo",
+                    record_type,
+                    SourceInformation::dummy(),
+                )],
                 vec![VariableDefinition::new(
                     "x",
                     RecordConstruction::new(
@@ -1485,10 +1941,11 @@ mod tests {
             );
             assert_eq!(
                 infer_types(&module),
-                Err(CompileError::TypesNotMatched(
-                    SourceInformation::dummy().into(),
-                    SourceInformation::dummy().into()
-                ))
+                Err(CompileError::TypesNotMatched {
+                    lower_type: types::Number::new(SourceInformation::dummy()).into(),
+                    upper_type: types::None::new(SourceInformation::dummy()).into(),
+                    union_type: None,
+                })
             );
         }
 
@@ -1501,8 +1958,8 @@ mod tests {
 
             let module = Module::from_definitions_and_type_definitions(
                 vec![
-                    TypeDefinition::new("Foo", foo_type),
-                    TypeDefinition::new("Bar", bar_type),
+                    TypeDefinition::new("Foo", foo_type.clone(), SourceInformation::dummy()),
+                    TypeDefinition::new("Bar", bar_type, SourceInformation::dummy()),
                 ],
                 vec![VariableDefinition::new(
                     "x",
@@ -1524,10 +1981,10 @@ mod tests {
 
             assert_eq!(
                 infer_types(&module),
-                Err(CompileError::TypesNotMatched(
-                    SourceInformation::dummy().into(),
-                    SourceInformation::dummy().into()
-                ))
+                Err(CompileError::RecordElementsNotMatched {
+                    record_type: foo_type,
+                    source_information: SourceInformation::dummy().into(),
+                })
             );
         }
 
@@ -1543,7 +2000,11 @@ mod tests {
             );
 
             assert_debug_snapshot!(infer_types(&Module::from_definitions_and_type_definitions(
-                vec![TypeDefinition::new("Foo", record_type)],
+                vec![TypeDefinition::new(
+                    "Foo",
+                    record_type,
+                    SourceInformation::dummy()
+                )],
                 vec![VariableDefinition::new(
                     "x",
                     RecordElementOperation::new(
@@ -1613,7 +2074,11 @@ mod tests {
             let reference_type = types::Reference::new("Foo", SourceInformation::dummy());
 
             assert_debug_snapshot!(infer_types(&Module::from_definitions_and_type_definitions(
-                vec![TypeDefinition::new("Foo", record_type)],
+                vec![TypeDefinition::new(
+                    "Foo",
+                    record_type,
+                    SourceInformation::dummy()
+                )],
                 vec![VariableDefinition::new(
                     "x",
                     EqualityOperation::with_type(
@@ -1780,10 +2245,66 @@ mod tests {
 
             assert_eq!(
                 infer_types(&module),
-                Err(CompileError::TypesNotMatched(
-                    SourceInformation::dummy().into(),
-                    SourceInformation::dummy().into()
-                ))
+                Err(CompileError::TypesNotMatched {
+                    lower_type: types::Number::new(SourceInformation::dummy()).into(),
+                    upper_type: types::Union::new(
+                        vec![
+                            types::Boolean::new(SourceInformation::dummy()).into(),
+                            types::None::new(SourceInformation::dummy()).into()
+                        ],
+                        SourceInformation::dummy()
+                    )
+                    .into(),
+                    union_type: Some(types::Union::new(
+                        vec![
+                            types::Boolean::new(SourceInformation::dummy()).into(),
+                            types::None::new(SourceInformation::dummy()).into(),
+                            types::Number::new(SourceInformation::dummy()).into()
+                        ],
+                        SourceInformation::dummy()
+                    )),
+                })
+            );
+        }
+
+        #[test]
+        fn fail_to_infer_casting_of_union_types_to_function_types() {
+            let function_type = types::Function::new(
+                types::Number::new(SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            );
+            let union_type = types::Union::new(
+                vec![
+                    function_type.clone().into(),
+                    types::None::new(SourceInformation::dummy()).into(),
+                ],
+                SourceInformation::dummy(),
+            );
+            let module = Module::from_definitions(vec![
+                VariableDefinition::new(
+                    "x",
+                    None::new(SourceInformation::dummy()),
+                    union_type.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+                VariableDefinition::new(
+                    "y",
+                    Variable::new("x", SourceInformation::dummy()),
+                    function_type.clone(),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ]);
+
+            assert_eq!(
+                infer_types(&module),
+                Err(CompileError::TypesNotMatched {
+                    lower_type: types::None::new(SourceInformation::dummy()).into(),
+                    upper_type: function_type.into(),
+                    union_type: Some(union_type),
+                })
             );
         }
 
@@ -1880,6 +2401,7 @@ mod tests {
                         )],
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
                 )],
                 vec![],
             );
@@ -1,10 +1,12 @@
-use crate::types::Type;
-use std::collections::HashSet;
+use crate::types::{self, Type};
+use std::collections::{HashMap, HashSet};
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct SubsumptionSet {
     cache: HashSet<(Type, Type)>,
     subsumptions: Vec<(Type, Type)>,
+    // Union types of lower types which are decomposed into their members
+    union_types: HashMap<(Type, Type), types::Union>,
 }
 
 impl SubsumptionSet {
@@ -12,6 +14,7 @@ impl SubsumptionSet {
         Self {
             cache: HashSet::new(),
             subsumptions: vec![],
+            union_types: HashMap::new(),
         }
     }
 
@@ -26,7 +29,17 @@ impl SubsumptionSet {
         self.subsumptions.push(subsumption);
     }
 
+    pub fn add_union_member(&mut self, lower: &Type, upper: &Type, union: &types::Union) {
+        self.union_types
+            .insert((lower.clone(), upper.clone()), union.clone());
+        self.add(lower.clone(), upper.clone());
+    }
+
     pub fn remove(&mut self) -> Option<(Type, Type)> {
         self.subsumptions.pop()
     }
+
+    pub fn get_union_type(&self, lower: &Type, upper: &Type) -> Option<&types::Union> {
+        self.union_types.get(&(lower.clone(), upper.clone()))
+    }
 }
@@ -1,23 +1,29 @@
 use super::{
     super::{
-        error::CompileError, reference_type_resolver::ReferenceTypeResolver,
-        type_canonicalizer::TypeCanonicalizer, type_equality_checker::TypeEqualityChecker,
+        error::{collect_results, CompileError},
+        module_environment_creator::ModuleEnvironmentCreator,
+        reference_type_resolver::ReferenceTypeResolver,
+        type_canonicalizer::TypeCanonicalizer,
+        type_equality_checker::TypeEqualityChecker,
     },
     constraint_checker::ConstraintChecker,
     constraint_collector::ConstraintCollector,
     constraint_solver::ConstraintSolver,
+    type_instantiator::TypeInstantiator,
     variable_substitutor::VariableSubstitutor,
 };
 use crate::{
     ast::*,
     types::{self, Type},
 };
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 pub struct TypeInferrer {
     reference_type_resolver: Arc<ReferenceTypeResolver>,
+    module_environment_creator: Arc<ModuleEnvironmentCreator>,
     type_equality_checker: Arc<TypeEqualityChecker>,
     type_canonicalizer: Arc<TypeCanonicalizer>,
+    type_instantiator: TypeInstantiator,
     constraint_collector: ConstraintCollector,
     constraint_solver: Arc<ConstraintSolver>,
 }
@@ -25,15 +31,19 @@ pub struct TypeInferrer {
 impl TypeInferrer {
     pub fn new(
         reference_type_resolver: Arc<ReferenceTypeResolver>,
+        module_environment_creator: Arc<ModuleEnvironmentCreator>,
         type_equality_checker: Arc<TypeEqualityChecker>,
         type_canonicalizer: Arc<TypeCanonicalizer>,
+        type_instantiator: TypeInstantiator,
         constraint_collector: ConstraintCollector,
         constraint_solver: Arc<ConstraintSolver>,
     ) -> Self {
         Self {
             reference_type_resolver,
+            module_environment_creator,
             type_equality_checker,
             type_canonicalizer,
+            type_instantiator,
             constraint_collector,
             constraint_solver,
         }
@@ -48,9 +58,42 @@ impl TypeInferrer {
                 _ => type_.clone(),
             })
         })?;
+        let module = self.type_instantiator.instantiate(&module)?;
+        let variables = self.module_environment_creator.create(&module);
 
-        let (solved_subsumption_set, mut checked_subsumption_set) =
-            self.constraint_collector.collect(&module)?;
+        // Types of definitions are inferred independently as global variables
+        // are typed explicitly. Therefore, errors in all definitions are
+        // reported at once.
+        let definitions = collect_results(
+            module
+                .definitions()
+                .iter()
+                .map(|definition| self.infer_definition(definition, &variables)),
+        )?;
+
+        let substitutor = VariableSubstitutor::new(self.type_canonicalizer.clone(), HashMap::new());
+
+        Module::new(
+            module.path().clone(),
+            module.export().clone(),
+            module.export_foreign().clone(),
+            module.imports().to_vec(),
+            module.import_foreigns().to_vec(),
+            module.type_definitions().to_vec(),
+            definitions,
+        )
+        .transform_types(&mut |type_| substitutor.substitute(type_))
+    }
+
+    fn infer_definition(
+        &self,
+        definition: &Definition,
+        variables: &HashMap<String, Type>,
+    ) -> Result<Definition, CompileError> {
+        let (solved_subsumption_set, mut checked_subsumption_set) = self
+            .constraint_collector
+            .clone()
+            .collect(definition, variables)?;
 
         let substitutions = self
             .constraint_solver
@@ -61,11 +104,11 @@ impl TypeInferrer {
         let checker = ConstraintChecker::new(
             substitutor.clone(),
             self.reference_type_resolver.clone(),
-            self.type_equality_checker,
+            self.type_equality_checker.clone(),
         );
 
         checker.check(checked_subsumption_set)?;
 
-        module.transform_types(&mut |type_| substitutor.substitute(type_))
+        definition.transform_types(&mut |type_| substitutor.substitute(type_))
     }
 }
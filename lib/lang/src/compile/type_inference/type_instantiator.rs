@@ -0,0 +1,127 @@
+use super::super::{
+    error::CompileError, module_environment_creator::ModuleEnvironmentCreator,
+    reference_type_resolver::ReferenceTypeResolver,
+};
+use crate::{
+    ast::*,
+    types::{self, Type},
+};
+use std::sync::Arc;
+
+// Instantiate type parameters of generic global variables and record types
+// with fresh type variables at each use.
+pub struct TypeInstantiator {
+    reference_type_resolver: Arc<ReferenceTypeResolver>,
+    module_environment_creator: Arc<ModuleEnvironmentCreator>,
+}
+
+impl TypeInstantiator {
+    pub fn new(
+        reference_type_resolver: Arc<ReferenceTypeResolver>,
+        module_environment_creator: Arc<ModuleEnvironmentCreator>,
+    ) -> Self {
+        Self {
+            reference_type_resolver,
+            module_environment_creator,
+        }
+    }
+
+    pub fn instantiate(&self, module: &Module) -> Result<Module, CompileError> {
+        // Global names are fully-qualified at this point and never shadowed by
+        // local variables.
+        let variables = self.module_environment_creator.create(module);
+
+        module.transform_expressions(&mut |expression| -> Result<_, CompileError> {
+            Ok(match expression {
+                Expression::RecordConstruction(record_construction) => {
+                    match self.instantiate_reference(record_construction.type_())? {
+                        Some(type_) => RecordConstruction::new(
+                            type_,
+                            record_construction.elements().clone(),
+                            record_construction.source_information().clone(),
+                        )
+                        .into(),
+                        None => expression.clone(),
+                    }
+                }
+                Expression::RecordElementOperation(operation) => {
+                    match self.instantiate_reference(operation.type_())? {
+                        Some(type_) => RecordElementOperation::new(
+                            type_,
+                            operation.element_name(),
+                            operation.argument().clone(),
+                            operation.source_information().clone(),
+                        )
+                        .into(),
+                        None => expression.clone(),
+                    }
+                }
+                Expression::RecordUpdate(record_update) => {
+                    match self.instantiate_reference(record_update.type_())? {
+                        Some(type_) => RecordUpdate::new(
+                            type_,
+                            record_update.argument().clone(),
+                            record_update.elements().clone(),
+                            record_update.source_information().clone(),
+                        )
+                        .into(),
+                        None => expression.clone(),
+                    }
+                }
+                Expression::Variable(variable) => match variables.get(variable.name()) {
+                    Some(type_) if !type_.parameters().is_empty() => TypeCoercion::new(
+                        variable.clone(),
+                        type_.clone(),
+                        type_.substitute_parameters(
+                            &type_
+                                .parameters()
+                                .iter()
+                                .map(|parameter| {
+                                    (
+                                        parameter.name().into(),
+                                        types::Variable::new(variable.source_information().clone())
+                                            .into(),
+                                    )
+                                })
+                                .collect(),
+                        ),
+                        variable.source_information().clone(),
+                    )
+                    .into(),
+                    _ => expression.clone(),
+                },
+                _ => expression.clone(),
+            })
+        })
+    }
+
+    fn instantiate_reference(&self, type_: &Type) -> Result<Option<Type>, CompileError> {
+        Ok(match type_ {
+            Type::Reference(reference) if reference.arguments().is_empty() => {
+                let parameters = self
+                    .reference_type_resolver
+                    .resolve_type_parameters(reference)?;
+
+                if parameters.is_empty() {
+                    None
+                } else {
+                    Some(
+                        types::Reference::with_arguments(
+                            reference.name(),
+                            parameters
+                                .iter()
+                                .map(|_| {
+                                    types::Variable::new(reference.source_information().clone())
+                                        .into()
+                                })
+                                .collect(),
+                            reference.source_information().clone(),
+                        )
+                        .into(),
+                    )
+                }
+            }
+            _ => None,
+        })
+    }
+}
@@ -1,5 +1,9 @@
-use super::error::CompileError;
-use crate::types::{self, Type};
+use super::{error::CompileError, list_type_configuration::ListTypeConfiguration};
+use crate::{
+    debug::SourceInformation,
+    types::{self, Type},
+};
+use std::sync::Arc;
 
 pub fn get_record_element<'a>(
     record_type: &'a types::Record,
@@ -15,3 +19,45 @@ pub fn get_record_element<'a>(
         })
         .map(|element| element.type_())
 }
+
+// Array types are transformed into references to a generic record type with
+// element types as type arguments before type inference.
+pub fn get_array_element_type(array_type: &Type) -> &Type {
+    match array_type {
+        Type::Reference(reference) => &reference.arguments()[0],
+        _ => unreachable!(),
+    }
+}
+
+// Map types are transformed into references to a generic record type with key
+// and value types as type arguments before type inference.
+pub fn get_map_element_types(map_type: &Type) -> (&Type, &Type) {
+    match map_type {
+        Type::Reference(reference) => (&reference.arguments()[0], &reference.arguments()[1]),
+        _ => unreachable!(),
+    }
+}
+
+// List types are generic record types in the prelude. Their elements are
+// erased into Any in lowered code.
+pub fn get_any_list_type(
+    configuration: &ListTypeConfiguration,
+    source_information: &Arc<SourceInformation>,
+) -> types::Reference {
+    types::Reference::with_arguments(
+        &configuration.list_type_name,
+        vec![types::Any::new(source_information.clone()).into()],
+        source_information.clone(),
+    )
+}
+
+pub fn get_any_first_rest_type(
+    configuration: &ListTypeConfiguration,
+    source_information: &Arc<SourceInformation>,
+) -> types::Reference {
+    types::Reference::with_arguments(
+        &configuration.first_rest_type_name,
+        vec![types::Any::new(source_information.clone()).into()],
+        source_information.clone(),
+    )
+}
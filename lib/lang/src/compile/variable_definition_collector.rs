@@ -0,0 +1,342 @@
+use crate::{ast::*, debug::SourceInformation};
+use std::{collections::HashMap, sync::Arc};
+
+type VariableDefinitions = Vec<(Arc<SourceInformation>, Arc<SourceInformation>)>;
+
+// Variables are paired with source information of their definitions in the same
+// modules so that editors can jump to them. Arguments and variables bound by
+// case expressions are defined by functions and expressions binding them.
+pub struct VariableDefinitionCollector {}
+
+impl VariableDefinitionCollector {
+    pub fn new() -> Arc<Self> {
+        Self {}.into()
+    }
+
+    pub fn collect(&self, module: &Module) -> VariableDefinitions {
+        let mut variables = HashMap::<String, Arc<SourceInformation>>::new();
+        let mut definitions = vec![];
+
+        for import_foreign in module.import_foreigns() {
+            variables.insert(
+                import_foreign.name().into(),
+                import_foreign.source_information().clone(),
+            );
+        }
+
+        for definition in module.definitions() {
+            variables.insert(
+                definition.name().into(),
+                definition.source_information().clone(),
+            );
+        }
+
+        for definition in module.definitions() {
+            self.collect_from_definition(definition, &variables, &mut definitions);
+        }
+
+        definitions
+    }
+
+    fn collect_from_definition(
+        &self,
+        definition: &Definition,
+        variables: &HashMap<String, Arc<SourceInformation>>,
+        definitions: &mut VariableDefinitions,
+    ) {
+        match definition {
+            Definition::FunctionDefinition(function_definition) => self.collect_from_function(
+                function_definition.arguments(),
+                function_definition.body(),
+                function_definition.source_information(),
+                variables,
+                definitions,
+            ),
+            Definition::VariableDefinition(variable_definition) => {
+                self.collect_from_expression(variable_definition.body(), variables, definitions)
+            }
+        }
+    }
+
+    fn collect_from_function(
+        &self,
+        arguments: &[String],
+        body: &Expression,
+        source_information: &Arc<SourceInformation>,
+        variables: &HashMap<String, Arc<SourceInformation>>,
+        definitions: &mut VariableDefinitions,
+    ) {
+        let mut variables = variables.clone();
+
+        for argument in arguments {
+            variables.insert(argument.into(), source_information.clone());
+        }
+
+        self.collect_from_expression(body, &variables, definitions)
+    }
+
+    fn collect_from_expression(
+        &self,
+        expression: &Expression,
+        variables: &HashMap<String, Arc<SourceInformation>>,
+        definitions: &mut VariableDefinitions,
+    ) {
+        match expression {
+            Expression::Application(application) => {
+                self.collect_from_expression(application.function(), variables, definitions);
+                self.collect_from_expression(application.argument(), variables, definitions);
+            }
+            Expression::Array(array) => {
+                for element in array.elements() {
+                    self.collect_from_expression(element, variables, definitions);
+                }
+            }
+            Expression::Case(case) => {
+                self.collect_from_expression(case.argument(), variables, definitions);
+
+                let mut variables = variables.clone();
+
+                variables.insert(case.name().into(), case.source_information().clone());
+
+                for alternative in case.alternatives() {
+                    self.collect_from_expression(alternative.expression(), &variables, definitions);
+                }
+            }
+            Expression::If(if_) => {
+                self.collect_from_expression(if_.condition(), variables, definitions);
+                self.collect_from_expression(if_.then(), variables, definitions);
+                self.collect_from_expression(if_.else_(), variables, definitions);
+            }
+            Expression::Lambda(lambda) => self.collect_from_function(
+                lambda.arguments(),
+                lambda.body(),
+                lambda.source_information(),
+                variables,
+                definitions,
+            ),
+            Expression::Let(let_) => {
+                let mut variables = variables.clone();
+
+                for definition in let_.definitions() {
+                    if let Definition::FunctionDefinition(_) = definition {
+                        variables.insert(
+                            definition.name().into(),
+                            definition.source_information().clone(),
+                        );
+                    }
+
+                    self.collect_from_definition(definition, &variables, definitions);
+
+                    variables.insert(
+                        definition.name().into(),
+                        definition.source_information().clone(),
+                    );
+                }
+
+                self.collect_from_expression(let_.expression(), &variables, definitions);
+            }
+            Expression::LetError(let_error) => {
+                let mut variables = variables.clone();
+
+                for definition in let_error.definitions() {
+                    self.collect_from_expression(definition.body(), &variables, definitions);
+
+                    variables.insert(
+                        definition.name().into(),
+                        definition.source_information().clone(),
+                    );
+                }
+
+                self.collect_from_expression(let_error.expression(), &variables, definitions);
+            }
+            Expression::List(list) => {
+                for element in list.elements() {
+                    match element {
+                        ListElement::Multiple(expression) | ListElement::Single(expression) => {
+                            self.collect_from_expression(expression, variables, definitions)
+                        }
+                    }
+                }
+            }
+            Expression::ListCase(case) => {
+                self.collect_from_expression(case.argument(), variables, definitions);
+                self.collect_from_expression(case.empty_alternative(), variables, definitions);
+
+                let mut variables = variables.clone();
+
+                variables.insert(case.first_name().into(), case.source_information().clone());
+                variables.insert(case.rest_name().into(), case.source_information().clone());
+
+                self.collect_from_expression(case.non_empty_alternative(), &variables, definitions);
+            }
+            Expression::Map(map) => {
+                for element in map.elements() {
+                    self.collect_from_expression(element.key(), variables, definitions);
+                    self.collect_from_expression(element.value(), variables, definitions);
+                }
+            }
+            Expression::Operation(operation) => {
+                self.collect_from_expression(operation.lhs(), variables, definitions);
+                self.collect_from_expression(operation.rhs(), variables, definitions);
+            }
+            Expression::RecordConstruction(record_construction) => {
+                for expression in record_construction.elements().values() {
+                    self.collect_from_expression(expression, variables, definitions);
+                }
+            }
+            Expression::RecordElementOperation(operation) => {
+                self.collect_from_expression(operation.argument(), variables, definitions);
+            }
+            Expression::RecordUpdate(record_update) => {
+                self.collect_from_expression(record_update.argument(), variables, definitions);
+
+                for expression in record_update.elements().values() {
+                    self.collect_from_expression(expression, variables, definitions);
+                }
+            }
+            Expression::TypeCoercion(coercion) => {
+                self.collect_from_expression(coercion.argument(), variables, definitions);
+            }
+            Expression::Variable(variable) => {
+                if let Some(source_information) = variables.get(variable.name()) {
+                    definitions.push((
+                        variable.source_information().clone(),
+                        source_information.clone(),
+                    ));
+                }
+            }
+            Expression::Boolean(_)
+            | Expression::None(_)
+            | Expression::Number(_)
+            | Expression::String(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::Location, types};
+    use pretty_assertions::assert_eq;
+
+    fn create_source_information(line: usize, column: usize) -> Arc<SourceInformation> {
+        SourceInformation::new("", Location::new(line, column), "").into()
+    }
+
+    #[test]
+    fn collect_definitions_of_global_variables() {
+        let definition_source_information = create_source_information(1, 1);
+        let variable_source_information = create_source_information(3, 5);
+
+        assert_eq!(
+            VariableDefinitionCollector::new().collect(&Module::from_definitions(vec![
+                VariableDefinition::new(
+                    "x",
+                    Number::new(42.0, SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    definition_source_information.clone(),
+                )
+                .into(),
+                VariableDefinition::new(
+                    "y",
+                    Variable::new("x", variable_source_information.clone()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ])),
+            vec![(variable_source_information, definition_source_information)]
+        );
+    }
+
+    #[test]
+    fn collect_definitions_of_arguments_shadowing_global_variables() {
+        let function_source_information = create_source_information(3, 1);
+        let variable_source_information = create_source_information(4, 7);
+
+        assert_eq!(
+            VariableDefinitionCollector::new().collect(&Module::from_definitions(vec![
+                VariableDefinition::new(
+                    "x",
+                    Number::new(42.0, SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    create_source_information(1, 1),
+                )
+                .into(),
+                FunctionDefinition::new(
+                    "f",
+                    vec!["x".into()],
+                    Variable::new("x", variable_source_information.clone()),
+                    types::Function::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        types::Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    function_source_information.clone(),
+                )
+                .into(),
+            ])),
+            vec![(variable_source_information, function_source_information)]
+        );
+    }
+
+    #[test]
+    fn collect_definitions_of_let_definitions_shadowing_global_variables() {
+        let definition_source_information = create_source_information(5, 5);
+        let variable_source_information = create_source_information(7, 5);
+
+        assert_eq!(
+            VariableDefinitionCollector::new().collect(&Module::from_definitions(vec![
+                VariableDefinition::new(
+                    "x",
+                    Number::new(42.0, SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    create_source_information(1, 1),
+                )
+                .into(),
+                VariableDefinition::new(
+                    "y",
+                    Let::new(
+                        vec![VariableDefinition::new(
+                            "x",
+                            Number::new(42.0, SourceInformation::dummy()),
+                            types::Number::new(SourceInformation::dummy()),
+                            definition_source_information.clone(),
+                        )
+                        .into()],
+                        Variable::new("x", variable_source_information.clone()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into(),
+            ])),
+            vec![(variable_source_information, definition_source_information)]
+        );
+    }
+
+    #[test]
+    fn collect_definitions_of_lambda_arguments() {
+        let lambda_source_information = create_source_information(2, 5);
+        let variable_source_information = create_source_information(2, 12);
+
+        assert_eq!(
+            VariableDefinitionCollector::new().collect(&Module::from_definitions(vec![
+                VariableDefinition::new(
+                    "f",
+                    Lambda::new(
+                        vec!["x".into()],
+                        Variable::new("x", variable_source_information.clone()),
+                        types::Unknown::new(SourceInformation::dummy()),
+                        lambda_source_information.clone(),
+                    ),
+                    types::Unknown::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            ])),
+            vec![(variable_source_information, lambda_source_information)]
+        );
+    }
+}
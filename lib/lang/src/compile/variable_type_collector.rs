@@ -0,0 +1,288 @@
+use super::{
+    error::CompileError, module_environment_creator::ModuleEnvironmentCreator,
+    reference_type_resolver::ReferenceTypeResolver,
+};
+use crate::{ast::*, debug::SourceInformation, types::Type};
+use std::{collections::HashMap, sync::Arc};
+
+// Types of variables are collected from typed modules so that editors can show
+// them at their source locations.
+pub struct VariableTypeCollector {
+    reference_type_resolver: Arc<ReferenceTypeResolver>,
+    module_environment_creator: Arc<ModuleEnvironmentCreator>,
+}
+
+impl VariableTypeCollector {
+    pub fn new(
+        reference_type_resolver: Arc<ReferenceTypeResolver>,
+        module_environment_creator: Arc<ModuleEnvironmentCreator>,
+    ) -> Arc<Self> {
+        Self {
+            reference_type_resolver,
+            module_environment_creator,
+        }
+        .into()
+    }
+
+    pub fn collect(
+        &self,
+        module: &Module,
+    ) -> Result<Vec<(Arc<SourceInformation>, Type)>, CompileError> {
+        let variables = self.module_environment_creator.create(module);
+        let mut types = vec![];
+
+        for definition in module.definitions() {
+            self.collect_from_definition(definition, &variables, &mut types)?;
+        }
+
+        Ok(types)
+    }
+
+    fn collect_from_definition(
+        &self,
+        definition: &Definition,
+        variables: &HashMap<String, Type>,
+        types: &mut Vec<(Arc<SourceInformation>, Type)>,
+    ) -> Result<(), CompileError> {
+        match definition {
+            Definition::FunctionDefinition(function_definition) => self.collect_from_function(
+                function_definition.arguments(),
+                function_definition.body(),
+                function_definition.type_(),
+                variables,
+                types,
+            ),
+            Definition::VariableDefinition(variable_definition) => {
+                self.collect_from_expression(variable_definition.body(), variables, types)
+            }
+        }
+    }
+
+    fn collect_from_function(
+        &self,
+        arguments: &[String],
+        body: &Expression,
+        type_: &Type,
+        variables: &HashMap<String, Type>,
+        types: &mut Vec<(Arc<SourceInformation>, Type)>,
+    ) -> Result<(), CompileError> {
+        let mut variables = variables.clone();
+        let mut type_ = type_.clone();
+
+        for argument in arguments {
+            let function_type = self
+                .reference_type_resolver
+                .resolve_to_function(&type_)?
+                .ok_or_else(|| {
+                    CompileError::FunctionExpected(type_.source_information().clone())
+                })?;
+
+            variables.insert(argument.into(), function_type.argument().clone());
+            type_ = function_type.result().clone();
+        }
+
+        self.collect_from_expression(body, &variables, types)
+    }
+
+    fn collect_from_expression(
+        &self,
+        expression: &Expression,
+        variables: &HashMap<String, Type>,
+        types: &mut Vec<(Arc<SourceInformation>, Type)>,
+    ) -> Result<(), CompileError> {
+        match expression {
+            Expression::Application(application) => {
+                self.collect_from_expression(application.function(), variables, types)?;
+                self.collect_from_expression(application.argument(), variables, types)?;
+            }
+            Expression::Array(array) => {
+                for element in array.elements() {
+                    self.collect_from_expression(element, variables, types)?;
+                }
+            }
+            Expression::Case(case) => {
+                self.collect_from_expression(case.argument(), variables, types)?;
+
+                for alternative in case.alternatives() {
+                    let mut variables = variables.clone();
+
+                    variables.insert(case.name().into(), alternative.type_().clone());
+
+                    self.collect_from_expression(alternative.expression(), &variables, types)?;
+                }
+            }
+            Expression::If(if_) => {
+                self.collect_from_expression(if_.condition(), variables, types)?;
+                self.collect_from_expression(if_.then(), variables, types)?;
+                self.collect_from_expression(if_.else_(), variables, types)?;
+            }
+            Expression::Lambda(lambda) => self.collect_from_function(
+                lambda.arguments(),
+                lambda.body(),
+                lambda.type_(),
+                variables,
+                types,
+            )?,
+            Expression::Let(let_) => {
+                let mut variables = variables.clone();
+
+                for definition in let_.definitions() {
+                    if let Definition::FunctionDefinition(_) = definition {
+                        variables.insert(definition.name().into(), definition.type_().clone());
+                    }
+
+                    self.collect_from_definition(definition, &variables, types)?;
+
+                    variables.insert(definition.name().into(), definition.type_().clone());
+                }
+
+                self.collect_from_expression(let_.expression(), &variables, types)?;
+            }
+            Expression::LetError(let_error) => {
+                let mut variables = variables.clone();
+
+                for definition in let_error.definitions() {
+                    self.collect_from_expression(definition.body(), &variables, types)?;
+
+                    variables.insert(definition.name().into(), definition.type_().clone());
+                }
+
+                self.collect_from_expression(let_error.expression(), &variables, types)?;
+            }
+            Expression::List(list) => {
+                for element in list.elements() {
+                    match element {
+                        ListElement::Multiple(expression) | ListElement::Single(expression) => {
+                            self.collect_from_expression(expression, variables, types)?
+                        }
+                    }
+                }
+            }
+            Expression::ListCase(case) => {
+                self.collect_from_expression(case.argument(), variables, types)?;
+                self.collect_from_expression(case.empty_alternative(), variables, types)?;
+
+                let mut variables = variables.clone();
+
+                if let Some(list_type) =
+                    self.reference_type_resolver.resolve_to_list(case.type_())?
+                {
+                    variables.insert(case.first_name().into(), list_type.element().clone());
+                }
+
+                variables.insert(case.rest_name().into(), case.type_().clone());
+
+                self.collect_from_expression(case.non_empty_alternative(), &variables, types)?;
+            }
+            Expression::Map(map) => {
+                for element in map.elements() {
+                    self.collect_from_expression(element.key(), variables, types)?;
+                    self.collect_from_expression(element.value(), variables, types)?;
+                }
+            }
+            Expression::Operation(operation) => {
+                self.collect_from_expression(operation.lhs(), variables, types)?;
+                self.collect_from_expression(operation.rhs(), variables, types)?;
+            }
+            Expression::RecordConstruction(record_construction) => {
+                for expression in record_construction.elements().values() {
+                    self.collect_from_expression(expression, variables, types)?;
+                }
+            }
+            Expression::RecordElementOperation(operation) => {
+                self.collect_from_expression(operation.argument(), variables, types)?;
+            }
+            Expression::RecordUpdate(record_update) => {
+                self.collect_from_expression(record_update.argument(), variables, types)?;
+
+                for expression in record_update.elements().values() {
+                    self.collect_from_expression(expression, variables, types)?;
+                }
+            }
+            Expression::TypeCoercion(coercion) => {
+                self.collect_from_expression(coercion.argument(), variables, types)?;
+            }
+            Expression::Variable(variable) => {
+                if let Some(type_) = variables.get(variable.name()) {
+                    types.push((variable.source_information().clone(), type_.clone()));
+                }
+            }
+            Expression::Boolean(_)
+            | Expression::None(_)
+            | Expression::Number(_)
+            | Expression::String(_) => {}
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::Location, types};
+    use pretty_assertions::assert_eq;
+
+    fn collect(module: &Module) -> Vec<(Arc<SourceInformation>, Type)> {
+        VariableTypeCollector::new(
+            ReferenceTypeResolver::new(module),
+            ModuleEnvironmentCreator::new(),
+        )
+        .collect(module)
+        .unwrap()
+    }
+
+    #[test]
+    fn collect_types_of_function_arguments() {
+        let source_information = Arc::new(SourceInformation::new("", Location::new(2, 7), ""));
+
+        let types = collect(&Module::from_definitions(vec![FunctionDefinition::new(
+            "f",
+            vec!["x".into()],
+            Variable::new("x", source_information.clone()),
+            types::Function::new(
+                types::Number::new(SourceInformation::dummy()),
+                types::Number::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            ),
+            SourceInformation::dummy(),
+        )
+        .into()]));
+
+        assert_eq!(
+            types,
+            vec![(
+                source_information,
+                types::Number::new(SourceInformation::dummy()).into()
+            )]
+        );
+        assert_eq!(types[0].0.location().column_number(), 7);
+    }
+
+    #[test]
+    fn collect_types_of_let_definitions() {
+        assert_eq!(
+            collect(&Module::from_definitions(vec![VariableDefinition::new(
+                "x",
+                Let::new(
+                    vec![VariableDefinition::new(
+                        "y",
+                        None::new(SourceInformation::dummy()),
+                        types::None::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    )
+                    .into()],
+                    Variable::new("y", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                types::None::new(SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )
+            .into()])),
+            vec![(
+                SourceInformation::dummy().into(),
+                types::None::new(SourceInformation::dummy()).into()
+            )]
+        );
+    }
+}
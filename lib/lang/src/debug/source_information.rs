@@ -26,6 +26,13 @@ pub struct SourceInformation {
         PartialEq = "ignore",
         PartialOrd = "ignore"
     )]
+    end_location: Location,
+    #[derivative(
+        Hash = "ignore",
+        Ord = "ignore",
+        PartialEq = "ignore",
+        PartialOrd = "ignore"
+    )]
     line: String,
 }
 
@@ -34,14 +41,53 @@ impl SourceInformation {
         source_name: impl Into<String>,
         location: Location,
         line: impl Into<String>,
+    ) -> Self {
+        Self::with_end_location(source_name, location, location, line)
+    }
+
+    pub fn with_end_location(
+        source_name: impl Into<String>,
+        location: Location,
+        end_location: Location,
+        line: impl Into<String>,
     ) -> Self {
         Self {
             source_name: source_name.into(),
             location,
+            end_location,
             line: line.into(),
         }
     }
 
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    pub fn location(&self) -> Location {
+        self.location
+    }
+
+    pub fn end_location(&self) -> Location {
+        self.end_location
+    }
+
+    pub fn line(&self) -> &str {
+        &self.line
+    }
+
+    // Spans over multiple lines are underlined until the ends of their first lines.
+    fn underline_length(&self) -> usize {
+        let line_length = self.line.chars().count() + 1;
+
+        if self.end_location.line_number() == self.location.line_number() {
+            self.end_location.column_number().min(line_length)
+        } else {
+            line_length
+        }
+        .saturating_sub(self.location.column_number())
+        .max(1)
+    }
+
     #[cfg(test)]
     pub fn dummy() -> Self {
         Self::new("", Location::new(0, 0), "")
@@ -58,12 +104,13 @@ impl Display for SourceInformation {
 
         write!(
             formatter,
-            "{}\n{}\t{}\n{}\t{}^",
+            "{}\n{}\t{}\n{}\t{}{}",
             self.source_name,
             &line_information,
             self.line,
             str::repeat(" ", line_information.len()),
-            str::repeat(" ", self.location.column_number() - 1),
+            str::repeat(" ", self.location.column_number().saturating_sub(1)),
+            str::repeat("^", self.underline_length()),
         )
     }
 }
@@ -90,4 +137,36 @@ mod tests {
             "file\n1:2:\t x\n    \t ^"
         );
     }
+
+    #[test]
+    fn display_span() {
+        assert_eq!(
+            format!(
+                "{}",
+                SourceInformation::with_end_location(
+                    "file",
+                    Location::new(1, 2),
+                    Location::new(1, 5),
+                    " foo x"
+                )
+            ),
+            "file\n1:2:\t foo x\n    \t ^^^"
+        );
+    }
+
+    #[test]
+    fn display_span_over_multiple_lines() {
+        assert_eq!(
+            format!(
+                "{}",
+                SourceInformation::with_end_location(
+                    "file",
+                    Location::new(1, 2),
+                    Location::new(2, 2),
+                    " foo"
+                )
+            ),
+            "file\n1:2:\t foo\n    \t ^^^"
+        );
+    }
 }
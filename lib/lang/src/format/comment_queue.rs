@@ -0,0 +1,79 @@
+use crate::ast::Comment;
+use std::{cell::RefCell, collections::VecDeque};
+
+// Comments are taken in source order while statements and expressions are
+// formatted.
+pub struct CommentQueue {
+    comments: RefCell<VecDeque<Comment>>,
+}
+
+impl CommentQueue {
+    pub fn new(comments: &[Comment]) -> Self {
+        let mut comments = comments.to_vec();
+
+        comments.sort_by_key(|comment| get_line(comment));
+
+        Self {
+            comments: RefCell::new(comments.into()),
+        }
+    }
+
+    // Takes comments on lines before a line.
+    pub fn take_before(&self, line: usize) -> Vec<Comment> {
+        self.take_until(line.saturating_sub(1))
+    }
+
+    // Takes comments on lines before or at a line.
+    pub fn take_until(&self, line: usize) -> Vec<Comment> {
+        let mut comments = self.comments.borrow_mut();
+        let mut taken = vec![];
+
+        while let Some(comment) = comments.front() {
+            if get_line(comment) > line {
+                break;
+            }
+
+            taken.extend(comments.pop_front());
+        }
+
+        taken
+    }
+
+    pub fn take_all(&self) -> Vec<Comment> {
+        self.comments.borrow_mut().drain(..).collect()
+    }
+
+    pub fn exists_until(&self, line: usize) -> bool {
+        self.comments
+            .borrow()
+            .front()
+            .map(|comment| get_line(comment) <= line)
+            .unwrap_or(false)
+    }
+}
+
+fn get_line(comment: &Comment) -> usize {
+    comment.source_information().location().line_number()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::debug::{Location, SourceInformation};
+
+    fn create_comment(line: usize) -> Comment {
+        Comment::new("", SourceInformation::new("", Location::new(line, 1), ""))
+    }
+
+    #[test]
+    fn take_comments_in_source_order() {
+        let comments = vec![create_comment(3), create_comment(1), create_comment(2)];
+        let queue = CommentQueue::new(&comments);
+
+        assert_eq!(queue.take_before(2), vec![comments[1].clone()]);
+        assert!(queue.exists_until(2));
+        assert_eq!(queue.take_until(2), vec![comments[2].clone()]);
+        assert!(!queue.exists_until(2));
+        assert_eq!(queue.take_all(), vec![comments[0].clone()]);
+    }
+}
@@ -0,0 +1,1430 @@
+use super::comment_queue::CommentQueue;
+use crate::{ast::*, debug::SourceInformation, types::Type};
+
+const INDENT: &str = "  ";
+const MAX_LINE_WIDTH: usize = 80;
+
+pub fn format_module(module: &UnresolvedModule, comments: &[Comment]) -> String {
+    let comments = CommentQueue::new(comments);
+
+    let sections: Vec<(Vec<String>, &str)> = vec![
+        (
+            format_export(module.export())
+                .map(|string| {
+                    format_statement(module.export().source_information(), 0, &comments, || {
+                        string
+                    })
+                })
+                .into_iter()
+                .collect(),
+            "",
+        ),
+        (
+            format_export_foreign(module.export_foreign())
+                .map(|string| {
+                    format_statement(
+                        module.export_foreign().source_information(),
+                        0,
+                        &comments,
+                        || string,
+                    )
+                })
+                .into_iter()
+                .collect(),
+            "",
+        ),
+        (
+            module
+                .imports()
+                .iter()
+                .map(|import| {
+                    format_statement(import.source_information(), 0, &comments, || {
+                        format_import(import)
+                    })
+                })
+                .collect(),
+            "\n",
+        ),
+        (
+            module
+                .import_foreigns()
+                .iter()
+                .map(|import_foreign| {
+                    format_statement(import_foreign.source_information(), 0, &comments, || {
+                        format_import_foreign(import_foreign)
+                    })
+                })
+                .collect(),
+            "\n",
+        ),
+        (
+            module
+                .type_definitions()
+                .iter()
+                .map(|type_definition| {
+                    format_statement(type_definition.source_information(), 0, &comments, || {
+                        format_type_definition(type_definition, &comments)
+                    })
+                })
+                .collect(),
+            "\n\n",
+        ),
+        (
+            module
+                .definitions()
+                .iter()
+                .map(|definition| {
+                    format_statement(definition.source_information(), 0, &comments, || {
+                        format_definition(definition, 0, &comments)
+                    })
+                })
+                .collect(),
+            "\n\n",
+        ),
+    ];
+
+    let mut strings = sections
+        .into_iter()
+        .filter(|(statements, _)| !statements.is_empty())
+        .map(|(statements, separator)| statements.join(separator))
+        .collect::<Vec<_>>();
+
+    let comments = comments
+        .take_all()
+        .into_iter()
+        .map(|comment| format_comment(&comment))
+        .collect::<Vec<_>>();
+
+    if !comments.is_empty() {
+        strings.push(comments.join("\n"));
+    }
+
+    if strings.is_empty() {
+        String::new()
+    } else {
+        strings.join("\n\n") + "\n"
+    }
+}
+
+// Statements and definitions in let expressions are formatted together with
+// comments before them and ones at their last lines.
+fn format_statement(
+    source_information: &SourceInformation,
+    indent: usize,
+    comments: &CommentQueue,
+    format: impl FnOnce() -> String,
+) -> String {
+    let (start_line, end_line) = get_line_range(source_information);
+    let leading_comments = format_leading_comments(comments, start_line, indent);
+    let statement = format();
+
+    leading_comments + &statement + &format_trailing_comments(comments, end_line, indent)
+}
+
+fn format_comment(comment: &Comment) -> String {
+    format!("#{}", comment.content().trim_end())
+}
+
+// Comments before a line are placed on their own lines before it.
+fn format_leading_comments(comments: &CommentQueue, line: usize, indent: usize) -> String {
+    comments
+        .take_before(line)
+        .into_iter()
+        .map(|comment| format_comment(&comment) + &create_newline(indent))
+        .collect()
+}
+
+// Comments up to a line are placed at the end of the line. They must be
+// followed by new lines.
+fn format_trailing_comments(comments: &CommentQueue, line: usize, indent: usize) -> String {
+    comments
+        .take_until(line)
+        .into_iter()
+        .enumerate()
+        .map(|(index, comment)| {
+            (if index == 0 {
+                " ".into()
+            } else {
+                create_newline(indent)
+            }) + &format_comment(&comment)
+        })
+        .collect()
+}
+
+// Comments on the last lines of expressions always follow them.
+fn has_inner_comments(comments: &CommentQueue, expression: &Expression) -> bool {
+    comments.exists_until(get_expression_line_range(expression).1.saturating_sub(1))
+}
+
+fn format_export(export: &Export) -> Option<String> {
+    format_names("export", export.names().iter())
+}
+
+fn format_export_foreign(export_foreign: &ExportForeign) -> Option<String> {
+    format_names("export foreign", export_foreign.names().iter())
+}
+
+fn format_names<'a>(head: &str, names: impl IntoIterator<Item = &'a String>) -> Option<String> {
+    let mut names = names.into_iter().collect::<Vec<_>>();
+    names.sort();
+
+    if names.is_empty() {
+        None
+    } else {
+        Some(format_names_in_order(head, &names))
+    }
+}
+
+fn format_names_in_order(head: &str, names: &[&String]) -> String {
+    let string = format!(
+        "{} {{ {} }}",
+        head,
+        names
+            .iter()
+            .map(|name| name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    if is_fit(0, &string) {
+        string
+    } else {
+        format!(
+            "{} {{{}\n}}",
+            head,
+            names
+                .iter()
+                .map(|name| format!("{}{},", create_newline(1), name))
+                .collect::<String>()
+        )
+    }
+}
+
+fn format_import(import: &UnresolvedImport) -> String {
+    let head = ["import".into()]
+        .iter()
+        .cloned()
+        .chain(import.alias().map(String::from))
+        .chain(vec![format!("\"{}\"", import.module_path())])
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    if import.names().is_empty() {
+        head
+    } else {
+        format_names_in_order(&head, &import.names().iter().collect::<Vec<_>>())
+    }
+}
+
+fn format_import_foreign(import_foreign: &ImportForeign) -> String {
+    format!(
+        "import foreign {}{} : {}",
+        match import_foreign.calling_convention() {
+            CallingConvention::C => "\"c\" ",
+            CallingConvention::Native => "",
+        },
+        import_foreign.name(),
+        import_foreign.type_()
+    )
+}
+
+fn format_type_definition(type_definition: &TypeDefinition, comments: &CommentQueue) -> String {
+    let head = ["type", type_definition.name()]
+        .iter()
+        .cloned()
+        .chain(type_definition.type_parameters().iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    match type_definition.type_() {
+        Type::Record(record) if record.name() == type_definition.name() => {
+            if record.elements().is_empty() {
+                return head;
+            }
+
+            let string = format!(
+                "{} {{ {} }}",
+                head,
+                record
+                    .elements()
+                    .iter()
+                    .map(|element| format!("{} : {}", element.name(), element.type_()))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+
+            if is_fit(0, &string)
+                && !comments.exists_until(
+                    get_line_range(type_definition.source_information())
+                        .1
+                        .saturating_sub(1),
+                )
+            {
+                string
+            } else {
+                format!(
+                    "{} {{{}\n}}",
+                    head,
+                    record
+                        .elements()
+                        .iter()
+                        .map(|element| {
+                            let line = element
+                                .type_()
+                                .source_information()
+                                .location()
+                                .line_number();
+
+                            format!(
+                                "{}{}{} : {},{}",
+                                create_newline(1),
+                                format_leading_comments(comments, line, 1),
+                                element.name(),
+                                element.type_(),
+                                format_trailing_comments(comments, line, 1),
+                            )
+                        })
+                        .collect::<String>()
+                )
+            }
+        }
+        type_ => format!("{} = {}", head, type_),
+    }
+}
+
+fn format_definition(definition: &Definition, indent: usize, comments: &CommentQueue) -> String {
+    match definition {
+        Definition::FunctionDefinition(function_definition) => format_typed_definition(
+            function_definition.name(),
+            function_definition.arguments(),
+            "=",
+            function_definition.body(),
+            function_definition.type_(),
+            function_definition.source_information(),
+            indent,
+            comments,
+        ),
+        Definition::VariableDefinition(variable_definition) => {
+            format_variable_definition(variable_definition, "=", indent, comments)
+        }
+    }
+}
+
+fn format_variable_definition(
+    variable_definition: &VariableDefinition,
+    sign: &str,
+    indent: usize,
+    comments: &CommentQueue,
+) -> String {
+    format_typed_definition(
+        variable_definition.name(),
+        &[],
+        sign,
+        variable_definition.body(),
+        variable_definition.type_(),
+        variable_definition.source_information(),
+        indent,
+        comments,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn format_typed_definition(
+    name: &str,
+    arguments: &[String],
+    sign: &str,
+    body: &Expression,
+    type_: &Type,
+    source_information: &SourceInformation,
+    indent: usize,
+    comments: &CommentQueue,
+) -> String {
+    let head = [name]
+        .iter()
+        .cloned()
+        .chain(arguments.iter().map(String::as_str))
+        .chain(vec![sign])
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    match type_ {
+        Type::Unknown(_) => format_assignment(&head, body, indent, indent * INDENT.len(), comments),
+        _ => {
+            let start_line = source_information.location().line_number();
+            let annotation = format!(
+                "{} : {}{}",
+                name,
+                type_,
+                format_trailing_comments(comments, start_line, indent)
+            );
+
+            // Comments right after type annotations are placed before heads.
+            let mut head_comments = String::new();
+            let body_line = get_expression_line_range(body).0;
+            let mut head_line = start_line + 1;
+
+            while head_line < body_line && comments.exists_until(head_line) {
+                head_comments += &format_leading_comments(comments, head_line + 1, indent);
+                head_line += 1;
+            }
+
+            format!(
+                "{}{}{}{}",
+                annotation,
+                create_newline(indent),
+                head_comments,
+                format_assignment(&head, body, indent, indent * INDENT.len(), comments)
+            )
+        }
+    }
+}
+
+// Assignments are expressions preceded by heads like `x =` or `Foo =>`. Their
+// expressions are moved to next lines if they do not fit in lines or comments
+// precede or are inside them.
+fn format_assignment(
+    head: &str,
+    expression: &Expression,
+    indent: usize,
+    column: usize,
+    comments: &CommentQueue,
+) -> String {
+    match format_flat_expression(expression) {
+        Some(string)
+            if is_fit(column + head.chars().count() + 1, &string)
+                && !has_inner_comments(comments, expression) =>
+        {
+            format!("{} {}", head, string)
+        }
+        _ => format!(
+            "{}{}{}",
+            head,
+            create_newline(indent + 1),
+            format_line(expression, indent + 1, comments)
+        ),
+    }
+}
+
+// Expressions at the beginning of lines are preceded by comments before them.
+fn format_line(expression: &Expression, indent: usize, comments: &CommentQueue) -> String {
+    format_leading_comments(comments, get_expression_line_range(expression).0, indent)
+        + &format_expression(expression, indent, indent * INDENT.len(), comments)
+}
+
+// Continuation lines of formatted expressions are indented by `indent` levels
+// while their first lines start at `column`.
+fn format_expression(
+    expression: &Expression,
+    indent: usize,
+    column: usize,
+    comments: &CommentQueue,
+) -> String {
+    if let Some(string) = format_flat_expression(expression) {
+        if is_fit(column, &string) && !has_inner_comments(comments, expression) {
+            return string;
+        }
+    }
+
+    let element_column = (indent + 1) * INDENT.len();
+
+    match expression {
+        Expression::Application(application) => {
+            let (function, arguments) = flatten_application(application);
+
+            format_parenthesized_expression(
+                function,
+                !is_atomic_expression(function),
+                indent,
+                column,
+                comments,
+            ) + &arguments
+                .iter()
+                .enumerate()
+                .map(|(index, argument)| {
+                    let (start_line, end_line) = get_expression_line_range(argument);
+
+                    create_newline(indent + 1)
+                        + &format_leading_comments(comments, start_line, indent + 1)
+                        + &format_parenthesized_expression(
+                            argument,
+                            !is_atomic_expression(argument),
+                            indent + 1,
+                            element_column,
+                            comments,
+                        )
+                        + &if index + 1 < arguments.len() {
+                            format_trailing_comments(comments, end_line, indent + 1)
+                        } else {
+                            String::new()
+                        }
+                })
+                .collect::<String>()
+        }
+        Expression::Array(array) => format_elements(
+            "Array[",
+            "]",
+            array.elements().iter().map(|element| {
+                (
+                    format_line(element, indent + 1, comments),
+                    get_expression_line_range(element).1,
+                )
+            }),
+            indent,
+            comments,
+        ),
+        Expression::Case(case) => {
+            let head = format!("case {} =", case.name());
+
+            format!(
+                "{} {}{}",
+                head,
+                format_parenthesized_expression(
+                    case.argument(),
+                    ends_with_case(case.argument()),
+                    indent + 1,
+                    column + head.chars().count() + 1,
+                    comments,
+                ),
+                case.alternatives()
+                    .iter()
+                    .enumerate()
+                    .map(|(index, alternative)| {
+                        format_alternative_line(
+                            &format!("{} =>", alternative.type_()),
+                            alternative.expression(),
+                            index + 1 < case.alternatives().len(),
+                            indent + 1,
+                            comments,
+                        )
+                    })
+                    .collect::<String>()
+            )
+        }
+        Expression::If(if_) => format_if(if_, indent, column, comments),
+        Expression::Lambda(lambda) => format_assignment(
+            &format_lambda_head(lambda),
+            lambda.body(),
+            indent,
+            column,
+            comments,
+        ),
+        Expression::Let(let_) => format_let(
+            let_.definitions().iter().map(|definition| {
+                format_statement(
+                    definition.source_information(),
+                    indent + 1,
+                    comments,
+                    || format_definition(definition, indent + 1, comments),
+                )
+            }),
+            let_.expression(),
+            indent,
+            comments,
+        ),
+        Expression::LetError(let_error) => format_let(
+            let_error.definitions().iter().map(|definition| {
+                format_statement(
+                    definition.source_information(),
+                    indent + 1,
+                    comments,
+                    || format_variable_definition(definition, "?=", indent + 1, comments),
+                )
+            }),
+            let_error.expression(),
+            indent,
+            comments,
+        ),
+        Expression::List(list) => format_elements(
+            "[",
+            "]",
+            list.elements().iter().map(|element| match element {
+                ListElement::Multiple(expression) => {
+                    let (start_line, end_line) = get_expression_line_range(expression);
+
+                    (
+                        format_leading_comments(comments, start_line, indent + 1)
+                            + "..."
+                            + &format_expression(
+                                expression,
+                                indent + 1,
+                                element_column + 3,
+                                comments,
+                            ),
+                        end_line,
+                    )
+                }
+                ListElement::Single(expression) => (
+                    format_line(expression, indent + 1, comments),
+                    get_expression_line_range(expression).1,
+                ),
+            }),
+            indent,
+            comments,
+        ),
+        Expression::ListCase(list_case) => format!(
+            "case {}{}{}",
+            format_parenthesized_expression(
+                list_case.argument(),
+                ends_with_case(list_case.argument()),
+                indent + 1,
+                column + 5,
+                comments,
+            ),
+            format_alternative_line(
+                "[] =>",
+                list_case.empty_alternative(),
+                true,
+                indent + 1,
+                comments
+            ),
+            format_alternative_line(
+                &format!(
+                    "[ {}, ...{} ] =>",
+                    list_case.first_name(),
+                    list_case.rest_name()
+                ),
+                list_case.non_empty_alternative(),
+                false,
+                indent + 1,
+                comments,
+            ),
+        ),
+        Expression::Map(map) => format_elements(
+            "{",
+            "}",
+            map.elements().iter().map(|element| {
+                let start_line = get_expression_line_range(element.key()).0;
+                let leading_comments = format_leading_comments(comments, start_line, indent + 1);
+                let key = format_parenthesized_expression(
+                    element.key(),
+                    !is_atomic_expression(element.key()),
+                    indent + 1,
+                    element_column,
+                    comments,
+                );
+
+                (
+                    leading_comments
+                        + &format_assignment(
+                            &format!("{} :", key),
+                            element.value(),
+                            indent + 1,
+                            element_column,
+                            comments,
+                        ),
+                    get_expression_line_range(element.value()).1,
+                )
+            }),
+            indent,
+            comments,
+        ),
+        Expression::Operation(operation) => {
+            let terms = flatten_operation(operation, true);
+            let mut string = String::new();
+
+            for (index, (_, expression, parenthesized)) in terms.iter().enumerate() {
+                if index > 0 {
+                    string += &create_newline(indent);
+                    string += &format_leading_comments(
+                        comments,
+                        get_expression_line_range(expression).0,
+                        indent,
+                    );
+                }
+
+                string += &format_parenthesized_expression(
+                    expression,
+                    *parenthesized,
+                    indent,
+                    if index == 0 {
+                        column
+                    } else {
+                        indent * INDENT.len()
+                    },
+                    comments,
+                );
+
+                if let Some((Some(operator), _, _)) = terms.get(index + 1) {
+                    string += " ";
+                    string += operator;
+                }
+            }
+
+            string
+        }
+        Expression::RecordConstruction(record_construction) => format_elements(
+            &format!("{}{{", record_construction.type_()),
+            "}",
+            format_record_elements(record_construction.elements(), indent + 1, comments),
+            indent,
+            comments,
+        ),
+        Expression::RecordUpdate(record_update) => {
+            let (start_line, end_line) = get_expression_line_range(record_update.argument());
+
+            format_elements(
+                &format!("{}{{", record_update.type_()),
+                "}",
+                vec![(
+                    format_leading_comments(comments, start_line, indent + 1)
+                        + "..."
+                        + &format_parenthesized_expression(
+                            record_update.argument(),
+                            !is_atomic_expression(record_update.argument()),
+                            indent + 1,
+                            element_column + 3,
+                            comments,
+                        ),
+                    end_line,
+                )]
+                .into_iter()
+                .chain(format_record_elements(
+                    record_update.elements(),
+                    indent + 1,
+                    comments,
+                )),
+                indent,
+                comments,
+            )
+        }
+        Expression::Boolean(_)
+        | Expression::None(_)
+        | Expression::Number(_)
+        | Expression::String(_)
+        | Expression::Variable(_) => format_flat_expression(expression).unwrap(),
+        Expression::RecordElementOperation(_) | Expression::TypeCoercion(_) => unreachable!(),
+    }
+}
+
+// Alternatives are placed on their own lines. Comments at their last lines are
+// kept there only if other alternatives follow them.
+fn format_alternative_line(
+    head: &str,
+    expression: &Expression,
+    followed: bool,
+    indent: usize,
+    comments: &CommentQueue,
+) -> String {
+    let (start_line, end_line) = get_expression_line_range(expression);
+
+    create_newline(indent)
+        + &format_leading_comments(comments, start_line, indent)
+        + &format_alternative(head, expression, followed, indent, comments)
+        + &if followed {
+            format_trailing_comments(comments, end_line, indent)
+        } else {
+            String::new()
+        }
+}
+
+fn format_alternative(
+    head: &str,
+    expression: &Expression,
+    followed: bool,
+    indent: usize,
+    comments: &CommentQueue,
+) -> String {
+    // Alternatives ending with case expressions are parenthesized if they are
+    // followed by other alternatives.
+    if followed && ends_with_case(expression) {
+        format!(
+            "{}{}{}",
+            head,
+            create_newline(indent + 1),
+            format_parenthesized_expression(
+                expression,
+                true,
+                indent + 1,
+                (indent + 1) * INDENT.len(),
+                comments,
+            )
+        )
+    } else {
+        format_assignment(head, expression, indent, indent * INDENT.len(), comments)
+    }
+}
+
+fn format_if(if_: &If, indent: usize, column: usize, comments: &CommentQueue) -> String {
+    let condition = match format_flat_expression(if_.condition()) {
+        Some(string)
+            if is_fit(column + 3, &(string.clone() + " then"))
+                && !has_inner_comments(comments, if_.condition()) =>
+        {
+            format!("if {} then", string)
+        }
+        _ => format!(
+            "if{}{}{}{}then",
+            create_newline(indent + 1),
+            format_line(if_.condition(), indent + 1, comments),
+            format_trailing_comments(
+                comments,
+                get_expression_line_range(if_.condition()).1,
+                indent + 1
+            ),
+            create_newline(indent),
+        ),
+    };
+
+    format!(
+        "{}{}{}{}{}else{}",
+        condition,
+        create_newline(indent + 1),
+        format_line(if_.then(), indent + 1, comments),
+        format_trailing_comments(
+            comments,
+            get_expression_line_range(if_.then()).1,
+            indent + 1
+        ),
+        create_newline(indent),
+        match if_.else_() {
+            Expression::If(else_) => format!(
+                " {}",
+                format_if(else_, indent, indent * INDENT.len() + 5, comments)
+            ),
+            else_ => format!(
+                "{}{}",
+                create_newline(indent + 1),
+                format_line(else_, indent + 1, comments)
+            ),
+        }
+    )
+}
+
+fn format_let(
+    definitions: impl IntoIterator<Item = String>,
+    expression: &Expression,
+    indent: usize,
+    comments: &CommentQueue,
+) -> String {
+    format!(
+        "let{}{}in{}{}",
+        definitions
+            .into_iter()
+            .map(|definition| create_newline(indent + 1) + &definition)
+            .collect::<String>(),
+        create_newline(indent),
+        create_newline(indent + 1),
+        format_line(expression, indent + 1, comments)
+    )
+}
+
+fn format_lambda_head(lambda: &Lambda) -> String {
+    format!("\\{} ->", lambda.arguments().join(" "))
+}
+
+fn format_record_elements<'a>(
+    elements: &'a std::collections::BTreeMap<String, Expression>,
+    indent: usize,
+    comments: &'a CommentQueue,
+) -> impl Iterator<Item = (String, usize)> + 'a {
+    elements.iter().map(move |(name, expression)| {
+        let (start_line, end_line) = get_expression_line_range(expression);
+
+        (
+            format_leading_comments(comments, start_line, indent)
+                + &format_assignment(
+                    &format!("{} =", name),
+                    expression,
+                    indent,
+                    indent * INDENT.len(),
+                    comments,
+                ),
+            end_line,
+        )
+    })
+}
+
+// Elements are formatted lazily so that comments at the end of their last
+// lines are taken in source order.
+fn format_elements(
+    open: &str,
+    close: &str,
+    elements: impl IntoIterator<Item = (String, usize)>,
+    indent: usize,
+    comments: &CommentQueue,
+) -> String {
+    format!(
+        "{}{}{}{}",
+        open,
+        elements
+            .into_iter()
+            .map(|(element, end_line)| format!(
+                "{}{},{}",
+                create_newline(indent + 1),
+                element,
+                format_trailing_comments(comments, end_line, indent + 1)
+            ))
+            .collect::<String>(),
+        create_newline(indent),
+        close
+    )
+}
+
+fn format_parenthesized_expression(
+    expression: &Expression,
+    parenthesized: bool,
+    indent: usize,
+    column: usize,
+    comments: &CommentQueue,
+) -> String {
+    if parenthesized {
+        format!(
+            "({})",
+            format_expression(expression, indent, column + 1, comments)
+        )
+    } else {
+        format_expression(expression, indent, column, comments)
+    }
+}
+
+// Flat expressions are formatted in single lines. `None` is returned for
+// expressions which always span multiple lines.
+fn format_flat_expression(expression: &Expression) -> Option<String> {
+    Some(match expression {
+        Expression::Application(application) => {
+            let (function, arguments) = flatten_application(application);
+
+            vec![format_flat_parenthesized_expression(
+                function,
+                !is_atomic_expression(function),
+            )?]
+            .into_iter()
+            .chain(
+                arguments
+                    .iter()
+                    .map(|argument| {
+                        format_flat_parenthesized_expression(
+                            argument,
+                            !is_atomic_expression(argument),
+                        )
+                    })
+                    .collect::<Option<Vec<_>>>()?,
+            )
+            .collect::<Vec<_>>()
+            .join(" ")
+        }
+        Expression::Array(array) => format_flat_elements(
+            "Array[",
+            "]",
+            array
+                .elements()
+                .iter()
+                .map(format_flat_expression)
+                .collect::<Option<Vec<_>>>()?,
+        ),
+        Expression::Boolean(boolean) => if boolean.value() { "True" } else { "False" }.into(),
+        Expression::Lambda(lambda) => format!(
+            "{} {}",
+            format_lambda_head(lambda),
+            format_flat_expression(lambda.body())?
+        ),
+        Expression::List(list) => format_flat_elements(
+            "[",
+            "]",
+            list.elements()
+                .iter()
+                .map(|element| match element {
+                    ListElement::Multiple(expression) => {
+                        Some("...".to_owned() + &format_flat_expression(expression)?)
+                    }
+                    ListElement::Single(expression) => format_flat_expression(expression),
+                })
+                .collect::<Option<Vec<_>>>()?,
+        ),
+        Expression::Map(map) => format_flat_elements(
+            "{",
+            "}",
+            map.elements()
+                .iter()
+                .map(|element| {
+                    Some(format!(
+                        "{} : {}",
+                        format_flat_parenthesized_expression(
+                            element.key(),
+                            !is_atomic_expression(element.key()),
+                        )?,
+                        format_flat_expression(element.value())?
+                    ))
+                })
+                .collect::<Option<Vec<_>>>()?,
+        ),
+        Expression::None(_) => "None".into(),
+        Expression::Number(number) => format!("{}", number.value()),
+        Expression::Operation(operation) => {
+            let mut string = String::new();
+
+            for (operator, expression, parenthesized) in flatten_operation(operation, true) {
+                if let Some(operator) = operator {
+                    string += " ";
+                    string += operator;
+                    string += " ";
+                }
+
+                string += &format_flat_parenthesized_expression(expression, parenthesized)?;
+            }
+
+            string
+        }
+        Expression::RecordConstruction(record_construction) => format_flat_elements(
+            &format!("{}{{", record_construction.type_()),
+            "}",
+            format_flat_record_elements(record_construction.elements())?,
+        ),
+        Expression::RecordUpdate(record_update) => format_flat_elements(
+            &format!("{}{{", record_update.type_()),
+            "}",
+            vec![
+                "...".to_owned()
+                    + &format_flat_parenthesized_expression(
+                        record_update.argument(),
+                        !is_atomic_expression(record_update.argument()),
+                    )?,
+            ]
+            .into_iter()
+            .chain(format_flat_record_elements(record_update.elements())?)
+            .collect(),
+        ),
+        Expression::String(string) => format_string(string.value()),
+        Expression::Variable(variable) => variable.name().into(),
+        Expression::Case(_)
+        | Expression::If(_)
+        | Expression::Let(_)
+        | Expression::LetError(_)
+        | Expression::ListCase(_) => return None,
+        Expression::RecordElementOperation(_) | Expression::TypeCoercion(_) => unreachable!(),
+    })
+}
+
+fn format_flat_parenthesized_expression(
+    expression: &Expression,
+    parenthesized: bool,
+) -> Option<String> {
+    let string = format_flat_expression(expression)?;
+
+    Some(if parenthesized {
+        format!("({})", string)
+    } else {
+        string
+    })
+}
+
+fn format_flat_record_elements(
+    elements: &std::collections::BTreeMap<String, Expression>,
+) -> Option<Vec<String>> {
+    elements
+        .iter()
+        .map(|(name, expression)| {
+            Some(format!(
+                "{} = {}",
+                name,
+                format_flat_expression(expression)?
+            ))
+        })
+        .collect()
+}
+
+fn format_flat_elements(open: &str, close: &str, elements: Vec<String>) -> String {
+    if elements.is_empty() {
+        format!("{}{}", open, close)
+    } else {
+        format!("{} {} {}", open, elements.join(", "), close)
+    }
+}
+
+fn format_string(string: &str) -> String {
+    format!(
+        "\"{}\"",
+        string
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+            .replace('\t', "\\t")
+    )
+}
+
+fn flatten_application(application: &Application) -> (&Expression, Vec<&Expression>) {
+    let mut function = application.function();
+    let mut arguments = vec![application.argument()];
+
+    while let Expression::Application(application) = function {
+        function = application.function();
+        arguments.push(application.argument());
+    }
+
+    arguments.reverse();
+
+    (function, arguments)
+}
+
+// Operations are flattened into sequences of terms with their preceding
+// operators. Right-hand sides of operations are kept unparenthesized only if
+// nothing follows them because operations are parsed left-associatively.
+fn flatten_operation(
+    operation: &Operation,
+    last: bool,
+) -> Vec<(Option<&'static str>, &Expression, bool)> {
+    let (operator, lhs, rhs) = get_operation_parts(operation);
+    let priority = get_operator_priority(operator);
+
+    let mut terms = match lhs {
+        Expression::Operation(lhs)
+            if get_operator_priority(get_operation_parts(lhs).0) >= priority =>
+        {
+            flatten_operation(lhs, false)
+        }
+        _ => vec![(None, lhs, is_operand_parenthesized(lhs))],
+    };
+
+    match rhs {
+        Expression::Operation(rhs)
+            if last && get_operator_priority(get_operation_parts(rhs).0) > priority =>
+        {
+            let mut rhs_terms = flatten_operation(rhs, true);
+            rhs_terms[0].0 = Some(operator);
+            terms.extend(rhs_terms);
+        }
+        _ => terms.push((Some(operator), rhs, is_operand_parenthesized(rhs))),
+    }
+
+    terms
+}
+
+fn get_operation_parts(operation: &Operation) -> (&'static str, &Expression, &Expression) {
+    match operation {
+        Operation::Arithmetic(operation) => (
+            match operation.operator() {
+                ArithmeticOperator::Add => "+",
+                ArithmeticOperator::Subtract => "-",
+                ArithmeticOperator::Multiply => "*",
+                ArithmeticOperator::Divide => "/",
+            },
+            operation.lhs(),
+            operation.rhs(),
+        ),
+        Operation::Boolean(operation) => (
+            match operation.operator() {
+                BooleanOperator::And => "&&",
+                BooleanOperator::Or => "||",
+            },
+            operation.lhs(),
+            operation.rhs(),
+        ),
+        Operation::Equality(operation) => (
+            match operation.operator() {
+                EqualityOperator::Equal => "==",
+                EqualityOperator::NotEqual => "/=",
+            },
+            operation.lhs(),
+            operation.rhs(),
+        ),
+        Operation::Order(operation) => (
+            match operation.operator() {
+                OrderOperator::LessThan => "<",
+                OrderOperator::LessThanOrEqual => "<=",
+                OrderOperator::GreaterThan => ">",
+                OrderOperator::GreaterThanOrEqual => ">=",
+            },
+            operation.lhs(),
+            operation.rhs(),
+        ),
+        Operation::Pipe(operation) => ("|>", operation.lhs(), operation.rhs()),
+    }
+}
+
+fn get_operator_priority(operator: &str) -> usize {
+    match operator {
+        "|>" => 0,
+        "||" => 1,
+        "&&" => 2,
+        "==" | "/=" | "<" | "<=" | ">" | ">=" => 3,
+        "+" | "-" => 4,
+        "*" | "/" => 5,
+        _ => unreachable!(),
+    }
+}
+
+fn is_operand_parenthesized(expression: &Expression) -> bool {
+    !matches!(expression, Expression::Application(_)) && !is_atomic_expression(expression)
+}
+
+fn is_atomic_expression(expression: &Expression) -> bool {
+    matches!(
+        expression,
+        Expression::Array(_)
+            | Expression::Boolean(_)
+            | Expression::List(_)
+            | Expression::Map(_)
+            | Expression::None(_)
+            | Expression::Number(_)
+            | Expression::RecordConstruction(_)
+            | Expression::RecordUpdate(_)
+            | Expression::String(_)
+            | Expression::Variable(_)
+    )
+}
+
+// Case expressions consume all alternatives following them.
+fn ends_with_case(expression: &Expression) -> bool {
+    match expression {
+        Expression::Case(_) | Expression::ListCase(_) => true,
+        Expression::If(if_) => ends_with_case(if_.else_()),
+        Expression::Lambda(lambda) => ends_with_case(lambda.body()),
+        Expression::Let(let_) => ends_with_case(let_.expression()),
+        Expression::LetError(let_error) => ends_with_case(let_error.expression()),
+        _ => false,
+    }
+}
+
+fn get_line_range(source_information: &SourceInformation) -> (usize, usize) {
+    (
+        source_information.location().line_number(),
+        source_information.end_location().line_number(),
+    )
+}
+
+// Source information of some expressions like operations does not cover their
+// child expressions.
+fn get_expression_line_range(expression: &Expression) -> (usize, usize) {
+    let children: Vec<&Expression> = match expression {
+        Expression::Application(application) => {
+            vec![application.function(), application.argument()]
+        }
+        Expression::Array(array) => array.elements().iter().collect(),
+        Expression::Case(case) => vec![case.argument()]
+            .into_iter()
+            .chain(case.alternatives().iter().map(Alternative::expression))
+            .collect(),
+        Expression::If(if_) => vec![if_.condition(), if_.then(), if_.else_()],
+        Expression::Lambda(lambda) => vec![lambda.body()],
+        Expression::Let(let_) => let_
+            .definitions()
+            .iter()
+            .map(|definition| match definition {
+                Definition::FunctionDefinition(function_definition) => function_definition.body(),
+                Definition::VariableDefinition(variable_definition) => variable_definition.body(),
+            })
+            .chain(vec![let_.expression()])
+            .collect(),
+        Expression::LetError(let_error) => let_error
+            .definitions()
+            .iter()
+            .map(VariableDefinition::body)
+            .chain(vec![let_error.expression()])
+            .collect(),
+        Expression::List(list) => list
+            .elements()
+            .iter()
+            .map(|element| match element {
+                ListElement::Multiple(expression) | ListElement::Single(expression) => expression,
+            })
+            .collect(),
+        Expression::ListCase(list_case) => vec![
+            list_case.argument(),
+            list_case.empty_alternative(),
+            list_case.non_empty_alternative(),
+        ],
+        Expression::Map(map) => map
+            .elements()
+            .iter()
+            .flat_map(|element| vec![element.key(), element.value()])
+            .collect(),
+        Expression::Operation(operation) => {
+            let (_, lhs, rhs) = get_operation_parts(operation);
+
+            vec![lhs, rhs]
+        }
+        Expression::RecordConstruction(record_construction) => {
+            record_construction.elements().values().collect()
+        }
+        Expression::RecordUpdate(record_update) => vec![record_update.argument()]
+            .into_iter()
+            .chain(record_update.elements().values())
+            .collect(),
+        Expression::RecordElementOperation(operation) => vec![operation.argument()],
+        Expression::TypeCoercion(coercion) => vec![coercion.argument()],
+        Expression::Boolean(_)
+        | Expression::None(_)
+        | Expression::Number(_)
+        | Expression::String(_)
+        | Expression::Variable(_) => vec![],
+    };
+
+    children.into_iter().map(get_expression_line_range).fold(
+        get_line_range(expression.source_information()),
+        |(start_line, end_line), (child_start_line, child_end_line)| {
+            (
+                start_line.min(child_start_line),
+                end_line.max(child_end_line),
+            )
+        },
+    )
+}
+
+fn is_fit(column: usize, string: &str) -> bool {
+    !string.contains('\n') && column + string.chars().count() <= MAX_LINE_WIDTH
+}
+
+fn create_newline(indent: usize) -> String {
+    "\n".to_owned() + &INDENT.repeat(indent)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{debug::SourceInformation, types};
+    use pretty_assertions::assert_eq;
+
+    fn format_expression(expression: impl Into<Expression>) -> String {
+        super::format_expression(&expression.into(), 0, 0, &CommentQueue::new(&[]))
+    }
+
+    #[test]
+    fn format_application() {
+        assert_eq!(
+            format_expression(Application::new(
+                Application::new(
+                    Variable::new("f", SourceInformation::dummy()),
+                    Variable::new("x", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                Application::new(
+                    Variable::new("g", SourceInformation::dummy()),
+                    Number::new(42.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )),
+            "f x (g 42)"
+        );
+    }
+
+    #[test]
+    fn format_long_application() {
+        assert_eq!(
+            format_expression(Application::new(
+                Application::new(
+                    Variable::new("f", SourceInformation::dummy()),
+                    Variable::new("x".repeat(40), SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                Variable::new("y".repeat(40), SourceInformation::dummy()),
+                SourceInformation::dummy(),
+            )),
+            format!("f\n  {}\n  {}", "x".repeat(40), "y".repeat(40))
+        );
+    }
+
+    #[test]
+    fn format_operations() {
+        let create_operation = |operator, lhs, rhs| -> Expression {
+            ArithmeticOperation::new(operator, lhs, rhs, SourceInformation::dummy()).into()
+        };
+        let create_variable =
+            |name| -> Expression { Variable::new(name, SourceInformation::dummy()).into() };
+
+        assert_eq!(
+            format_expression(create_operation(
+                ArithmeticOperator::Subtract,
+                create_operation(
+                    ArithmeticOperator::Subtract,
+                    create_variable("a"),
+                    create_variable("b")
+                ),
+                create_variable("c"),
+            )),
+            "a - b - c"
+        );
+        assert_eq!(
+            format_expression(create_operation(
+                ArithmeticOperator::Subtract,
+                create_variable("a"),
+                create_operation(
+                    ArithmeticOperator::Subtract,
+                    create_variable("b"),
+                    create_variable("c")
+                ),
+            )),
+            "a - (b - c)"
+        );
+        assert_eq!(
+            format_expression(create_operation(
+                ArithmeticOperator::Add,
+                create_variable("a"),
+                create_operation(
+                    ArithmeticOperator::Multiply,
+                    create_variable("b"),
+                    create_variable("c")
+                ),
+            )),
+            "a + b * c"
+        );
+        assert_eq!(
+            format_expression(create_operation(
+                ArithmeticOperator::Multiply,
+                create_operation(
+                    ArithmeticOperator::Add,
+                    create_variable("a"),
+                    create_variable("b")
+                ),
+                create_variable("c"),
+            )),
+            "(a + b) * c"
+        );
+    }
+
+    #[test]
+    fn format_if() {
+        assert_eq!(
+            format_expression(If::new(
+                Boolean::new(true, SourceInformation::dummy()),
+                Number::new(1.0, SourceInformation::dummy()),
+                If::new(
+                    Boolean::new(false, SourceInformation::dummy()),
+                    Number::new(2.0, SourceInformation::dummy()),
+                    Number::new(3.0, SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+                SourceInformation::dummy(),
+            )),
+            "if True then\n  1\nelse if False then\n  2\nelse\n  3"
+        );
+    }
+
+    #[test]
+    fn format_nested_case() {
+        assert_eq!(
+            format_expression(Case::new(
+                "x",
+                Variable::new("x", SourceInformation::dummy()),
+                vec![
+                    Alternative::new(
+                        types::Number::new(SourceInformation::dummy()),
+                        Case::new(
+                            "y",
+                            Variable::new("y", SourceInformation::dummy()),
+                            vec![Alternative::new(
+                                types::Number::new(SourceInformation::dummy()),
+                                Variable::new("y", SourceInformation::dummy()),
+                            )],
+                            SourceInformation::dummy(),
+                        ),
+                    ),
+                    Alternative::new(
+                        types::None::new(SourceInformation::dummy()),
+                        Variable::new("x", SourceInformation::dummy()),
+                    ),
+                ],
+                SourceInformation::dummy(),
+            )),
+            "case x = x\n  Number =>\n    (case y = y\n      Number => y)\n  None => x"
+        );
+    }
+
+    #[test]
+    fn format_string_with_escapes() {
+        assert_eq!(
+            format_expression(EinString::new("\"\\\n\t", SourceInformation::dummy())),
+            "\"\\\"\\\\\\n\\t\""
+        );
+    }
+}
@@ -0,0 +1,244 @@
+mod comment_queue;
+mod formatters;
+
+use crate::ast::{Comment, UnresolvedModule};
+
+// Comments are kept before or at the end of lines of statements and expressions
+// which follow or contain them.
+pub fn format(module: &UnresolvedModule, comments: &[Comment]) -> String {
+    formatters::format_module(module, comments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::{parse, parse_comments};
+    use indoc::indoc;
+    use pretty_assertions::assert_eq;
+
+    fn format_source(source: &str) -> String {
+        format(
+            &parse(source, "").unwrap(),
+            &parse_comments(source, "").unwrap(),
+        )
+    }
+
+    fn assert_round_trip(source: &str) {
+        let formatted = format_source(source);
+
+        assert_eq!(parse(&formatted, ""), parse(source, ""));
+        assert_eq!(format_source(&formatted), formatted);
+    }
+
+    #[test]
+    fn format_empty_module() {
+        assert_eq!(format_source(""), "");
+    }
+
+    #[test]
+    fn format_module() {
+        assert_eq!(
+            format_source(indoc!(
+                "
+                export { foo, Foo }
+                import   Bar \"/Bar\"   { bar }
+                import foreign \"c\" baz : Number -> Number
+                type Foo { x : Number, y : Number }
+                type Bar = Number | None
+                foo : Number -> Number
+                foo x = bar (baz x)
+                "
+            )),
+            indoc!(
+                "
+                export { Foo, foo }
+
+                import Bar \"/Bar\" { bar }
+
+                import foreign \"c\" baz : Number -> Number
+
+                type Foo { x : Number, y : Number }
+
+                type Bar = None | Number
+
+                foo : Number -> Number
+                foo x = bar (baz x)
+                "
+            )
+            .trim_start()
+        );
+    }
+
+    #[test]
+    fn format_comments() {
+        assert_eq!(
+            format_source(indoc!(
+                "
+                # foo
+                export { x }
+
+                # bar
+                x : Number
+                x =
+                  # baz
+                  42 # qux
+                # quux
+                "
+            )),
+            indoc!(
+                "
+                # foo
+                export { x }
+
+                # bar
+                x : Number
+                x =
+                  # baz
+                  42 # qux
+
+                # quux
+                "
+            )
+            .trim_start()
+        );
+    }
+
+    #[test]
+    fn format_comments_between_type_definitions_and_definitions() {
+        assert_eq!(
+            format_source(indoc!(
+                "
+                # foo
+                type Foo { x : Number } # bar
+                # baz
+                type Bar = Number
+                # qux
+                x : Number
+                x = 42
+                "
+            )),
+            indoc!(
+                "
+                # foo
+                type Foo { x : Number } # bar
+
+                # baz
+                type Bar = Number
+
+                # qux
+                x : Number
+                x = 42
+                "
+            )
+            .trim_start()
+        );
+    }
+
+    #[test]
+    fn format_comments_in_expressions() {
+        let source = indoc!(
+            "
+            type Foo {
+              # foo
+              x : Number, # bar
+            }
+
+            x : Number # baz
+            # qux
+            x =
+              let
+                # quux
+                y = 1 # corge
+              in
+                [
+                  # grault
+                  y, # garply
+                  2,
+                ]
+            "
+        );
+
+        assert_eq!(format_source(source), source);
+    }
+
+    #[test]
+    fn format_comments_in_flat_expressions() {
+        assert_eq!(
+            format_source(indoc!(
+                "
+                x : Number
+                x = f # foo
+                  42
+                "
+            )),
+            indoc!(
+                "
+                x : Number
+                x =
+                  f
+                    # foo
+                    42
+                "
+            )
+            .trim_start()
+        );
+    }
+
+    #[test]
+    fn format_long_definition() {
+        assert_eq!(
+            format_source(&format!(
+                "x : Number\nx = {} + {}",
+                "a".repeat(40),
+                "b".repeat(40)
+            )),
+            format!(
+                "x : Number\nx =\n  {} +\n  {}\n",
+                "a".repeat(40),
+                "b".repeat(40)
+            )
+        );
+    }
+
+    #[test]
+    fn format_expressions() {
+        assert_round_trip(indoc!(
+            "
+            type Foo { x : Number, y : List (Number | None) }
+
+            x : Number -> Foo
+            x y =
+              let
+                z = \\a -> a + 1 * 2 - 3
+                f a b = a || b && not a
+              in
+                Foo{ x = (if y > 0 then y else z y) |> f, y = [ 1, ...[ None ] ] }
+
+            y : Number | None -> List Number -> Number
+            y z zs =
+              case z = z
+                Number => (
+                  case zs
+                    [] => z
+                    [ w, ...ws ] => w
+                )
+                None => Array.length Array[ 1, 2 ] + { \"a\" : 1, (f x) : 2 }
+            "
+        ));
+    }
+
+    #[test]
+    fn format_prelude_modules() {
+        for source in &[
+            include_str!("../../../prelude/Array.ein"),
+            include_str!("../../../prelude/Boolean.ein"),
+            include_str!("../../../prelude/Error.ein"),
+            include_str!("../../../prelude/Hash.ein"),
+            include_str!("../../../prelude/List.ein"),
+            include_str!("../../../prelude/Map.ein"),
+            include_str!("../../../prelude/String.ein"),
+        ] {
+            assert_round_trip(source);
+        }
+    }
+}
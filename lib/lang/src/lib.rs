@@ -1,18 +1,25 @@
 mod ast;
 mod compile;
 pub mod debug;
+mod format;
 mod package;
 mod parse;
 mod path;
 pub mod types;
 
-pub use ast::{Import, ModuleInterface, UnresolvedModule};
+pub use ast::{Comment, Import, ModuleInterface, UnresolvedModule};
 pub use compile::{
-    compile, CompileConfiguration, ErrorTypeConfiguration, ListTypeConfiguration,
-    MainModuleConfiguration, StringTypeConfiguration,
+    analyze, check, compile, compile_with_intermediate_representations, ArrayTypeConfiguration,
+    CompileConfiguration, CompileError, ErrorTypeConfiguration, IntermediateRepresentation,
+    ListTypeConfiguration, MainModuleConfiguration, MapTypeConfiguration, ModuleAnalysis,
+    StringTypeConfiguration,
 };
+pub use format::format;
 pub use package::Package;
-pub use parse::{parse, ParseError};
+pub use parse::{parse, parse_comments, ParseError, UnexpectedInput};
 pub use path::{
     ExternalUnresolvedModulePath, InternalUnresolvedModulePath, ModulePath, UnresolvedModulePath,
 };
+
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+pub const BUILD_ID: &str = env!("EIN_BUILD_ID");
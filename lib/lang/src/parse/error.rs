@@ -1,28 +1,201 @@
+use crate::debug::{Location, SourceInformation};
+use combine::{
+    easy::{self, Info},
+    stream::position::SourcePosition,
+};
 use std::{error::Error, fmt::Display};
 
+#[derive(Clone, Debug, PartialEq)]
+pub enum UnexpectedInput {
+    EndOfInput,
+    Token(String),
+}
+
 #[derive(Debug, PartialEq)]
 pub struct ParseError {
-    source_name: String,
-    details: String,
+    unexpected: Option<UnexpectedInput>,
+    expected: Vec<String>,
+    messages: Vec<String>,
+    source_information: SourceInformation,
 }
 
 impl ParseError {
-    pub fn new(source_name: &str, errors: &impl std::error::Error) -> Self {
+    pub fn new(
+        source_name: &str,
+        source_content: &str,
+        errors: &easy::Errors<char, &str, SourcePosition>,
+    ) -> Self {
+        let mut unexpected = None;
+        let mut expected = vec![];
+        let mut messages = vec![];
+
+        for error in &errors.errors {
+            match error {
+                easy::Error::Unexpected(_) if error == &easy::Error::end_of_input() => {
+                    unexpected = Some(UnexpectedInput::EndOfInput)
+                }
+                easy::Error::Unexpected(info @ Info::Token(_))
+                | easy::Error::Unexpected(info @ Info::Range(_)) => {
+                    unexpected = Some(UnexpectedInput::Token(format_info(info)))
+                }
+                easy::Error::Unexpected(info) => {
+                    messages.push(format!("unexpected {}", format_info(info)))
+                }
+                easy::Error::Expected(info) => expected.push(format_info(info)),
+                easy::Error::Message(info) => messages.push(format_info(info)),
+                easy::Error::Other(error) => messages.push(error.to_string()),
+            }
+        }
+
+        let line_number = errors.position.line as usize;
+        let location = Location::new(line_number, errors.position.column as usize);
+
         Self {
-            source_name: source_name.into(),
-            details: format!("{}", errors),
+            // Unexpected tokens are spanned in source lines.
+            source_information: SourceInformation::with_end_location(
+                source_name,
+                location,
+                match &unexpected {
+                    Some(UnexpectedInput::Token(token)) => Location::new(
+                        line_number,
+                        location.column_number() + token.chars().count(),
+                    ),
+                    _ => location,
+                },
+                source_content
+                    .split('\n')
+                    .nth(line_number.saturating_sub(1))
+                    .unwrap_or_default(),
+            ),
+            unexpected,
+            expected,
+            messages,
         }
     }
+
+    pub fn code(&self) -> &'static str {
+        match &self.unexpected {
+            Some(UnexpectedInput::EndOfInput) => "P0003",
+            Some(UnexpectedInput::Token(_)) => "P0002",
+            None => "P0001",
+        }
+    }
+
+    pub fn unexpected(&self) -> Option<&UnexpectedInput> {
+        self.unexpected.as_ref()
+    }
+
+    pub fn expected(&self) -> &[String] {
+        &self.expected
+    }
+
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+
+    pub fn source_information(&self) -> &SourceInformation {
+        &self.source_information
+    }
+}
+
+fn format_info(info: &Info<char, &str>) -> String {
+    match info {
+        Info::Token(character) => character.to_string(),
+        Info::Range(range) => range.to_string(),
+        Info::Owned(string) => string.clone(),
+        Info::Static(string) => string.to_string(),
+    }
 }
 
 impl Error for ParseError {}
 
 impl Display for ParseError {
     fn fmt(&self, formatter: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
-        write!(
-            formatter,
-            "failed to parse module {}\n{}",
-            self.source_name, self.details
-        )
+        writeln!(formatter, "failed to parse module")?;
+
+        match &self.unexpected {
+            Some(UnexpectedInput::EndOfInput) => writeln!(formatter, "unexpected end of input")?,
+            Some(UnexpectedInput::Token(token)) => writeln!(formatter, "unexpected `{}`", token)?,
+            None => {}
+        }
+
+        if !self.expected.is_empty() {
+            writeln!(formatter, "expected {}", self.expected.join(", "))?;
+        }
+
+        for message in &self.messages {
+            writeln!(formatter, "{}", message)?;
+        }
+
+        write!(formatter, "{}", self.source_information)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{super::parse, *};
+
+    #[test]
+    fn display_error_with_source_information() {
+        let error = parse("x : Number\nx = ?", "foo").unwrap_err();
+
+        assert!(format!("{}", error).starts_with("failed to parse module\n"));
+        assert!(format!("{}", error).contains(&format!("{}", error.source_information())));
+    }
+
+    #[test]
+    fn report_unexpected_token() {
+        let error = parse("x : Number\nx = 1 +", "foo").unwrap_err();
+
+        assert_eq!(error.code(), "P0002");
+        assert_eq!(
+            error.unexpected(),
+            Some(&UnexpectedInput::Token("+".into()))
+        );
+        assert_eq!(error.expected(), &["end of file".to_string()]);
+        assert_eq!(
+            (
+                error.source_information().location().line_number(),
+                error.source_information().location().column_number(),
+                error.source_information().end_location().column_number(),
+            ),
+            (2, 7, 8)
+        );
+        assert!(format!("{}", error).contains("unexpected `+`\nexpected end of file\n"));
+    }
+
+    #[test]
+    fn report_unexpected_end_of_input() {
+        let error = ParseError::new(
+            "foo",
+            "x : Number\nx =",
+            &easy::Errors::new(
+                SourcePosition { line: 2, column: 4 },
+                easy::Error::end_of_input(),
+            ),
+        );
+
+        assert_eq!(error.code(), "P0003");
+        assert_eq!(error.unexpected(), Some(&UnexpectedInput::EndOfInput));
+        assert!(format!("{}", error).contains("unexpected end of input"));
+    }
+
+    #[test]
+    fn report_message() {
+        let error = ParseError::new(
+            "foo",
+            "x : Number\nx =",
+            &easy::Errors::new(
+                SourcePosition { line: 2, column: 4 },
+                easy::Error::Unexpected("capitalized identifier".into()),
+            ),
+        );
+
+        assert_eq!(error.code(), "P0001");
+        assert_eq!(error.unexpected(), None);
+        assert_eq!(
+            error.messages(),
+            &["unexpected capitalized identifier".to_string()]
+        );
     }
 }
@@ -6,14 +6,24 @@ mod utilities;
 
 use crate::ast;
 use combine::Parser;
-pub use error::ParseError;
-use parsers::{module, stream};
+pub use error::{ParseError, UnexpectedInput};
+use parsers::{comments, module, stream};
 
 pub fn parse(source_content: &str, source_name: &str) -> Result<ast::UnresolvedModule, ParseError> {
     module()
         .parse(stream(source_content, source_name))
         .map(|(module, _)| module)
-        .map_err(|error| ParseError::new(source_name, &error))
+        .map_err(|error| ParseError::new(source_name, source_content, &error))
+}
+
+pub fn parse_comments(
+    source_content: &str,
+    source_name: &str,
+) -> Result<Vec<ast::Comment>, ParseError> {
+    comments()
+        .parse(stream(source_content, source_name))
+        .map(|(comments, _)| comments)
+        .map_err(|error| ParseError::new(source_name, source_content, &error))
 }
 
 #[cfg(test)]
@@ -153,11 +163,12 @@ mod tests {
                 ""
             ),
             Ok(UnresolvedModule::new(
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
-                vec![UnresolvedImport::new(ExternalUnresolvedModulePath::new(
-                    vec!["package".into(), "Module".into()]
-                ))],
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![UnresolvedImport::new(
+                    ExternalUnresolvedModulePath::new(vec!["package".into(), "Module".into()]),
+                    SourceInformation::dummy()
+                )],
                 vec![],
                 vec![],
                 vec![FunctionDefinition::new(
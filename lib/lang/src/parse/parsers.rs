@@ -9,7 +9,7 @@ use crate::{
     types::{self, Type},
 };
 use combine::{
-    easy, from_str, none_of, one_of,
+    any, easy, from_str, none_of, one_of,
     parser::{
         char::{alpha_num, char as character, letter, string},
         combinator::{lazy, look_ahead, no_partial, not_followed_by},
@@ -57,6 +57,7 @@ pub fn stream<'a>(source: &'a str, source_name: &'a str) -> Stream<'a> {
 
 pub fn module<'a>() -> impl Parser<Stream<'a>, Output = UnresolvedModule> {
     (
+        source_information(),
         optional(export()),
         optional(export_foreign()),
         many(import()),
@@ -67,10 +68,24 @@ pub fn module<'a>() -> impl Parser<Stream<'a>, Output = UnresolvedModule> {
         .skip(blank())
         .skip(eof())
         .map(
-            |(export, export_foreign, imports, import_foreigns, type_definitions, definitions)| {
+            |(
+                source_information,
+                export,
+                export_foreign,
+                imports,
+                import_foreigns,
+                type_definitions,
+                definitions,
+            )| {
+                let source_information = Arc::new(source_information);
+
                 UnresolvedModule::new(
-                    export.unwrap_or_else(|| Export::new(Default::default())),
-                    export_foreign.unwrap_or_else(|| ExportForeign::new(Default::default())),
+                    export.unwrap_or_else(|| {
+                        Export::new(Default::default(), source_information.clone())
+                    }),
+                    export_foreign.unwrap_or_else(|| {
+                        ExportForeign::new(Default::default(), source_information.clone())
+                    }),
                     imports,
                     import_foreigns,
                     type_definitions,
@@ -80,22 +95,71 @@ pub fn module<'a>() -> impl Parser<Stream<'a>, Output = UnresolvedModule> {
         )
 }
 
+pub fn comments<'a>() -> impl Parser<Stream<'a>, Output = Vec<Comment>> {
+    many(choice!(
+        comment_content().map(Some),
+        raw_string_literal().with(value(None)),
+        any().with(value(None)),
+    ))
+    .skip(combine::eof())
+    .map(|comments: Vec<_>| comments.into_iter().flatten().collect())
+    .expected("comments")
+}
+
+fn comment_content<'a>() -> impl Parser<Stream<'a>, Output = Comment> {
+    (
+        raw_source_information(),
+        string("#"),
+        many(none_of("\n".chars())),
+    )
+        .map(|(source_information, _, content): (_, _, String)| {
+            Comment::new(content, source_information)
+        })
+}
+
+// Raw string literals are skipped while comments are collected so that `#`
+// characters in them are not regarded as comments.
+fn raw_string_literal<'a>() -> impl Parser<Stream<'a>, Output = ()> {
+    (
+        character('"'),
+        many::<Vec<_>, _, _>(choice!(
+            character('\\').with(any()).with(value(())),
+            none_of("\"".chars()).with(value(())),
+        )),
+        character('"'),
+    )
+        .with(value(()))
+}
+
 fn export<'a>() -> impl Parser<Stream<'a>, Output = Export> {
-    keyword("export")
-        .with(between(
-            sign("{"),
-            sign("}"),
-            sep_end_by1(identifier(), sign(",")),
-        ))
-        .map(Export::new)
-        .expected("export statement")
+    spanned(keyword("export").with(between(
+        sign("{"),
+        sign("}"),
+        sep_end_by1(identifier(), sign(",")),
+    )))
+    .map(|(source_information, names)| Export::new(names, source_information))
+    .expected("export statement")
 }
 
 fn import<'a>() -> impl Parser<Stream<'a>, Output = UnresolvedImport> {
-    keyword("import")
-        .with(module_path())
-        .map(UnresolvedImport::new)
-        .expected("import statement")
+    spanned(keyword("import").with((
+        optional(identifier()),
+        module_path(),
+        optional(between(
+            sign("{"),
+            sign("}"),
+            sep_end_by1(identifier(), sign(",")),
+        )),
+    )))
+    .map(|(source_information, (alias, module_path, names))| {
+        UnresolvedImport::with_alias_and_names(
+            module_path,
+            alias,
+            names.unwrap_or_default(),
+            source_information,
+        )
+    })
+    .expected("import statement")
 }
 
 fn module_path<'a>() -> impl Parser<Stream<'a>, Output = UnresolvedModulePath> {
@@ -127,36 +191,34 @@ fn path_component<'a>() -> impl Parser<Stream<'a>, Output = String> {
 }
 
 fn export_foreign<'a>() -> impl Parser<Stream<'a>, Output = ExportForeign> {
-    (keyword("export"), keyword("foreign"))
-        .with(between(
-            sign("{"),
-            sign("}"),
-            sep_end_by1(identifier(), sign(",")),
-        ))
-        .map(ExportForeign::new)
-        .expected("export foreign statement")
+    spanned((keyword("export"), keyword("foreign")).with(between(
+        sign("{"),
+        sign("}"),
+        sep_end_by1(identifier(), sign(",")),
+    )))
+    .map(|(source_information, names)| ExportForeign::new(names, source_information))
+    .expected("export foreign statement")
 }
 
 fn import_foreign<'a>() -> impl Parser<Stream<'a>, Output = ImportForeign> {
-    (
-        source_information(),
+    spanned((
         keyword("import"),
         keyword("foreign"),
         optional(calling_convention()),
         type_annotation(),
+    ))
+    .map(
+        |(source_information, (_, _, calling_convention, (name, type_)))| {
+            ImportForeign::new(
+                &name,
+                &name,
+                calling_convention.unwrap_or(CallingConvention::Native),
+                type_,
+                source_information,
+            )
+        },
     )
-        .map(
-            |(source_information, _, _, calling_convention, (name, type_))| {
-                ImportForeign::new(
-                    &name,
-                    &name,
-                    calling_convention.unwrap_or(CallingConvention::Native),
-                    type_,
-                    source_information,
-                )
-            },
-        )
-        .expected("import foreign")
+    .expected("import foreign")
 }
 
 fn calling_convention<'a>() -> impl Parser<Stream<'a>, Output = CallingConvention> {
@@ -177,80 +239,65 @@ fn definition<'a>() -> impl Parser<Stream<'a>, Output = Definition> {
 }
 
 fn function_definition<'a>() -> impl Parser<Stream<'a>, Output = FunctionDefinition> {
-    (
-        source_information(),
+    spanned((
         type_annotation(),
         identifier(),
         many1(identifier()),
         sign("="),
         expression(),
+    ))
+    .then(
+        |(source_information, ((typed_name, type_), name, arguments, _, expression))| {
+            if typed_name == name {
+                value(FunctionDefinition::new(
+                    name,
+                    arguments,
+                    expression,
+                    type_,
+                    source_information,
+                ))
+                .left()
+            } else {
+                unexpected_any("unmatched identifiers in definition").right()
+            }
+        },
     )
-        .then(
-            |(source_information, (typed_name, type_), name, arguments, _, expression)| {
-                if typed_name == name {
-                    value(FunctionDefinition::new(
-                        name,
-                        arguments,
-                        expression,
-                        type_,
-                        source_information,
-                    ))
-                    .left()
-                } else {
-                    unexpected_any("unmatched identifiers in definition").right()
-                }
-            },
-        )
 }
 
 fn variable_definition<'a>() -> impl Parser<Stream<'a>, Output = VariableDefinition> {
-    (
-        source_information(),
-        type_annotation(),
-        identifier(),
-        sign("="),
-        expression(),
+    spanned((type_annotation(), identifier(), sign("="), expression())).then(
+        |(source_information, ((typed_name, type_), name, _, expression))| {
+            if typed_name == name {
+                value(VariableDefinition::new(
+                    name,
+                    expression,
+                    type_,
+                    source_information,
+                ))
+                .left()
+            } else {
+                unexpected_any("unmatched identifiers in definition").right()
+            }
+        },
     )
-        .then(
-            |(source_information, (typed_name, type_), name, _, expression)| {
-                if typed_name == name {
-                    value(VariableDefinition::new(
-                        name,
-                        expression,
-                        type_,
-                        source_information,
-                    ))
-                    .left()
-                } else {
-                    unexpected_any("unmatched identifiers in definition").right()
-                }
-            },
-        )
 }
 
 fn result_definition<'a>() -> impl Parser<Stream<'a>, Output = VariableDefinition> {
-    (
-        source_information(),
-        type_annotation(),
-        identifier(),
-        sign("?="),
-        expression(),
+    spanned((type_annotation(), identifier(), sign("?="), expression())).then(
+        |(source_information, ((typed_name, type_), name, _, expression))| {
+            if typed_name == name {
+                value(VariableDefinition::new(
+                    name,
+                    expression,
+                    type_,
+                    source_information,
+                ))
+                .left()
+            } else {
+                unexpected_any("unmatched identifiers in definition").right()
+            }
+        },
     )
-        .then(
-            |(source_information, (typed_name, type_), name, _, expression)| {
-                if typed_name == name {
-                    value(VariableDefinition::new(
-                        name,
-                        expression,
-                        type_,
-                        source_information,
-                    ))
-                    .left()
-                } else {
-                    unexpected_any("unmatched identifiers in definition").right()
-                }
-            },
-        )
 }
 
 fn type_annotation<'a>() -> impl Parser<Stream<'a>, Output = (String, Type)> {
@@ -258,14 +305,8 @@ fn type_annotation<'a>() -> impl Parser<Stream<'a>, Output = (String, Type)> {
 }
 
 fn untyped_function_definition<'a>() -> impl Parser<Stream<'a>, Output = FunctionDefinition> {
-    (
-        source_information(),
-        identifier(),
-        many1(identifier()),
-        sign("="),
-        expression(),
-    )
-        .map(|(source_information, name, arguments, _, expression)| {
+    spanned((identifier(), many1(identifier()), sign("="), expression())).map(
+        |(source_information, (name, arguments, _, expression))| {
             let source_information = Arc::new(source_information);
             FunctionDefinition::new(
                 name,
@@ -274,12 +315,13 @@ fn untyped_function_definition<'a>() -> impl Parser<Stream<'a>, Output = Functio
                 types::Unknown::new(source_information.clone()),
                 source_information,
             )
-        })
+        },
+    )
 }
 
 fn untyped_variable_definition<'a>() -> impl Parser<Stream<'a>, Output = VariableDefinition> {
-    (source_information(), identifier(), sign("="), expression()).map(
-        |(source_information, name, _, expression)| {
+    spanned((identifier(), sign("="), expression())).map(
+        |(source_information, (name, _, expression))| {
             let source_information = Arc::new(source_information);
             VariableDefinition::new(
                 name,
@@ -292,8 +334,8 @@ fn untyped_variable_definition<'a>() -> impl Parser<Stream<'a>, Output = Variabl
 }
 
 fn untyped_result_definition<'a>() -> impl Parser<Stream<'a>, Output = VariableDefinition> {
-    (source_information(), identifier(), sign("?="), expression()).map(
-        |(source_information, name, _, expression)| {
+    spanned((identifier(), sign("?="), expression())).map(
+        |(source_information, (name, _, expression))| {
             let source_information = Arc::new(source_information);
             VariableDefinition::new(
                 name,
@@ -310,39 +352,74 @@ fn type_definition<'a>() -> impl Parser<Stream<'a>, Output = TypeDefinition> {
 }
 
 fn record_type_definition<'a>() -> impl Parser<Stream<'a>, Output = TypeDefinition> {
-    (
+    spanned((
         keyword("type"),
-        source_information(),
-        identifier(),
+        spanned(identifier()),
+        many(type_parameter()),
         optional(between(
             sign("{"),
             sign("}"),
             sep_end_by1((identifier().skip(sign(":")), type_()), sign(",")),
         )),
-    )
-        .map(
-            |(_, source_information, name, elements): (_, _, _, Option<Vec<_>>)| {
-                TypeDefinition::new(
+    ))
+    .map(
+        |(
+            definition_source_information,
+            (_, (source_information, name), type_parameters, elements),
+        ): (_, (_, _, _, Option<Vec<_>>))| {
+            TypeDefinition::with_type_parameters(
+                &name,
+                type_parameters,
+                types::Record::new(
                     &name,
-                    types::Record::new(
-                        &name,
-                        elements
-                            .unwrap_or_default()
-                            .into_iter()
-                            .map(|(name, type_)| types::RecordElement::new(name, type_))
-                            .collect(),
-                        source_information,
-                    ),
-                )
-            },
-        )
-        .expected("record type definition")
+                    elements
+                        .unwrap_or_default()
+                        .into_iter()
+                        .map(|(name, type_)| types::RecordElement::new(name, type_))
+                        .collect(),
+                    source_information,
+                ),
+                definition_source_information,
+            )
+        },
+    )
+    .expected("record type definition")
 }
 
 fn type_alias_definition<'a>() -> impl Parser<Stream<'a>, Output = TypeDefinition> {
-    (keyword("type"), identifier(), sign("="), type_())
-        .map(|(_, name, _, type_)| TypeDefinition::new(&name, type_))
-        .expected("type alias definition")
+    spanned((
+        keyword("type"),
+        identifier(),
+        many(type_parameter()),
+        sign("="),
+        type_(),
+    ))
+    .map(
+        |(source_information, (_, name, type_parameters, _, type_))| {
+            TypeDefinition::with_type_parameters(&name, type_parameters, type_, source_information)
+        },
+    )
+    .expected("type alias definition")
+}
+
+// Type parameters in type definitions must be on the same lines as their
+// type names.
+fn type_parameter<'a>() -> impl Parser<Stream<'a>, Output = String> {
+    optional(spaces1())
+        .with(raw_type_parameter())
+        .expected("type parameter")
+}
+
+fn raw_type_parameter<'a>() -> impl Parser<Stream<'a>, Output = String> {
+    raw_identifier()
+        .skip(not_followed_by(string(".")))
+        .then(|identifier| {
+            if identifier.starts_with(|character: char| character.is_ascii_lowercase()) {
+                value(identifier).left()
+            } else {
+                unexpected_any("capitalized identifier").right()
+            }
+        })
 }
 
 fn type_<'a>() -> impl Parser<Stream<'a>, Output = Type> {
@@ -352,18 +429,15 @@ fn type_<'a>() -> impl Parser<Stream<'a>, Output = Type> {
 }
 
 fn function_type<'a>() -> impl Parser<Stream<'a>, Output = types::Function> {
-    (source_information(), union_type(), sign("->"), type_())
-        .map(|(source_information, argument, _, result)| {
+    spanned((union_type(), sign("->"), type_()))
+        .map(|(source_information, (argument, _, result))| {
             types::Function::new(argument, result, source_information)
         })
         .expected("function type")
 }
 
 fn union_type<'a>() -> impl Parser<Stream<'a>, Output = Type> {
-    (
-        source_information(),
-        sep_end_by1(type_application(), sign("|")),
-    )
+    spanned(sep_end_by1(type_application(), sign("|")))
         .map(|(source_information, types)| {
             let types: Vec<_> = types;
 
@@ -377,70 +451,135 @@ fn union_type<'a>() -> impl Parser<Stream<'a>, Output = Type> {
 }
 
 fn type_application<'a>() -> impl Parser<Stream<'a>, Output = Type> {
-    choice!(list_type().map(Type::from), atomic_type())
+    choice!(
+        array_type().map(Type::from),
+        list_type().map(Type::from),
+        reference_type_application().map(Type::from),
+        atomic_type()
+    )
+}
+
+fn array_type<'a>() -> impl Parser<Stream<'a>, Output = types::Array> {
+    spanned((keyword("Array"), atomic_type()))
+        .map(|(source_information, (_, element))| types::Array::new(element, source_information))
+        .expected("array type")
 }
 
 fn list_type<'a>() -> impl Parser<Stream<'a>, Output = types::List> {
-    (source_information(), keyword("List"), atomic_type())
-        .map(|(source_information, _, element)| types::List::new(element, source_information))
+    spanned((keyword("List"), atomic_type()))
+        .map(|(source_information, (_, element))| types::List::new(element, source_information))
         .expected("list type")
 }
 
+fn map_type<'a>() -> impl Parser<Stream<'a>, Output = types::Map> {
+    spanned(between(sign("{"), sign("}"), (type_(), sign(":"), type_())))
+        .map(|(source_information, (key, _, value))| {
+            types::Map::new(key, value, source_information)
+        })
+        .expected("map type")
+}
+
 fn atomic_type<'a>() -> impl Parser<Stream<'a>, Output = Type> {
     choice!(
+        map_type().map(Type::from),
         boolean_type().map(Type::from),
         none_type().map(Type::from),
         number_type().map(Type::from),
         string_type().map(Type::from),
         any_type().map(Type::from),
+        parameter_type().map(Type::from),
         reference_type().map(Type::from),
         between(sign("("), sign(")"), type_()),
     )
 }
 
 fn boolean_type<'a>() -> impl Parser<Stream<'a>, Output = types::Boolean> {
-    source_information()
-        .skip(keyword("Boolean"))
-        .map(types::Boolean::new)
+    spanned(keyword("Boolean"))
+        .map(|(source_information, _)| types::Boolean::new(source_information))
         .expected("boolean type")
 }
 
 fn none_type<'a>() -> impl Parser<Stream<'a>, Output = types::None> {
-    source_information()
-        .skip(keyword("None"))
-        .map(types::None::new)
+    spanned(keyword("None"))
+        .map(|(source_information, _)| types::None::new(source_information))
         .expected("none type")
 }
 
 fn number_type<'a>() -> impl Parser<Stream<'a>, Output = types::Number> {
-    source_information()
-        .skip(keyword("Number"))
-        .map(types::Number::new)
+    spanned(keyword("Number"))
+        .map(|(source_information, _)| types::Number::new(source_information))
         .expected("number type")
 }
 
 fn string_type<'a>() -> impl Parser<Stream<'a>, Output = types::EinString> {
-    source_information()
-        .skip(keyword("String"))
-        .map(types::EinString::new)
+    spanned(keyword("String"))
+        .map(|(source_information, _)| types::EinString::new(source_information))
         .expected("string type")
 }
 
 fn any_type<'a>() -> impl Parser<Stream<'a>, Output = types::Any> {
-    source_information()
-        .skip(keyword("Any"))
-        .map(types::Any::new)
+    spanned(keyword("Any"))
+        .map(|(source_information, _)| types::Any::new(source_information))
         .expected("any type")
 }
 
 fn reference_type<'a>() -> impl Parser<Stream<'a>, Output = types::Reference> {
-    (source_information(), qualified_identifier())
+    spanned(qualified_identifier())
         .map(|(source_information, identifier)| {
             types::Reference::new(identifier, source_information)
         })
         .expected("reference type")
 }
 
+fn reference_type_application<'a>() -> impl Parser<Stream<'a>, Output = types::Reference> {
+    spanned((
+        qualified_identifier(),
+        many1((
+            many(type_argument().skip(not_followed_by(type_application_terminator()))),
+            type_argument().skip(look_ahead(type_application_terminator())),
+        )),
+    ))
+    .map(|(source_information, (identifier, argument_sets))| {
+        let argument_sets: Vec<(Vec<Type>, _)> = argument_sets;
+
+        let mut arguments = vec![];
+
+        for (types, type_) in argument_sets {
+            arguments.extend(types);
+            arguments.push(type_);
+        }
+
+        types::Reference::with_arguments(identifier, arguments, source_information)
+    })
+    .expected("type application")
+}
+
+// Type arguments are on the same lines as their type applications.
+fn type_argument<'a>() -> impl Parser<Stream<'a>, Output = Type> {
+    not_followed_by(newline().with(value("newline"))).with(atomic_type())
+}
+
+fn type_application_terminator<'a>() -> impl Parser<Stream<'a>, Output = &'static str> {
+    choice!(
+        newlines1(),
+        sign(","),
+        sign(":"),
+        sign(")"),
+        sign("}"),
+        sign("->"),
+        sign("=>"),
+        sign("|"),
+    )
+    .with(value("type application terminator"))
+    .expected("type application terminator")
+}
+
+fn parameter_type<'a>() -> impl Parser<Stream<'a>, Output = types::Parameter> {
+    spanned(raw_type_parameter())
+        .map(|(source_information, name)| types::Parameter::new(name, source_information))
+        .expected("parameter type")
+}
+
 fn expression<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
     lazy(|| no_partial(operation_or_term()))
         .boxed()
@@ -455,7 +594,9 @@ fn strict_atomic_expression<'a>() -> impl Parser<Stream<'a>, Output = Expression
     choice!(
         record_construction().map(Expression::from),
         record_update().map(Expression::from),
+        array_literal().map(Expression::from),
         list_literal().map(Expression::from),
+        map_literal().map(Expression::from),
         boolean_literal().map(Expression::from),
         none_literal().map(Expression::from),
         number_literal().map(Expression::from),
@@ -465,42 +606,52 @@ fn strict_atomic_expression<'a>() -> impl Parser<Stream<'a>, Output = Expression
     )
 }
 
+fn lambda<'a>() -> impl Parser<Stream<'a>, Output = Lambda> {
+    spanned((sign("\\"), many1(identifier()), sign("->"), expression()))
+        .map(|(source_information, (_, arguments, _, body))| {
+            Lambda::new(
+                arguments,
+                body,
+                types::Unknown::new(source_information.clone()),
+                source_information,
+            )
+        })
+        .expected("lambda expression")
+}
+
 fn if_<'a>() -> impl Parser<Stream<'a>, Output = If> {
-    (
-        source_information(),
+    spanned((
         keyword("if").expected("if keyword"),
         expression(),
         keyword("then").expected("then keyword"),
         expression(),
         keyword("else").expected("else keyword"),
         expression(),
-    )
-        .map(|(source_information, _, condition, _, then, _, else_)| {
-            If::new(condition, then, else_, source_information)
-        })
-        .expected("if expression")
+    ))
+    .map(|(source_information, (_, condition, _, then, _, else_))| {
+        If::new(condition, then, else_, source_information)
+    })
+    .expected("if expression")
 }
 
 fn case<'a>() -> impl Parser<Stream<'a>, Output = Case> {
-    (
-        source_information(),
+    spanned((
         keyword("case").expected("case keyword"),
         identifier(),
         sign("="),
         expression(),
         many1(alternative()),
+    ))
+    .map(
+        |(source_information, (_, identifier, _, argument, alternatives))| {
+            Case::new(identifier, argument, alternatives, source_information)
+        },
     )
-        .map(
-            |(source_information, _, identifier, _, argument, alternatives)| {
-                Case::new(identifier, argument, alternatives, source_information)
-            },
-        )
-        .expected("type case expression")
+    .expected("type case expression")
 }
 
 fn list_case<'a>() -> impl Parser<Stream<'a>, Output = ListCase> {
-    (
-        source_information(),
+    spanned((
         keyword("case").expected("case keyword"),
         expression(),
         sign("[]"),
@@ -514,10 +665,11 @@ fn list_case<'a>() -> impl Parser<Stream<'a>, Output = ListCase> {
         sign("]"),
         sign("=>"),
         expression(),
-    )
-        .map(
-            |(
-                source_information,
+    ))
+    .map(
+        |(
+            source_information,
+            (
                 _,
                 argument,
                 _,
@@ -531,19 +683,20 @@ fn list_case<'a>() -> impl Parser<Stream<'a>, Output = ListCase> {
                 _,
                 _,
                 non_empty_alternative,
-            )| {
-                ListCase::new(
-                    argument,
-                    types::Unknown::new(source_information.clone()),
-                    first_name,
-                    rest_name,
-                    empty_alternative,
-                    non_empty_alternative,
-                    source_information,
-                )
-            },
-        )
-        .expected("list case expression")
+            ),
+        )| {
+            ListCase::new(
+                argument,
+                types::Unknown::new(source_information.clone()),
+                first_name,
+                rest_name,
+                empty_alternative,
+                non_empty_alternative,
+                source_information,
+            )
+        },
+    )
+    .expected("list case expression")
 }
 
 fn alternative<'a>() -> impl Parser<Stream<'a>, Output = Alternative> {
@@ -552,8 +705,7 @@ fn alternative<'a>() -> impl Parser<Stream<'a>, Output = Alternative> {
 }
 
 fn let_<'a>() -> impl Parser<Stream<'a>, Output = Let> {
-    (
-        source_information(),
+    spanned((
         keyword("let").expected("let keyword"),
         many1(choice!(
             variable_definition().map(From::from),
@@ -563,60 +715,63 @@ fn let_<'a>() -> impl Parser<Stream<'a>, Output = Let> {
         )),
         keyword("in").expected("in keyword"),
         expression(),
-    )
-        .map(|(source_information, _, definitions, _, expression)| {
-            Let::new(definitions, expression, source_information)
-        })
-        .expected("let expression")
+    ))
+    .map(|(source_information, (_, definitions, _, expression))| {
+        Let::new(definitions, expression, source_information)
+    })
+    .expected("let expression")
 }
 
 fn let_error<'a>() -> impl Parser<Stream<'a>, Output = LetError> {
-    (
-        source_information(),
+    spanned((
         keyword("let").expected("let keyword"),
         many1(choice!(result_definition(), untyped_result_definition())),
         keyword("in").expected("in keyword"),
         expression(),
-    )
-        .map(|(source_information, _, definitions, _, expression)| {
-            LetError::new(definitions, expression, source_information)
-        })
-        .expected("let-error expression")
+    ))
+    .map(|(source_information, (_, definitions, _, expression))| {
+        LetError::new(definitions, expression, source_information)
+    })
+    .expected("let-error expression")
 }
 
 fn application_or_atomic_expression<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
-    (
-        source_information(),
+    spanned((
         atomic_expression(),
         many((
-            many(atomic_expression().skip(not_followed_by(application_terminator()))),
-            atomic_expression().skip(look_ahead(application_terminator())),
+            many(application_argument().skip(not_followed_by(application_terminator()))),
+            application_argument().skip(look_ahead(application_terminator())),
         )),
-    )
-        .map(|(source_information, function, argument_sets)| {
-            let source_information = Arc::new(source_information);
-            let argument_sets: Vec<(Vec<Expression>, _)> = argument_sets;
+    ))
+    .map(|(source_information, (function, argument_sets))| {
+        let source_information = Arc::new(source_information);
+        let argument_sets: Vec<(Vec<Expression>, _)> = argument_sets;
 
-            let mut all_arguments = vec![];
+        let mut all_arguments = vec![];
 
-            for (arguments, argument) in argument_sets {
-                all_arguments.extend(arguments);
-                all_arguments.push(argument);
-            }
+        for (arguments, argument) in argument_sets {
+            all_arguments.extend(arguments);
+            all_arguments.push(argument);
+        }
 
-            all_arguments
-                .into_iter()
-                .fold(function, |application, argument| {
-                    Application::new(application, argument, source_information.clone()).into()
-                })
-        })
-        .expected("application")
+        all_arguments
+            .into_iter()
+            .fold(function, |application, argument| {
+                Application::new(application, argument, source_information.clone()).into()
+            })
+    })
+    .expected("application")
+}
+
+fn application_argument<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
+    not_followed_by(definition_start()).with(atomic_expression())
 }
 
 fn application_terminator<'a>() -> impl Parser<Stream<'a>, Output = &'static str> {
     choice!(
         newlines1(),
         sign(","),
+        sign(":"),
         sign(")"),
         sign("}"),
         sign("]"),
@@ -628,14 +783,47 @@ fn application_terminator<'a>() -> impl Parser<Stream<'a>, Output = &'static str
 }
 
 fn record_construction<'a>() -> impl Parser<Stream<'a>, Output = RecordConstruction> {
-    (
-        source_information(),
+    spanned((
         reference_type(),
         string("{"),
         sep_end_by1((identifier().skip(sign("=")), expression()), sign(",")),
         sign("}"),
-    )
-        .then(|(source_information, reference_type, _, elements, _)| {
+    ))
+    .then(|(source_information, (reference_type, _, elements, _))| {
+        let elements: Vec<_> = elements;
+
+        if elements
+            .iter()
+            .map(|(key, _)| key.into())
+            .collect::<HashSet<String>>()
+            .len()
+            == elements.len()
+        {
+            value(RecordConstruction::new(
+                reference_type,
+                elements.into_iter().collect(),
+                source_information,
+            ))
+            .left()
+        } else {
+            unexpected_any("duplicate keys in record construction").right()
+        }
+    })
+    .expected("record construction")
+}
+
+fn record_update<'a>() -> impl Parser<Stream<'a>, Output = RecordUpdate> {
+    spanned((
+        reference_type(),
+        string("{"),
+        sign("..."),
+        atomic_expression(),
+        sign(","),
+        sep_end_by1((identifier().skip(sign("=")), expression()), sign(",")),
+        sign("}"),
+    ))
+    .then(
+        |(source_information, (reference_type, _, _, argument, _, elements, _))| {
             let elements: Vec<_> = elements;
 
             if elements
@@ -645,58 +833,24 @@ fn record_construction<'a>() -> impl Parser<Stream<'a>, Output = RecordConstruct
                 .len()
                 == elements.len()
             {
-                value(RecordConstruction::new(
+                value(RecordUpdate::new(
                     reference_type,
+                    argument,
                     elements.into_iter().collect(),
                     source_information,
                 ))
                 .left()
             } else {
-                unexpected_any("duplicate keys in record construction").right()
+                unexpected_any("duplicate keys in record update").right()
             }
-        })
-        .expected("record construction")
-}
-
-fn record_update<'a>() -> impl Parser<Stream<'a>, Output = RecordUpdate> {
-    (
-        source_information(),
-        reference_type(),
-        string("{"),
-        sign("..."),
-        atomic_expression(),
-        sign(","),
-        sep_end_by1((identifier().skip(sign("=")), expression()), sign(",")),
-        sign("}"),
+        },
     )
-        .then(
-            |(source_information, reference_type, _, _, argument, _, elements, _)| {
-                let elements: Vec<_> = elements;
-
-                if elements
-                    .iter()
-                    .map(|(key, _)| key.into())
-                    .collect::<HashSet<String>>()
-                    .len()
-                    == elements.len()
-                {
-                    value(RecordUpdate::new(
-                        reference_type,
-                        argument,
-                        elements.into_iter().collect(),
-                        source_information,
-                    ))
-                    .left()
-                } else {
-                    unexpected_any("duplicate keys in record update").right()
-                }
-            },
-        )
 }
 
 fn term<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
     choice!(
         application_or_atomic_expression(),
+        lambda().map(Expression::from),
         if_().map(Expression::from),
         case().map(Expression::from),
         list_case().map(Expression::from),
@@ -709,9 +863,11 @@ fn term<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
 fn operation_or_term<'a>() -> impl Parser<Stream<'a>, Output = Expression> {
     (
         term(),
-        many((source_information(), operator(), term()).map(
-            |(source_information, operator, expression)| (operator, expression, source_information),
-        )),
+        many(
+            (spanned(operator()), term()).map(|((source_information, operator), expression)| {
+                (operator, expression, source_information)
+            }),
+        ),
     )
         .map(|(expression, pairs): (_, Vec<_>)| reduce_operations(expression, &pairs))
 }
@@ -752,26 +908,24 @@ fn concrete_operator<'a>(
 
 fn boolean_literal<'a>() -> impl Parser<Stream<'a>, Output = Boolean> {
     token(choice!(
-        source_information()
-            .skip(keyword("False"))
-            .map(|source_information| Boolean::new(false, source_information)),
-        source_information()
-            .skip(keyword("True"))
-            .map(|source_information| Boolean::new(true, source_information)),
+        spanned(keyword("False"))
+            .map(|(source_information, _)| Boolean::new(false, source_information)),
+        spanned(keyword("True"))
+            .map(|(source_information, _)| Boolean::new(true, source_information)),
     ))
     .expected("boolean literal")
 }
 
 fn none_literal<'a>() -> impl Parser<Stream<'a>, Output = None> {
-    token(source_information().skip(keyword("None")))
-        .map(None::new)
+    token(spanned(keyword("None")))
+        .map(|(source_information, _)| None::new(source_information))
         .expected("none literal")
 }
 
 fn number_literal<'a>() -> impl Parser<Stream<'a>, Output = Number> {
     let regex: &'static regex::Regex = &NUMBER_REGEX;
 
-    token((source_information(), from_str(find(regex))))
+    token(spanned(from_str(find(regex))))
         .map(|(source_information, number)| Number::new(number, source_information))
         .expected("number literal")
 }
@@ -779,8 +933,7 @@ fn number_literal<'a>() -> impl Parser<Stream<'a>, Output = Number> {
 fn string_literal<'a>() -> impl Parser<Stream<'a>, Output = EinString> {
     let regex: &'static regex::Regex = &STRING_REGEX;
 
-    token((
-        source_information(),
+    token(spanned((
         character('"'),
         many(choice!(
             from_str(find(regex)),
@@ -790,22 +943,34 @@ fn string_literal<'a>() -> impl Parser<Stream<'a>, Output = EinString> {
             string("\\t").map(|_| "\t".into())
         )),
         character('"'),
-    ))
+    )))
     .map(
-        |(source_information, _, strings, _): (_, _, Vec<String>, _)| {
+        |(source_information, (_, strings, _)): (_, (_, Vec<String>, _))| {
             EinString::new(strings.join(""), source_information)
         },
     )
     .expected("string literal")
 }
 
+fn array_literal<'a>() -> impl Parser<Stream<'a>, Output = Array> {
+    spanned((
+        keyword("Array"),
+        string("["),
+        sep_end_by(expression(), sign(",")),
+        sign("]"),
+    ))
+    .map(|(source_information, (_, _, elements, _))| Array::new(elements, source_information))
+    .expected("array literal")
+}
+
 fn list_literal<'a>() -> impl Parser<Stream<'a>, Output = List> {
-    (
-        source_information(),
-        between(sign("["), sign("]"), sep_end_by(list_element(), sign(","))),
-    )
-        .map(|(source_information, elements)| List::new(elements, source_information))
-        .expected("list literal")
+    spanned(between(
+        sign("["),
+        sign("]"),
+        sep_end_by(list_element(), sign(",")),
+    ))
+    .map(|(source_information, elements)| List::new(elements, source_information))
+    .expected("list literal")
 }
 
 fn list_element<'a>() -> impl Parser<Stream<'a>, Output = ListElement> {
@@ -818,8 +983,22 @@ fn list_element<'a>() -> impl Parser<Stream<'a>, Output = ListElement> {
     })
 }
 
+fn map_literal<'a>() -> impl Parser<Stream<'a>, Output = Map> {
+    spanned(between(
+        sign("{"),
+        sign("}"),
+        sep_end_by(map_element(), sign(",")),
+    ))
+    .map(|(source_information, elements)| Map::new(elements, source_information))
+    .expected("map literal")
+}
+
+fn map_element<'a>() -> impl Parser<Stream<'a>, Output = MapElement> {
+    (expression(), sign(":"), expression()).map(|(key, _, value)| MapElement::new(key, value))
+}
+
 fn variable<'a>() -> impl Parser<Stream<'a>, Output = Variable> {
-    token((source_information(), qualified_identifier()))
+    token(spanned(qualified_identifier()))
         .map(|(source_information, identifier)| Variable::new(identifier, source_information))
         .expected("variable")
 }
@@ -854,6 +1033,14 @@ fn unchecked_identifier<'a>() -> impl Parser<Stream<'a>, Output = String> {
     .map(|(head, tail): (char, String)| [head.into(), tail].concat())
 }
 
+// Type annotations of definitions on the next lines terminate applications
+// instead of colons in map elements.
+fn definition_start<'a>() -> impl Parser<Stream<'a>, Output = &'static str> {
+    (newline(), identifier(), sign(":"))
+        .with(value("definition start"))
+        .expected("definition start")
+}
+
 fn keyword<'a>(name: &'static str) -> impl Parser<Stream<'a>, Output = ()> {
     token(string(name).skip(not_followed_by(alpha_num())))
         .with(value(()))
@@ -880,15 +1067,39 @@ fn token<'a, O, P: Parser<Stream<'a>, Output = O>>(p: P) -> impl Parser<Stream<'
 
 fn source_information<'a>() -> impl Parser<Stream<'a>, Output = SourceInformation> {
     blank()
-        .map_input(|_, stream: &mut Stream<'a>| {
+        .with(raw_source_information())
+        .expected("source information")
+}
+
+fn raw_source_information<'a>() -> impl Parser<Stream<'a>, Output = SourceInformation> {
+    value(()).map_input(|_, stream: &mut Stream<'a>| {
+        let position = stream.position();
+        SourceInformation::new(
+            stream.0.state.source_name,
+            Location::new(position.line as usize, position.column as usize),
+            stream.0.state.lines[position.line as usize - 1],
+        )
+    })
+}
+
+fn spanned<'a, O>(
+    parser: impl Parser<Stream<'a>, Output = O>,
+) -> impl Parser<Stream<'a>, Output = (SourceInformation, O)> {
+    (source_information(), parser).map_input(
+        |(source_information, output), stream: &mut Stream<'a>| {
             let position = stream.position();
-            SourceInformation::new(
-                stream.0.state.source_name,
-                Location::new(position.line as usize, position.column as usize),
-                stream.0.state.lines[position.line as usize - 1],
+
+            (
+                SourceInformation::with_end_location(
+                    source_information.source_name(),
+                    source_information.location(),
+                    Location::new(position.line as usize, position.column as usize),
+                    source_information.line(),
+                ),
+                output,
             )
-        })
-        .expected("source information")
+        },
+    )
 }
 
 fn blank<'a>() -> impl Parser<Stream<'a>, Output = ()> {
@@ -954,8 +1165,11 @@ mod tests {
         assert_eq!(
             module().parse(stream("export { foo }", "")).unwrap().0,
             UnresolvedModule::new(
-                Export::new(vec!["foo".into()].drain(..).collect()),
-                ExportForeign::new(Default::default()),
+                Export::new(
+                    vec!["foo".into()].drain(..).collect(),
+                    SourceInformation::dummy()
+                ),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![],
@@ -968,11 +1182,15 @@ mod tests {
                 .unwrap()
                 .0,
             UnresolvedModule::new(
-                Export::new(vec!["foo".into()].drain(..).collect()),
-                ExportForeign::new(Default::default()),
-                vec![UnresolvedImport::new(ExternalUnresolvedModulePath::new(
-                    vec!["Foo".into(), "Bar".into()]
-                ))],
+                Export::new(
+                    vec!["foo".into()].drain(..).collect(),
+                    SourceInformation::dummy()
+                ),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![UnresolvedImport::new(
+                    ExternalUnresolvedModulePath::new(vec!["Foo".into(), "Bar".into()]),
+                    SourceInformation::dummy()
+                )],
                 vec![],
                 vec![],
                 vec![]
@@ -981,8 +1199,8 @@ mod tests {
         assert_eq!(
             module().parse(stream("x : Number\nx = 42", "")).unwrap().0,
             UnresolvedModule::new(
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![],
@@ -1001,8 +1219,8 @@ mod tests {
                 .unwrap()
                 .0,
             UnresolvedModule::new(
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![],
@@ -1030,8 +1248,8 @@ mod tests {
                 .unwrap()
                 .0,
             UnresolvedModule::new(
-                Export::new(Default::default()),
-                ExportForeign::new(Default::default()),
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
                 vec![],
                 vec![],
                 vec![],
@@ -1056,26 +1274,41 @@ mod tests {
         assert!(export().parse(stream("export {}", "")).is_err());
         assert_eq!(
             export().parse(stream("export { foo }", "")).unwrap().0,
-            Export::new(vec!["foo".into()].drain(..).collect()),
+            Export::new(
+                vec!["foo".into()].drain(..).collect(),
+                SourceInformation::dummy()
+            ),
         );
         assert_eq!(
             export().parse(stream("export { foo, }", "")).unwrap().0,
-            Export::new(vec!["foo".into()].drain(..).collect()),
+            Export::new(
+                vec!["foo".into()].drain(..).collect(),
+                SourceInformation::dummy()
+            ),
         );
         assert_eq!(
             export().parse(stream("export { foo, bar }", "")).unwrap().0,
-            Export::new(vec!["foo".into(), "bar".into()].drain(..).collect()),
+            Export::new(
+                vec!["foo".into(), "bar".into()].drain(..).collect(),
+                SourceInformation::dummy()
+            ),
         );
         assert_eq!(
             export()
                 .parse(stream("export { foo, bar, }", ""))
                 .unwrap()
                 .0,
-            Export::new(vec!["foo".into(), "bar".into()].drain(..).collect()),
+            Export::new(
+                vec!["foo".into(), "bar".into()].drain(..).collect(),
+                SourceInformation::dummy()
+            ),
         );
         assert_eq!(
             export().parse(stream("export {\nfoo }", "")).unwrap().0,
-            Export::new(vec!["foo".into()].drain(..).collect()),
+            Export::new(
+                vec!["foo".into()].drain(..).collect(),
+                SourceInformation::dummy()
+            ),
         );
     }
 
@@ -1083,14 +1316,53 @@ mod tests {
     fn parse_import() {
         assert_eq!(
             import().parse(stream("import \"/Foo\"", "")).unwrap().0,
-            UnresolvedImport::new(InternalUnresolvedModulePath::new(vec!["Foo".into()])),
+            UnresolvedImport::new(
+                InternalUnresolvedModulePath::new(vec!["Foo".into()]),
+                SourceInformation::dummy()
+            ),
         );
         assert_eq!(
             import().parse(stream("import \"Foo/Bar\"", "")).unwrap().0,
-            UnresolvedImport::new(ExternalUnresolvedModulePath::new(vec![
-                "Foo".into(),
-                "Bar".into()
-            ])),
+            UnresolvedImport::new(
+                ExternalUnresolvedModulePath::new(vec!["Foo".into(), "Bar".into()]),
+                SourceInformation::dummy()
+            ),
+        );
+        assert_eq!(
+            import()
+                .parse(stream("import Baz \"Foo/Bar\"", ""))
+                .unwrap()
+                .0,
+            UnresolvedImport::with_alias_and_names(
+                ExternalUnresolvedModulePath::new(vec!["Foo".into(), "Bar".into()]),
+                Some("Baz".into()),
+                vec![],
+                SourceInformation::dummy()
+            ),
+        );
+        assert_eq!(
+            import()
+                .parse(stream("import \"/Foo/Bar\" { baz, Qux }", ""))
+                .unwrap()
+                .0,
+            UnresolvedImport::with_alias_and_names(
+                InternalUnresolvedModulePath::new(vec!["Foo".into(), "Bar".into()]),
+                None,
+                vec!["baz".into(), "Qux".into()],
+                SourceInformation::dummy()
+            ),
+        );
+        assert_eq!(
+            import()
+                .parse(stream("import Baz \"/Foo/Bar\" { baz }", ""))
+                .unwrap()
+                .0,
+            UnresolvedImport::with_alias_and_names(
+                InternalUnresolvedModulePath::new(vec!["Foo".into(), "Bar".into()]),
+                Some("Baz".into()),
+                vec!["baz".into()],
+                SourceInformation::dummy()
+            ),
         );
     }
 
@@ -1161,7 +1433,10 @@ mod tests {
                 .parse(stream("export foreign { foo }", ""))
                 .unwrap()
                 .0,
-            ExportForeign::new(vec!["foo".into()].into_iter().collect()),
+            ExportForeign::new(
+                vec!["foo".into()].into_iter().collect(),
+                SourceInformation::dummy()
+            ),
         );
     }
 
@@ -1330,6 +1605,7 @@ mod tests {
                 TypeDefinition::new(
                     "Foo",
                     types::Record::new("Foo", Default::default(), SourceInformation::dummy()),
+                    SourceInformation::dummy(),
                 ),
             ),
             (
@@ -1344,6 +1620,7 @@ mod tests {
                         )],
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
                 ),
             ),
             (
@@ -1358,6 +1635,7 @@ mod tests {
                         )],
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
                 ),
             ),
             (
@@ -1378,6 +1656,7 @@ mod tests {
                         ],
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
                 ),
             ),
             (
@@ -1398,29 +1677,124 @@ mod tests {
                         ],
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
                 ),
             ),
             (
                 "type Foo = Boolean | None",
                 TypeDefinition::new(
                     "Foo",
-                    types::Union::new(
-                        vec![
-                            types::Boolean::new(SourceInformation::dummy()).into(),
-                            types::None::new(SourceInformation::dummy()).into(),
-                        ]
-                        .into_iter()
-                        .collect(),
-                        SourceInformation::dummy(),
+                    types::Union::new(
+                        vec![
+                            types::Boolean::new(SourceInformation::dummy()).into(),
+                            types::None::new(SourceInformation::dummy()).into(),
+                        ]
+                        .into_iter()
+                        .collect(),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+            ),
+            (
+                "type Foo a b { foo : a, bar : b }",
+                TypeDefinition::with_type_parameters(
+                    "Foo",
+                    vec!["a".into(), "b".into()],
+                    types::Record::new(
+                        "Foo",
+                        vec![
+                            types::RecordElement::new(
+                                "foo",
+                                types::Parameter::new("a", SourceInformation::dummy()),
+                            ),
+                            types::RecordElement::new(
+                                "bar",
+                                types::Parameter::new("b", SourceInformation::dummy()),
+                            ),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ),
+            ),
+            (
+                "type Foo\nfoo = 42",
+                TypeDefinition::new(
+                    "Foo",
+                    types::Record::new("Foo", Default::default(), SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
+            ),
+        ] {
+            assert_eq!(
+                &type_definition().parse(stream(source, "")).unwrap().0,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn parse_application_followed_by_definition() {
+        assert_eq!(
+            module()
+                .parse(stream("x : Number\nx = f 42\n\ny : Number\ny = 42", ""))
+                .unwrap()
+                .0,
+            UnresolvedModule::from_definitions(vec![
+                VariableDefinition::new(
+                    "x",
+                    Application::new(
+                        Variable::new("f", SourceInformation::dummy()),
+                        Number::new(42.0, SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into(),
+                VariableDefinition::new(
+                    "y",
+                    Number::new(42.0, SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_type_application_followed_by_definition() {
+        assert_eq!(
+            module()
+                .parse(stream("type Foo a = Bar a\n\nx : Number\nx = 42", ""))
+                .unwrap()
+                .0,
+            UnresolvedModule::new(
+                Export::new(Default::default(), SourceInformation::dummy()),
+                ExportForeign::new(Default::default(), SourceInformation::dummy()),
+                vec![],
+                vec![],
+                vec![TypeDefinition::with_type_parameters(
+                    "Foo",
+                    vec!["a".into()],
+                    types::Reference::with_arguments(
+                        "Bar",
+                        vec![types::Parameter::new("a", SourceInformation::dummy()).into()],
+                        SourceInformation::dummy()
                     ),
-                ),
-            ),
-        ] {
-            assert_eq!(
-                &type_definition().parse(stream(source, "")).unwrap().0,
-                expected
-            );
-        }
+                    SourceInformation::dummy(),
+                )],
+                vec![VariableDefinition::new(
+                    "x",
+                    Number::new(42.0, SourceInformation::dummy()),
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()]
+            )
+        );
     }
 
     #[test]
@@ -1428,7 +1802,11 @@ mod tests {
         for (source, expected) in &[
             (
                 "type Foo = Number",
-                TypeDefinition::new("Foo", types::Number::new(SourceInformation::dummy())),
+                TypeDefinition::new(
+                    "Foo",
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ),
             ),
             (
                 "type Foo = Number | None",
@@ -1443,6 +1821,19 @@ mod tests {
                         .collect(),
                         SourceInformation::dummy(),
                     ),
+                    SourceInformation::dummy(),
+                ),
+            ),
+            (
+                "type Foo a = List a",
+                TypeDefinition::with_type_parameters(
+                    "Foo",
+                    vec!["a".into()],
+                    types::List::new(
+                        types::Parameter::new("a", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
                 ),
             ),
         ] {
@@ -1617,6 +2008,96 @@ mod tests {
             );
         }
 
+        #[test]
+        fn parse_parameter_type() {
+            assert_eq!(
+                type_().parse(stream("a", "")).unwrap().0,
+                types::Parameter::new("a", SourceInformation::dummy()).into()
+            );
+            assert_eq!(
+                type_().parse(stream("a -> b", "")).unwrap().0,
+                types::Function::new(
+                    types::Parameter::new("a", SourceInformation::dummy()),
+                    types::Parameter::new("b", SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+            assert_eq!(
+                type_().parse(stream("List a", "")).unwrap().0,
+                types::List::new(
+                    types::Parameter::new("a", SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+        }
+
+        #[test]
+        fn parse_reference_type_application() {
+            assert_eq!(
+                type_().parse(stream("Foo Number", "")).unwrap().0,
+                types::Reference::with_arguments(
+                    "Foo",
+                    vec![types::Number::new(SourceInformation::dummy()).into()],
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+            assert_eq!(
+                type_().parse(stream("Foo a (List b)", "")).unwrap().0,
+                types::Reference::with_arguments(
+                    "Foo",
+                    vec![
+                        types::Parameter::new("a", SourceInformation::dummy()).into(),
+                        types::List::new(
+                            types::Parameter::new("b", SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        )
+                        .into()
+                    ],
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+            assert_eq!(
+                type_()
+                    .parse(stream("Foo a -> Foo b | None", ""))
+                    .unwrap()
+                    .0,
+                types::Function::new(
+                    types::Reference::with_arguments(
+                        "Foo",
+                        vec![types::Parameter::new("a", SourceInformation::dummy()).into()],
+                        SourceInformation::dummy()
+                    ),
+                    types::Union::new(
+                        vec![
+                            types::Reference::with_arguments(
+                                "Foo",
+                                vec![types::Parameter::new("b", SourceInformation::dummy()).into()],
+                                SourceInformation::dummy()
+                            )
+                            .into(),
+                            types::None::new(SourceInformation::dummy()).into()
+                        ],
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+            assert_eq!(
+                type_().parse(stream("Foo a\nfoo", "")).unwrap().0,
+                types::Reference::with_arguments(
+                    "Foo",
+                    vec![types::Parameter::new("a", SourceInformation::dummy()).into()],
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+        }
+
         #[test]
         fn parse_list_type() {
             assert_eq!(
@@ -1682,6 +2163,67 @@ mod tests {
                 .into()
             );
         }
+
+        #[test]
+        fn parse_array_type() {
+            assert_eq!(
+                type_().parse(stream("Array Number", "")).unwrap().0,
+                types::Array::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+
+            assert_eq!(
+                type_().parse(stream("Array (List a)", "")).unwrap().0,
+                types::Array::new(
+                    types::List::new(
+                        types::Parameter::new("a", SourceInformation::dummy()),
+                        SourceInformation::dummy()
+                    ),
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+        }
+
+        #[test]
+        fn parse_map_type() {
+            assert_eq!(
+                type_().parse(stream("{Number : String}", "")).unwrap().0,
+                types::Map::new(
+                    types::Number::new(SourceInformation::dummy()),
+                    types::EinString::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+
+            assert_eq!(
+                type_()
+                    .parse(stream("{ Foo a : List b } -> None", ""))
+                    .unwrap()
+                    .0,
+                types::Function::new(
+                    types::Map::new(
+                        types::Reference::with_arguments(
+                            "Foo",
+                            vec![types::Parameter::new("a", SourceInformation::dummy()).into()],
+                            SourceInformation::dummy()
+                        ),
+                        types::List::new(
+                            types::Parameter::new("b", SourceInformation::dummy()),
+                            SourceInformation::dummy()
+                        ),
+                        SourceInformation::dummy()
+                    ),
+                    types::None::new(SourceInformation::dummy()),
+                    SourceInformation::dummy()
+                )
+                .into()
+            );
+        }
     }
 
     mod expressions {
@@ -1795,6 +2337,51 @@ mod tests {
             );
         }
 
+        #[test]
+        fn parse_lambda() {
+            assert_eq!(
+                lambda().parse(stream("\\x -> x", "")).unwrap().0,
+                Lambda::new(
+                    vec!["x".into()],
+                    Variable::new("x", SourceInformation::dummy()),
+                    types::Unknown::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+            );
+            assert_eq!(
+                lambda().parse(stream("\\x y -> x + y", "")).unwrap().0,
+                Lambda::new(
+                    vec!["x".into(), "y".into()],
+                    ArithmeticOperation::new(
+                        ArithmeticOperator::Add,
+                        Variable::new("x", SourceInformation::dummy()),
+                        Variable::new("y", SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    types::Unknown::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+            );
+            assert_eq!(
+                expression().parse(stream("f (\\x -> x) xs", "")).unwrap().0,
+                Application::new(
+                    Application::new(
+                        Variable::new("f", SourceInformation::dummy()),
+                        Lambda::new(
+                            vec!["x".into()],
+                            Variable::new("x", SourceInformation::dummy()),
+                            types::Unknown::new(SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        ),
+                        SourceInformation::dummy(),
+                    ),
+                    Variable::new("xs", SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                )
+                .into()
+            );
+        }
+
         #[test]
         fn parse_if() {
             assert_eq!(
@@ -2870,6 +3457,86 @@ mod tests {
             }
         }
 
+        #[test]
+        fn parse_array() {
+            for (source, target) in vec![
+                ("Array[]", Array::new(vec![], SourceInformation::dummy())),
+                (
+                    "Array[ 1, 2, ]",
+                    Array::new(
+                        vec![
+                            Number::new(1.0, SourceInformation::dummy()).into(),
+                            Number::new(2.0, SourceInformation::dummy()).into(),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                ),
+                (
+                    "Array[ f x ]",
+                    Array::new(
+                        vec![Application::new(
+                            Variable::new("f", SourceInformation::dummy()),
+                            Variable::new("x", SourceInformation::dummy()),
+                            SourceInformation::dummy(),
+                        )
+                        .into()],
+                        SourceInformation::dummy(),
+                    ),
+                ),
+            ] {
+                assert_eq!(
+                    expression().parse(stream(source, "")).unwrap().0,
+                    target.into()
+                );
+            }
+        }
+
+        #[test]
+        fn parse_map() {
+            for (source, target) in vec![
+                ("{}", Map::new(vec![], SourceInformation::dummy())),
+                (
+                    "{ 1 : \"foo\" }",
+                    Map::new(
+                        vec![MapElement::new(
+                            Number::new(1.0, SourceInformation::dummy()),
+                            EinString::new("foo", SourceInformation::dummy()),
+                        )],
+                        SourceInformation::dummy(),
+                    ),
+                ),
+                (
+                    "{ x : y, f x : g y, }",
+                    Map::new(
+                        vec![
+                            MapElement::new(
+                                Variable::new("x", SourceInformation::dummy()),
+                                Variable::new("y", SourceInformation::dummy()),
+                            ),
+                            MapElement::new(
+                                Application::new(
+                                    Variable::new("f", SourceInformation::dummy()),
+                                    Variable::new("x", SourceInformation::dummy()),
+                                    SourceInformation::dummy(),
+                                ),
+                                Application::new(
+                                    Variable::new("g", SourceInformation::dummy()),
+                                    Variable::new("y", SourceInformation::dummy()),
+                                    SourceInformation::dummy(),
+                                ),
+                            ),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                ),
+            ] {
+                assert_eq!(
+                    expression().parse(stream(source, "")).unwrap().0,
+                    target.into()
+                );
+            }
+        }
+
         #[test]
         fn parse_list() {
             for (source, target) in vec![
@@ -3046,6 +3713,14 @@ mod tests {
             .is_ok());
     }
 
+    #[test]
+    fn parse_span() {
+        let (source_information, _) = spanned(keyword("foo")).parse(stream(" foo", "")).unwrap().0;
+
+        assert_eq!(source_information.location().column_number(), 2);
+        assert_eq!(source_information.end_location().column_number(), 5);
+    }
+
     #[test]
     fn parse_blank() {
         for source in &[
@@ -3093,4 +3768,25 @@ mod tests {
         assert!(comment().parse(stream("#\n", "")).is_ok());
         assert!(comment().parse(stream("#x\n", "")).is_ok());
     }
+
+    #[test]
+    fn parse_comments() {
+        let comments = comments()
+            .parse(stream("# foo\nx = \"#\" # bar", ""))
+            .unwrap()
+            .0;
+
+        assert_eq!(
+            comments,
+            vec![
+                Comment::new(" foo", SourceInformation::dummy()),
+                Comment::new(" bar", SourceInformation::dummy()),
+            ]
+        );
+        assert_eq!(comments[1].source_information().location().line_number(), 2);
+        assert_eq!(
+            comments[1].source_information().location().column_number(),
+            9
+        );
+    }
 }
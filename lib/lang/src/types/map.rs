@@ -0,0 +1,48 @@
+use super::Type;
+use crate::debug::SourceInformation;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Map {
+    key: Arc<Type>,
+    value: Arc<Type>,
+    source_information: Arc<SourceInformation>,
+}
+
+impl Map {
+    pub fn new(
+        key: impl Into<Type>,
+        value: impl Into<Type>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            key: Arc::new(key.into()),
+            value: Arc::new(value.into()),
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn key(&self) -> &Type {
+        &self.key
+    }
+
+    pub fn value(&self) -> &Type {
+        &self.value
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::new(
+            self.key.transform_types(transform)?,
+            self.value.transform_types(transform)?,
+            self.source_information.clone(),
+        ))
+    }
+}
@@ -1,9 +1,12 @@
 mod any;
+mod array;
 mod boolean;
 mod function;
 mod list;
+mod map;
 mod none;
 mod number;
+mod parameter;
 mod record;
 mod record_element;
 mod reference;
@@ -14,11 +17,14 @@ mod unknown;
 mod variable;
 
 pub use any::*;
+pub use array::*;
 pub use boolean::*;
 pub use function::*;
 pub use list::*;
+pub use map::*;
 pub use none::*;
 pub use number::*;
+pub use parameter::*;
 pub use record::*;
 pub use record_element::*;
 pub use reference::*;
@@ -0,0 +1,29 @@
+use crate::debug::SourceInformation;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Parameter {
+    name: String,
+    source_information: Arc<SourceInformation>,
+}
+
+impl Parameter {
+    pub fn new(
+        name: impl Into<String>,
+        source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            source_information: source_information.into(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn source_information(&self) -> &Arc<SourceInformation> {
+        &self.source_information
+    }
+}
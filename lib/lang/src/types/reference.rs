@@ -1,3 +1,4 @@
+use super::Type;
 use crate::debug::SourceInformation;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -5,6 +6,7 @@ use std::sync::Arc;
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Reference {
     name: String,
+    arguments: Vec<Type>,
     source_information: Arc<SourceInformation>,
 }
 
@@ -12,9 +14,18 @@ impl Reference {
     pub fn new(
         name: impl Into<String>,
         source_information: impl Into<Arc<SourceInformation>>,
+    ) -> Self {
+        Self::with_arguments(name, vec![], source_information)
+    }
+
+    pub fn with_arguments(
+        name: impl Into<String>,
+        arguments: Vec<Type>,
+        source_information: impl Into<Arc<SourceInformation>>,
     ) -> Self {
         Self {
             name: name.into(),
+            arguments,
             source_information: source_information.into(),
         }
     }
@@ -23,7 +34,25 @@ impl Reference {
         &self.name
     }
 
+    pub fn arguments(&self) -> &[Type] {
+        &self.arguments
+    }
+
     pub fn source_information(&self) -> &Arc<SourceInformation> {
         &self.source_information
     }
+
+    pub fn transform_types<E>(
+        &self,
+        transform: &mut impl FnMut(&Type) -> Result<Type, E>,
+    ) -> Result<Self, E> {
+        Ok(Self::with_arguments(
+            self.name.clone(),
+            self.arguments
+                .iter()
+                .map(|type_| type_.transform_types(transform))
+                .collect::<Result<_, _>>()?,
+            self.source_information.clone(),
+        ))
+    }
 }
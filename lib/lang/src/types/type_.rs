@@ -1,20 +1,23 @@
 use super::{
-    any::Any, boolean::Boolean, function::Function, list::List, none::None, number::Number,
-    record::Record, reference::Reference, string::EinString, union::Union, unknown::Unknown,
-    variable::Variable,
+    any::Any, array::Array, boolean::Boolean, function::Function, list::List, map::Map, none::None,
+    number::Number, parameter::Parameter, record::Record, reference::Reference, string::EinString,
+    union::Union, unknown::Unknown, variable::Variable,
 };
 use crate::debug::SourceInformation;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, fmt::Display, sync::Arc};
 
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub enum Type {
     Any(Any),
+    Array(Array),
     Boolean(Boolean),
     Function(Function),
     List(List),
+    Map(Map),
     None(None),
     Number(Number),
+    Parameter(Parameter),
     Record(Record),
     Reference(Reference),
     String(EinString),
@@ -27,11 +30,14 @@ impl Type {
     pub fn source_information(&self) -> &Arc<SourceInformation> {
         match self {
             Self::Any(any) => any.source_information(),
+            Self::Array(array) => array.source_information(),
             Self::Boolean(boolean) => boolean.source_information(),
             Self::Function(function) => function.source_information(),
             Self::List(list) => list.source_information(),
+            Self::Map(map) => map.source_information(),
             Self::None(none) => none.source_information(),
             Self::Number(number) => number.source_information(),
+            Self::Parameter(parameter) => parameter.source_information(),
             Self::Record(record) => record.source_information(),
             Self::Reference(reference) => reference.source_information(),
             Self::String(string) => string.source_information(),
@@ -58,20 +64,53 @@ impl Type {
         .unwrap()
     }
 
+    pub fn substitute_parameters(&self, substitutions: &HashMap<String, Type>) -> Self {
+        self.transform_types(&mut |type_| -> Result<_, ()> {
+            Ok(match type_ {
+                Self::Parameter(parameter) => match substitutions.get(parameter.name()) {
+                    Some(type_) => type_.clone(),
+                    None => type_.clone(),
+                },
+                _ => type_.clone(),
+            })
+        })
+        .unwrap()
+    }
+
+    pub fn parameters(&self) -> Vec<Parameter> {
+        let mut parameters = vec![];
+
+        self.transform_types(&mut |type_| -> Result<_, ()> {
+            if let Self::Parameter(parameter) = type_ {
+                if !parameters.contains(parameter) {
+                    parameters.push(parameter.clone());
+                }
+            }
+
+            Ok(type_.clone())
+        })
+        .unwrap();
+
+        parameters
+    }
+
     pub fn transform_types<E>(
         &self,
         transform: &mut impl FnMut(&Self) -> Result<Self, E>,
     ) -> Result<Self, E> {
         let type_ = match self {
+            Self::Array(array) => array.transform_types(transform)?.into(),
             Self::Function(function) => function.transform_types(transform)?.into(),
             Self::List(list) => list.transform_types(transform)?.into(),
+            Self::Map(map) => map.transform_types(transform)?.into(),
             Self::Record(record) => record.transform_types(transform)?.into(),
+            Self::Reference(reference) => reference.transform_types(transform)?.into(),
             Self::Union(union) => union.transform_types(transform)?.into(),
             Self::Any(_)
             | Self::Boolean(_)
             | Self::None(_)
             | Self::Number(_)
-            | Self::Reference(_)
+            | Self::Parameter(_)
             | Self::String(_)
             | Self::Unknown(_)
             | Self::Variable(_) => self.clone(),
@@ -81,12 +120,94 @@ impl Type {
     }
 }
 
+impl Display for Type {
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Any(_) => write!(formatter, "Any"),
+            Self::Array(array) => write!(formatter, "Array {}", format_atomic(array.element())),
+            Self::Boolean(_) => write!(formatter, "Boolean"),
+            Self::Function(function) => write!(
+                formatter,
+                "{} -> {}",
+                match function.argument() {
+                    Self::Function(_) => format!("({})", function.argument()),
+                    type_ => format!("{}", type_),
+                },
+                function.result()
+            ),
+            Self::List(list) => write!(formatter, "List {}", format_atomic(list.element())),
+            Self::Map(map) => write!(
+                formatter,
+                "{{{} : {}}}",
+                match map.key() {
+                    Self::Reference(reference) if !reference.arguments().is_empty() => {
+                        format!("({})", map.key())
+                    }
+                    type_ => format!("{}", type_),
+                },
+                map.value()
+            ),
+            Self::None(_) => write!(formatter, "None"),
+            Self::Number(_) => write!(formatter, "Number"),
+            Self::Parameter(parameter) => write!(formatter, "{}", parameter.name()),
+            Self::Record(record) => write!(formatter, "{}", format_name(record.name())),
+            Self::Reference(reference) => {
+                write!(formatter, "{}", format_name(reference.name()))?;
+
+                for argument in reference.arguments() {
+                    write!(formatter, " {}", format_atomic(argument))?;
+                }
+
+                Ok(())
+            }
+            Self::String(_) => write!(formatter, "String"),
+            Self::Union(union) => write!(
+                formatter,
+                "{}",
+                union
+                    .types()
+                    .iter()
+                    .map(|type_| match type_ {
+                        Self::Function(_) | Self::Union(_) => format!("({})", type_),
+                        _ => format!("{}", type_),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            ),
+            Self::Unknown(_) | Self::Variable(_) => write!(formatter, "<unknown>"),
+        }
+    }
+}
+
+// Names are shown without qualification by module paths.
+fn format_name(name: &str) -> &str {
+    name.rsplit('.').next().unwrap_or(name)
+}
+
+fn format_atomic(type_: &Type) -> String {
+    match type_ {
+        Type::Array(_) | Type::Function(_) | Type::List(_) | Type::Union(_) => {
+            format!("({})", type_)
+        }
+        Type::Reference(reference) if !reference.arguments().is_empty() => {
+            format!("({})", type_)
+        }
+        _ => format!("{}", type_),
+    }
+}
+
 impl From<Any> for Type {
     fn from(any: Any) -> Self {
         Self::Any(any)
     }
 }
 
+impl From<Array> for Type {
+    fn from(array: Array) -> Self {
+        Self::Array(array)
+    }
+}
+
 impl From<Boolean> for Type {
     fn from(boolean: Boolean) -> Self {
         Self::Boolean(boolean)
@@ -111,6 +232,12 @@ impl From<List> for Type {
     }
 }
 
+impl From<Map> for Type {
+    fn from(map: Map) -> Self {
+        Self::Map(map)
+    }
+}
+
 impl From<None> for Type {
     fn from(none: None) -> Self {
         Self::None(none)
@@ -123,6 +250,12 @@ impl From<Number> for Type {
     }
 }
 
+impl From<Parameter> for Type {
+    fn from(parameter: Parameter) -> Self {
+        Self::Parameter(parameter)
+    }
+}
+
 impl From<Record> for Type {
     fn from(record: Record) -> Self {
         Self::Record(record)
@@ -152,3 +285,80 @@ impl From<Variable> for Type {
         Self::Variable(variable)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_function() {
+        assert_eq!(
+            format!(
+                "{}",
+                Type::from(Function::new(
+                    Function::new(
+                        Number::new(SourceInformation::dummy()),
+                        Number::new(SourceInformation::dummy()),
+                        SourceInformation::dummy(),
+                    ),
+                    Union::new(
+                        vec![
+                            Number::new(SourceInformation::dummy()).into(),
+                            None::new(SourceInformation::dummy()).into(),
+                        ],
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ))
+            ),
+            "(Number -> Number) -> None | Number"
+        );
+    }
+
+    #[test]
+    fn display_list() {
+        assert_eq!(
+            format!(
+                "{}",
+                Type::from(List::new(
+                    Reference::with_arguments(
+                        "Foo",
+                        vec![Parameter::new("a", SourceInformation::dummy()).into()],
+                        SourceInformation::dummy(),
+                    ),
+                    SourceInformation::dummy(),
+                ))
+            ),
+            "List (Foo a)"
+        );
+    }
+
+    #[test]
+    fn display_map() {
+        assert_eq!(
+            format!(
+                "{}",
+                Type::from(Map::new(
+                    EinString::new(SourceInformation::dummy()),
+                    Boolean::new(SourceInformation::dummy()),
+                    SourceInformation::dummy(),
+                ))
+            ),
+            "{String : Boolean}"
+        );
+    }
+
+    #[test]
+    fn display_qualified_reference() {
+        assert_eq!(
+            format!(
+                "{}",
+                Type::from(Reference::new(
+                    "github.com/foo/bar(1.0.0).Baz.Blah",
+                    SourceInformation::dummy(),
+                ))
+            ),
+            "Blah"
+        );
+    }
+}
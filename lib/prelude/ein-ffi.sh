@@ -3,5 +3,11 @@
 set -e
 
 cd ffi
-cargo build --release
-echo ffi/target/release/libprelude.a
+
+if [ -n "$CARGO_BUILD_TARGET" ]; then
+  cargo build --release --target $CARGO_BUILD_TARGET
+  echo ffi/target/$CARGO_BUILD_TARGET/release/libprelude.a
+else
+  cargo build --release
+  echo ffi/target/release/libprelude.a
+fi
@@ -1,12 +1,120 @@
+#[cfg(target_arch = "wasm32")]
+mod runtime;
+
 #[no_mangle]
 extern "C" fn _ein_equal_strings(one: ffi::EinString, other: ffi::EinString) -> ffi::Boolean {
     (one.as_slice() == other.as_slice()).into()
 }
 
+const FNV_OFFSET_BASIS: u32 = 0x811c_9dc5;
+const FNV_PRIME: u32 = 0x0100_0193;
+
+#[no_mangle]
+extern "C" fn _ein_hash_number(number: ffi::Number) -> ffi::Number {
+    let number = f64::from(number);
+    // Positive and negative zeros are equal.
+    let bits = if number == 0.0 { 0 } else { number.to_bits() };
+
+    hash_bytes(&bits.to_le_bytes())
+}
+
+#[no_mangle]
+extern "C" fn _ein_hash_string(string: ffi::EinString) -> ffi::Number {
+    hash_bytes(string.as_slice())
+}
+
+#[no_mangle]
+extern "C" fn _ein_combine_hashes(one: ffi::Number, other: ffi::Number) -> ffi::Number {
+    ((f64::from(one) as u32)
+        .wrapping_mul(FNV_PRIME)
+        .wrapping_add(f64::from(other) as u32) as f64)
+        .into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_hash_chunk(hash: ffi::Number, level: ffi::Number) -> ffi::Number {
+    get_chunk(hash, level)
+}
+
+// Tries in the prelude have 4 children at each level.
+fn get_chunk(number: ffi::Number, level: ffi::Number) -> ffi::Number {
+    (((f64::from(number) as u32)
+        .checked_shr(2 * f64::from(level) as u32)
+        .unwrap_or(0)
+        & 0b11) as f64)
+        .into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_empty_array(_: ffi::None) -> ffi::Any {
+    ffi::Array::empty().into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_array_size(array: ffi::Any) -> ffi::Number {
+    (ffi::Array::from(array).len() as f64).into()
+}
+
+// Default values are returned for invalid indices, such as NaN, so that no
+// panic unwinds across FFI boundaries.
+#[no_mangle]
+extern "C" fn _ein_array_get(array: ffi::Any, index: ffi::Number, default: ffi::Any) -> ffi::Any {
+    ffi::Array::from(array).get(index).unwrap_or(default)
+}
+
+#[no_mangle]
+extern "C" fn _ein_array_set(array: ffi::Any, index: ffi::Number, element: ffi::Any) -> ffi::Any {
+    ffi::Array::from(array).set(index, element).into()
+}
+
+#[no_mangle]
+extern "C" fn _ein_array_push(array: ffi::Any, element: ffi::Any) -> ffi::Any {
+    ffi::Array::from(array).push(element).into()
+}
+
+fn hash_bytes(bytes: &[u8]) -> ffi::Number {
+    (bytes.iter().fold(FNV_OFFSET_BASIS, |hash, &byte| {
+        (hash ^ byte as u32).wrapping_mul(FNV_PRIME)
+    }) as f64)
+        .into()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn hash_zeros() {
+        assert_eq!(
+            _ein_hash_number(0.0.into()),
+            _ein_hash_number((-0.0).into())
+        );
+    }
+
+    #[test]
+    fn hash_different_numbers() {
+        assert_ne!(_ein_hash_number(1.0.into()), _ein_hash_number(2.0.into()));
+    }
+
+    #[test]
+    fn hash_strings() {
+        assert_eq!(
+            _ein_hash_string("foo".into()),
+            _ein_hash_string("foo".into())
+        );
+        assert_ne!(
+            _ein_hash_string("foo".into()),
+            _ein_hash_string("bar".into())
+        );
+    }
+
+    #[test]
+    fn hash_chunks() {
+        assert_eq!(_ein_hash_chunk(14.0.into(), 0.0.into()), 2.0.into());
+        assert_eq!(_ein_hash_chunk(14.0.into(), 1.0.into()), 3.0.into());
+        assert_eq!(_ein_hash_chunk(14.0.into(), 16.0.into()), 0.0.into());
+    }
+
     #[test]
     fn equal_empty_strings() {
         let string = ffi::EinString::empty();
@@ -48,4 +156,30 @@ mod tests {
             false.into()
         );
     }
+
+    #[test]
+    fn push_to_array() {
+        let array = _ein_array_push(
+            _ein_empty_array(ffi::None::new()),
+            _ein_empty_array(ffi::None::new()),
+        );
+
+        assert_eq!(_ein_array_size(array.clone()), 1.0.into());
+        assert_eq!(ffi::Array::from(array).len(), 1);
+    }
+
+    #[test]
+    fn get_from_array_with_invalid_index() {
+        let array = _ein_array_push(
+            _ein_empty_array(ffi::None::new()),
+            _ein_empty_array(ffi::None::new()),
+        );
+
+        for index in [0.0, 1.5, 2.0, f64::NAN] {
+            assert_eq!(
+                _ein_array_get(array.clone(), index.into(), array.clone()),
+                array
+            );
+        }
+    }
 }
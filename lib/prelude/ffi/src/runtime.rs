@@ -0,0 +1,24 @@
+// Allocation functions of applications are provided by system packages on
+// native targets. On WASI, they are forwarded to the C library of a sysroot
+// which applications are linked against.
+
+extern "C" {
+    fn malloc(size: usize) -> *mut u8;
+    fn realloc(pointer: *mut u8, size: usize) -> *mut u8;
+    fn free(pointer: *mut u8);
+}
+
+#[no_mangle]
+unsafe extern "C" fn _ein_malloc(size: usize) -> *mut u8 {
+    malloc(size)
+}
+
+#[no_mangle]
+unsafe extern "C" fn _ein_realloc(pointer: *mut u8, size: usize) -> *mut u8 {
+    realloc(pointer, size)
+}
+
+#[no_mangle]
+unsafe extern "C" fn _ein_free(pointer: *mut u8) {
+    free(pointer)
+}